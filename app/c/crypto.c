@@ -0,0 +1,33 @@
+/*******************************************************************************
+*   (c) 2018-2021 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+#include "rslib.h"
+#include <os.h>
+#include <cx.h>
+
+bool crypto_verify_secp256k1(const uint8_t *pubkey, uint16_t pubkey_len,
+                             const uint8_t *hash, uint16_t hash_len,
+                             const uint8_t *signature, uint16_t signature_len) {
+    cx_ecfp_public_key_t public_key;
+    cx_ecfp_init_public_key(CX_CURVE_256K1, pubkey, pubkey_len, &public_key);
+
+    return cx_ecdsa_verify(&public_key, CX_LAST, CX_SHA256,
+                           hash, hash_len,
+                           signature, signature_len) == 1;
+}
+
+uint32_t crypto_random_u32() {
+    return cx_rng_u32();
+}
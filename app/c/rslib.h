@@ -1,5 +1,6 @@
 #pragma once
 
+#include <stdbool.h>
 #include <stdint.h>
 #include "coin.h"
 
@@ -7,6 +8,12 @@ void check_canary();
 
 void zemu_log(const char *buf);
 
+bool crypto_verify_secp256k1(const uint8_t *pubkey, uint16_t pubkey_len,
+                             const uint8_t *hash, uint16_t hash_len,
+                             const uint8_t *signature, uint16_t signature_len);
+
+uint32_t crypto_random_u32();
+
 void
 rs_handle_apdu(volatile uint32_t *flags, volatile uint32_t *tx, uint32_t rx, const uint8_t *buffer, uint16_t bufferLen);
 
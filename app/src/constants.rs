@@ -26,6 +26,15 @@ pub enum ApduError {
     ApduCodeConditionsNotSatisfied = 0x6985,
     CommandNotAllowed = 0x6986,
     BadKeyExample = 0x6A81, //unused
+    PathTooDeep = 0x6A8A,
+    InvalidPathPrefix = 0x6A8B,
+    DataTruncated = 0x6A8C,
+    DataInvalidLength = 0x6A8D,
+    DataUnsupported = 0x6A8E,
+    RecipientMismatch = 0x6A8F,
+    TxTooLarge = 0x6A84,
+    TrailingData = 0x6A85,
+    BlockedAddress = 0x6A86,
     InvalidP1P2 = 0x6B00,
     InsNotSupported = 0x6D00,
     ClaNotSupported = 0x6E00,
@@ -75,6 +84,15 @@ impl std::convert::TryFrom<u16> for ApduError {
             0x6985 => Ok(Self::ApduCodeConditionsNotSatisfied),
             0x6986 => Ok(Self::CommandNotAllowed),
             0x6A81 => Ok(Self::BadKeyExample),
+            0x6A8A => Ok(Self::PathTooDeep),
+            0x6A8B => Ok(Self::InvalidPathPrefix),
+            0x6A8C => Ok(Self::DataTruncated),
+            0x6A8D => Ok(Self::DataInvalidLength),
+            0x6A8E => Ok(Self::DataUnsupported),
+            0x6A8F => Ok(Self::RecipientMismatch),
+            0x6A84 => Ok(Self::TxTooLarge),
+            0x6A85 => Ok(Self::TrailingData),
+            0x6A86 => Ok(Self::BlockedAddress),
             0x6B00 => Ok(Self::InvalidP1P2),
             0x6D00 => Ok(Self::InsNotSupported),
             0x6E00 => Ok(Self::ClaNotSupported),
@@ -96,6 +114,10 @@ pub const APDU_INDEX_LEN: usize = 4;
 pub const APDU_MIN_LENGTH: u32 = 5;
 
 pub const SECP256_SIGN_BUFFER_MIN_LENGTH: usize = 100;
+// a DER signature: the sequence header, then R and S
+// of up to 33 bytes each, with their own tag and length
+pub const MAX_DER_SIGNATURE_LEN: usize = 2 + 2 * (2 + 33);
+const _: () = assert!(SECP256_SIGN_BUFFER_MIN_LENGTH >= MAX_DER_SIGNATURE_LEN);
 
 //Constants taken from obsidian app
 pub const CHAIN_ID_LEN: usize = 32;
@@ -108,6 +130,103 @@ pub const FIRST_MESSAGE: u8 = 0x01;
 pub const NEXT_MESSAGE: u8 = 0x03;
 pub const LAST_MESSAGE: u8 = 0x02;
 
+/// Public key of the provider trusted to sign NFT information,
+/// none has been published yet so the `erc721` feature is left
+/// out of the release builds
+#[cfg(not(test))]
+pub const NFT_INFO_PUBLIC_KEY: Option<[u8; 65]> = None;
+/// Stand-in for the NFT information provider, to sign test collections
+#[cfg(test)]
+pub const NFT_INFO_PUBLIC_KEY: Option<[u8; 65]> = Some([
+    0x04, 0xd3, 0xcc, 0x18, 0x1f, 0x2c, 0x39, 0xfa, 0xbd, 0x6e, 0x1c, 0x34, 0xc6, 0xcf, 0x68, 0x24,
+    0x19, 0x9b, 0xc8, 0x71, 0xa5, 0x6d, 0x32, 0xdc, 0x0f, 0x63, 0xc5, 0x28, 0x4c, 0x5b, 0xa6, 0x80,
+    0xac, 0x6c, 0x26, 0xbb, 0xd0, 0x15, 0x46, 0x5a, 0x9d, 0xb5, 0x21, 0xda, 0xd2, 0x82, 0x4b, 0x50,
+    0x8b, 0xf4, 0x18, 0xef, 0xea, 0x3a, 0x59, 0xa3, 0x8a, 0xc4, 0x42, 0xf3, 0x3e, 0xe1, 0x6a, 0x5c,
+    0x4d,
+]);
+/// Key and signature algorithm ids expected in signed NFT information
+pub const NFT_INFO_KEY_ID: u8 = 1;
+pub const NFT_INFO_ALGORITHM_ID: u8 = 1;
+
+/// Ledger's trusted name key, signing domain names
+#[cfg(not(test))]
+pub const DOMAIN_INFO_PUBLIC_KEY: [u8; 65] = [
+    0x04, 0x6a, 0x94, 0xe7, 0xa4, 0x2c, 0xd0, 0xc3, 0x3f, 0xdf, 0x44, 0x0c, 0x8e, 0x2a, 0xb2, 0x54,
+    0x2c, 0xef, 0xbe, 0x5d, 0xb7, 0xaa, 0x0b, 0x93, 0xa9, 0xfc, 0x81, 0x4b, 0x9a, 0xcf, 0xa7, 0x5e,
+    0xb4, 0xe5, 0x3d, 0x6f, 0x00, 0x25, 0x94, 0xbd, 0xb6, 0x05, 0xd9, 0xb5, 0xbd, 0xa9, 0xfa, 0x4b,
+    0x4b, 0xf3, 0xa5, 0x49, 0x6f, 0xd3, 0x16, 0x4b, 0xae, 0xf5, 0xaf, 0xcf, 0x90, 0xe8, 0x40, 0x88,
+    0x71,
+];
+/// Stand-in for the trusted name key, to sign test domain names
+#[cfg(test)]
+pub const DOMAIN_INFO_PUBLIC_KEY: [u8; 65] = [
+    0x04, 0xc5, 0x21, 0xcf, 0xac, 0xe8, 0x52, 0xe2, 0x94, 0xe7, 0x2c, 0xee, 0xd8, 0xb0, 0x9a, 0xa4,
+    0x56, 0xdf, 0x1f, 0xcb, 0xb5, 0x76, 0xda, 0x0b, 0xfb, 0x0c, 0x04, 0xb9, 0x97, 0xf0, 0x7f, 0x7d,
+    0x0f, 0xfa, 0x82, 0xbc, 0x55, 0xc4, 0x3e, 0x7d, 0xeb, 0x92, 0xe6, 0x54, 0x42, 0x59, 0x9d, 0xc7,
+    0xc3, 0xa0, 0x0e, 0x23, 0x80, 0xd0, 0xdc, 0xe7, 0xd9, 0xee, 0x91, 0x97, 0x14, 0x17, 0xef, 0x5d,
+    0x11,
+];
+/// Key and signature algorithm ids expected in trusted names,
+/// app-ethereum gives one id to the production key and another to the test one
+#[cfg(not(test))]
+pub const DOMAIN_INFO_KEY_ID: u16 = 0x03;
+#[cfg(test)]
+pub const DOMAIN_INFO_KEY_ID: u16 = 0x00;
+pub const DOMAIN_INFO_ALGORITHM_ID: u8 = 0x01;
+
+/// Ledger's crypto asset list (CAL) key, signing ERC20 token information
+#[cfg(not(test))]
+pub const CAL_PUBLIC_KEY: [u8; 65] = [
+    0x04, 0x5e, 0x6c, 0x10, 0x20, 0xc1, 0x4d, 0xc4, 0x64, 0x42, 0xfe, 0x89, 0xf9, 0x7c, 0x0b, 0x68,
+    0xcd, 0xb1, 0x59, 0x76, 0xdc, 0x24, 0xf2, 0x4c, 0x31, 0x6e, 0x7b, 0x30, 0xfe, 0x4e, 0x8c, 0xc7,
+    0x6b, 0x14, 0x89, 0x15, 0x0c, 0x21, 0x51, 0x4e, 0xbf, 0x44, 0x0f, 0xf5, 0xde, 0xa5, 0x39, 0x3d,
+    0x83, 0xde, 0x53, 0x58, 0xcd, 0x09, 0x8f, 0xce, 0x8f, 0xd0, 0xf8, 0x1d, 0xaa, 0x94, 0x97, 0x91,
+    0x83,
+];
+/// Stand-in for the CAL key, to sign test token information
+#[cfg(test)]
+pub const CAL_PUBLIC_KEY: [u8; 65] = [
+    0x04, 0xf4, 0x2a, 0x95, 0xde, 0xfa, 0x85, 0xe2, 0xfd, 0x1d, 0x65, 0x6e, 0x5e, 0xf8, 0x1d, 0x91,
+    0x9a, 0xca, 0xf9, 0xc7, 0x7d, 0x47, 0x57, 0x6d, 0xa3, 0x7e, 0x94, 0x5b, 0xdb, 0x5c, 0xe1, 0x4a,
+    0xbb, 0xc9, 0x1d, 0xf5, 0x54, 0xf8, 0x0c, 0x08, 0xf5, 0x5e, 0xb4, 0xc1, 0xa4, 0xa0, 0xa4, 0x3e,
+    0xdd, 0x9f, 0x85, 0xbc, 0x87, 0x41, 0xa7, 0x7e, 0x09, 0x7b, 0x73, 0x2a, 0xa1, 0xaf, 0x8e, 0xc9,
+    0xf8,
+];
+
+/// Public key of the fleet operator trusted to sign address denylists,
+/// none has been configured so the `address-denylist` feature is left
+/// out of the release builds
+#[cfg(not(test))]
+pub const DENYLIST_PUBLIC_KEY: Option<[u8; 65]> = None;
+/// Stand-in for the denylist operator, to sign test denylists
+#[cfg(test)]
+pub const DENYLIST_PUBLIC_KEY: Option<[u8; 65]> = Some([
+    0x04, 0x76, 0x86, 0x10, 0x39, 0x88, 0xc5, 0x4f, 0x52, 0x8d, 0x97, 0xf9, 0x4d, 0xba, 0x4d, 0xf8,
+    0x98, 0x0b, 0x35, 0xba, 0x23, 0x9a, 0x8a, 0x8c, 0x45, 0x62, 0xcf, 0xf8, 0xf9, 0x50, 0x5e, 0xd2,
+    0xe7, 0xd7, 0x6f, 0x9c, 0x5b, 0xb3, 0xfe, 0x4f, 0x77, 0x48, 0xb0, 0x5d, 0x1a, 0x2e, 0x81, 0x7b,
+    0x4d, 0x28, 0x22, 0x62, 0x19, 0xd4, 0x2e, 0xa5, 0x4e, 0x33, 0x6a, 0xb4, 0x67, 0xb3, 0x2f, 0x1c,
+    0xd3,
+]);
+/// Leading byte of a signed denylist, so no other signed data passes for one
+pub const DENYLIST_TYPE: u8 = 0x01;
+
+/// Public key of the provider trusted to sign the native currency of EVM chains,
+/// none has been published yet so the `chain-config` feature is left
+/// out of the release builds
+#[cfg(not(test))]
+pub const CHAIN_CONFIG_PUBLIC_KEY: Option<[u8; 65]> = None;
+/// Stand-in for the chain configuration provider, to sign test configurations
+#[cfg(test)]
+pub const CHAIN_CONFIG_PUBLIC_KEY: Option<[u8; 65]> = Some([
+    0x04, 0xb2, 0x46, 0x64, 0x88, 0x41, 0xe9, 0x97, 0xdf, 0x32, 0x21, 0xfa, 0x9b, 0x16, 0xc4, 0x9b,
+    0x88, 0xe7, 0xf0, 0xb1, 0xa0, 0xc5, 0x14, 0xde, 0x89, 0x29, 0x42, 0x97, 0x4b, 0x34, 0x05, 0xff,
+    0x69, 0x29, 0x3a, 0x73, 0xf4, 0x6c, 0x67, 0x89, 0xac, 0x96, 0x05, 0x03, 0xe8, 0x5a, 0x13, 0x8f,
+    0xcd, 0x43, 0x63, 0x54, 0x23, 0x03, 0x86, 0x88, 0x31, 0x7a, 0x1a, 0xd1, 0x29, 0xd6, 0xd2, 0x84,
+    0xc7,
+]);
+/// Leading byte of a signed chain configuration
+pub const CHAIN_CONFIG_TYPE: u8 = 0x02;
+
 pub(crate) mod instructions {
     pub const CLA: u8 = 0x80;
 
@@ -118,6 +237,11 @@ pub(crate) mod instructions {
     pub const INS_SIGN_HASH: u8 = 0x04;
     pub const INS_SIGN: u8 = 0x05;
     pub const INS_SIGN_MSG: u8 = 0x06;
+    pub const INS_GET_APP_INFO: u8 = 0x07;
+    pub const INS_SIGN_WARP_MSG: u8 = 0x08;
+    pub const INS_PROVIDE_ASSET_INFO: u8 = 0x09;
+    pub const INS_RESET: u8 = 0x0A;
+    pub const INS_GET_C_CHAIN_ADDRESSES: u8 = 0x0B;
 }
 
 pub(crate) mod evm_instructions {
@@ -130,11 +254,20 @@ pub(crate) mod evm_instructions {
     pub const INS_PROVIDE_NFT_INFORMATION: u8 = 0x14;
     pub const INS_ETH_PROVIDE_ERC20: u8 = 0x0A;
     pub const INS_SIGN_ETH_MSG: u8 = 0x08;
+    pub const INS_GET_CHALLENGE: u8 = 0x20;
+    pub const INS_PROVIDE_DOMAIN_NAME: u8 = 0x22;
+    pub const INS_PROVIDE_RECIPIENT: u8 = 0x24;
+    pub const INS_ETH_PROVIDE_ERC20_BATCH: u8 = 0x26;
+    pub const INS_PROVIDE_CHAIN_CONFIG: u8 = 0x30;
+    pub const INS_PROVIDE_DENYLIST: u8 = 0x32;
 }
 
 pub const BIP32_PATH_ROOT_0: u32 = 0x8000_0000 + 44;
 pub const BIP32_PATH_ROOT_1: u32 = 0x8000_0000 + 9000;
-pub const MAX_BIP32_PATH_DEPTH: usize = 6;
+// coin type of ethereum paths m/44'/60'
+pub const BIP32_PATH_ROOT_ETH: u32 = 0x8000_0000 + 60;
+// same as MAX_BIP32_PATH in ledger's sdk
+pub const MAX_BIP32_PATH_DEPTH: usize = 10;
 // path prefix for signer_list and change_path lists
 // It is the same for both and has 3 components m/44'/9000'/0'/
 pub const BIP32_PATH_PREFIX_DEPTH: usize = 3;
@@ -142,6 +275,9 @@ pub const BIP32_PATH_PREFIX_DEPTH: usize = 3;
 // This should be concatenated with the path_prefix to get a full
 // signer/owner path.
 pub const BIP32_PATH_SUFFIX_DEPTH: usize = 2;
+// distinct signers of a transaction shown
+// as its sender during the review
+pub const MAX_SIGNER_ADDRESSES: usize = 8;
 
 pub use evm_instructions::*;
 pub use instructions::*;
@@ -16,7 +16,11 @@
 use core::{mem::MaybeUninit, ptr::addr_of_mut};
 use std::convert::{TryFrom, TryInto};
 
-use crate::{constants::SECP256_SIGN_BUFFER_MIN_LENGTH, sys, utils::ApduPanic};
+use crate::{
+    constants::{MAX_DER_SIGNATURE_LEN, SECP256_SIGN_BUFFER_MIN_LENGTH},
+    sys,
+    utils::{ApduPanic, SECP256K1_PUBKEY_LEN},
+};
 use sys::{
     crypto::{bip32::BIP32Path, CHAIN_CODE_LEN},
     errors::Error,
@@ -77,6 +81,8 @@ pub struct SecretKey<const B: usize>(sys::crypto::ecfp256::SecretKey<B>);
 
 pub enum SignError {
     BufferTooSmall,
+    /// The signature written is longer than any DER signature
+    Overflow,
     Sys(Error),
 }
 
@@ -123,9 +129,17 @@ impl<const B: usize> SecretKey<B> {
         if out.len() < SECP256_SIGN_BUFFER_MIN_LENGTH {
             Err(SignError::BufferTooSmall)
         } else {
-            self.0
+            let (flags, sz) = self
+                .0
                 .sign::<Sha256>(data, out) //pass Sha256 for the signature nonce hasher
-                .map_err(SignError::Sys)
+                .map_err(SignError::Sys)?;
+
+            // don't trust the backend with the bounds of `out`
+            if sz > MAX_DER_SIGNATURE_LEN {
+                return Err(SignError::Overflow);
+            }
+
+            Ok((flags, sz))
         }
     }
 }
@@ -134,4 +148,15 @@ impl Curve {
     pub fn to_secret<const B: usize>(self, path: &BIP32Path<B>) -> SecretKey<B> {
         SecretKey::new(self, *path)
     }
+
+    /// Verifies the DER encoded `signature` of `data` against
+    /// the uncompressed public key `pubkey`
+    pub fn verify(
+        self,
+        pubkey: &[u8; SECP256K1_PUBKEY_LEN],
+        data: &[u8],
+        signature: &[u8],
+    ) -> bool {
+        crate::utils::verify_secp256k1(pubkey, data, signature)
+    }
 }
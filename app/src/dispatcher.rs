@@ -16,26 +16,42 @@
 
 use crate::constants::{evm_instructions::*, instructions::*, ApduError};
 
-#[cfg(feature = "erc20")]
-use crate::handlers::eth::provide_erc20::ProvideERC20;
 use crate::handlers::{
-    eth::{
-        get_app_configuration::GetAppConfiguration as EthGetAppConfig,
-        personal_msg::Sign as EthSignMsg, public_key::GetPublicKey as GetEthPublicKey,
-        set_plugin::SetPlugin, signing::Sign as EthSign,
-    },
-    public_key::{GetExtendedPublicKey, GetPublicKey},
-    version::GetVersion,
-    wallet_id::WalletId,
+    app_info::GetAppInfo, reset::Reset, version::GetVersion, wallet_id::WalletId,
+};
+
+#[cfg(feature = "evm")]
+use crate::handlers::eth::{
+    get_app_configuration::GetAppConfiguration as EthGetAppConfig, get_challenge::GetChallenge,
+    personal_msg::Sign as EthSignMsg, provide_domain::ProvideDomain,
+    provide_recipient::ProvideRecipient, public_key::GetPublicKey as GetEthPublicKey,
+    set_plugin::SetPlugin, signing::Sign as EthSign,
 };
 
+#[cfg(feature = "chain-config")]
+use crate::handlers::eth::provide_chain_config::ProvideChainConfig;
+
+#[cfg(feature = "erc20")]
+use crate::handlers::eth::provide_erc20::{ProvideERC20, ProvideERC20Batch};
+
 #[cfg(feature = "erc721")]
 use crate::handlers::eth::provide_nft_info::Info as NftProvider;
 
-use crate::handlers::avax::{
-    message::Sign as AvaxSignMsg, sign_hash::Sign as SignHash, signing::Sign as AvaxSign,
+#[cfg(feature = "address-denylist")]
+use crate::handlers::eth::provide_denylist::ProvideDenylist;
+
+#[cfg(any(feature = "avm", feature = "pvm"))]
+use crate::handlers::{
+    avax::{
+        message::Sign as AvaxSignMsg, provide_asset::ProvideAssetInfo, sign_hash::Sign as SignHash,
+        signing::Sign as AvaxSign, warp::Sign as SignWarp,
+    },
+    public_key::{GetExtendedPublicKey, GetPublicKey},
 };
 
+#[cfg(feature = "evm")]
+use crate::handlers::public_key::GetCChainAddresses;
+
 #[cfg(feature = "dev")]
 use crate::handlers::dev::*;
 
@@ -69,21 +85,52 @@ pub fn apdu_dispatch<'apdu>(
     //common instructions
     match (cla, ins) {
         (CLA, INS_GET_VERSION) => GetVersion::handle(flags, tx, apdu_buffer),
+        (CLA, INS_GET_APP_INFO) => GetAppInfo::handle(flags, tx, apdu_buffer),
+        (CLA, INS_GET_WALLET_ID) => WalletId::handle(flags, tx, apdu_buffer),
+        (CLA, INS_RESET) => Reset::handle(flags, tx, apdu_buffer),
+
+        #[cfg(any(feature = "avm", feature = "pvm"))]
         (CLA, INS_GET_PUBLIC_KEY) => GetPublicKey::handle(flags, tx, apdu_buffer),
+        #[cfg(any(feature = "avm", feature = "pvm"))]
         (CLA, INS_GET_EXTENDED_PUBLIC_KEY) => GetExtendedPublicKey::handle(flags, tx, apdu_buffer),
-        (CLA, INS_GET_WALLET_ID) => WalletId::handle(flags, tx, apdu_buffer),
+        #[cfg(feature = "evm")]
+        (CLA, INS_GET_C_CHAIN_ADDRESSES) => GetCChainAddresses::handle(flags, tx, apdu_buffer),
+        #[cfg(any(feature = "avm", feature = "pvm"))]
         (CLA, INS_SIGN) => AvaxSign::handle(flags, tx, apdu_buffer),
+        #[cfg(any(feature = "avm", feature = "pvm"))]
         (CLA, INS_SIGN_HASH) => SignHash::handle(flags, tx, apdu_buffer),
+        #[cfg(any(feature = "avm", feature = "pvm"))]
         (CLA, INS_SIGN_MSG) => AvaxSignMsg::handle(flags, tx, apdu_buffer),
+        #[cfg(any(feature = "avm", feature = "pvm"))]
+        (CLA, INS_SIGN_WARP_MSG) => SignWarp::handle(flags, tx, apdu_buffer),
+        #[cfg(any(feature = "avm", feature = "pvm"))]
+        (CLA, INS_PROVIDE_ASSET_INFO) => ProvideAssetInfo::handle(flags, tx, apdu_buffer),
 
+        #[cfg(feature = "evm")]
         (CLA_ETH, INS_ETH_GET_PUBLIC_KEY) => GetEthPublicKey::handle(flags, tx, apdu_buffer),
+        #[cfg(feature = "evm")]
         (CLA_ETH, INS_SET_PLUGIN) => SetPlugin::handle(flags, tx, apdu_buffer),
         #[cfg(feature = "erc20")]
         (CLA_ETH, INS_ETH_PROVIDE_ERC20) => ProvideERC20::handle(flags, tx, apdu_buffer),
+        #[cfg(feature = "erc20")]
+        (CLA_ETH, INS_ETH_PROVIDE_ERC20_BATCH) => ProvideERC20Batch::handle(flags, tx, apdu_buffer),
         #[cfg(feature = "erc721")]
         (CLA_ETH, INS_PROVIDE_NFT_INFORMATION) => NftProvider::handle(flags, tx, apdu_buffer),
+        #[cfg(feature = "evm")]
+        (CLA_ETH, INS_GET_CHALLENGE) => GetChallenge::handle(flags, tx, apdu_buffer),
+        #[cfg(feature = "evm")]
+        (CLA_ETH, INS_PROVIDE_DOMAIN_NAME) => ProvideDomain::handle(flags, tx, apdu_buffer),
+        #[cfg(feature = "evm")]
+        (CLA_ETH, INS_PROVIDE_RECIPIENT) => ProvideRecipient::handle(flags, tx, apdu_buffer),
+        #[cfg(feature = "chain-config")]
+        (CLA_ETH, INS_PROVIDE_CHAIN_CONFIG) => ProvideChainConfig::handle(flags, tx, apdu_buffer),
+        #[cfg(feature = "address-denylist")]
+        (CLA_ETH, INS_PROVIDE_DENYLIST) => ProvideDenylist::handle(flags, tx, apdu_buffer),
+        #[cfg(feature = "evm")]
         (CLA_ETH, INS_ETH_GET_APP_CONFIGURATION) => EthGetAppConfig::handle(flags, tx, apdu_buffer),
+        #[cfg(feature = "evm")]
         (CLA_ETH, INS_ETH_SIGN) => EthSign::handle(flags, tx, apdu_buffer),
+        #[cfg(feature = "evm")]
         (CLA_ETH, INS_SIGN_ETH_MSG) => EthSignMsg::handle(flags, tx, apdu_buffer),
 
         #[cfg(feature = "dev")]
@@ -128,6 +175,7 @@ mod tests {
     use crate::assert_error_code;
     use crate::constants::ApduError;
     use crate::dispatcher::handle_apdu;
+    use serial_test::serial;
     use std::convert::TryInto;
 
     #[test]
@@ -142,6 +190,77 @@ mod tests {
         assert_error_code!(*tx, buffer, ApduError::WrongLength);
     }
 
+    // sends `ins` without any payload, which is enough
+    // to tell apart the instructions without a handler
+    #[cfg(not(feature = "dev"))]
+    fn is_registered(cla: u8, ins: u8) -> bool {
+        let flags = &mut 0u32;
+        let tx = &mut 0u32;
+        let buffer = &mut [0u8; 260];
+        buffer[..5].copy_from_slice(&[cla, ins, 0, 0, 0]);
+
+        handle_apdu(flags, tx, 5, buffer);
+        let sw = u16::from_be_bytes([buffer[*tx as usize - 2], buffer[*tx as usize - 1]]);
+        sw != ApduError::CommandNotAllowed as u16 && sw != ApduError::ClaNotSupported as u16
+    }
+
+    #[test]
+    #[serial]
+    #[cfg(not(feature = "dev"))]
+    fn registered_handlers() {
+        use crate::constants::{evm_instructions::*, instructions::*};
+
+        let avax = [
+            INS_GET_PUBLIC_KEY,
+            INS_GET_EXTENDED_PUBLIC_KEY,
+            INS_SIGN,
+            INS_SIGN_HASH,
+            INS_SIGN_MSG,
+            INS_SIGN_WARP_MSG,
+            INS_PROVIDE_ASSET_INFO,
+        ];
+        for ins in avax {
+            let expected = cfg!(any(feature = "avm", feature = "pvm"));
+            assert_eq!(is_registered(CLA, ins), expected, "ins {:#04x}", ins);
+        }
+
+        // under the avalanche class, but only about EVM addresses
+        let expected = cfg!(feature = "evm");
+        assert_eq!(is_registered(CLA, INS_GET_C_CHAIN_ADDRESSES), expected);
+
+        let evm = [
+            INS_ETH_GET_PUBLIC_KEY,
+            INS_SET_PLUGIN,
+            INS_GET_CHALLENGE,
+            INS_PROVIDE_DOMAIN_NAME,
+            INS_PROVIDE_RECIPIENT,
+            INS_ETH_GET_APP_CONFIGURATION,
+            INS_ETH_SIGN,
+            INS_SIGN_ETH_MSG,
+        ];
+        for ins in evm {
+            let expected = cfg!(feature = "evm");
+            assert_eq!(is_registered(CLA_ETH, ins), expected, "ins {:#04x}", ins);
+        }
+
+        let expected = cfg!(feature = "chain-config");
+        assert_eq!(is_registered(CLA_ETH, INS_PROVIDE_CHAIN_CONFIG), expected);
+
+        let expected = cfg!(feature = "address-denylist");
+        assert_eq!(is_registered(CLA_ETH, INS_PROVIDE_DENYLIST), expected);
+
+        // available in every build, reset last to
+        // drop whatever the other handlers started
+        for ins in [
+            INS_GET_VERSION,
+            INS_GET_APP_INFO,
+            INS_GET_WALLET_ID,
+            INS_RESET,
+        ] {
+            assert!(is_registered(CLA, ins), "ins {:#04x}", ins);
+        }
+    }
+
     #[test]
     fn apdu_invalid_cla() {
         let flags = &mut 0u32;
@@ -13,8 +13,12 @@
 *  See the License for the specific language governing permissions and
 *  limitations under the License.
 ********************************************************************************/
+pub mod app_info;
+#[cfg(any(feature = "avm", feature = "pvm"))]
 pub mod avax;
+#[cfg(any(feature = "avm", feature = "pvm", feature = "evm"))]
 pub mod public_key;
+pub mod reset;
 pub mod version;
 pub mod wallet_id;
 
@@ -27,14 +31,20 @@ mod utils;
 pub use utils::*;
 
 pub mod resources {
-    use crate::constants::MAX_BIP32_PATH_DEPTH;
+    use crate::{constants::MAX_BIP32_PATH_DEPTH, parser::ADDRESS_LEN};
 
     use super::lock::Lock;
     use bolos::{
-        crypto::bip32::BIP32Path, hash::Sha256, lazy_static, new_swapping_buffer, pic::PIC,
+        crypto::bip32::BIP32Path,
+        hash::{Keccak, Sha256},
+        lazy_static, new_swapping_buffer,
+        pic::PIC,
         SwappingBuffer,
     };
 
+    /// Most bytes BUFFER can hold
+    pub const BUFFER_CAPACITY: usize = 0x1FFF;
+
     #[lazy_static]
     pub static mut BUFFER: Lock<SwappingBuffer<'static, 'static, 0xFF, 0x1FFF>, BUFFERAccessors> =
         Lock::new(new_swapping_buffer!(0xFF, 0x1FFF));
@@ -46,17 +56,48 @@ pub mod resources {
     #[lazy_static]
     pub static mut HASH: Lock<Option<[u8; Sha256::DIGEST_LEN]>, HASHAccessors> = Lock::new(None);
 
+    // the eth transaction being uploaded is hashed as its packets arrive
+    #[lazy_static]
+    pub static mut TX_HASHER: Lock<Option<Keccak<32>>, TxHasherAccessors> = Lock::new(None);
+
+    // and its header found as soon as it was received, only good
+    // for the bytes of BUFFER it was found in
+    #[lazy_static]
+    pub static mut TX_HEADER: Lock<Option<crate::parser::EthHeader>, TxHeaderAccessors> =
+        Lock::new(None);
+
     #[cfg(feature = "erc721")]
     #[lazy_static]
     pub static mut NFT_INFO: Lock<Option<crate::parser::NftInfo>, NFTInfoAccessors> =
         Lock::new(None);
 
+    #[cfg(feature = "erc20")]
+    #[lazy_static]
+    pub static mut ERC20_INFO: Lock<crate::parser::TokenTable, ERC20InfoAccessors> =
+        Lock::new(crate::parser::TokenTable::new());
+
+    #[lazy_static]
+    pub static mut CHAIN_CONFIG: Lock<crate::parser::ChainTable, ChainConfigAccessors> =
+        Lock::new(crate::parser::ChainTable::new());
+
+    #[lazy_static]
+    pub static mut DOMAIN_INFO: Lock<Option<crate::parser::DomainInfo>, DomainInfoAccessors> =
+        Lock::new(None);
+
+    #[lazy_static]
+    pub static mut RECIPIENT: Lock<Option<[u8; ADDRESS_LEN]>, RecipientAccessors> = Lock::new(None);
+
+    #[lazy_static]
+    pub static mut ASSET_INFO: Lock<Option<crate::parser::AssetInfo>, AssetInfoAccessors> =
+        Lock::new(None);
+
     #[derive(Clone, Copy, PartialEq, Eq)]
     pub enum BUFFERAccessors {
         Sign,
         EthSign,
         SignHash,
         SignMsg,
+        SignWarp,
         EthSignMsg,
         #[cfg(feature = "dev")]
         Debug,
@@ -68,6 +109,7 @@ pub mod resources {
         EthSign,
         SignHash,
         SignMsg,
+        SignWarp,
         EthSignMsg,
     }
 
@@ -76,6 +118,17 @@ pub mod resources {
         Sign,
         SignHash,
         SignMsg,
+        SignWarp,
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub enum TxHasherAccessors {
+        EthSign,
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub enum TxHeaderAccessors {
+        EthSign,
     }
 
     #[derive(Clone, Copy, PartialEq, Eq)]
@@ -87,36 +140,102 @@ pub mod resources {
         ERC721Parser,
     }
 
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    #[cfg(feature = "erc20")]
+    pub enum ERC20InfoAccessors {
+        ProvideERC20,
+        EthSign,
+        // to look up the tokens of erc20 calls
+        ERC20Parser,
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub enum ChainConfigAccessors {
+        #[cfg(feature = "chain-config")]
+        ProvideChainConfig,
+        EthSign,
+        // to render the native currency of eth transactions
+        NativeCurrency,
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub enum DomainInfoAccessors {
+        ProvideDomain,
+        EthSign,
+        // to render the recipient of eth transactions
+        DomainName,
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub enum RecipientAccessors {
+        ProvideRecipient,
+        EthSign,
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub enum AssetInfoAccessors {
+        ProvideAssetInfo,
+        Sign,
+        // to format the amounts of avax transactions
+        AssetDenomination,
+    }
+
+    #[cfg(any(feature = "avm", feature = "pvm"))]
     impl From<super::avax::signing::Sign> for BUFFERAccessors {
         fn from(_: super::avax::signing::Sign) -> Self {
             Self::Sign
         }
     }
 
+    #[cfg(any(feature = "avm", feature = "pvm"))]
     impl From<super::avax::message::Sign> for BUFFERAccessors {
         fn from(_: super::avax::message::Sign) -> Self {
             Self::SignMsg
         }
     }
 
+    #[cfg(any(feature = "avm", feature = "pvm"))]
+    impl From<super::avax::warp::Sign> for BUFFERAccessors {
+        fn from(_: super::avax::warp::Sign) -> Self {
+            Self::SignWarp
+        }
+    }
+
+    #[cfg(any(feature = "avm", feature = "pvm"))]
     impl From<super::avax::sign_hash::Sign> for BUFFERAccessors {
         fn from(_: super::avax::sign_hash::Sign) -> Self {
             Self::SignHash
         }
     }
 
+    #[cfg(feature = "evm")]
     impl From<super::eth::signing::Sign> for BUFFERAccessors {
         fn from(_: super::eth::signing::Sign) -> Self {
             Self::EthSign
         }
     }
 
+    #[cfg(feature = "evm")]
     impl From<super::eth::personal_msg::Sign> for BUFFERAccessors {
         fn from(_: super::eth::personal_msg::Sign) -> Self {
             Self::EthSignMsg
         }
     }
 
+    #[cfg(feature = "evm")]
+    impl From<super::eth::signing::Sign> for TxHasherAccessors {
+        fn from(_: super::eth::signing::Sign) -> Self {
+            Self::EthSign
+        }
+    }
+
+    #[cfg(feature = "evm")]
+    impl From<super::eth::signing::Sign> for TxHeaderAccessors {
+        fn from(_: super::eth::signing::Sign) -> Self {
+            Self::EthSign
+        }
+    }
+
     #[cfg(feature = "erc721")]
     impl From<super::eth::provide_nft_info::Info> for NFTInfoAccessors {
         fn from(_: super::eth::provide_nft_info::Info) -> Self {
@@ -142,6 +261,101 @@ pub mod resources {
         }
     }
 
+    #[cfg(feature = "erc20")]
+    impl From<super::eth::provide_erc20::ProvideERC20> for ERC20InfoAccessors {
+        fn from(_: super::eth::provide_erc20::ProvideERC20) -> Self {
+            Self::ProvideERC20
+        }
+    }
+
+    #[cfg(feature = "erc20")]
+    impl From<super::eth::signing::Sign> for ERC20InfoAccessors {
+        fn from(_: super::eth::signing::Sign) -> Self {
+            Self::EthSign
+        }
+    }
+
+    #[cfg(feature = "erc20")]
+    impl From<crate::parser::ERC20Info> for ERC20InfoAccessors {
+        fn from(_: crate::parser::ERC20Info) -> Self {
+            Self::ERC20Parser
+        }
+    }
+
+    #[cfg(feature = "chain-config")]
+    impl From<super::eth::provide_chain_config::ProvideChainConfig> for ChainConfigAccessors {
+        fn from(_: super::eth::provide_chain_config::ProvideChainConfig) -> Self {
+            Self::ProvideChainConfig
+        }
+    }
+
+    #[cfg(feature = "evm")]
+    impl From<super::eth::signing::Sign> for ChainConfigAccessors {
+        fn from(_: super::eth::signing::Sign) -> Self {
+            Self::EthSign
+        }
+    }
+
+    impl From<crate::parser::NativeCurrency> for ChainConfigAccessors {
+        fn from(_: crate::parser::NativeCurrency) -> Self {
+            Self::NativeCurrency
+        }
+    }
+
+    #[cfg(feature = "evm")]
+    impl From<super::eth::provide_domain::ProvideDomain> for DomainInfoAccessors {
+        fn from(_: super::eth::provide_domain::ProvideDomain) -> Self {
+            Self::ProvideDomain
+        }
+    }
+
+    #[cfg(feature = "evm")]
+    impl From<super::eth::signing::Sign> for DomainInfoAccessors {
+        fn from(_: super::eth::signing::Sign) -> Self {
+            Self::EthSign
+        }
+    }
+
+    impl From<crate::parser::DomainName> for DomainInfoAccessors {
+        fn from(_: crate::parser::DomainName) -> Self {
+            Self::DomainName
+        }
+    }
+
+    #[cfg(feature = "evm")]
+    impl From<super::eth::provide_recipient::ProvideRecipient> for RecipientAccessors {
+        fn from(_: super::eth::provide_recipient::ProvideRecipient) -> Self {
+            Self::ProvideRecipient
+        }
+    }
+
+    #[cfg(feature = "evm")]
+    impl From<super::eth::signing::Sign> for RecipientAccessors {
+        fn from(_: super::eth::signing::Sign) -> Self {
+            Self::EthSign
+        }
+    }
+
+    #[cfg(any(feature = "avm", feature = "pvm"))]
+    impl From<super::avax::provide_asset::ProvideAssetInfo> for AssetInfoAccessors {
+        fn from(_: super::avax::provide_asset::ProvideAssetInfo) -> Self {
+            Self::ProvideAssetInfo
+        }
+    }
+
+    #[cfg(any(feature = "avm", feature = "pvm"))]
+    impl From<super::avax::signing::Sign> for AssetInfoAccessors {
+        fn from(_: super::avax::signing::Sign) -> Self {
+            Self::Sign
+        }
+    }
+
+    impl From<crate::parser::AssetDenomination> for AssetInfoAccessors {
+        fn from(_: crate::parser::AssetDenomination) -> Self {
+            Self::AssetDenomination
+        }
+    }
+
     #[cfg(feature = "dev")]
     impl From<super::dev::Debug> for BUFFERAccessors {
         fn from(_: super::dev::Debug) -> Self {
@@ -149,48 +363,70 @@ pub mod resources {
         }
     }
 
+    #[cfg(any(feature = "avm", feature = "pvm"))]
     impl From<super::avax::signing::Sign> for PATHAccessors {
         fn from(_: super::avax::signing::Sign) -> Self {
             Self::Sign
         }
     }
 
+    #[cfg(any(feature = "avm", feature = "pvm"))]
     impl From<super::avax::message::Sign> for PATHAccessors {
         fn from(_: super::avax::message::Sign) -> Self {
             Self::SignMsg
         }
     }
 
+    #[cfg(any(feature = "avm", feature = "pvm"))]
+    impl From<super::avax::warp::Sign> for PATHAccessors {
+        fn from(_: super::avax::warp::Sign) -> Self {
+            Self::SignWarp
+        }
+    }
+
+    #[cfg(feature = "evm")]
     impl From<super::eth::signing::Sign> for PATHAccessors {
         fn from(_: super::eth::signing::Sign) -> Self {
             Self::EthSign
         }
     }
 
+    #[cfg(feature = "evm")]
     impl From<super::eth::personal_msg::Sign> for PATHAccessors {
         fn from(_: super::eth::personal_msg::Sign) -> Self {
             Self::EthSignMsg
         }
     }
 
+    #[cfg(any(feature = "avm", feature = "pvm"))]
     impl From<super::avax::sign_hash::Sign> for PATHAccessors {
         fn from(_: super::avax::sign_hash::Sign) -> Self {
             Self::SignHash
         }
     }
 
+    #[cfg(any(feature = "avm", feature = "pvm"))]
     impl From<super::avax::signing::Sign> for HASHAccessors {
         fn from(_: super::avax::signing::Sign) -> Self {
             Self::Sign
         }
     }
 
+    #[cfg(any(feature = "avm", feature = "pvm"))]
     impl From<super::avax::message::Sign> for HASHAccessors {
         fn from(_: super::avax::message::Sign) -> Self {
             Self::SignMsg
         }
     }
 
+    #[cfg(any(feature = "avm", feature = "pvm"))]
+    impl From<super::avax::warp::Sign> for HASHAccessors {
+        fn from(_: super::avax::warp::Sign) -> Self {
+            Self::SignWarp
+        }
+    }
+
+    #[cfg(any(feature = "avm", feature = "pvm"))]
     impl From<super::avax::sign_hash::Sign> for HASHAccessors {
         fn from(_: super::avax::sign_hash::Sign) -> Self {
             Self::SignHash
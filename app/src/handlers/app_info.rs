@@ -0,0 +1,129 @@
+/*******************************************************************************
+*   (c) 2018 - 2022 Zondax AG
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use crate::constants::{version::*, ApduError};
+use crate::dispatcher::ApduHandler;
+use crate::utils::{
+    blind_sign_toggle::blind_sign_enabled, is_app_mode_expert,
+    pre_eip155_toggle::pre_eip155_enabled, ApduBufferRead,
+};
+
+/// Return the app version and the capabilities it supports
+///
+/// The response is [MAJOR][MINOR][PATCH][FLAGS], with FLAGS
+/// a big endian u32 bitmask. Bits are never reassigned:
+///
+/// bits 0-7, settings:
+/// 0 blind signing enabled, 1 expert mode, 2 pre-EIP155 signing enabled
+///
+/// bits 8-15, chains:
+/// 8 X-chain, 9 P-chain, 10 C-chain
+///
+/// bits 16-31, clear signing:
+/// 16 ERC20, 17 ERC721 and ERC1155, 18 create asset, 19 create chain,
+/// 20 create subnet, 21 add subnet validator, 22 add validator,
+/// 23 add delegator, 24 add permissionless validator,
+/// 25 add permissionless delegator, 26 transform subnet,
+/// 27 remove subnet validator
+pub struct GetAppInfo;
+
+impl GetAppInfo {
+    pub const BLIND_SIGN: u32 = 1 << 0;
+    pub const EXPERT_MODE: u32 = 1 << 1;
+    pub const PRE_EIP155: u32 = 1 << 2;
+
+    pub const X_CHAIN: u32 = 1 << 8;
+    pub const P_CHAIN: u32 = 1 << 9;
+    pub const C_CHAIN: u32 = 1 << 10;
+
+    pub const ERC20: u32 = 1 << 16;
+    pub const ERC721: u32 = 1 << 17;
+    pub const CREATE_ASSET: u32 = 1 << 18;
+    pub const CREATE_CHAIN: u32 = 1 << 19;
+    pub const CREATE_SUBNET: u32 = 1 << 20;
+    pub const ADD_SUBNET_VALIDATOR: u32 = 1 << 21;
+    pub const ADD_VALIDATOR: u32 = 1 << 22;
+    pub const ADD_DELEGATOR: u32 = 1 << 23;
+    pub const ADD_PERMISSIONLESS_VALIDATOR: u32 = 1 << 24;
+    pub const ADD_PERMISSIONLESS_DELEGATOR: u32 = 1 << 25;
+    pub const TRANSFORM_SUBNET: u32 = 1 << 26;
+    pub const REMOVE_SUBNET_VALIDATOR: u32 = 1 << 27;
+
+    pub fn flags() -> u32 {
+        let mut flags = 0;
+
+        let enabled = [
+            (blind_sign_enabled(), Self::BLIND_SIGN),
+            (is_app_mode_expert(), Self::EXPERT_MODE),
+            (pre_eip155_enabled(), Self::PRE_EIP155),
+            (cfg!(feature = "avm"), Self::X_CHAIN),
+            (cfg!(feature = "pvm"), Self::P_CHAIN),
+            (cfg!(feature = "evm"), Self::C_CHAIN),
+            (cfg!(feature = "erc20"), Self::ERC20),
+            (cfg!(feature = "erc721"), Self::ERC721),
+            (cfg!(feature = "create-asset"), Self::CREATE_ASSET),
+            (cfg!(feature = "create-chain"), Self::CREATE_CHAIN),
+            (cfg!(feature = "create-subnet"), Self::CREATE_SUBNET),
+            (
+                cfg!(feature = "add-subnet-validator"),
+                Self::ADD_SUBNET_VALIDATOR,
+            ),
+            (cfg!(feature = "add-validator"), Self::ADD_VALIDATOR),
+            (cfg!(feature = "add-delegator"), Self::ADD_DELEGATOR),
+            (
+                cfg!(feature = "add-permissionless-validator"),
+                Self::ADD_PERMISSIONLESS_VALIDATOR,
+            ),
+            (
+                cfg!(feature = "add-permissionless-delegator"),
+                Self::ADD_PERMISSIONLESS_DELEGATOR,
+            ),
+            (cfg!(feature = "transform-subnet"), Self::TRANSFORM_SUBNET),
+            (
+                cfg!(feature = "remove-subnet-validator"),
+                Self::REMOVE_SUBNET_VALIDATOR,
+            ),
+        ];
+
+        for (on, flag) in enabled {
+            if on {
+                flags |= flag;
+            }
+        }
+
+        flags
+    }
+}
+
+impl ApduHandler for GetAppInfo {
+    #[inline(never)]
+    fn handle<'apdu>(
+        _: &mut u32,
+        tx: &mut u32,
+        apdu_buffer: ApduBufferRead<'apdu>,
+    ) -> Result<(), ApduError> {
+        crate::sys::zemu_log_stack("GetAppInfo\x00");
+        *tx = 0;
+
+        let apdu_buffer = apdu_buffer.write();
+        apdu_buffer[0] = APPVERSION_M;
+        apdu_buffer[1] = APPVERSION_N;
+        apdu_buffer[2] = APPVERSION_P;
+        apdu_buffer[3..7].copy_from_slice(&Self::flags().to_be_bytes());
+        *tx = 7;
+
+        Ok(())
+    }
+}
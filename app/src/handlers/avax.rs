@@ -15,5 +15,7 @@
 ********************************************************************************/
 
 pub mod message;
+pub mod provide_asset;
 pub mod sign_hash;
 pub mod signing;
+pub mod warp;
@@ -13,9 +13,12 @@
 *  See the License for the specific language governing permissions and
 *  limitations under the License.
 ********************************************************************************/
+use core::{convert::TryFrom, mem::MaybeUninit};
+
 use bolos::{
     crypto::bip32::BIP32Path,
     hash::{Hasher, Sha256},
+    pic_str, PIC,
 };
 use zemu_sys::{Show, ViewError, Viewable};
 
@@ -24,7 +27,9 @@ use crate::{
     dispatcher::ApduHandler,
     handlers::{
         avax::sign_hash::Sign as SignHash,
-        resources::{HASH, PATH},
+        check_not_busy,
+        resources::{BUFFER, HASH, PATH},
+        PathGuard, ZPacketType,
     },
     parser::{AvaxMessage, DisplayableItem},
     sys,
@@ -38,9 +43,27 @@ impl Sign {
     // sha256 is used
     pub const SIGN_HASH_SIZE: usize = Sha256::DIGEST_LEN;
 
+    /// Hashes the envelope of `msg`, the header followed
+    /// by the length of the message and the message itself
     #[inline(never)]
-    fn sha256_digest(buffer: &[u8]) -> Result<[u8; Self::SIGN_HASH_SIZE], Error> {
-        Sha256::digest(buffer).map_err(|_| Error::ExecutionError)
+    fn digest(msg: &[u8]) -> Result<[u8; Self::SIGN_HASH_SIZE], Error> {
+        let mut hasher = {
+            let mut h = MaybeUninit::uninit();
+            Sha256::new_gce(&mut h).map_err(|_| Error::Unknown)?;
+
+            //safe: initialized
+            unsafe { h.assume_init() }
+        };
+
+        let header = pic_str!(b"\x1AAvalanche Signed Message:\n"!);
+        let len = u32::try_from(msg.len()).map_err(|_| Error::DataInvalid)?;
+        hasher.update(header).map_err(|_| Error::Unknown)?;
+        hasher
+            .update(&len.to_be_bytes()[..])
+            .map_err(|_| Error::Unknown)?;
+        hasher.update(msg).map_err(|_| Error::Unknown)?;
+
+        hasher.finalize().map_err(|_| Error::Unknown)
     }
 
     #[inline(never)]
@@ -55,17 +78,19 @@ impl Sign {
             return Err(Error::WrongLength);
         }
 
-        unsafe {
-            PATH.lock(Self)?.replace(root_path);
-        }
+        let path = PathGuard::lock(Self, root_path)?;
 
-        let digest = Self::sha256_digest(data)?;
         // parse message
         let msg = AvaxMessage::new(data).map_err(|_| Error::DataInvalid)?;
+        // only what is shown gets signed
+        let digest = Self::digest(msg.msg())?;
 
         let ui = SignUI { hash: digest, msg };
 
-        crate::show_ui!(ui.show(flags))
+        let tx = crate::show_ui!(ui.show(flags))?;
+        path.keep();
+
+        Ok(tx)
     }
 }
 
@@ -80,6 +105,10 @@ impl ApduHandler for Sign {
 
         *tx = 0;
 
+        if buffer.p1() == ZPacketType::Init as u8 {
+            check_not_busy(Self, Self)?;
+        }
+
         if let Some(upload) = Uploader::new(Self).upload(&buffer)? {
             *tx = Self::start_sign(upload.first, upload.data, flags)?;
         }
@@ -137,7 +166,7 @@ impl Viewable for SignUI {
     }
 }
 
-fn cleanup_globals() -> Result<(), Error> {
+pub(crate) fn cleanup_globals() -> Result<(), Error> {
     unsafe {
         if let Ok(path) = PATH.acquire(Sign) {
             path.take();
@@ -146,6 +175,14 @@ fn cleanup_globals() -> Result<(), Error> {
             let _ = PATH.release(Sign);
         }
 
+        // the upload of the data, if interrupted
+        if let Ok(buffer) = BUFFER.acquire(Sign) {
+            buffer.reset();
+
+            //let's release the lock for the future
+            let _ = BUFFER.release(Sign);
+        }
+
         if let Ok(hash) = HASH.acquire(Sign) {
             hash.take();
 
@@ -157,3 +194,18 @@ fn cleanup_globals() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn envelope_digest() {
+        let expected =
+            hex::decode("148442c7ea8229ca66861531fe4cffaf8466c943b7590fbd9dafbfda2a0bb518")
+                .unwrap();
+
+        let hash = Sign::digest(b"An AvalancheMessage to sign").unwrap();
+        assert_eq!(&hash[..], &expected[..]);
+    }
+}
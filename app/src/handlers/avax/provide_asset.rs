@@ -0,0 +1,113 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use crate::{constants::ApduError as Error, dispatcher::ApduHandler, sys, utils::ApduBufferRead};
+
+pub struct ProvideAssetInfo;
+
+impl ProvideAssetInfo {
+    fn process(input: &[u8]) -> Result<(), Error> {
+        let mut info = core::mem::MaybeUninit::uninit();
+        let rem = crate::parser::FromBytes::from_bytes_into(input, &mut info)
+            .map_err(|_| Error::DataInvalid)?;
+
+        if !rem.is_empty() {
+            return Err(Error::DataInvalid);
+        }
+
+        let info = unsafe { info.assume_init() };
+
+        // store the denomination to format the amounts of the next transaction,
+        // it is shown before them so it is approved along with the transaction
+        unsafe {
+            crate::handlers::resources::ASSET_INFO
+                .lock(Self)?
+                .replace(info);
+        }
+
+        Ok(())
+    }
+}
+
+impl ApduHandler for ProvideAssetInfo {
+    #[inline(never)]
+    fn handle<'apdu>(
+        _flags: &mut u32,
+        tx: &mut u32,
+        buffer: ApduBufferRead<'apdu>,
+    ) -> Result<(), Error> {
+        sys::zemu_log_stack("ProvideAssetInfo::handle\x00");
+
+        *tx = 0;
+
+        // asset id and denomination
+        let payload = buffer.payload().map_err(|_| Error::WrongLength)?;
+
+        ProvideAssetInfo::process(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::{avax::signing::Sign, resources::ASSET_INFO};
+    use serial_test::serial;
+    use std::prelude::v1::*;
+
+    // asset 0x22..22 with 6 decimals
+    const INFO: &str = "222222222222222222222222222222222222222222222222222222222222222206";
+
+    #[test]
+    #[serial]
+    fn provided_info() {
+        let data = hex::decode(INFO).unwrap();
+        ProvideAssetInfo::process(&data).unwrap();
+
+        let info = unsafe { ASSET_INFO.lock(Sign) }.unwrap().take().unwrap();
+        assert_eq!(info.id(), &[0x22; 32]);
+        assert_eq!(info.denomination(), 6);
+    }
+
+    #[test]
+    #[serial]
+    fn invalid_info() {
+        let data = hex::decode(INFO).unwrap();
+
+        // truncated
+        assert_eq!(
+            ProvideAssetInfo::process(&data[..data.len() - 1]),
+            Err(Error::DataInvalid)
+        );
+
+        // with trailing data, e.g. a signature
+        let mut trailing = data.clone();
+        trailing.push(0x30);
+        assert_eq!(
+            ProvideAssetInfo::process(&trailing),
+            Err(Error::DataInvalid)
+        );
+
+        // more decimals than an asset can have
+        let mut denomination = data;
+        *denomination.last_mut().unwrap() = 0xff;
+        assert_eq!(
+            ProvideAssetInfo::process(&denomination),
+            Err(Error::DataInvalid)
+        );
+
+        // rejected info is not stored
+        assert!(unsafe { ASSET_INFO.lock(Sign) }.unwrap().is_none());
+    }
+}
@@ -28,12 +28,13 @@ use crate::{
     crypto::{Curve, ECCInfoFlags},
     dispatcher::ApduHandler,
     handlers::{
-        handle_ui_message,
+        handle_ui_message_hex,
         resources::{HASH, PATH},
+        PathGuard,
     },
     parser::{FromBytes, PathWrapper},
     sys,
-    utils::{convert_der_to_rs, ApduBufferRead},
+    utils::{convert_der_to_rs, normalize_low_s, ApduBufferRead},
 };
 
 pub struct Sign;
@@ -84,9 +85,7 @@ impl Sign {
             return Err(Error::WrongLength);
         }
 
-        unsafe {
-            PATH.lock(Self)?.replace(root_path);
-        }
+        let path = PathGuard::lock(Self, root_path)?;
 
         if rem.len() != Self::SIGN_HASH_SIZE {
             return Err(Error::WrongLength);
@@ -99,7 +98,10 @@ impl Sign {
             hash: unsigned_hash,
         };
 
-        crate::show_ui!(ui.show(flags))
+        let tx = crate::show_ui!(ui.show(flags))?;
+        path.keep();
+
+        Ok(tx)
     }
 
     fn get_signing_info(data: &[u8]) -> Result<BIP32Path<MAX_BIP32_PATH_DEPTH>, Error> {
@@ -147,7 +149,6 @@ impl Viewable for SignUI {
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
-        use crate::utils::hex_encode;
         use bolos::{pic_str, PIC};
 
         if item_n != 0 {
@@ -156,11 +157,7 @@ impl Viewable for SignUI {
         let title_content = pic_str!(b"Hash: ");
         title[..title_content.len()].copy_from_slice(title_content);
 
-        let mut hex = [0; Sign::SIGN_HASH_SIZE * 2];
-
-        let size = hex_encode(self.hash, &mut hex[..]).map_err(|_| ViewError::Unknown)?;
-
-        handle_ui_message(&hex[..size], message, page)
+        handle_ui_message_hex(&[], &self.hash[..], message, page)
     }
 
     fn accept(&mut self, _out: &mut [u8]) -> (usize, u16) {
@@ -206,6 +203,10 @@ impl ApduHandler for Sign {
         // either for signing transactions, messages or a hash all of them,
         // previously reviewed.
         if p1 == FIRST_MESSAGE {
+            //don't take over the path of another signing session
+            if unsafe { PATH.is_locked_by_other(Self) } {
+                return Err(Error::Busy);
+            }
             return Self::start_sign(cdata, flags).map(|_| ());
         }
 
@@ -218,7 +219,7 @@ impl ApduHandler for Sign {
 
         //write signature as RSV
         //write V, which is the oddity of the signature
-        let v = flags.contains(ECCInfo::ParityOdd) as u8;
+        let mut v = flags.contains(ECCInfo::ParityOdd) as u8;
 
         //set to 0x30 for the DER conversion
         sig[0] = 0x30;
@@ -236,7 +237,12 @@ impl ApduHandler for Sign {
                     // this is consistent with the fact that in `convert_der_to_rs`
                     // we put the bytes at the end of the buffer first
                     let r = &r[1..];
-                    let s = &s[1..];
+                    let s = arrayref::array_mut_ref!(s, 1, 32);
+
+                    //low-S normalization negates the signature, so V flips too
+                    if normalize_low_s(s) {
+                        v ^= 1;
+                    }
 
                     out[offset..][..32].copy_from_slice(r);
                     offset += 32;
@@ -260,7 +266,7 @@ impl ApduHandler for Sign {
     }
 }
 
-fn cleanup_globals() -> Result<(), Error> {
+pub(crate) fn cleanup_globals() -> Result<(), Error> {
     unsafe {
         if let Ok(path) = PATH.acquire(Sign) {
             path.take();
@@ -13,6 +13,7 @@
 *  See the License for the specific language governing permissions and
 *  limitations under the License.
 ********************************************************************************/
+use arrayvec::ArrayVec;
 use core::mem::MaybeUninit;
 use nom::number::complete::be_u8;
 
@@ -25,23 +26,33 @@ use zemu_sys::{Show, ViewError, Viewable};
 use crate::{
     constants::{
         ApduError as Error, BIP32_PATH_PREFIX_DEPTH, BIP32_PATH_SUFFIX_DEPTH, MAX_BIP32_PATH_DEPTH,
+        MAX_SIGNER_ADDRESSES,
     },
     dispatcher::ApduHandler,
     handlers::{
         avax::sign_hash::Sign as SignHash,
-        resources::{HASH, PATH},
+        check_not_busy, handle_ui_message,
+        resources::{ASSET_INFO, BUFFER, HASH, PATH},
+        ui_items, PathGuard, ZPacketType,
+    },
+    parser::{
+        Address, AssetDenomination, AssetInfo, ChainId, DisplayableItem, FromBytes, ObjectList,
+        ParserError, PathWrapper, Transaction, TxSummary, UtxoList, ADDRESS_LEN,
+        MAX_ADDRESS_ENCODED_LEN, U32_SIZE,
     },
-    parser::{DisplayableItem, ObjectList, ParserError, PathWrapper, Transaction},
     sys,
-    utils::{ApduBufferRead, Uploader},
+    utils::{
+        is_app_mode_expert, strict_outputs_toggle::strict_outputs_enabled, ApduBufferRead,
+        DerivationScheme, DigestScheme, Uploader,
+    },
 };
 
 pub struct Sign;
 
 impl Sign {
-    // For avax transactions which includes P, C, X chains,
-    // sha256 is used
-    pub const SIGN_HASH_SIZE: usize = Sha256::DIGEST_LEN;
+    // the digest scheme is given by the parsed transaction,
+    // which is sha256 for P, C and X-chain transactions
+    pub const SIGN_HASH_SIZE: usize = DigestScheme::DIGEST_LEN;
 
     fn get_derivation_info() -> Result<&'static BIP32Path<MAX_BIP32_PATH_DEPTH>, Error> {
         match unsafe { PATH.acquire(Self) } {
@@ -50,11 +61,6 @@ impl Sign {
         }
     }
 
-    #[inline(never)]
-    fn sha256_digest(buffer: &[u8]) -> Result<[u8; Self::SIGN_HASH_SIZE], Error> {
-        Sha256::digest(buffer).map_err(|_| Error::ExecutionError)
-    }
-
     #[inline(never)]
     pub fn compute_keyhash(
         path: &BIP32Path<MAX_BIP32_PATH_DEPTH>,
@@ -77,9 +83,22 @@ impl Sign {
         Ok(())
     }
 
-    fn disable_outputs(
-        list: &mut ObjectList<PathWrapper<BIP32_PATH_SUFFIX_DEPTH>>,
-        tx: &mut Transaction,
+    // X and P-chain transactions are only signed with avalanche paths,
+    // while C-chain atomic transactions spend from EVM addresses
+    // which can be derived with either scheme
+    fn check_scheme(scheme: DerivationScheme, tx: &Transaction) -> Result<(), Error> {
+        match (scheme, tx) {
+            (DerivationScheme::Avalanche, _)
+            | (DerivationScheme::Ethereum, Transaction::CImport(_))
+            | (DerivationScheme::Ethereum, Transaction::CExport(_)) => Ok(()),
+            _ => Err(Error::InvalidPathPrefix),
+        }
+    }
+
+    // computes the address of the key at root_path + suffix
+    fn suffix_keyhash(
+        suffix: &BIP32Path<BIP32_PATH_SUFFIX_DEPTH>,
+        out_hash: &mut [u8; Ripemd160::DIGEST_LEN],
     ) -> Result<(), Error> {
         // get root path
         let path_root = Self::get_derivation_info()?;
@@ -89,6 +108,27 @@ impl Sign {
             return Err(Error::WrongLength);
         }
 
+        //We expect a path suffix of the form x/x
+        if suffix.components().len() != BIP32_PATH_SUFFIX_DEPTH {
+            return Err(Error::WrongLength);
+        }
+
+        let path_iter = path_root
+            .components()
+            .iter()
+            .chain(suffix.components())
+            .copied();
+
+        let full_path: BIP32Path<MAX_BIP32_PATH_DEPTH> =
+            BIP32Path::new(path_iter).map_err(|_| Error::DataInvalid)?;
+
+        Self::compute_keyhash(&full_path, out_hash)
+    }
+
+    fn disable_outputs(
+        list: &mut ObjectList<PathWrapper<BIP32_PATH_SUFFIX_DEPTH>>,
+        tx: &mut Transaction,
+    ) -> Result<(), Error> {
         let mut path_wrapper: MaybeUninit<PathWrapper<BIP32_PATH_SUFFIX_DEPTH>> =
             MaybeUninit::uninit();
 
@@ -97,25 +137,68 @@ impl Sign {
             let path_ptr = path_wrapper.as_mut_ptr();
             let suffix = unsafe { &(*path_ptr).path() };
 
-            //We expect a path suffix of the form x/x
-            if suffix.components().len() != BIP32_PATH_SUFFIX_DEPTH {
-                return Err(Error::WrongLength);
-            }
+            Self::suffix_keyhash(suffix, &mut address)?;
+
+            tx.disable_output_if(&address[..]);
+        }
+        Ok(())
+    }
 
-            let path_iter = path_root
-                .components()
-                .iter()
-                .chain(suffix.components())
-                .copied();
+    // the root path can be followed by the list of the
+    // path suffixes of the signers, in the change path format
+    fn split_init_data(init_data: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+        let depth = *init_data.first().ok_or(Error::WrongLength)? as usize;
+        let root_len = 1 + depth * U32_SIZE;
 
-            let full_path: BIP32Path<MAX_BIP32_PATH_DEPTH> =
-                BIP32Path::new(path_iter).map_err(|_| Error::DataInvalid)?;
+        if init_data.len() < root_len {
+            return Err(Error::WrongLength);
+        }
 
-            Self::compute_keyhash(&full_path, &mut address)?;
+        Ok(init_data.split_at(root_len))
+    }
 
-            tx.disable_output_if(&address[..]);
+    // the distinct addresses of the provided signers,
+    // which X and P-chain reviews show as the sender
+    #[inline(never)]
+    fn signer_addresses(
+        signers: &[u8],
+    ) -> Result<ArrayVec<[u8; ADDRESS_LEN], MAX_SIGNER_ADDRESSES>, Error> {
+        let mut addresses = ArrayVec::new();
+
+        // no list was sent along the root path
+        if signers.is_empty() {
+            return Ok(addresses);
         }
-        Ok(())
+
+        let mut list: MaybeUninit<ObjectList<PathWrapper<BIP32_PATH_SUFFIX_DEPTH>>> =
+            MaybeUninit::uninit();
+        let (rem, num_paths) = be_u8::<_, ParserError>(signers).map_err(|_| Error::DataInvalid)?;
+        let rem = ObjectList::new_into_with_len(rem, &mut list, num_paths as _)
+            .map_err(|_| Error::DataInvalid)?;
+        if !rem.is_empty() {
+            return Err(Error::DataInvalid);
+        }
+        let mut list = unsafe { list.assume_init() };
+
+        let mut path_wrapper: MaybeUninit<PathWrapper<BIP32_PATH_SUFFIX_DEPTH>> =
+            MaybeUninit::uninit();
+
+        let mut address = [0; ADDRESS_LEN];
+        while let Some(()) = list.parse_next(&mut path_wrapper) {
+            let path_ptr = path_wrapper.as_mut_ptr();
+            let suffix = unsafe { &(*path_ptr).path() };
+
+            Self::suffix_keyhash(suffix, &mut address)?;
+
+            // several inputs are often spent by the same key
+            if !addresses.contains(&address) {
+                addresses
+                    .try_push(address)
+                    .map_err(|_| Error::DataInvalid)?;
+            }
+        }
+
+        Ok(addresses)
     }
 
     #[inline(never)]
@@ -127,15 +210,16 @@ impl Sign {
         // read root path and store it in ram as during the
         // signing process and diseabling outputs we use it
         // to get a full path: root_path + path_suffix
-        let root_path = BIP32Path::read(init_data).map_err(|_| Error::DataInvalid)?;
+        let (root_path, signers) = Self::split_init_data(init_data)?;
+        let root_path = BIP32Path::read(root_path).map_err(|_| Error::DataInvalid)?;
         //We expect a path prefix of the form x'/x'/x'
         if root_path.components().len() != BIP32_PATH_PREFIX_DEPTH {
             return Err(Error::WrongLength);
         }
+        let scheme =
+            DerivationScheme::from_path(root_path.components()).ok_or(Error::InvalidPathPrefix)?;
 
-        unsafe {
-            PATH.lock(Self)?.replace(root_path);
-        }
+        let path = PathGuard::lock(Self, root_path)?;
 
         // then, get the change_path list.
         let mut path_list: MaybeUninit<ObjectList<PathWrapper<BIP32_PATH_SUFFIX_DEPTH>>> =
@@ -145,21 +229,46 @@ impl Sign {
             .map_err(|_| Error::DataInvalid)?;
         let mut path_list = unsafe { path_list.assume_init() };
 
-        let unsigned_hash = Self::sha256_digest(rem)?;
+        // amounts are formatted with the provided
+        // asset denomination during the review
+        unsafe {
+            _ = ASSET_INFO.lock(crate::parser::AssetDenomination);
+        }
 
         // parse transaction
         let mut tx = MaybeUninit::uninit();
         Transaction::new_into(rem, &mut tx).map_err(|_| Error::DataInvalid)?;
         let mut transaction = unsafe { tx.assume_init() };
+        Self::check_scheme(scheme, &transaction)?;
+
+        let unsigned_hash = transaction.digest_scheme().digest(rem)?;
 
         Self::disable_outputs(&mut path_list, &mut transaction)?;
 
-        let ui = SignUI {
+        // the signers of C-chain atomic transactions are
+        // not shown, the inputs of exports are EVM accounts
+        let info = Transaction::network_info(rem).map_err(|_| Error::DataInvalid)?;
+        let from = match info.chain_id {
+            ChainId::CChain => ArrayVec::new(),
+            ChainId::XChain | ChainId::PChain => Self::signer_addresses(signers)?,
+        };
+
+        let mut ui = SignUI {
             hash: unsigned_hash,
+            scheme,
+            hrp: info.network_id.hrp(),
+            from,
             transaction,
         };
 
-        crate::show_ui!(ui.show(flags))
+        // the pages added around the transaction
+        // could leave some of its items out of reach
+        ui.num_items().map_err(|_| Error::DataInvalid)?;
+
+        let tx = crate::show_ui!(ui.show(flags))?;
+        path.keep();
+
+        Ok(tx)
     }
 }
 
@@ -174,6 +283,10 @@ impl ApduHandler for Sign {
 
         *tx = 0;
 
+        if buffer.p1() == ZPacketType::Init as u8 {
+            check_not_busy(Self, Self)?;
+        }
+
         if let Some(upload) = Uploader::new(Self).upload(&buffer)? {
             *tx = Self::start_sign(upload.first, upload.data, flags)?;
         }
@@ -184,12 +297,99 @@ impl ApduHandler for Sign {
 
 pub(crate) struct SignUI {
     hash: [u8; Sign::SIGN_HASH_SIZE],
+    scheme: DerivationScheme,
+    // the network of the signer addresses
+    hrp: &'static str,
+    from: ArrayVec<[u8; ADDRESS_LEN], MAX_SIGNER_ADDRESSES>,
     transaction: Transaction<'static>,
 }
 
+impl SignUI {
+    fn render_from(
+        &self,
+        from_n: usize,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+
+        let raw = self.from.get(from_n).ok_or(ViewError::NoData)?;
+        let mut address = MaybeUninit::uninit();
+        Address::from_bytes_into(&raw[..], &mut address).map_err(|_| ViewError::Unknown)?;
+        let address = unsafe { address.assume_init() };
+
+        let label = pic_str!(b"From");
+        title[..label.len()].copy_from_slice(label);
+
+        let mut encoded = [0; MAX_ADDRESS_ENCODED_LEN];
+        let len = address
+            .encode_into(self.hrp, &mut encoded[..])
+            .map_err(|_| ViewError::Unknown)?;
+
+        handle_ui_message(&encoded[..len], message, page)
+    }
+
+    // the summary page is only shown in expert mode
+    fn summary(&self) -> Option<TxSummary> {
+        if !is_app_mode_expert() {
+            return None;
+        }
+        self.transaction.summary()
+    }
+
+    // as well as the utxos spent by the transaction
+    fn utxos(&self) -> Option<UtxoList> {
+        if !is_app_mode_expert() {
+            return None;
+        }
+        self.transaction.utxos()
+    }
+
+    // a provided asset denomination is reviewed
+    // before the amounts formatted with it
+    fn asset_info(&self) -> Option<AssetInfo> {
+        AssetDenomination::provided()
+    }
+
+    // outputs without value are left out of the review,
+    // in strict mode they are reviewed after a notice
+    fn zero_value_notice_with(&self, strict: bool) -> bool {
+        strict
+            && self
+                .transaction
+                .summary()
+                .map(|s| s.zero_outputs > 0)
+                .unwrap_or_default()
+    }
+
+    fn zero_value_notice(&self) -> bool {
+        self.zero_value_notice_with(strict_outputs_enabled())
+    }
+
+    // every page of the review, the transaction ones
+    // and the ones shown before and after them
+    fn items(&self) -> usize {
+        let notice_items = self.zero_value_notice() as usize;
+        let summary_items = self.summary().map(|s| s.num_items()).unwrap_or_default();
+        let asset_items = self.asset_info().map(|a| a.num_items()).unwrap_or_default();
+        let utxo_items = self.utxos().map(|u| u.num_items()).unwrap_or_default();
+        // the derivation scheme is shown last, in expert mode only
+        let scheme_items = is_app_mode_expert() as usize;
+
+        notice_items
+            + summary_items
+            + asset_items
+            + self.transaction.num_items()
+            + self.from.len()
+            + utxo_items
+            + scheme_items
+    }
+}
+
 impl Viewable for SignUI {
     fn num_items(&mut self) -> Result<u8, ViewError> {
-        Ok(self.transaction.num_items() as _)
+        ui_items(self.items())
     }
 
     #[inline(never)]
@@ -200,7 +400,60 @@ impl Viewable for SignUI {
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
-        self.transaction.render_item(item_n, title, message, page)
+        use bolos::{pic_str, PIC};
+
+        let mut item_n = item_n;
+
+        if self.zero_value_notice() {
+            if item_n == 0 {
+                let label = pic_str!(b"Warning");
+                title[..label.len()].copy_from_slice(label);
+                let content = pic_str!(b"Zero-value output");
+                return handle_ui_message(content, message, page);
+            }
+            item_n -= 1;
+        }
+
+        if let Some(summary) = self.summary() {
+            let summary_items = summary.num_items() as u8;
+            if item_n < summary_items {
+                return summary.render_item(item_n, title, message, page);
+            }
+            item_n -= summary_items;
+        }
+
+        if let Some(info) = self.asset_info() {
+            let asset_items = info.num_items() as u8;
+            if item_n < asset_items {
+                return info.render_item(item_n, title, message, page);
+            }
+            item_n -= asset_items;
+        }
+
+        let tx_items = self.transaction.num_items();
+        if (item_n as usize) < tx_items {
+            return self.transaction.render_item(item_n, title, message, page);
+        }
+        item_n -= tx_items as u8;
+
+        // a page per signer follows the transaction
+        if (item_n as usize) < self.from.len() {
+            return self.render_from(item_n as usize, title, message, page);
+        }
+        item_n -= self.from.len() as u8;
+
+        if let Some(utxos) = self.utxos() {
+            let utxo_items = utxos.num_items() as u8;
+            if item_n < utxo_items {
+                return utxos.render_item(item_n, title, message, page);
+            }
+            item_n -= utxo_items;
+        }
+
+        match item_n {
+            0 if is_app_mode_expert() => self.scheme.render(title, message, page),
+            _ => Err(ViewError::NoData),
+        }
     }
 
     fn accept(&mut self, _out: &mut [u8]) -> (usize, u16) {
@@ -221,6 +474,7 @@ impl Viewable for SignUI {
             let _ = PATH.lock(SignHash);
             let _ = HASH.lock(SignHash);
         }
+        clear_asset_info();
 
         (tx, Error::Success as _)
     }
@@ -231,7 +485,7 @@ impl Viewable for SignUI {
     }
 }
 
-fn cleanup_globals() -> Result<(), Error> {
+pub(crate) fn cleanup_globals() -> Result<(), Error> {
     unsafe {
         if let Ok(path) = PATH.acquire(Sign) {
             path.take();
@@ -239,8 +493,236 @@ fn cleanup_globals() -> Result<(), Error> {
             //let's release the lock for the future
             let _ = PATH.release(Sign);
         }
+
+        // the upload of the data, if interrupted
+        if let Ok(buffer) = BUFFER.acquire(Sign) {
+            buffer.reset();
+
+            //let's release the lock for the future
+            let _ = BUFFER.release(Sign);
+        }
     }
     //if we failed to aquire then someone else is using it anyways
 
+    clear_asset_info();
+
     Ok(())
 }
+
+// the provided asset denomination only applies to the reviewed transaction
+fn clear_asset_info() {
+    unsafe {
+        if let Ok(info) = ASSET_INFO.lock(Sign) {
+            info.take();
+
+            let _ = ASSET_INFO.release(Sign);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::prelude::v1::*;
+
+    fn vector(json: &str) -> Vec<u8> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn derivation_scheme() {
+        let x_chain = vector(include_str!(
+            "../../parser/testvectors/simple_transfer.json"
+        ));
+        let x_chain = Transaction::new(&x_chain).unwrap();
+
+        let c_chain = vector(include_str!("../../parser/testvectors/c_export_to_x.json"));
+        let c_chain = Transaction::new(&c_chain).unwrap();
+
+        // avalanche paths sign both
+        assert!(Sign::check_scheme(DerivationScheme::Avalanche, &x_chain).is_ok());
+        assert!(Sign::check_scheme(DerivationScheme::Avalanche, &c_chain).is_ok());
+
+        // ethereum paths only sign from the C-chain
+        assert!(Sign::check_scheme(DerivationScheme::Ethereum, &c_chain).is_ok());
+        assert_eq!(
+            Sign::check_scheme(DerivationScheme::Ethereum, &x_chain),
+            Err(Error::InvalidPathPrefix)
+        );
+    }
+
+    #[test]
+    fn x_chain_digest() {
+        use bolos::hash::Keccak;
+
+        let data = vector(include_str!(
+            "../../parser/testvectors/x_transfer_mainnet.json"
+        ));
+        let tx = Transaction::new(&data).unwrap();
+        assert!(matches!(tx, Transaction::Transfer(_)));
+        assert_eq!(tx.digest_scheme(), DigestScheme::Sha256);
+
+        let expected =
+            hex::decode("bc050b9819fd170ebb35d52468c38c16969ee890ee25401c534a982ee269b83b")
+                .unwrap();
+        let digest = tx.digest_scheme().digest(&data).unwrap();
+        assert_eq!(&digest[..], &expected[..]);
+
+        // not the hash of EVM transactions
+        assert_ne!(digest, Keccak::<32>::digest(&data).unwrap());
+    }
+
+    #[test]
+    fn zero_value_outputs() {
+        let data = vector(include_str!(
+            "../../parser/testvectors/simple_transfer.json"
+        ));
+        let items = Transaction::new(&data).unwrap().num_items();
+
+        // the first output, 0.000001 AVAX, moves no value now
+        let mut data = data;
+        data[82..90].copy_from_slice(&0u64.to_be_bytes());
+        let data: &'static [u8] = Box::leak(data.into_boxed_slice());
+
+        let ui = SignUI {
+            hash: [0; Sign::SIGN_HASH_SIZE],
+            scheme: DerivationScheme::Avalanche,
+            hrp: "avax",
+            from: ArrayVec::new(),
+            transaction: Transaction::new(data).unwrap(),
+        };
+
+        // its amount and address are left out
+        assert_eq!(ui.transaction.num_items(), items - 2);
+        assert_eq!(ui.transaction.summary().unwrap().zero_outputs, 1);
+
+        assert!(!ui.zero_value_notice_with(false));
+        assert!(ui.zero_value_notice_with(true));
+        assert!(!ui.zero_value_notice());
+    }
+
+    #[test]
+    fn review_items() {
+        let data = vector(include_str!(
+            "../../parser/testvectors/simple_transfer.json"
+        ));
+        let data: &'static [u8] = Box::leak(data.into_boxed_slice());
+
+        let mut ui = SignUI {
+            hash: [0; Sign::SIGN_HASH_SIZE],
+            scheme: DerivationScheme::Avalanche,
+            hrp: "avax",
+            from: ArrayVec::new(),
+            transaction: Transaction::new(data).unwrap(),
+        };
+
+        // the summary, a page per spent utxo and the scheme
+        // are reviewed in expert mode along the transaction
+        let utxos = ui.transaction.utxos().unwrap().num_items();
+        let expected = 1 + ui.transaction.num_items() + utxos + 1;
+        assert_eq!(ui.items(), expected);
+        assert_eq!(ui.num_items().unwrap() as usize, expected);
+    }
+
+    #[test]
+    fn init_data_signers() {
+        // m/44'/9000'/0'
+        let root = [
+            0x03, 0x80, 0x00, 0x00, 0x2c, 0x80, 0x00, 0x23, 0x28, 0x80, 0x00, 0x00, 0x00,
+        ];
+
+        // hosts sending only the root path
+        let (path, signers) = Sign::split_init_data(&root).unwrap();
+        assert_eq!(path, &root[..]);
+        assert!(signers.is_empty());
+
+        let mut data = root.to_vec();
+        data.extend_from_slice(&[0x01, 0x02, 0, 0, 0, 0, 0, 0, 0, 0x01]);
+        let (path, signers) = Sign::split_init_data(&data).unwrap();
+        assert_eq!(path, &root[..]);
+        assert_eq!(signers, &data[root.len()..]);
+
+        // a truncated root path
+        assert_eq!(
+            Sign::split_init_data(&root[..root.len() - 1]),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(Sign::split_init_data(&[]), Err(Error::WrongLength));
+    }
+
+    #[test]
+    #[serial]
+    fn from_address_pages() {
+        use crate::constants::{BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_1};
+
+        let root: BIP32Path<MAX_BIP32_PATH_DEPTH> =
+            BIP32Path::new([BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_1, 0x8000_0000]).unwrap();
+        let guard = PathGuard::lock(Sign, root).unwrap();
+
+        // 0/0, 0/1 and 0/0 again, spending another input
+        let signers = [
+            0x03, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0x02, 0, 0, 0, 0, 0, 0, 0, 0x01, 0x02, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ];
+        let from = Sign::signer_addresses(&signers).unwrap();
+        assert_eq!(from.len(), 2);
+
+        // trailing bytes after the list
+        let mut trailing = signers.to_vec();
+        trailing.push(0);
+        assert_eq!(
+            Sign::signer_addresses(&trailing).unwrap_err(),
+            Error::DataInvalid
+        );
+        drop(guard);
+
+        let data = vector(include_str!(
+            "../../parser/testvectors/simple_transfer.json"
+        ));
+        let data: &'static [u8] = Box::leak(data.into_boxed_slice());
+        let info = Transaction::network_info(data).unwrap();
+
+        let mut ui = SignUI {
+            hash: [0; Sign::SIGN_HASH_SIZE],
+            scheme: DerivationScheme::Avalanche,
+            hrp: info.network_id.hrp(),
+            from,
+            transaction: Transaction::new(data).unwrap(),
+        };
+
+        // the signer pages follow the transaction ones
+        let first = ui.summary().unwrap().num_items() + ui.transaction.num_items();
+        assert_eq!(ui.items(), first + 2 + ui.utxos().unwrap().num_items() + 1);
+
+        for (i, suffix) in [[0, 0], [0, 1]].iter().enumerate() {
+            // a fresh derivation of the address of the full path
+            let path: BIP32Path<MAX_BIP32_PATH_DEPTH> = BIP32Path::new([
+                BIP32_PATH_ROOT_0,
+                BIP32_PATH_ROOT_1,
+                0x8000_0000,
+                suffix[0],
+                suffix[1],
+            ])
+            .unwrap();
+            let mut keyhash = [0; ADDRESS_LEN];
+            Sign::compute_keyhash(&path, &mut keyhash).unwrap();
+
+            let mut address = MaybeUninit::uninit();
+            Address::from_bytes_into(&keyhash[..], &mut address).unwrap();
+            let mut expected = [0; MAX_ADDRESS_ENCODED_LEN];
+            let len = unsafe { address.assume_init() }
+                .encode_into(info.network_id.hrp(), &mut expected[..])
+                .unwrap();
+
+            let mut title = [0; 18];
+            let mut message = [0; 128];
+            ui.render_item((first + i) as u8, &mut title, &mut message, 0)
+                .unwrap();
+
+            assert_eq!(&title[..5], b"From\0");
+            assert_eq!(&message[..len], &expected[..len]);
+            assert_eq!(message[len], 0);
+        }
+    }
+}
@@ -14,36 +14,67 @@
 *  limitations under the License.
 ********************************************************************************/
 
-pub mod get_app_configuration;
-pub mod personal_msg;
-pub mod provide_erc20;
-pub mod provide_nft_info;
-pub mod public_key;
-pub mod set_plugin;
-pub mod signing;
+cfg_if::cfg_if! {
+    if #[cfg(feature = "evm")] {
+        pub mod get_app_configuration;
+        pub mod get_challenge;
+        pub mod personal_msg;
+        #[cfg(feature = "chain-config")]
+        pub mod provide_chain_config;
+        #[cfg(feature = "address-denylist")]
+        pub mod provide_denylist;
+        pub mod provide_domain;
+        pub mod provide_erc20;
+        pub mod provide_nft_info;
+        pub mod provide_recipient;
+        pub mod public_key;
+        pub mod set_plugin;
+        pub mod signing;
+    }
+}
 
 mod utils {
     pub mod u256;
 
     use crate::constants::ApduError as Error;
-    use crate::{constants::MAX_BIP32_PATH_DEPTH, parser::ParserError, utils::ApduPanic};
-    use bolos::crypto::bip32::BIP32Path;
+    use crate::{
+        constants::{BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_ETH, MAX_BIP32_PATH_DEPTH},
+        parser::{ParserError, ADDRESS_LEN},
+        utils::{hex_encode, ApduPanic},
+    };
+    use bolos::{
+        crypto::bip32::BIP32Path,
+        hash::{Hasher, Keccak},
+    };
     use nom::{bytes::complete::take, number::complete::le_u8};
 
     /// Parse a BIP32 path
     ///
     /// This function is here to guarantee the parsing
     /// is fixed and the same as what the eth app does
-    pub fn parse_bip32_eth(
-        data: &[u8],
-    ) -> Result<(&[u8], BIP32Path<MAX_BIP32_PATH_DEPTH>), nom::Err<ParserError>> {
-        let (rem, len) = le_u8(data)?;
+    ///
+    /// The path must start with m/44'/60', avalanche paths
+    /// (m/44'/9000') are only used by the avax instructions
+    pub fn parse_bip32_eth(data: &[u8]) -> Result<(&[u8], BIP32Path<MAX_BIP32_PATH_DEPTH>), Error> {
+        let (rem, len) = le_u8::<_, ParserError>(data).map_err(|_| Error::DataInvalid)?;
+
+        if len as usize > MAX_BIP32_PATH_DEPTH {
+            return Err(Error::PathTooDeep);
+        }
 
-        let (rem, components) = take(len as usize * 4)(rem)?;
+        let (rem, components) =
+            take::<_, _, ParserError>(len as usize * 4)(rem).map_err(|_| Error::DataInvalid)?;
         let components: &[[u8; 4]] = bytemuck::try_cast_slice(components).apdu_unwrap();
 
+        let mut iter = components.iter().map(|n| u32::from_be_bytes(*n));
+        let purpose = iter.next();
+        let coin_type = iter.next();
+        if purpose != Some(BIP32_PATH_ROOT_0) || coin_type != Some(BIP32_PATH_ROOT_ETH) {
+            return Err(Error::InvalidPathPrefix);
+        }
+
         let path = BIP32Path::new(components.iter().map(|n| u32::from_be_bytes(*n)))
-            .map_err(|_| ParserError::ValueOutOfRange)?;
+            .map_err(|_| Error::DataInvalid)?;
 
         Ok((rem, path))
     }
@@ -65,7 +96,7 @@ mod utils {
         // otherwise tx is probably legacy so no version, just rlp data
         let version = *data.first().ok_or(Error::DataInvalid)?;
         match version {
-            0x01 | 0x02 => {
+            0x01 | 0x02 | 0x04 => {
                 data = data.get(1..).ok_or(Error::DataInvalid)?;
                 read += 1;
             }
@@ -99,5 +130,117 @@ mod utils {
             _ => Err(Error::DataInvalid),
         }
     }
+
+    /// Hex encodes `address` with the EIP-55 mixed case checksum,
+    /// without the 0x prefix
+    pub fn eip55_encode(
+        address: &[u8; ADDRESS_LEN],
+        out: &mut [u8; ADDRESS_LEN * 2],
+    ) -> Result<(), Error> {
+        hex_encode(address, &mut out[..]).map_err(|_| Error::ExecutionError)?;
+
+        // letters are uppercased when the nibble of
+        // keccak256(lowercase address) at the same index is >= 8
+        let hash = Keccak::<32>::digest(&out[..]).map_err(|_| Error::ExecutionError)?;
+        for (i, c) in out.iter_mut().enumerate() {
+            let nibble = (hash[i / 2] >> (4 * (1 - i % 2))) & 0x0f;
+            if nibble >= 8 {
+                c.make_ascii_uppercase();
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::constants::BIP32_PATH_ROOT_1;
+
+        #[test]
+        fn eip55_checksum() {
+            // from the EIP-55 test cases
+            let cases = [
+                "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+                "fB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+                "dbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+                "D1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+            ];
+
+            for case in cases {
+                let bytes = hex::decode(case).unwrap();
+                let address = arrayref::array_ref!(bytes, 0, ADDRESS_LEN);
+
+                let mut out = [0; ADDRESS_LEN * 2];
+                eip55_encode(address, &mut out).unwrap();
+                assert_eq!(&out[..], case.as_bytes());
+            }
+        }
+
+        fn encode_path(components: &[u32]) -> std::vec::Vec<u8> {
+            let mut data = std::vec![components.len() as u8];
+            for c in components {
+                data.extend_from_slice(&c.to_be_bytes());
+            }
+            data
+        }
+
+        const HARDENED: u32 = 0x8000_0000;
+
+        #[test]
+        fn parse_valid_paths() {
+            let eth = [BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_ETH, HARDENED, 0, 0];
+            let (_, path) = parse_bip32_eth(&encode_path(&eth)).unwrap();
+            assert_eq!(path.components(), &eth[..]);
+
+            let mut deepest = [0; MAX_BIP32_PATH_DEPTH];
+            deepest[0] = BIP32_PATH_ROOT_0;
+            deepest[1] = BIP32_PATH_ROOT_ETH;
+            let (_, path) = parse_bip32_eth(&encode_path(&deepest)).unwrap();
+            assert_eq!(path.components().len(), MAX_BIP32_PATH_DEPTH);
+        }
+
+        #[test]
+        fn parse_too_deep_path() {
+            let mut path = [0; MAX_BIP32_PATH_DEPTH + 1];
+            path[0] = BIP32_PATH_ROOT_0;
+            path[1] = BIP32_PATH_ROOT_ETH;
+
+            let err = parse_bip32_eth(&encode_path(&path)).unwrap_err();
+            assert_eq!(err, Error::PathTooDeep);
+        }
+
+        #[test]
+        fn parse_wrong_prefix() {
+            // bitcoin coin type
+            let btc = [BIP32_PATH_ROOT_0, HARDENED, HARDENED, 0, 0];
+            let err = parse_bip32_eth(&encode_path(&btc)).unwrap_err();
+            assert_eq!(err, Error::InvalidPathPrefix);
+
+            // avalanche paths are not used for evm instructions
+            let avax = [BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_1, HARDENED, 0, 0];
+            let err = parse_bip32_eth(&encode_path(&avax)).unwrap_err();
+            assert_eq!(err, Error::InvalidPathPrefix);
+
+            let no_purpose = [BIP32_PATH_ROOT_ETH, HARDENED, 0, 0];
+            let err = parse_bip32_eth(&encode_path(&no_purpose)).unwrap_err();
+            assert_eq!(err, Error::InvalidPathPrefix);
+
+            let err = parse_bip32_eth(&encode_path(&[])).unwrap_err();
+            assert_eq!(err, Error::InvalidPathPrefix);
+        }
+
+        #[test]
+        fn parse_truncated_path() {
+            let mut data = encode_path(&[BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_ETH, HARDENED]);
+            data.truncate(data.len() - 2);
+
+            let err = parse_bip32_eth(&data).unwrap_err();
+            assert_eq!(err, Error::DataInvalid);
+        }
+    }
 }
 pub use utils::u256::{u256, BorrowedU256};
+
+#[cfg(feature = "host")]
+pub use utils::get_tx_rlp_len;
@@ -0,0 +1,67 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use crate::{
+    constants::ApduError as Error,
+    dispatcher::ApduHandler,
+    sys,
+    utils::{random_u32, ApduBufferRead},
+};
+
+#[bolos::lazy_static]
+static mut CHALLENGE: Option<u32> = None;
+
+/// Return a random challenge, to be part of the next
+/// trusted name provided, as done by app-ethereum
+///
+/// The response is the challenge as a big endian u32
+pub struct GetChallenge;
+
+impl GetChallenge {
+    /// Takes the last challenge given to the host,
+    /// so each one is only accepted once
+    pub fn take() -> Option<u32> {
+        unsafe { CHALLENGE.take() }
+    }
+
+    #[cfg(test)]
+    pub fn set(challenge: u32) {
+        unsafe { CHALLENGE.replace(challenge) };
+    }
+}
+
+impl ApduHandler for GetChallenge {
+    #[inline(never)]
+    fn handle<'apdu>(
+        _: &mut u32,
+        tx: &mut u32,
+        buffer: ApduBufferRead<'apdu>,
+    ) -> Result<(), Error> {
+        sys::zemu_log_stack("GetChallenge::handle\x00");
+
+        // a new challenge every time, the previous one is forgotten
+        let challenge = random_u32();
+        unsafe {
+            CHALLENGE.replace(challenge);
+        }
+
+        let buffer = buffer.write();
+        buffer[..4].copy_from_slice(&challenge.to_be_bytes());
+
+        *tx = 4;
+
+        Ok(())
+    }
+}
@@ -27,14 +27,17 @@ use crate::{
     constants::{ApduError as Error, MAX_BIP32_PATH_DEPTH},
     crypto::{Curve, ECCInfoFlags},
     dispatcher::ApduHandler,
-    handlers::resources::{BUFFER, PATH},
+    handlers::{
+        check_not_busy,
+        resources::{BUFFER, PATH},
+    },
     parser::{DisplayableItem, FromBytes, ParserError, PersonalMsg},
     sys,
     utils::ApduBufferRead,
 };
 
 use super::utils::parse_bip32_eth;
-use crate::utils::convert_der_to_rs;
+use crate::utils::{convert_der_to_rs, normalize_low_s};
 
 pub struct Sign;
 
@@ -112,6 +115,23 @@ impl ApduHandler for Sign {
 
         *tx = 0;
 
+        // release what a failed session locked, as eth signing does
+        Self::handle_packet(flags, tx, buffer).map_err(|e| {
+            if !matches!(e, Error::Busy) {
+                let _ = cleanup_globals();
+            }
+            e
+        })
+    }
+}
+
+impl Sign {
+    #[inline(never)]
+    fn handle_packet(
+        flags: &mut u32,
+        tx: &mut u32,
+        buffer: ApduBufferRead<'_>,
+    ) -> Result<(), Error> {
         // hw-app-eth encodes the packet type in p1
         // with 0x00 being init and 0x80 being next
         //
@@ -127,8 +147,11 @@ impl ApduHandler for Sign {
                 let payload = buffer.payload().map_err(|_| Error::WrongLength)?;
 
                 //parse path to verify it's the data we expect
-                let (rest, bip32_path) =
-                    parse_bip32_eth(payload).map_err(|_| Error::DataInvalid)?;
+                let (rest, bip32_path) = parse_bip32_eth(payload)?;
+
+                //don't take over the resources of another signing session,
+                // it has to be completed or reset first
+                check_not_busy(Self, Self)?;
 
                 unsafe {
                     PATH.lock(Self)?.replace(bip32_path);
@@ -234,7 +257,12 @@ impl Viewable for SignUI {
                     // this is consistent with the fact that in `convert_der_to_rs`
                     // we put the bytes at the end of the buffer first
                     let r = &r[1..];
-                    let s = &s[1..];
+                    let s = arrayref::array_mut_ref!(s, 1, 32);
+
+                    //V has to follow S when it gets replaced by `n - S`
+                    if normalize_low_s(s) {
+                        out[0] ^= 1;
+                    }
 
                     out[tx..][..32].copy_from_slice(r);
                     tx += 32;
@@ -255,7 +283,7 @@ impl Viewable for SignUI {
     }
 }
 
-fn cleanup_globals() -> Result<(), Error> {
+pub(crate) fn cleanup_globals() -> Result<(), Error> {
     unsafe {
         if let Ok(path) = PATH.acquire(Sign) {
             path.take();
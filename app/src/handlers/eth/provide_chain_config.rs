@@ -0,0 +1,162 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use crate::{constants::ApduError as Error, dispatcher::ApduHandler, sys, utils::ApduBufferRead};
+
+pub struct ProvideChainConfig;
+
+impl ProvideChainConfig {
+    fn process(input: &[u8]) -> Result<(), Error> {
+        use crate::{
+            constants::{CHAIN_CONFIG_PUBLIC_KEY, CHAIN_CONFIG_TYPE},
+            crypto::Curve,
+        };
+
+        let rem = match input.split_first() {
+            Some((&kind, rem)) if kind == CHAIN_CONFIG_TYPE => rem,
+            _ => return Err(Error::DataInvalid),
+        };
+
+        let mut config = core::mem::MaybeUninit::uninit();
+        let signature = crate::parser::FromBytes::from_bytes_into(rem, &mut config)
+            .map_err(|_| Error::DataInvalid)?;
+
+        // the type, chain id, symbol length, symbol and decimals
+        let signed = &input[..input.len() - signature.len()];
+
+        let key = CHAIN_CONFIG_PUBLIC_KEY.ok_or(Error::DataInvalid)?;
+        if signature.is_empty() || !Curve.verify(&key, signed, signature) {
+            return Err(Error::DataInvalid);
+        }
+
+        let config: crate::parser::ChainConfig = unsafe { config.assume_init() };
+
+        // store the currency to show the value of eth transactions in
+        unsafe {
+            crate::handlers::resources::CHAIN_CONFIG
+                .lock(Self)?
+                .insert(config);
+        }
+
+        Ok(())
+    }
+}
+
+impl ApduHandler for ProvideChainConfig {
+    #[inline(never)]
+    fn handle<'apdu>(
+        _flags: &mut u32,
+        tx: &mut u32,
+        buffer: ApduBufferRead<'apdu>,
+    ) -> Result<(), Error> {
+        sys::zemu_log_stack("ProvideChainConfig::handle\x00");
+
+        *tx = 0;
+
+        // type, chain id, symbol length, symbol, decimals and the DER signature
+        let payload = buffer.payload().map_err(|_| Error::WrongLength)?;
+
+        ProvideChainConfig::process(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        handlers::{eth::signing::Sign, resources::CHAIN_CONFIG},
+        parser::{parse_rlp_item, DisplayableItem, FromBytes, Legacy, NativeCurrency},
+    };
+    use serial_test::serial;
+    use std::prelude::v1::*;
+
+    // AVAX on the C-chain, 43114, with 18 decimals
+    const MAINNET: &str = "02000000000000a86a0441564158123045022100eb5d496431dd0d65ce5ee65e6e06b0a00b6b06cae9d2d51bc45e5bf340b5ae93022002ec7a3fc85cc4cc12fc161651fb73a9388ca167fc1d0c0cb73aea104f26c454";
+    // GAS on a subnet-EVM chain, 12345678, with 6 decimals
+    const SUBNET: &str = "020000000000bc614e0347415306304402202ea63b66704280aaad3b66f001b0b000f80976637afb5ee5c105d6a8c2c922c402207afb0b93f751ccfea1d7114644da3fb8c9a2e7db68385ad9b3e36198694dabd0";
+    // type, chain id, symbol length, symbol and decimals
+    const SUBNET_LEN: usize = 1 + 8 + 1 + 3 + 1;
+
+    fn render_transfer(data: &str) -> Vec<u8> {
+        let data = hex::decode(data).unwrap();
+        let (_, bytes) = parse_rlp_item(&data).unwrap();
+        let (_, tx) = Legacy::from_bytes(bytes).unwrap();
+
+        unsafe { CHAIN_CONFIG.lock(NativeCurrency) }.unwrap();
+        let mut title = [0; 32];
+        let mut message = [0; 64];
+        tx.render_item(0, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..9], b"Transfer\0");
+
+        let len = message.iter().position(|b| *b == 0).unwrap();
+        message[..len].to_vec()
+    }
+
+    #[test]
+    #[serial]
+    fn mainnet_config() {
+        ProvideChainConfig::process(&hex::decode(MAINNET).unwrap()).unwrap();
+
+        let table = unsafe { CHAIN_CONFIG.lock(Sign) }.unwrap();
+        let config = table.find(43114).unwrap();
+        assert_eq!(config.symbol(), b"AVAX");
+        assert_eq!(config.decimals(), 18);
+
+        // 0.01234 AVAX on chain 43114
+        let amount = render_transfer("ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080");
+        assert_eq!(amount, b"AVAX 0.01234");
+
+        unsafe { CHAIN_CONFIG.lock(Sign) }.unwrap().clear();
+    }
+
+    #[test]
+    #[serial]
+    fn subnet_config() {
+        // 1.5 GAS on chain 12345678
+        let tx = "ea018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c79528316e3608083bc614e8080";
+
+        // shown in AVAX while the chain is unknown
+        unsafe { CHAIN_CONFIG.lock(Sign) }.unwrap().clear();
+        assert_eq!(render_transfer(tx), b"AVAX 0.0000000000015");
+
+        ProvideChainConfig::process(&hex::decode(SUBNET).unwrap()).unwrap();
+        assert_eq!(render_transfer(tx), b"GAS 1.5");
+
+        unsafe { CHAIN_CONFIG.lock(Sign) }.unwrap().clear();
+    }
+
+    #[test]
+    #[serial]
+    fn tampered_config() {
+        let data = hex::decode(SUBNET).unwrap();
+
+        // every signed field is covered
+        for at in [0, 8, 9, 10, SUBNET_LEN - 1] {
+            let mut tampered = data.clone();
+            tampered[at] ^= 0x01;
+            assert_eq!(
+                ProvideChainConfig::process(&tampered),
+                Err(Error::DataInvalid)
+            );
+        }
+
+        // unsigned
+        assert_eq!(
+            ProvideChainConfig::process(&data[..SUBNET_LEN]),
+            Err(Error::DataInvalid)
+        );
+        assert!(unsafe { CHAIN_CONFIG.lock(Sign) }.unwrap().is_empty());
+    }
+}
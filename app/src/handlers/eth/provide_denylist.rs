@@ -0,0 +1,140 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use nom::{bytes::complete::take, number::complete::be_u8};
+
+use crate::{
+    constants::ApduError as Error,
+    dispatcher::ApduHandler,
+    parser::{ParserError, ADDRESS_LEN},
+    sys,
+    utils::{
+        address_denylist::{store_denied_addresses, MAX_DENIED_ADDRESSES},
+        ApduBufferRead,
+    },
+};
+
+pub struct ProvideDenylist;
+
+impl ProvideDenylist {
+    fn process(input: &[u8]) -> Result<(), Error> {
+        use crate::{
+            constants::{DENYLIST_PUBLIC_KEY, DENYLIST_TYPE},
+            crypto::Curve,
+        };
+
+        let (rem, kind) = be_u8::<_, ParserError>(input).map_err(|_| Error::DataInvalid)?;
+        if kind != DENYLIST_TYPE {
+            return Err(Error::DataInvalid);
+        }
+
+        let (rem, count) = be_u8::<_, ParserError>(rem).map_err(|_| Error::DataInvalid)?;
+        if count as usize > MAX_DENIED_ADDRESSES {
+            return Err(Error::DataInvalid);
+        }
+
+        let (signature, addresses) = take::<_, _, ParserError>(count as usize * ADDRESS_LEN)(rem)
+            .map_err(|_| Error::DataInvalid)?;
+
+        // the type, the count and the addresses
+        let signed = &input[..input.len() - signature.len()];
+
+        let key = DENYLIST_PUBLIC_KEY.ok_or(Error::DataInvalid)?;
+        if signature.is_empty() || !Curve.verify(&key, signed, signature) {
+            return Err(Error::DataInvalid);
+        }
+
+        let mut list = [[0; ADDRESS_LEN]; MAX_DENIED_ADDRESSES];
+        for (address, chunk) in list.iter_mut().zip(addresses.chunks_exact(ADDRESS_LEN)) {
+            address.copy_from_slice(chunk);
+        }
+
+        // the whole list is replaced, an empty one clears it
+        store_denied_addresses(&list[..count as usize]);
+
+        Ok(())
+    }
+}
+
+impl ApduHandler for ProvideDenylist {
+    #[inline(never)]
+    fn handle<'apdu>(
+        _flags: &mut u32,
+        tx: &mut u32,
+        buffer: ApduBufferRead<'apdu>,
+    ) -> Result<(), Error> {
+        sys::zemu_log_stack("ProvideDenylist::handle\x00");
+
+        *tx = 0;
+
+        // type, count, addresses and the DER signature
+        let payload = buffer.payload().map_err(|_| Error::WrongLength)?;
+
+        ProvideDenylist::process(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::address_denylist::denied_addresses;
+    use serial_test::serial;
+
+    // 0x28ee52a8f3d6e5d15f8b131996950d7f296c7952 and 0x1111111111111111111111111111111111111111
+    const DENYLIST: &str = "010228ee52a8f3d6e5d15f8b131996950d7f296c795211111111111111111111111111111111111111113045022100acb4a18c3e771a668cea434f10f2064f0dd57d78e72ae8cb0557c76945d5783e0220734a93e170cd786b776dec08d53585398b5176a06ad3a1b2319dc10ffaa70474";
+    const EMPTY: &str = "01003044022044f5d5ba24e25b6948e502f71a3130e0bc0a61c67f946dfa64a151f74e782f4002207df457fc05512cedd82871cd1cda3bccf2aa87b44e45e5d3143ba6d603ac7690";
+
+    #[test]
+    #[serial]
+    fn store_denylist() {
+        ProvideDenylist::process(&hex::decode(DENYLIST).unwrap()).unwrap();
+
+        let denied = denied_addresses();
+        assert_eq!(denied.len(), 2);
+        assert_eq!(
+            &denied[0][..],
+            &hex::decode("28ee52a8f3d6e5d15f8b131996950d7f296c7952").unwrap()[..]
+        );
+        assert_eq!(denied[1], [0x11; ADDRESS_LEN]);
+
+        ProvideDenylist::process(&hex::decode(EMPTY).unwrap()).unwrap();
+        assert!(denied_addresses().is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn tampered_denylist() {
+        let data = hex::decode(DENYLIST).unwrap();
+        let signed_len = 2 + 2 * ADDRESS_LEN;
+
+        for at in [0, 1, 2, signed_len - 1] {
+            let mut tampered = data.clone();
+            tampered[at] ^= 0x01;
+            assert_eq!(ProvideDenylist::process(&tampered), Err(Error::DataInvalid));
+        }
+
+        // unsigned
+        assert_eq!(
+            ProvideDenylist::process(&data[..signed_len]),
+            Err(Error::DataInvalid)
+        );
+
+        // more addresses than can be kept
+        let mut too_many = data;
+        too_many[1] = MAX_DENIED_ADDRESSES as u8 + 1;
+        assert_eq!(ProvideDenylist::process(&too_many), Err(Error::DataInvalid));
+        assert!(denied_addresses().is_empty());
+    }
+}
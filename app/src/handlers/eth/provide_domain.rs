@@ -0,0 +1,176 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use crate::{
+    constants::ApduError as Error, dispatcher::ApduHandler,
+    handlers::eth::get_challenge::GetChallenge, sys, utils::ApduBufferRead,
+};
+
+pub struct ProvideDomain;
+
+impl ProvideDomain {
+    // the structure is sent in one packet, marked as the first one
+    const FIRST_CHUNK: u8 = 0x01;
+
+    fn process(input: &[u8]) -> Result<(), Error> {
+        use crate::{
+            constants::{DOMAIN_INFO_ALGORITHM_ID, DOMAIN_INFO_KEY_ID, DOMAIN_INFO_PUBLIC_KEY},
+            crypto::Curve,
+            parser::{FromBytes, TrustedName},
+        };
+
+        // whatever the outcome, a challenge is only used once
+        let challenge = GetChallenge::take();
+
+        let mut name = core::mem::MaybeUninit::uninit();
+        let rem = TrustedName::from_bytes_into(input, &mut name).map_err(|_| Error::DataInvalid)?;
+        if !rem.is_empty() {
+            return Err(Error::DataInvalid);
+        }
+        let name = unsafe { name.assume_init() };
+
+        if name.key_id != DOMAIN_INFO_KEY_ID || name.algorithm_id != DOMAIN_INFO_ALGORITHM_ID {
+            return Err(Error::DataInvalid);
+        }
+
+        // the name was signed for this device and session
+        if challenge != Some(name.challenge) {
+            return Err(Error::DataInvalid);
+        }
+
+        if !Curve.verify(&DOMAIN_INFO_PUBLIC_KEY, name.signed, name.signature) {
+            return Err(Error::DataInvalid);
+        }
+
+        // store the name to be shown along the recipient of eth transactions
+        unsafe {
+            crate::handlers::resources::DOMAIN_INFO
+                .lock(Self)?
+                .replace(name.info);
+        }
+
+        Ok(())
+    }
+}
+
+impl ApduHandler for ProvideDomain {
+    #[inline(never)]
+    fn handle<'apdu>(
+        _flags: &mut u32,
+        tx: &mut u32,
+        buffer: ApduBufferRead<'apdu>,
+    ) -> Result<(), Error> {
+        sys::zemu_log_stack("ProvideDomain::handle\x00");
+
+        *tx = 0;
+
+        if buffer.p1() != Self::FIRST_CHUNK {
+            return Err(Error::InvalidP1P2);
+        }
+
+        // the length of the trusted name, then its fields
+        let payload = buffer.payload().map_err(|_| Error::WrongLength)?;
+        let (len, fields) = match payload {
+            [hi, lo, fields @ ..] => (u16::from_be_bytes([*hi, *lo]) as usize, fields),
+            _ => return Err(Error::WrongLength),
+        };
+
+        if len != fields.len() {
+            return Err(Error::WrongLength);
+        }
+
+        ProvideDomain::process(fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::{eth::signing::Sign, resources::DOMAIN_INFO};
+    use serial_test::serial;
+    use std::prelude::v1::*;
+
+    // "zondax.eth" resolving to 0x11..11 on the C-chain, for
+    // the challenge 0x01020304, signed by the trusted provider
+    const SIGNED_INFO: &str = "010103020102120401020304130100140101200a7a6f6e6461782e657468221411111111111111111111111111111111111111112302a86a70010171010615463044022016838808e78e10a4cbb6e6d113e7e57bf81b9a698c815f00669c6610cea0622402207f0d3dce1fac6b584dbae486a1f750c676c6dbeb4cdc52f5ed19190c20763465";
+    const CHALLENGE: u32 = 0x01020304;
+    // every field but the signature
+    const SIGNED_LEN: usize = 62;
+
+    #[test]
+    #[serial]
+    fn signed_info() {
+        let data = hex::decode(SIGNED_INFO).unwrap();
+        GetChallenge::set(CHALLENGE);
+        ProvideDomain::process(&data).unwrap();
+
+        let info = unsafe { DOMAIN_INFO.lock(Sign) }.unwrap().take().unwrap();
+        assert_eq!(info.name(), b"zondax.eth");
+        assert_eq!(info.address().raw_address(), &[0x11; 20]);
+        assert_eq!(info.chain_id(), 43114);
+    }
+
+    #[test]
+    #[serial]
+    fn challenge() {
+        let data = hex::decode(SIGNED_INFO).unwrap();
+
+        // none was requested
+        let _ = GetChallenge::take();
+        assert_eq!(ProvideDomain::process(&data), Err(Error::DataInvalid));
+
+        // another one was
+        GetChallenge::set(CHALLENGE + 1);
+        assert_eq!(ProvideDomain::process(&data), Err(Error::DataInvalid));
+
+        // each one is only accepted once
+        GetChallenge::set(CHALLENGE);
+        ProvideDomain::process(&data).unwrap();
+        assert_eq!(ProvideDomain::process(&data), Err(Error::DataInvalid));
+
+        unsafe { DOMAIN_INFO.lock(Sign) }.unwrap().take();
+    }
+
+    #[test]
+    #[serial]
+    fn tampered_info() {
+        let data = hex::decode(SIGNED_INFO).unwrap();
+
+        // the name, the address and the chain id are covered
+        for at in [20, 32, 55] {
+            let mut tampered = data.clone();
+            tampered[at] ^= 0x01;
+            GetChallenge::set(CHALLENGE);
+            assert_eq!(ProvideDomain::process(&tampered), Err(Error::DataInvalid));
+        }
+
+        // tampered signature
+        let mut tampered = data.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 0xff;
+        GetChallenge::set(CHALLENGE);
+        assert_eq!(ProvideDomain::process(&tampered), Err(Error::DataInvalid));
+
+        // unsigned
+        GetChallenge::set(CHALLENGE);
+        assert_eq!(
+            ProvideDomain::process(&data[..SIGNED_LEN]),
+            Err(Error::DataInvalid)
+        );
+
+        // rejected names are not stored
+        assert!(unsafe { DOMAIN_INFO.lock(Sign) }.unwrap().is_none());
+    }
+}
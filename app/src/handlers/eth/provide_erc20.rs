@@ -17,13 +17,248 @@ use crate::{constants::ApduError as Error, dispatcher::ApduHandler, sys, utils::
 
 pub struct ProvideERC20;
 
+#[cfg(feature = "erc20")]
+impl ProvideERC20 {
+    fn process(input: &[u8]) -> Result<(), Error> {
+        use crate::{constants::CAL_PUBLIC_KEY, crypto::Curve};
+
+        let mut info = core::mem::MaybeUninit::uninit();
+        let signature = crate::parser::FromBytes::from_bytes_into(input, &mut info)
+            .map_err(|_| Error::DataInvalid)?;
+
+        // the crypto asset list signs the ticker, contract address,
+        // decimals and chain id, that is everything but the ticker length
+        let signed = &input[1..input.len() - signature.len()];
+
+        if signature.is_empty() || !Curve.verify(&CAL_PUBLIC_KEY, signed, signature) {
+            return Err(Error::DataInvalid);
+        }
+
+        let info = unsafe { info.assume_init() };
+
+        // store the information to be used when parsing erc20 calls
+        unsafe {
+            crate::handlers::resources::ERC20_INFO
+                .lock(Self)?
+                .insert(info);
+        }
+
+        Ok(())
+    }
+
+    fn clear() -> Result<(), Error> {
+        unsafe {
+            crate::handlers::resources::ERC20_INFO.lock(Self)?.clear();
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "erc20"))]
+impl ProvideERC20 {
+    fn process(_: &[u8]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn clear() -> Result<(), Error> {
+        Ok(())
+    }
+}
+
 impl ApduHandler for ProvideERC20 {
     #[inline(never)]
-    fn handle<'apdu>(_: &mut u32, tx: &mut u32, _: ApduBufferRead<'apdu>) -> Result<(), Error> {
+    fn handle<'apdu>(
+        _flags: &mut u32,
+        tx: &mut u32,
+        buffer: ApduBufferRead<'apdu>,
+    ) -> Result<(), Error> {
         sys::zemu_log_stack("ProvideERC20::handle\x00");
 
         *tx = 0;
 
+        // the blob as given by the crypto asset list:
+        // ticker length, ticker, contract address, decimals,
+        // chain id and the DER signature
+        let payload = buffer.payload().map_err(|_| Error::WrongLength)?;
+
+        ProvideERC20::process(payload)
+    }
+}
+
+/// Provides several tokens at once, each record prefixed by its length
+///
+/// The first packet (P1 = 0x00) replaces the previously provided tokens
+/// while the following ones (P1 = 0x80) add to them.
+pub struct ProvideERC20Batch;
+
+impl ProvideERC20Batch {
+    fn process(p1: u8, mut records: &[u8]) -> Result<(), Error> {
+        match p1 {
+            0x00 => ProvideERC20::clear()?,
+            0x80 => {}
+            _ => return Err(Error::InvalidP1P2),
+        }
+
+        if records.is_empty() {
+            return Err(Error::DataInvalid);
+        }
+
+        while let Some((&len, rem)) = records.split_first() {
+            let len = len as usize;
+            if len == 0 || len > rem.len() {
+                return Err(Error::DataInvalid);
+            }
+
+            let (record, rem) = rem.split_at(len);
+            ProvideERC20::process(record)?;
+            records = rem;
+        }
+
         Ok(())
     }
 }
+
+impl ApduHandler for ProvideERC20Batch {
+    #[inline(never)]
+    fn handle<'apdu>(
+        _flags: &mut u32,
+        tx: &mut u32,
+        buffer: ApduBufferRead<'apdu>,
+    ) -> Result<(), Error> {
+        sys::zemu_log_stack("ProvideERC20Batch::handle\x00");
+
+        *tx = 0;
+
+        let p1 = buffer.p1();
+        let payload = buffer.payload().map_err(|_| Error::WrongLength)?;
+
+        ProvideERC20Batch::process(p1, payload)
+    }
+}
+
+#[cfg(all(test, feature = "erc20"))]
+mod tests {
+    use super::*;
+    use crate::{
+        handlers::{eth::signing::Sign, resources::ERC20_INFO},
+        parser::{Address, FromBytes},
+    };
+    use serial_test::serial;
+    use std::prelude::v1::*;
+
+    // USDT on chain 1, with 6 decimals, signed by the test CAL key
+    const SIGNED_INFO: &str = "0455534454dac17f958d2ee523a2206206994597c13d831ec7000000060000000130450221008cbe9325fdf5200b894f871d8b51ec87bbfb7415035c0eec160dc5ac052b1c13022037e7c60d52909d566f342cbfe167ea5d32b8ba4bc56327427d3a9c230eeddfc6";
+    // ticker length, ticker, address, decimals and chain id
+    const INFO_LEN: usize = 1 + 4 + 20 + 4 + 4;
+
+    #[test]
+    #[serial]
+    fn signed_info() {
+        let data = hex::decode(SIGNED_INFO).unwrap();
+        ProvideERC20::process(&data).unwrap();
+
+        let address = Address::from_bytes(&data[5..25]).unwrap().1;
+        let table = unsafe { ERC20_INFO.lock(Sign) }.unwrap();
+        let info = table.find(&address).unwrap();
+        assert_eq!(info.ticker(), b"USDT");
+        assert_eq!(info.address().raw_address(), &data[5..25]);
+        assert_eq!(info.decimals, 6);
+        assert_eq!(info.chain_id, 1);
+    }
+
+    #[test]
+    #[serial]
+    fn tampered_info() {
+        let data = hex::decode(SIGNED_INFO).unwrap();
+
+        // every signed field is covered
+        for at in [1, 5, INFO_LEN - 5, INFO_LEN - 1] {
+            let mut tampered = data.clone();
+            tampered[at] ^= 0x01;
+            assert_eq!(ProvideERC20::process(&tampered), Err(Error::DataInvalid));
+        }
+
+        // tampered signature
+        let mut tampered = data.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 0xff;
+        assert_eq!(ProvideERC20::process(&tampered), Err(Error::DataInvalid));
+
+        // unsigned
+        assert_eq!(
+            ProvideERC20::process(&data[..INFO_LEN]),
+            Err(Error::DataInvalid)
+        );
+    }
+
+    // WAVAX, JOE and USDC, in that order
+    const TOKENS: [&str; 3] = [
+        "055741564158b31f66aa3c1e785363f0875a1b74e27b85fd66c7000000120000a86a3045022100ce96f52d2862c806e4b82e96b6147dc18051749a9d1a5192c7a2452825efa6c6022054b60cd3e07abbd246400c39f5151461eeca677f15342661ab1ee305afe97af4",
+        "034a4f456e84a6216ea6dacc71ee8e6b0a5b7322eebc0fdd000000120000a86a3045022100c6b933b7b8bbeff571170cbba75df55aec481fb37720ba90e5b77ee4adef92bf022000d84d73fa09b5f4afde71752ff35136f7e5b37bcf196cd0d60e061842d77d17",
+        "0455534443b97ef9ef8734c71904d8002f8b6bc66dd9c48a6e000000060000a86a3044022032ab609e6f09ecd8550f144df34e7c2f754843b1772f7164217288c8bc4c0616022018f009a7dcce832987886f7c11088680b6563d651b388d017f2c676481ca4317",
+    ];
+
+    fn batch(tokens: &[&str]) -> Vec<u8> {
+        let mut records = Vec::new();
+        for token in tokens {
+            let record = hex::decode(token).unwrap();
+            records.push(record.len() as u8);
+            records.extend_from_slice(&record);
+        }
+        records
+    }
+
+    #[test]
+    #[serial]
+    fn batch_of_tokens() {
+        use crate::parser::{parse_rlp_item, DisplayableItem, ERC20Info, Legacy};
+
+        // the three records do not fit a single packet
+        ProvideERC20Batch::process(0x00, &batch(&TOKENS[..2])).unwrap();
+        ProvideERC20Batch::process(0x80, &batch(&TOKENS[2..])).unwrap();
+        assert_eq!(unsafe { ERC20_INFO.lock(Sign) }.unwrap().len(), 3);
+
+        // 1.5 JOE transferred to 0x28ee52a8f3d6e5d15f8b131996950d7f296c7952
+        let data = hex::decode("f86b018504e3b2920082fde8946e84a6216ea6dacc71ee8e6b0a5b7322eebc0fdd80b844a9059cbb00000000000000000000000028ee52a8f3d6e5d15f8b131996950d7f296c795200000000000000000000000000000000000000000000000014d1120d7b16000082a86a8080").unwrap();
+        let (_, bytes) = parse_rlp_item(&data).unwrap();
+        let (_, tx) = Legacy::from_bytes(bytes).unwrap();
+
+        unsafe { ERC20_INFO.lock(ERC20Info) }.unwrap();
+        let mut title = [0; 32];
+        let mut message = [0; 64];
+        tx.render_item(2, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..7], b"Amount\0");
+        assert_eq!(&message[..8], b"JOE 1.5\0");
+
+        // a new batch replaces the provided tokens
+        ProvideERC20Batch::process(0x00, &batch(&TOKENS[..1])).unwrap();
+        message.iter_mut().for_each(|v| *v = 0);
+        unsafe { ERC20_INFO.lock(ERC20Info) }.unwrap();
+        tx.render_item(2, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&message[..20], b"1500000000000000000\0");
+
+        unsafe { ERC20_INFO.lock(Sign) }.unwrap().clear();
+    }
+
+    #[test]
+    #[serial]
+    fn malformed_batch() {
+        let mut records = batch(&TOKENS);
+
+        assert_eq!(
+            ProvideERC20Batch::process(0x01, &records),
+            Err(Error::InvalidP1P2)
+        );
+        assert_eq!(
+            ProvideERC20Batch::process(0x00, &[]),
+            Err(Error::DataInvalid)
+        );
+        // a record going past the packet
+        records.pop();
+        assert_eq!(
+            ProvideERC20Batch::process(0x00, &records),
+            Err(Error::DataInvalid)
+        );
+    }
+}
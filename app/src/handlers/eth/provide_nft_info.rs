@@ -23,9 +23,11 @@ impl Info {
         // skip type and version
         let mut nft_info = core::mem::MaybeUninit::uninit();
 
-        _ = crate::parser::FromBytes::from_bytes_into(input, &mut nft_info)
+        let rem = crate::parser::FromBytes::from_bytes_into(input, &mut nft_info)
             .map_err(|_| Error::DataInvalid)?;
 
+        Self::verify(input, rem)?;
+
         let nft_info = unsafe { nft_info.assume_init() };
 
         // store the information use to parse erc721 token
@@ -37,6 +39,38 @@ impl Info {
 
         Ok(())
     }
+
+    /// Checks the NFT information held in `input`, up to `rem`,
+    /// was signed by the trusted provider
+    ///
+    /// `rem` is expected to start with the key id, the algorithm id
+    /// and a length prefixed DER signature, as done by app-ethereum
+    fn verify(input: &[u8], rem: &[u8]) -> Result<(), Error> {
+        use crate::{
+            constants::{NFT_INFO_ALGORITHM_ID, NFT_INFO_KEY_ID, NFT_INFO_PUBLIC_KEY},
+            crypto::Curve,
+        };
+
+        let (key_id, algorithm_id, sig_len) = match rem {
+            [key_id, algorithm_id, sig_len, ..] => (*key_id, *algorithm_id, *sig_len as usize),
+            _ => return Err(Error::DataInvalid),
+        };
+
+        if key_id != NFT_INFO_KEY_ID || algorithm_id != NFT_INFO_ALGORITHM_ID {
+            return Err(Error::DataInvalid);
+        }
+
+        // both ids are part of the signed data
+        let signed = &input[..input.len() - rem.len() + 2];
+        let signature = rem.get(3..3 + sig_len).ok_or(Error::DataInvalid)?;
+
+        let key = NFT_INFO_PUBLIC_KEY.ok_or(Error::DataInvalid)?;
+        if signature.is_empty() || !Curve.verify(&key, signed, signature) {
+            return Err(Error::DataInvalid);
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(not(feature = "erc721"))]
@@ -67,3 +101,68 @@ impl ApduHandler for Info {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "erc721"))]
+mod tests {
+    use super::*;
+    use crate::handlers::{eth::signing::Sign, resources::NFT_INFO};
+    use serial_test::serial;
+    use std::prelude::v1::*;
+
+    // "Lucid" collection on chain 1, signed by the trusted provider
+    const SIGNED_INFO: &str = "0101054c7563696434bc797f40df0445c8429d485232874b1556172800000000000000010101473045022100953c33fe23375b186478bf9ecbc89a7720adf52ddc665846a1ca92bda820c9c90220016fafc5d90fc604d6eb204695bb50a3f38a2f0a43bf1839d7a88912bd7265b2";
+    // type, version, name, address and chain id
+    const INFO_LEN: usize = 3 + 5 + 20 + 8;
+
+    #[test]
+    #[serial]
+    fn signed_info() {
+        let data = hex::decode(SIGNED_INFO).unwrap();
+        Info::process(&data).unwrap();
+
+        let info = unsafe { NFT_INFO.lock(Sign) }.unwrap().unwrap();
+        assert_eq!(info.address().raw_address(), &data[8..28]);
+        assert_eq!(info.chain_id, 1);
+    }
+
+    #[test]
+    #[serial]
+    fn bad_signature() {
+        let data = hex::decode(SIGNED_INFO).unwrap();
+
+        // tampered collection name
+        let mut tampered = data.clone();
+        tampered[7] = b'e';
+        assert_eq!(Info::process(&tampered), Err(Error::DataInvalid));
+
+        // tampered signature
+        let mut tampered = data.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 0xff;
+        assert_eq!(Info::process(&tampered), Err(Error::DataInvalid));
+
+        // unknown key id
+        let mut tampered = data.clone();
+        tampered[INFO_LEN] = 2;
+        assert_eq!(Info::process(&tampered), Err(Error::DataInvalid));
+
+        // truncated signature
+        assert_eq!(
+            Info::process(&data[..data.len() - 1]),
+            Err(Error::DataInvalid)
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn unsigned_info() {
+        let data = hex::decode(SIGNED_INFO).unwrap();
+
+        assert_eq!(Info::process(&data[..INFO_LEN]), Err(Error::DataInvalid));
+
+        // padded with zeroes, as sent by hosts without a signature
+        let mut padded = data[..INFO_LEN].to_vec();
+        padded.extend_from_slice(&[0; 16]);
+        assert_eq!(Info::process(&padded), Err(Error::DataInvalid));
+    }
+}
@@ -0,0 +1,142 @@
+/*******************************************************************************
+*   (c) 2022 Zondax AG
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use crate::{
+    constants::ApduError as Error,
+    dispatcher::ApduHandler,
+    parser::ADDRESS_LEN,
+    sys::{
+        self,
+        hash::{Hasher, Keccak},
+    },
+    utils::ApduBufferRead,
+};
+
+/// Receives the recipient the host expects the next
+/// ERC-20/ERC-721 transfer to move the tokens to
+pub struct ProvideRecipient;
+
+impl ProvideRecipient {
+    /// Decodes an EIP-55 checksummed address, with or without the 0x prefix
+    pub fn parse_checksummed(input: &[u8]) -> Result<[u8; ADDRESS_LEN], Error> {
+        let input = input.strip_prefix(b"0x").unwrap_or(input);
+        if input.len() != ADDRESS_LEN * 2 {
+            return Err(Error::DataInvalidLength);
+        }
+
+        let mut lower = [0; ADDRESS_LEN * 2];
+        for (l, c) in lower.iter_mut().zip(input) {
+            if !c.is_ascii_hexdigit() {
+                return Err(Error::DataInvalid);
+            }
+            *l = c.to_ascii_lowercase();
+        }
+
+        // the case of each letter is given by the nibble
+        // of keccak256(lowercase address) at the same index
+        let hash = Keccak::<32>::digest(&lower).map_err(|_| Error::ExecutionError)?;
+        for (i, c) in input.iter().enumerate() {
+            let nibble = (hash[i / 2] >> (4 * (1 - i % 2))) & 0x0f;
+            if c.is_ascii_alphabetic() && c.is_ascii_uppercase() != (nibble >= 8) {
+                return Err(Error::DataInvalid);
+            }
+        }
+
+        let mut address = [0; ADDRESS_LEN];
+        for (byte, pair) in address.iter_mut().zip(lower.chunks_exact(2)) {
+            let nibble = |c: u8| match c {
+                b'0'..=b'9' => c - b'0',
+                _ => c - b'a' + 10,
+            };
+            *byte = (nibble(pair[0]) << 4) | nibble(pair[1]);
+        }
+
+        Ok(address)
+    }
+
+    fn process(input: &[u8]) -> Result<(), Error> {
+        let address = Self::parse_checksummed(input)?;
+
+        unsafe {
+            crate::handlers::resources::RECIPIENT
+                .lock(Self)?
+                .replace(address);
+        }
+
+        Ok(())
+    }
+}
+
+impl ApduHandler for ProvideRecipient {
+    #[inline(never)]
+    fn handle<'apdu>(
+        _flags: &mut u32,
+        tx: &mut u32,
+        buffer: ApduBufferRead<'apdu>,
+    ) -> Result<(), Error> {
+        sys::zemu_log_stack("ProvideRecipient::handle\x00");
+
+        *tx = 0;
+
+        // the checksummed hex address
+        let payload = buffer.payload().map_err(|_| Error::WrongLength)?;
+
+        ProvideRecipient::process(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::prelude::v1::*;
+
+    // from the EIP-55 test cases
+    const CHECKSUMMED: &[u8] = b"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+    #[test]
+    fn checksummed_address() {
+        let expected = hex::decode("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap();
+
+        let address = ProvideRecipient::parse_checksummed(CHECKSUMMED).unwrap();
+        assert_eq!(&address[..], &expected[..]);
+
+        // the prefix is optional
+        let address = ProvideRecipient::parse_checksummed(&CHECKSUMMED[2..]).unwrap();
+        assert_eq!(&address[..], &expected[..]);
+    }
+
+    #[test]
+    fn bad_checksum() {
+        // a single letter with the wrong case
+        let mut tampered = CHECKSUMMED.to_vec();
+        tampered[3] = b'A';
+        assert_eq!(
+            ProvideRecipient::parse_checksummed(&tampered),
+            Err(Error::DataInvalid)
+        );
+
+        // no checksum at all
+        let lower = CHECKSUMMED.to_ascii_lowercase();
+        assert_eq!(
+            ProvideRecipient::parse_checksummed(&lower),
+            Err(Error::DataInvalid)
+        );
+
+        assert_eq!(
+            ProvideRecipient::parse_checksummed(&CHECKSUMMED[..40]),
+            Err(Error::DataInvalidLength)
+        );
+    }
+}
@@ -82,7 +82,7 @@ impl ApduHandler for GetPublicKey {
         let req_chaincode = buffer.p2() >= 1;
         let cdata = buffer.payload().map_err(|_| Error::DataInvalid)?;
 
-        let (_, bip32_path) = parse_bip32_eth(cdata).map_err(|_| Error::DataInvalid)?;
+        let (_, bip32_path) = parse_bip32_eth(cdata)?;
 
         let mut ui = MaybeUninit::uninit();
         Self::initialize_ui(bip32_path, req_chaincode, &mut ui)?;
@@ -23,22 +23,117 @@ use zemu_sys::{Show, ViewError, Viewable};
 
 use crate::{
     constants::{ApduError as Error, MAX_BIP32_PATH_DEPTH},
-    crypto::{Curve, ECCInfoFlags},
+    crypto::{self, Curve, ECCInfoFlags},
     dispatcher::ApduHandler,
-    handlers::resources::{BUFFER, PATH},
-    parser::{bytes_to_u64, DisplayableItem, EthTransaction, FromBytes, U32_SIZE},
+    handlers::{
+        check_not_busy, handle_ui_message, handle_ui_message_hex,
+        resources::{BUFFER, BUFFER_CAPACITY, PATH, RECIPIENT, TX_HASHER, TX_HEADER},
+        ui_items,
+    },
+    parser::{
+        bytes_to_u64, u64_to_str, Address, DisplayableItem, EthHeader, EthTransaction, FromBytes,
+        OwnedAddress, ParserError, ADDRESS_LEN, U32_SIZE,
+    },
     sys,
-    utils::ApduBufferRead,
+    utils::{is_app_mode_expert, ApduBufferRead, DerivationScheme, DigestScheme},
 };
 
+use super::public_key::GetPublicKey;
 use super::utils::get_tx_rlp_len;
-use super::utils::parse_bip32_eth;
-use crate::utils::convert_der_to_rs;
+use super::utils::{eip55_encode, parse_bip32_eth};
+use crate::utils::{
+    address_denylist::denied_addresses, convert_der_to_rs, normalize_low_s,
+    trailing_data_toggle::reject_trailing_data,
+};
 
 pub struct Sign;
 
+/// Byte layout of the signature returned to the host
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub enum SignatureLayout {
+    /// V || R || S, the default
+    Vrs,
+    /// R || S || V
+    Rsv,
+}
+
+impl SignatureLayout {
+    /// P2 flag to request the R || S || V layout
+    pub const P2_RSV: u8 = 0x01;
+
+    pub fn from_p2(p2: u8) -> Self {
+        if p2 & Self::P2_RSV != 0 {
+            Self::Rsv
+        } else {
+            Self::Vrs
+        }
+    }
+
+    /// Writes the signature components in this layout,
+    /// returning the number of bytes written
+    pub fn write(&self, v: u8, r: &[u8; 32], s: &[u8; 32], out: &mut [u8]) -> usize {
+        let mut tx = 0;
+
+        if let Self::Vrs = self {
+            out[tx] = v;
+            tx += 1;
+        }
+
+        out[tx..][..32].copy_from_slice(r);
+        tx += 32;
+
+        out[tx..][..32].copy_from_slice(s);
+        tx += 32;
+
+        if let Self::Rsv = self {
+            out[tx] = v;
+            tx += 1;
+        }
+
+        tx
+    }
+}
+
+/// Public key and address of the signer, derived once
+/// when the signing starts and reused until the signature
+#[derive(Clone, Copy)]
+pub(crate) struct Signer {
+    // uncompressed
+    key: crypto::PublicKey,
+    address: [u8; ADDRESS_LEN],
+}
+
+impl Signer {
+    #[inline(never)]
+    fn derive<const LEN: usize>(path: &BIP32Path<LEN>) -> Result<Self, Error> {
+        let mut key = MaybeUninit::uninit();
+        GetPublicKey::new_key_into(path, &mut key, None).map_err(|_| Error::ExecutionError)?;
+        let key = unsafe { key.assume_init() };
+
+        // keccak256 of the uncompressed key without its 0x04 prefix
+        let raw = key.as_ref().get(1..).ok_or(Error::ExecutionError)?;
+        let hash = Keccak::<32>::digest(raw).map_err(|_| Error::ExecutionError)?;
+        let address = *arrayref::array_ref!(hash, 32 - ADDRESS_LEN, ADDRESS_LEN);
+
+        Ok(Self { key, address })
+    }
+
+    /// Compressed public key of the signer
+    fn compressed_key(&self) -> Result<crypto::PublicKey, Error> {
+        let mut key = self.key;
+        key.compress().map_err(|_| Error::ExecutionError)?;
+
+        Ok(key)
+    }
+}
+
 impl Sign {
-    pub const SIGN_HASH_SIZE: usize = Keccak::<32>::DIGEST_LEN;
+    pub const SIGN_HASH_SIZE: usize = DigestScheme::DIGEST_LEN;
+
+    /// P2 flag to append the compressed public key
+    /// of the signer after the signature
+    pub const P2_WITH_KEY: u8 = 0x02;
 
     fn get_derivation_info() -> Result<&'static BIP32Path<MAX_BIP32_PATH_DEPTH>, Error> {
         match unsafe { PATH.acquire(Self) } {
@@ -47,6 +142,16 @@ impl Sign {
         }
     }
 
+    // EVM transactions are only signed with ethereum paths, as
+    // `parse_bip32_eth` enforces, avalanche paths are meant
+    // for X, P and atomic C-chain transactions
+    fn check_scheme(path: &[u32]) -> Result<DerivationScheme, Error> {
+        match DerivationScheme::from_path(path) {
+            Some(scheme @ DerivationScheme::Ethereum) => Ok(scheme),
+            _ => Err(Error::InvalidPathPrefix),
+        }
+    }
+
     //(actual_size, [u8; MAX_SIGNATURE_SIZE])
     #[inline(never)]
     pub fn sign<const LEN: usize>(
@@ -63,22 +168,120 @@ impl Sign {
         Ok((flags, sz, out))
     }
 
+    fn new_hasher() -> Result<Keccak<32>, Error> {
+        let mut k = MaybeUninit::uninit();
+        Keccak::<32>::new_gce(&mut k).map_err(|_| Error::Unknown)?;
+
+        //safe: initialized
+        Ok(unsafe { k.assume_init() })
+    }
+
+    // The transaction is hashed as its packets are written to the
+    // swapping buffer, so `start_sign` does not go over it a second time
+    // and the Keccak context is not on the stack while it parses
+    #[inline(never)]
+    fn start_digest(data: &[u8]) -> Result<(), Error> {
+        let mut hasher = Self::new_hasher()?;
+        hasher.update(data).map_err(|_| Error::Unknown)?;
+
+        unsafe {
+            TX_HASHER.lock(Self)?.replace(hasher);
+        }
+
+        Ok(())
+    }
+
     #[inline(never)]
-    fn digest(to_hash: &[u8]) -> Result<[u8; Self::SIGN_HASH_SIZE], Error> {
-        let mut hasher = {
-            let mut k = MaybeUninit::uninit();
-            Keccak::<32>::new_gce(&mut k).map_err(|_| Error::Unknown)?;
+    fn update_digest(data: &[u8]) -> Result<(), Error> {
+        let hasher = unsafe { TX_HASHER.acquire(Self)? };
+        let hasher = hasher.as_mut().ok_or(Error::ExecutionError)?;
+
+        hasher.update(data).map_err(|_| Error::Unknown)
+    }
 
-            //safe: initialized
-            unsafe { k.assume_init() }
+    #[inline(never)]
+    fn finish_digest() -> Result<[u8; Self::SIGN_HASH_SIZE], Error> {
+        let hasher = unsafe {
+            let hasher = TX_HASHER.acquire(Self)?.take();
+            let _ = TX_HASHER.release(Self);
+            hasher
         };
 
-        hasher.update(to_hash).map_err(|_| Error::Unknown)?;
-        hasher.finalize().map_err(|_| Error::Unknown)
+        hasher
+            .ok_or(Error::ExecutionError)?
+            .finalize()
+            .map_err(|_| Error::Unknown)
+    }
+
+    // The header of the transaction is looked for as its packets are written
+    // to the swapping buffer, and kept once found, so `start_sign` parses
+    // the transaction from the calldata on
+    #[inline(never)]
+    fn update_header(txdata: &[u8]) -> Result<(), Error> {
+        let header = unsafe { TX_HEADER.acquire(Self)? };
+
+        if header.is_none() {
+            // either not received yet or not valid, which
+            // is reported when the transaction is parsed
+            *header = EthHeader::parse(txdata).ok();
+        }
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn take_header() -> Option<EthHeader> {
+        unsafe {
+            let header = TX_HEADER
+                .acquire(Self)
+                .ok()
+                .and_then(|header| header.take());
+            let _ = TX_HEADER.release(Self);
+            header
+        }
+    }
+
+    /// Errors if the host provided the recipient it expects
+    /// and the calldata moves the tokens to someone else
+    fn check_recipient_with(
+        expected: Option<&[u8; ADDRESS_LEN]>,
+        recipient: Option<Address<'_>>,
+    ) -> Result<(), Error> {
+        match (expected, recipient) {
+            (None, _) => Ok(()),
+            (Some(expected), Some(to)) if to.raw_address() == expected => Ok(()),
+            _ => Err(Error::RecipientMismatch),
+        }
+    }
+
+    /// Returns the recipient if it is one of the `denied` addresses
+    fn denied_recipient_with(
+        denied: &[[u8; ADDRESS_LEN]],
+        to: Option<&Address<'_>>,
+    ) -> Option<OwnedAddress> {
+        let to = to.filter(|to| denied.contains(to.raw_address()))?;
+        OwnedAddress::from_bytes(to.raw_address())
+            .ok()
+            .map(|(_, to)| to)
+    }
+
+    /// Errors on data following the encoded transaction if `strict`,
+    /// otherwise it is skipped
+    fn check_trailing_with(rem: &[u8], strict: bool) -> Result<(), Error> {
+        if strict && !rem.is_empty() {
+            return Err(Error::TrailingData);
+        }
+
+        Ok(())
     }
 
     #[inline(never)]
-    pub fn start_sign(txdata: &'static [u8], flags: &mut u32) -> Result<u32, Error> {
+    pub fn start_sign(
+        txdata: &'static [u8],
+        layout: SignatureLayout,
+        with_key: bool,
+        flags: &mut u32,
+    ) -> Result<u32, Error> {
         // The ERC721 parser might need access to the NFT_INFO resource
         // also during the review part
         #[cfg(feature = "erc721")]
@@ -86,27 +289,83 @@ impl Sign {
             _ = crate::handlers::resources::NFT_INFO.lock(crate::parser::ERC721Info)
         };
 
-        // now parse the transaction
+        // and the ERC20 parser to the provided tokens
+        #[cfg(feature = "erc20")]
+        unsafe {
+            _ = crate::handlers::resources::ERC20_INFO.lock(crate::parser::ERC20Info)
+        };
+
+        // same for the domain name of the recipient
+        unsafe {
+            _ = crate::handlers::resources::DOMAIN_INFO.lock(crate::parser::DomainName);
+        }
+
+        // and the currency of the chain, to show the value in
+        unsafe {
+            _ = crate::handlers::resources::CHAIN_CONFIG.lock(crate::parser::NativeCurrency);
+        }
+
+        let path = Self::get_derivation_info()?;
+        let scheme = Self::check_scheme(path.components())?;
+
+        // now parse the transaction, from the calldata on
+        // if its header was found while it was received
         let mut tx = MaybeUninit::uninit();
-        let rem =
-            EthTransaction::from_bytes_into(txdata, &mut tx).map_err(|_| Error::DataInvalid)?;
+        let rem = match Self::take_header() {
+            Some(header) => EthTransaction::from_header_into(txdata, &header, &mut tx),
+            None => EthTransaction::from_bytes_into(txdata, &mut tx),
+        }
+        .map_err(|e| Error::from(ParserError::from(e)))?;
 
         // some applications might append data at the end of an encoded
-        // transaction, so skip it to get the right hash.
+        // transaction, so skip it to get the right hash, unless
+        // the user asked to reject it.
         //
         // this would also include the tx type, as required by EIP-2718
         // since the tx type is at the start of the data
-        let to_hash = txdata.len() - rem.len();
-        let to_hash = &txdata[..to_hash];
+        Self::check_trailing_with(rem, reject_trailing_data())?;
+        let tx_size = txdata.len() - rem.len();
+        let to_hash = &txdata[..tx_size];
 
-        let unsigned_hash = Self::digest(to_hash)?;
         let tx = unsafe { tx.assume_init() };
 
-        let ui = SignUI {
+        // the digest of what was received is only
+        // good if nothing follows the transaction,
+        // otherwise the transaction is hashed again on its own
+        let received_hash = Self::finish_digest()?;
+        let unsigned_hash = if rem.is_empty() {
+            received_hash
+        } else {
+            tx.digest_scheme().digest(to_hash)?
+        };
+
+        // the expected recipient is only good for one transaction
+        let expected = unsafe { RECIPIENT.lock(Self)?.take() };
+        Self::check_recipient_with(expected.as_ref(), tx.data().recipient())?;
+
+        // contracts denied by the device manager are never signed for,
+        // the user is only shown why
+        if let Some(to) = Self::denied_recipient_with(denied_addresses(), tx.to()) {
+            return crate::show_ui!(BlockedUI { to }.show(flags));
+        }
+
+        // derived once, for the review and the signature
+        let signer = Signer::derive(path)?;
+
+        let mut ui = SignUI {
             hash: unsigned_hash,
+            layout,
+            with_key,
+            scheme,
+            signer,
+            tx_size,
             tx,
         };
 
+        // every item has to be reviewed, reject what
+        // the UI is not able to index
+        ui.num_items().map_err(|_| Error::DataInvalid)?;
+
         crate::show_ui!(ui.show(flags))
     }
 }
@@ -122,6 +381,25 @@ impl ApduHandler for Sign {
 
         *tx = 0;
 
+        // a session failing before its review is shown would keep
+        // the resources it locked, so they are released here, unless
+        // they are held by another session
+        Self::handle_packet(flags, tx, buffer).map_err(|e| {
+            if !matches!(e, Error::Busy) {
+                let _ = cleanup_globals();
+            }
+            e
+        })
+    }
+}
+
+impl Sign {
+    #[inline(never)]
+    fn handle_packet(
+        flags: &mut u32,
+        tx: &mut u32,
+        buffer: ApduBufferRead<'_>,
+    ) -> Result<(), Error> {
         // hw-app-eth encodes the packet type in p1
         // with 0x00 being init and 0x80 being next
         //
@@ -132,39 +410,61 @@ impl ApduHandler for Sign {
         //
         // therefore, the data received self-describes how many bytes the app can expect and
         // when all data has been received
+        //
+        // the fields before the calldata are parsed as soon as they are received
+        // and kept until all of the transaction is, what was found is dropped
+        // whenever the buffer is reset
 
         let packet_type = buffer.p1();
+        // the layout is taken from the packet that completes the transaction
+        let layout = SignatureLayout::from_p2(buffer.p2());
+        let with_key = buffer.p2() & Self::P2_WITH_KEY != 0;
 
         match packet_type {
             //init
             0x00 => {
                 let payload = buffer.payload().map_err(|_| Error::WrongLength)?;
                 //parse path to verify it's the data we expect
-                let (rest, bip32_path) =
-                    parse_bip32_eth(payload).map_err(|_| Error::DataInvalid)?;
+                let (rest, bip32_path) = parse_bip32_eth(payload)?;
 
-                unsafe {
-                    PATH.lock(Self)?.replace(bip32_path);
-                }
+                //don't take over the resources of another signing session,
+                // it has to be completed or reset first
+                check_not_busy(Self, Self)?;
 
                 //parse the length of the RLP message
                 let (read, to_read) = get_tx_rlp_len(rest)?;
+
+                //there is no streaming, the whole transaction has to fit
+                if (to_read as usize).saturating_add(read) > BUFFER_CAPACITY {
+                    return Err(Error::TxTooLarge);
+                }
+
+                unsafe {
+                    PATH.lock(Self)?.replace(bip32_path);
+                }
                 let len = core::cmp::min((to_read as usize).saturating_add(read), rest.len());
 
                 //write the rest to the swapping buffer so we persist this data
                 let buffer = unsafe { BUFFER.lock(Self)? };
                 buffer.reset();
 
+                // along with the header of the previous transaction
+                unsafe {
+                    TX_HEADER.lock(Self)?.take();
+                }
+
                 buffer
                     .write(&rest[..len])
                     .map_err(|_| Error::ExecutionError)?;
+                Self::start_digest(&rest[..len])?;
+                Self::update_header(buffer.read_exact())?;
 
                 //if the number of bytes read and the number of bytes to read
                 // is the same as what we read...
                 if (to_read as usize).saturating_add(read).saturating_sub(len) == 0 {
                     //then we actually had all bytes in this tx!
                     // we should sign directly
-                    *tx = Self::start_sign(buffer.read_exact(), flags)?;
+                    *tx = Self::start_sign(buffer.read_exact(), layout, with_key, flags)?;
                 }
 
                 Ok(())
@@ -184,17 +484,26 @@ impl ApduHandler for Sign {
                 let rlp_read = buffer.read_exact().len() - read;
 
                 //either the entire buffer of the remaining bytes we expect
-                let missing = to_read as usize - rlp_read;
-                let len = core::cmp::min(missing, payload.len());
+                // a buffer already holding more than the declared length, or a packet
+                // going past it, means the packet sequence is inconsistent
+                let missing = (to_read as usize)
+                    .checked_sub(rlp_read)
+                    .ok_or(Error::DataInvalid)?;
+                if payload.len() > missing {
+                    return Err(Error::DataInvalid);
+                }
+                let len = payload.len();
 
                 buffer
                     .write(&payload[..len])
                     .map_err(|_| Error::ExecutionError)?;
+                Self::update_digest(&payload[..len])?;
+                Self::update_header(buffer.read_exact())?;
 
                 if missing - len == 0 {
                     //we read all the missing bytes so we can proceed with the signature
                     // nwo
-                    *tx = Self::start_sign(buffer.read_exact(), flags)?;
+                    *tx = Self::start_sign(buffer.read_exact(), layout, with_key, flags)?;
                 }
 
                 Ok(())
@@ -206,12 +515,96 @@ impl ApduHandler for Sign {
 
 pub(crate) struct SignUI {
     hash: [u8; Sign::SIGN_HASH_SIZE],
+    layout: SignatureLayout,
+    with_key: bool,
+    scheme: DerivationScheme,
+    signer: Signer,
+    // bytes of the encoded transaction, trailing data excluded
+    tx_size: usize,
     tx: EthTransaction<'static>,
 }
 
+impl SignUI {
+    fn render_from(&self, title: &mut [u8], message: &mut [u8], page: u8) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+
+        let label = pic_str!(b"From");
+        title[..label.len()].copy_from_slice(label);
+
+        let mut address = [0; 2 + ADDRESS_LEN * 2];
+        address[..2].copy_from_slice(pic_str!(b"0x"!));
+        eip55_encode(
+            &self.signer.address,
+            arrayref::array_mut_ref!(address, 2, ADDRESS_LEN * 2),
+        )
+        .map_err(|_| ViewError::Unknown)?;
+
+        handle_ui_message(&address[..], message, page)
+    }
+
+    // the signer's own address as recipient is often a mistake
+    fn self_transfer(&self) -> bool {
+        self.tx.sends_to(&self.signer.address)
+    }
+
+    fn render_self_transfer(
+        &self,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+
+        let label = pic_str!(b"Self-transfer");
+        title[..label.len()].copy_from_slice(label);
+
+        handle_ui_message(pic_str!(b"Recipient is the signer"), message, page)
+    }
+
+    fn render_tx_size(
+        &self,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+        use lexical_core::Number;
+
+        let label = pic_str!(b"Tx size");
+        title[..label.len()].copy_from_slice(label);
+
+        let suffix = pic_str!(b" bytes"!);
+        let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 6];
+        let len = u64_to_str(self.tx_size as u64, &mut buffer[..])
+            .map_err(|_| ViewError::Unknown)?
+            .len();
+        buffer[len..len + suffix.len()].copy_from_slice(suffix);
+
+        handle_ui_message(&buffer[..len + suffix.len()], message, page)
+    }
+
+    fn render_hash(&self, title: &mut [u8], message: &mut [u8], page: u8) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+
+        let label = pic_str!(b"Sign hash");
+        title[..label.len()].copy_from_slice(label);
+
+        let prefix = pic_str!(b"0x"!);
+        handle_ui_message_hex(prefix, &self.hash[..], message, page)
+    }
+}
+
 impl Viewable for SignUI {
     fn num_items(&mut self) -> Result<u8, ViewError> {
-        Ok(self.tx.num_items() as _)
+        // the signer address follows the transaction, along with a notice if it
+        // is also the recipient, the derivation scheme, the size of the transaction
+        // and the hash being signed are shown last, in expert mode only
+        ui_items(
+            self.tx.num_items()
+                + 1
+                + self.self_transfer() as usize
+                + 3 * is_app_mode_expert() as usize,
+        )
     }
 
     #[inline(never)]
@@ -222,7 +615,27 @@ impl Viewable for SignUI {
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
-        self.tx.render_item(item_n, title, message, page)
+        let tx_items = self.tx.num_items();
+        let self_transfer = self.self_transfer();
+        let expert_items = tx_items + 1 + self_transfer as usize;
+
+        match item_n as usize {
+            n if n < tx_items => self.tx.render_item(item_n, title, message, page),
+            n if n == tx_items => self.render_from(title, message, page),
+            n if n == tx_items + 1 && self_transfer => {
+                self.render_self_transfer(title, message, page)
+            }
+            n if n == expert_items && is_app_mode_expert() => {
+                self.scheme.render(title, message, page)
+            }
+            n if n == expert_items + 1 && is_app_mode_expert() => {
+                self.render_tx_size(title, message, page)
+            }
+            n if n == expert_items + 2 && is_app_mode_expert() => {
+                self.render_hash(title, message, page)
+            }
+            _ => Err(ViewError::NoData),
+        }
     }
 
     fn accept(&mut self, out: &mut [u8]) -> (usize, u16) {
@@ -236,14 +649,41 @@ impl Viewable for SignUI {
             Ok(k) => k,
         };
 
+        // the public key was already derived for the review
+        let key = match self
+            .with_key
+            .then(|| self.signer.compressed_key())
+            .transpose()
+        {
+            Err(e) => return (0, e as _),
+            Ok(k) => k,
+        };
+
         //reset globals to avoid skipping `Init`
         if let Err(e) = cleanup_globals() {
             return (0, e as _);
         }
 
-        let mut tx = 0;
+        //set to 0x30 for the DER conversion
+        sig[0] = 0x30;
+
+        let mut r = [0; 33];
+        let mut s = [0; 33];
+
+        if convert_der_to_rs(&sig[..sig_size], &mut r, &mut s).is_err() {
+            return (0, Error::ExecutionError as _);
+        }
+
+        //format R and S by only having 32 bytes each,
+        // skipping the first byte if necessary
+        // if we have less than 32 bytes we just have 0s at the start
+        // this is consistent with the fact that in `convert_der_to_rs`
+        // we put the bytes at the end of the buffer first
+        let r = arrayref::array_ref!(r, 1, 32);
+        let s = arrayref::array_mut_ref!(s, 1, 32);
 
-        //write signature as VRS
+        //a high S is replaced by `n - S`, which flips the parity of Y
+        let odd = flags.contains(ECCInfo::ParityOdd) ^ normalize_low_s(s);
 
         // It is necessary to write the right V
         // component as it depends on the chainID(lowest byte) and the
@@ -251,10 +691,10 @@ impl Viewable for SignUI {
         // defined by EIP-155.
         //
         // Check for typed transactions
+        let mut v = 0;
         if let Some(_) = self.tx.raw_tx_type() {
-            //write V, which is the oddity of the signature
-            out[tx] = flags.contains(ECCInfo::ParityOdd) as u8;
-            tx += 1;
+            //V is the oddity of the signature
+            v = odd as u8;
         } else {
             let chain_id = self.tx.chain_id();
             if chain_id.is_empty() {
@@ -265,7 +705,7 @@ impl Viewable for SignUI {
                 // see https://bitcoin.stackexchange.com/a/112489
                 //     https://ethereum.stackexchange.com/a/113505
                 //     https://eips.ethereum.org/EIPS/eip-155
-                out[tx] = 27 + flags.contains(ECCInfo::ParityOdd) as u8;
+                v = 27 + odd as u8;
             } else {
                 // app-ethereum reads the first 4 bytes then cast it to an u8
                 // this is not good but it relies on hw-eth-app lib from ledger
@@ -273,40 +713,18 @@ impl Viewable for SignUI {
                 // which is returned with the signature
                 let len = core::cmp::min(U32_SIZE, chain_id.len());
                 if let Ok(chain_id) = bytes_to_u64(&chain_id[..len]) {
-                    let v = (35 + flags.contains(ECCInfo::ParityOdd) as u32)
-                        .saturating_add((chain_id as u32) << 1);
-                    out[tx] = v as u8;
+                    let id_v = (35 + odd as u32).saturating_add((chain_id as u32) << 1);
+                    v = id_v as u8;
                 }
             }
-            tx += 1;
         }
 
-        //set to 0x30 for the DER conversion
-        sig[0] = 0x30;
-        {
-            let mut r = [0; 33];
-            let mut s = [0; 33];
-
-            //write as R S (V written earlier)
-            // this will write directly to buffer
-            match convert_der_to_rs(&sig[..sig_size], &mut r, &mut s) {
-                Ok(_) => {
-                    //format R and S by only having 32 bytes each,
-                    // skipping the first byte if necessary
-                    // if we have less than 32 bytes we just have 0s at the start
-                    // this is consistent with the fact that in `convert_der_to_rs`
-                    // we put the bytes at the end of the buffer first
-                    let r = &r[1..];
-                    let s = &s[1..];
-
-                    out[tx..][..32].copy_from_slice(r);
-                    tx += 32;
-
-                    out[tx..][..32].copy_from_slice(s);
-                    tx += 32;
-                }
-                Err(_) => return (0, Error::ExecutionError as _),
-            }
+        let mut tx = self.layout.write(v, r, s, out);
+
+        if let Some(key) = key {
+            let key = key.as_ref();
+            out[tx..][..key.len()].copy_from_slice(key);
+            tx += key.len();
         }
 
         (tx, Error::Success as _)
@@ -318,7 +736,48 @@ impl Viewable for SignUI {
     }
 }
 
-fn cleanup_globals() -> Result<(), Error> {
+/// Shown instead of the review of a transaction to a denied address
+pub(crate) struct BlockedUI {
+    to: OwnedAddress,
+}
+
+impl Viewable for BlockedUI {
+    fn num_items(&mut self) -> Result<u8, ViewError> {
+        Ok(1)
+    }
+
+    #[inline(never)]
+    fn render_item(
+        &mut self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+
+        if item_n != 0 {
+            return Err(ViewError::NoData);
+        }
+
+        let label = pic_str!(b"Blocked address");
+        title[..label.len()].copy_from_slice(label);
+
+        self.to.render_eth_address(message, page)
+    }
+
+    fn accept(&mut self, _: &mut [u8]) -> (usize, u16) {
+        let _ = cleanup_globals();
+        (0, Error::BlockedAddress as _)
+    }
+
+    fn reject(&mut self, _: &mut [u8]) -> (usize, u16) {
+        let _ = cleanup_globals();
+        (0, Error::BlockedAddress as _)
+    }
+}
+
+pub(crate) fn cleanup_globals() -> Result<(), Error> {
     unsafe {
         if let Ok(path) = PATH.acquire(Sign) {
             path.take();
@@ -343,6 +802,49 @@ fn cleanup_globals() -> Result<(), Error> {
             //let's release the lock for the future
             let _ = crate::handlers::resources::NFT_INFO.release(Sign);
         }
+
+        #[cfg(feature = "erc20")]
+        if let Ok(info) = crate::handlers::resources::ERC20_INFO.lock(Sign) {
+            info.clear();
+
+            //let's release the lock for the future
+            let _ = crate::handlers::resources::ERC20_INFO.release(Sign);
+        }
+
+        if let Ok(info) = crate::handlers::resources::DOMAIN_INFO.lock(Sign) {
+            info.take();
+
+            //let's release the lock for the future
+            let _ = crate::handlers::resources::DOMAIN_INFO.release(Sign);
+        }
+
+        if let Ok(table) = crate::handlers::resources::CHAIN_CONFIG.lock(Sign) {
+            table.clear();
+
+            //let's release the lock for the future
+            let _ = crate::handlers::resources::CHAIN_CONFIG.release(Sign);
+        }
+
+        if let Ok(recipient) = RECIPIENT.lock(Sign) {
+            recipient.take();
+
+            //let's release the lock for the future
+            let _ = RECIPIENT.release(Sign);
+        }
+
+        if let Ok(hasher) = TX_HASHER.lock(Sign) {
+            hasher.take();
+
+            //let's release the lock for the future
+            let _ = TX_HASHER.release(Sign);
+        }
+
+        if let Ok(header) = TX_HEADER.lock(Sign) {
+            header.take();
+
+            //let's release the lock for the future
+            let _ = TX_HEADER.release(Sign);
+        }
     }
 
     //if we failed to aquire then someone else is using it anyways
@@ -352,6 +854,7 @@ fn cleanup_globals() -> Result<(), Error> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serial_test::serial;
 
     #[test]
     fn rlp_decoder() {
@@ -362,4 +865,436 @@ mod tests {
         assert_eq!(read, 3);
         assert_eq!(to_read, 0x78);
     }
+
+    #[test]
+    fn signature_layouts() {
+        let v = 0xa5;
+        let r = [0x11; 32];
+        let s = [0x22; 32];
+
+        let mut vrs = [0; 65];
+        let written = SignatureLayout::Vrs.write(v, &r, &s, &mut vrs);
+        assert_eq!(written, 65);
+        assert_eq!(vrs[0], v);
+        assert_eq!(&vrs[1..33], &r[..]);
+        assert_eq!(&vrs[33..], &s[..]);
+
+        let mut rsv = [0; 65];
+        let written = SignatureLayout::Rsv.write(v, &r, &s, &mut rsv);
+        assert_eq!(written, 65);
+        assert_eq!(&rsv[..32], &r[..]);
+        assert_eq!(&rsv[32..64], &s[..]);
+        assert_eq!(rsv[64], v);
+
+        // same components, different order
+        assert_eq!(&vrs[1..], &rsv[..64]);
+    }
+
+    #[test]
+    fn signature_layout_from_p2() {
+        assert_eq!(SignatureLayout::from_p2(0), SignatureLayout::Vrs);
+        assert_eq!(
+            SignatureLayout::from_p2(SignatureLayout::P2_RSV),
+            SignatureLayout::Rsv
+        );
+    }
+
+    #[test]
+    fn digest_rlp() {
+        let data = hex::decode("02f5018402a8af41843b9aca00850d8c7b50e68303d090944a2962ac08962819a8a17661970e3c0db765565e8817addd0864728ae780c0").unwrap();
+
+        let expected =
+            hex::decode("da3d8d60b190855b2861bb3b0f8700f9f7e494dfed5dadf60bcc7e3369a05184")
+                .unwrap();
+
+        // EVM transactions are hashed with keccak
+        let (_, tx) = EthTransaction::from_bytes(&data).expect("unable to parse tx");
+        assert_eq!(tx.digest_scheme(), DigestScheme::Keccak256);
+
+        let hash = tx
+            .digest_scheme()
+            .digest(&data)
+            .expect("unable to hash tx data");
+        assert_eq!(&hash[..], &expected[..]);
+    }
+
+    #[test]
+    #[serial]
+    fn digest_rlp_by_packets() {
+        let data = hex::decode("02f878018402a8af41843b9aca00850d8c7b50e68303d090944a2962ac08962819a8a17661970e3c0db765565e8817addd0864728ae780c080a01e514f7fc78197c66589083cc8fd06376bae627a4080f5fb58d52d90c0df340da049b048717f215e622c93722ff5b1e38e1d1a4ab9e26a39183969a34a5f8dea75").unwrap();
+
+        // fed as the packets would arrive
+        let mut packets = data.chunks(50);
+        Sign::start_digest(packets.next().unwrap()).unwrap();
+        for packet in packets {
+            Sign::update_digest(packet).unwrap();
+        }
+
+        let hash = Sign::finish_digest().unwrap();
+        assert_eq!(hash, DigestScheme::Keccak256.digest(&data).unwrap());
+
+        // the hasher is gone with the digest
+        assert_eq!(Sign::update_digest(&data), Err(Error::ExecutionError));
+    }
+
+    #[test]
+    #[serial]
+    fn header_by_packets() {
+        // an ERC-20 transfer, the header is found before the calldata
+        let data = hex::decode("02f871018347eae184773594008517bfac7c008303291894dac17f958d2ee523a2206206994597c13d831ec780b844a9059cbb000000000000000000000000bb98f2a83d78310342da3e63278ce7515d52619d00000000000000000000000000000000000000000000000000000006e0456cd0c0").unwrap();
+
+        // the calldata starts after the recipient and the empty value
+        let calldata = 45;
+
+        // as the buffer is filled by the init packet and the next ones
+        unsafe { TX_HEADER.lock(Sign).unwrap().take() };
+        let mut received = std::vec::Vec::new();
+        for packet in data.chunks(20) {
+            received.extend_from_slice(packet);
+            Sign::update_header(&received).unwrap();
+
+            let found = unsafe { TX_HEADER.acquire(Sign).unwrap().is_some() };
+            assert_eq!(found, received.len() >= calldata);
+        }
+
+        // the same as found in the whole transaction
+        let header = Sign::take_header().expect("header found while receiving");
+        assert_eq!(header, EthHeader::parse(&data).unwrap());
+
+        let mut tx = MaybeUninit::uninit();
+        EthTransaction::from_header_into(&data, &header, &mut tx).unwrap();
+        let tx = unsafe { tx.assume_init() };
+
+        let (_, expected) = EthTransaction::from_bytes(&data).unwrap();
+        assert_eq!(tx, expected);
+
+        // gone once taken
+        assert!(Sign::take_header().is_none());
+        assert!(Sign::update_header(&data).is_err());
+    }
+
+    fn test_signer() -> Signer {
+        use crate::constants::{BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_ETH};
+
+        let path: BIP32Path<MAX_BIP32_PATH_DEPTH> =
+            BIP32Path::new([BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_ETH, 0x8000_0000, 0, 0]).unwrap();
+        Signer::derive(&path).unwrap()
+    }
+
+    #[test]
+    fn sign_hash_page() {
+        let data = hex::decode("ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+        let data: &'static [u8] = Box::leak(data.into_boxed_slice());
+
+        let (rem, tx) = EthTransaction::from_bytes(data).expect("unable to parse tx");
+        let hash = Keccak::<32>::digest(&data[..data.len() - rem.len()]).unwrap();
+
+        let tx_items = tx.num_items();
+        let mut ui = SignUI {
+            hash,
+            layout: SignatureLayout::Vrs,
+            with_key: false,
+            scheme: DerivationScheme::Ethereum,
+            signer: test_signer(),
+            tx_size: data.len() - rem.len(),
+            tx,
+        };
+
+        // expert mode is always on in tests
+        let num_items = ui.num_items().unwrap() as usize;
+        assert_eq!(num_items, tx_items + 4);
+
+        let mut title = [0; 18];
+        let mut message = [0; 128];
+        ui.render_item((num_items - 1) as u8, &mut title, &mut message, 0)
+            .unwrap();
+
+        let expected = std::format!("0x{}", hex::encode(hash));
+        assert_eq!(&title[..9], b"Sign hash");
+        assert_eq!(&message[..expected.len()], expected.as_bytes());
+    }
+
+    #[test]
+    fn tx_size_page() {
+        // a transfer followed by data some applications append
+        let data = hex::decode("ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080c0ffee").unwrap();
+        let data: &'static [u8] = Box::leak(data.into_boxed_slice());
+
+        let (rem, tx) = EthTransaction::from_bytes(data).expect("unable to parse tx");
+        let consumed = data.len() - rem.len();
+        assert_eq!(consumed, data.len() - 3);
+
+        let tx_items = tx.num_items();
+        let mut ui = SignUI {
+            hash: [0; Sign::SIGN_HASH_SIZE],
+            layout: SignatureLayout::Vrs,
+            with_key: false,
+            scheme: DerivationScheme::Ethereum,
+            signer: test_signer(),
+            tx_size: consumed,
+            tx,
+        };
+
+        // shown before the hash, in expert mode
+        let mut title = [0; 18];
+        let mut message = [0; 128];
+        ui.render_item(tx_items as u8 + 2, &mut title, &mut message, 0)
+            .unwrap();
+
+        let expected = std::format!("{} bytes\0", consumed);
+        assert_eq!(&title[..8], b"Tx size\0");
+        assert_eq!(&message[..expected.len()], expected.as_bytes());
+    }
+
+    #[test]
+    fn trailing_data() {
+        let clean = hex::decode("ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+        let trailing = [&clean[..], &[0xc0, 0xff, 0xee][..]].concat();
+
+        let (rem, _) = EthTransaction::from_bytes(&clean).unwrap();
+        assert!(Sign::check_trailing_with(rem, false).is_ok());
+        assert!(Sign::check_trailing_with(rem, true).is_ok());
+
+        let (rem, _) = EthTransaction::from_bytes(&trailing).unwrap();
+        assert_eq!(rem, &[0xc0, 0xff, 0xee]);
+        assert!(Sign::check_trailing_with(rem, false).is_ok());
+        assert_eq!(
+            Sign::check_trailing_with(rem, true),
+            Err(Error::TrailingData)
+        );
+
+        // skipped by default
+        assert!(!reject_trailing_data());
+    }
+
+    #[test]
+    fn from_address_page() {
+        use crate::constants::{BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_ETH};
+
+        let path: BIP32Path<MAX_BIP32_PATH_DEPTH> =
+            BIP32Path::new([BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_ETH, 0x8000_0000, 0, 0]).unwrap();
+
+        let data = hex::decode("ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+        let data: &'static [u8] = Box::leak(data.into_boxed_slice());
+        let (_, tx) = EthTransaction::from_bytes(data).unwrap();
+
+        let tx_items = tx.num_items();
+        let mut ui = SignUI {
+            hash: [0; Sign::SIGN_HASH_SIZE],
+            layout: SignatureLayout::Vrs,
+            with_key: false,
+            scheme: DerivationScheme::Ethereum,
+            signer: Signer::derive(&path).unwrap(),
+            tx_size: data.len(),
+            tx,
+        };
+
+        // a fresh derivation of the address of the path
+        let expected = {
+            let key = crate::crypto::SecretKey::new(crate::crypto::Curve, path)
+                .public()
+                .unwrap();
+            let hash = Keccak::<32>::digest(&key.as_ref()[1..]).unwrap();
+            hex::encode(&hash[32 - ADDRESS_LEN..])
+        };
+
+        let mut title = [0; 18];
+        let mut message = [0; 128];
+        ui.render_item(tx_items as u8, &mut title, &mut message, 0)
+            .unwrap();
+
+        let len = crate::utils::strlen(&message);
+        let message = std::str::from_utf8(&message[..len]).unwrap();
+        assert_eq!(&title[..5], b"From\0");
+        assert!(message.starts_with("0x"));
+        // checksummed, same address
+        assert_eq!(message[2..].to_ascii_lowercase(), expected);
+    }
+
+    #[test]
+    fn self_transfer_page() {
+        use core::convert::TryInto;
+
+        // sends value to 0x28ee52a8f3d6e5d15f8b131996950d7f296c7952
+        let data = hex::decode("ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+        let data: &'static [u8] = Box::leak(data.into_boxed_slice());
+        let (_, tx) = EthTransaction::from_bytes(data).unwrap();
+        let tx_items = tx.num_items();
+
+        let signer = test_signer();
+        let ui = |from: &str| SignUI {
+            hash: [0; Sign::SIGN_HASH_SIZE],
+            layout: SignatureLayout::Vrs,
+            with_key: false,
+            scheme: DerivationScheme::Ethereum,
+            signer: Signer {
+                address: hex::decode(from).unwrap().try_into().unwrap(),
+                ..signer
+            },
+            tx_size: data.len(),
+            tx,
+        };
+        let mut title = [0; 18];
+        let mut message = [0; 128];
+
+        let mut own = ui("28ee52a8f3d6e5d15f8b131996950d7f296c7952");
+        assert_eq!(own.num_items().unwrap() as usize, tx_items + 5);
+        own.render_item(tx_items as u8 + 1, &mut title, &mut message, 0)
+            .unwrap();
+        assert_eq!(&title[..14], b"Self-transfer\0");
+
+        // a normal transfer goes on with the expert pages
+        let mut other = ui("0000000000000000000000000000000000000001");
+        assert_eq!(other.num_items().unwrap() as usize, tx_items + 4);
+        let mut title = [0; 18];
+        other
+            .render_item(tx_items as u8 + 1, &mut title, &mut message, 0)
+            .unwrap();
+        assert_ne!(&title[..14], b"Self-transfer\0");
+    }
+
+    #[test]
+    fn signer_key() {
+        use crate::constants::{BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_ETH};
+
+        let path: BIP32Path<MAX_BIP32_PATH_DEPTH> =
+            BIP32Path::new([BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_ETH, 0x8000_0000, 0, 0]).unwrap();
+
+        let signer = Signer::derive(&path).unwrap();
+        let key = signer.compressed_key().unwrap();
+        let uncompressed = crypto::SecretKey::new(Curve, path).public().unwrap();
+        let uncompressed = uncompressed.as_ref();
+
+        // the cached key is left uncompressed
+        assert_eq!(signer.key.as_ref(), uncompressed);
+
+        // the x coordinate, prefixed by the parity of y
+        let key = key.as_ref();
+        assert_eq!(key.len(), 33);
+        assert_eq!(key[0], 0x02 | (uncompressed[64] & 1));
+        assert_eq!(&key[1..], &uncompressed[1..33]);
+    }
+
+    #[test]
+    fn signature_size() {
+        use crate::constants::{BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_ETH, MAX_DER_SIGNATURE_LEN};
+
+        let path: BIP32Path<MAX_BIP32_PATH_DEPTH> =
+            BIP32Path::new([BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_ETH, 0x8000_0000, 0, 0]).unwrap();
+
+        for hash in [
+            [0; 32],
+            [0xff; 32],
+            DigestScheme::Keccak256.digest(b"avalanche").unwrap(),
+        ] {
+            let (_, sz, _) = Sign::sign(&path, &hash).unwrap();
+            assert!(sz > 0 && sz <= MAX_DER_SIGNATURE_LEN);
+        }
+    }
+
+    #[test]
+    fn parse_error_status_words() {
+        let parse = |data: &[u8]| {
+            let mut tx = MaybeUninit::uninit();
+            EthTransaction::from_bytes_into(data, &mut tx)
+                .map(|_| ())
+                .map_err(|e| Error::from(ParserError::from(e)))
+        };
+
+        let legacy = hex::decode("ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+        assert_eq!(parse(&legacy), Ok(()));
+        assert_eq!(parse(&legacy[..20]), Err(Error::DataTruncated));
+
+        // a 33 bytes nonce
+        let long_nonce = hex::decode("f84ea10101010101010101010101010101010101010101010101010101010101010101018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+        assert_eq!(parse(&long_nonce), Err(Error::DataInvalidLength));
+
+        let mut blob = legacy.clone();
+        blob.insert(0, 0x03);
+        assert_eq!(parse(&blob), Err(Error::DataUnsupported));
+
+        // an empty list of fields
+        assert_eq!(parse(&[0xc0]), Err(Error::DataTruncated));
+
+        // a recipient that is not an address
+        let mut bad_to = legacy;
+        bad_to[11] = 0x93;
+        assert_eq!(parse(&bad_to), Err(Error::DataInvalid));
+    }
+
+    #[test]
+    fn expected_recipient() {
+        // ERC-20 transfer to 0xbb98f2a83d78310342da3e63278ce7515d52619d
+        let data = hex::decode("02f871018347eae184773594008517bfac7c008303291894dac17f958d2ee523a2206206994597c13d831ec780b844a9059cbb000000000000000000000000bb98f2a83d78310342da3e63278ce7515d52619d00000000000000000000000000000000000000000000000000000006e0456cd0c0").unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+        let recipient = tx.data().recipient();
+        assert!(recipient.is_some());
+
+        let to = hex::decode("bb98f2a83d78310342da3e63278ce7515d52619d").unwrap();
+        let to = arrayref::array_ref!(to, 0, ADDRESS_LEN);
+        assert_eq!(Sign::check_recipient_with(Some(to), recipient), Ok(()));
+
+        let other = [0x11; ADDRESS_LEN];
+        assert_eq!(
+            Sign::check_recipient_with(Some(&other), recipient),
+            Err(Error::RecipientMismatch)
+        );
+
+        // nothing to check against
+        assert_eq!(Sign::check_recipient_with(None, recipient), Ok(()));
+
+        // a plain transfer moves no tokens
+        let legacy = hex::decode("ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&legacy).unwrap();
+        assert_eq!(
+            Sign::check_recipient_with(Some(&other), tx.data().recipient()),
+            Err(Error::RecipientMismatch)
+        );
+    }
+
+    #[test]
+    fn denied_recipient() {
+        // plain transfer to 0x28ee52a8f3d6e5d15f8b131996950d7f296c7952
+        let legacy = hex::decode("ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&legacy).unwrap();
+
+        let blocked = hex::decode("28ee52a8f3d6e5d15f8b131996950d7f296c7952").unwrap();
+        let blocked = *arrayref::array_ref!(blocked, 0, ADDRESS_LEN);
+        let other = [0x11; ADDRESS_LEN];
+
+        let to = Sign::denied_recipient_with(&[other, blocked], tx.to()).unwrap();
+        assert_eq!(to.raw_address(), &blocked[..]);
+
+        let mut ui = BlockedUI { to };
+        let mut title = [0; 32];
+        let mut message = [0; 64];
+        ui.render_item(0, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..16], b"Blocked address\0");
+        assert_eq!(
+            &message[..43],
+            b"0x28ee52a8f3d6e5d15f8b131996950d7f296c7952\0"
+        );
+
+        // an allowed recipient
+        assert!(Sign::denied_recipient_with(&[other], tx.to()).is_none());
+        assert!(Sign::denied_recipient_with(&[], tx.to()).is_none());
+    }
+
+    #[test]
+    fn too_many_items() {
+        assert!(matches!(ui_items(255), Ok(255)));
+        assert!(ui_items(256).is_err());
+        assert!(ui_items(300).is_err());
+    }
+
+    #[test]
+    fn derivation_scheme() {
+        use crate::constants::{BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_1, BIP32_PATH_ROOT_ETH};
+
+        let eth = [BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_ETH, 0x8000_0000, 0, 0];
+        assert_eq!(Sign::check_scheme(&eth), Ok(DerivationScheme::Ethereum));
+
+        let avax = [BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_1, 0x8000_0000, 0, 0];
+        assert_eq!(Sign::check_scheme(&avax), Err(Error::InvalidPathPrefix));
+    }
 }
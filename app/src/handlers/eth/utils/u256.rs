@@ -53,6 +53,11 @@ impl<'b> BorrowedU256<'b> {
         }
     }
 
+    /// Retrieve the big-endian bytes of the number
+    pub fn as_bytes(&self) -> &'b [u8] {
+        self.0
+    }
+
     /// Retrieve the underlying u256
     pub fn as_u256(&self) -> u256 {
         u256::pic_from_big_endian()(self.0)
@@ -60,7 +65,7 @@ impl<'b> BorrowedU256<'b> {
 
     /// Returns `true` if the number is zero
     pub fn is_zero(&self) -> bool {
-        self.0.iter().all(|v| *v != 0)
+        self.0.iter().all(|v| *v == 0)
     }
 }
 
@@ -31,6 +31,12 @@ impl<T, A> Lock<T, A> {
     pub const fn new(item: T) -> Self {
         Self { item, lock: None }
     }
+
+    ///Drops the lock, whoever holds it, and retrieve the resource
+    pub fn unlock(&mut self) -> &mut T {
+        self.lock = None;
+        &mut self.item
+    }
 }
 
 impl<T, A: Eq> Lock<T, A> {
@@ -47,6 +53,12 @@ impl<T, A: Eq> Lock<T, A> {
         }
     }
 
+    ///Checks if the resource is locked by someone other than `acquirer`
+    pub fn is_locked_by_other(&self, acquirer: impl Into<A>) -> bool {
+        let acq = acquirer.into();
+        matches!(self.lock, Some(ref a) if a != &acq)
+    }
+
     ///Acquire the resource if locked by `acquirer`
     pub fn acquire(&mut self, acquirer: impl Into<A>) -> Result<&mut T, LockError> {
         let acq = acquirer.into();
@@ -126,4 +138,19 @@ mod tests {
         lock.acquire(0).unwrap_err();
         lock.acquire(1).unwrap();
     }
+
+    #[test]
+    fn locked_by_other() {
+        let mut lock = build_lock(3);
+        assert!(!lock.is_locked_by_other(0));
+
+        lock.lock(0).unwrap();
+        assert!(!lock.is_locked_by_other(0));
+        assert!(lock.is_locked_by_other(1));
+
+        *lock.unlock() += 1;
+        assert!(!lock.is_locked_by_other(1));
+        lock.acquire(0).unwrap_err();
+        assert_eq!(4, *lock.lock(1).unwrap());
+    }
 }
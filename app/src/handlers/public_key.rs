@@ -19,9 +19,16 @@ use std::convert::TryFrom;
 use bolos::{crypto::bip32::BIP32Path, PIC};
 use zemu_sys::{Show, Viewable};
 
+#[cfg(any(feature = "avm", feature = "pvm"))]
 mod xpub;
+#[cfg(any(feature = "avm", feature = "pvm"))]
 pub use xpub::GetExtendedPublicKey;
 
+#[cfg(feature = "evm")]
+mod c_chain;
+#[cfg(feature = "evm")]
+pub use c_chain::{CChainAddressesUI, GetCChainAddresses};
+
 mod ui;
 pub use ui::{AddrUI, AddrUIInitError, AddrUIInitializer};
 
@@ -96,12 +103,14 @@ impl GetPublicKey {
         hrp: &[u8],
         chain_id: &[u8],
         path: BIP32Path<MAX_BIP32_PATH_DEPTH>,
+        with_cc: bool,
         ui: &mut MaybeUninit<AddrUI>,
     ) -> Result<(), Error> {
         let mut ui_initializer = AddrUIInitializer::new(ui);
 
         ui_initializer
             .with_path(path)
+            .with_chaincode(with_cc)
             .with_chain(chain_id)?
             .with_hrp(hrp)?;
 
@@ -123,6 +132,7 @@ impl ApduHandler for GetPublicKey {
         *tx = 0;
 
         let req_confirmation = buffer.p1() >= 1;
+        let with_cc = buffer.p2() >= 1;
 
         let mut cdata = buffer.payload().map_err(|_| Error::DataInvalid)?;
 
@@ -133,7 +143,7 @@ impl ApduHandler for GetPublicKey {
             .map_err(|_| Error::DataInvalid)?;
 
         let mut ui = MaybeUninit::uninit();
-        Self::initialize_ui(hrp, chainid, bip32_path, &mut ui)?;
+        Self::initialize_ui(hrp, chainid, bip32_path, with_cc, &mut ui)?;
 
         //safe since it's all initialized now
         let mut ui = unsafe { ui.assume_init() };
@@ -0,0 +1,193 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use core::{convert::TryFrom, mem::MaybeUninit};
+
+use zemu_sys::{Show, ViewError, Viewable};
+
+use crate::{
+    constants::{ApduError as Error, MAX_BIP32_PATH_DEPTH},
+    crypto,
+    dispatcher::ApduHandler,
+    parser::{Address, FromBytes, ADDRESS_LEN, C_CHAIN_ID_MAINNET},
+    sys::{
+        self,
+        crypto::bip32::BIP32Path,
+        hash::{Hasher, Keccak},
+        PIC,
+    },
+    utils::ApduBufferRead,
+};
+
+use super::{AddrUI, GetPublicKey};
+
+pub struct GetCChainAddresses;
+
+impl GetCChainAddresses {
+    /// Computes the EVM address of the key at `path`, the last 20 bytes of
+    /// the keccak256 of the uncompressed key without its 0x04 prefix
+    #[inline(never)]
+    pub fn evm_address<const B: usize>(path: &BIP32Path<B>) -> Result<[u8; ADDRESS_LEN], Error> {
+        let mut key = MaybeUninit::uninit();
+        crypto::Curve
+            .to_secret(path)
+            .into_public_into(None, &mut key)
+            .map_err(|_| Error::ExecutionError)?;
+        let key = unsafe { key.assume_init() };
+
+        let key = key.as_ref().get(1..).ok_or(Error::ExecutionError)?;
+        let hash = Keccak::<32>::digest(key).map_err(|_| Error::ExecutionError)?;
+
+        Ok(*arrayref::array_ref!(hash, 32 - ADDRESS_LEN, ADDRESS_LEN))
+    }
+
+    pub fn initialize_ui(
+        hrp: &[u8],
+        path: BIP32Path<MAX_BIP32_PATH_DEPTH>,
+        ui: &mut MaybeUninit<CChainAddressesUI>,
+    ) -> Result<(), Error> {
+        let evm_address = Self::evm_address(&path)?;
+
+        // the C-chain alias is the same on every network
+        let chain_id = PIC::new(&C_CHAIN_ID_MAINNET).into_inner();
+        let mut addr_ui = MaybeUninit::uninit();
+        GetPublicKey::initialize_ui(hrp, chain_id, path, false, &mut addr_ui)?;
+
+        ui.write(CChainAddressesUI {
+            //safe since it's all initialized now
+            addr_ui: unsafe { addr_ui.assume_init() },
+            evm_address,
+        });
+
+        Ok(())
+    }
+}
+
+impl ApduHandler for GetCChainAddresses {
+    #[inline(never)]
+    fn handle<'apdu>(
+        flags: &mut u32,
+        tx: &mut u32,
+        buffer: ApduBufferRead<'apdu>,
+    ) -> Result<(), Error> {
+        sys::zemu_log_stack("GetCChainAddresses::handle\x00");
+
+        *tx = 0;
+
+        let req_confirmation = buffer.p1() >= 1;
+
+        let mut cdata = buffer.payload().map_err(|_| Error::DataInvalid)?;
+
+        let hrp = GetPublicKey::get_hrp(&mut cdata)?;
+
+        let bip32_path = sys::crypto::bip32::BIP32Path::<MAX_BIP32_PATH_DEPTH>::read(cdata)
+            .map_err(|_| Error::DataInvalid)?;
+
+        let mut ui = MaybeUninit::uninit();
+        Self::initialize_ui(hrp, bip32_path, &mut ui)?;
+
+        //safe since it's all initialized now
+        let mut ui = unsafe { ui.assume_init() };
+
+        if req_confirmation {
+            crate::show_ui!(ui.show(flags), tx)
+        } else {
+            //we don't need to show so we execute the "accept" already
+            // this way the "formatting" to `buffer` is all in the ui code
+            let (sz, code) = ui.accept(buffer.write());
+
+            if code != Error::Success as u16 {
+                Err(Error::try_from(code).map_err(|_| Error::ExecutionError)?)
+            } else {
+                *tx = sz as u32;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Shows the bech32 C-chain address and the EVM address,
+/// both controlled by the same key
+pub struct CChainAddressesUI {
+    addr_ui: AddrUI,
+    evm_address: [u8; ADDRESS_LEN],
+}
+
+impl Viewable for CChainAddressesUI {
+    fn num_items(&mut self) -> Result<u8, ViewError> {
+        Ok(2)
+    }
+
+    fn render_item(
+        &mut self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::pic_str;
+
+        match item_n {
+            0 => self.addr_ui.render_item(0, title, message, page),
+            1 => {
+                let title_content = pic_str!(b"EVM address");
+                title[..title_content.len()].copy_from_slice(title_content);
+
+                let (_, address) =
+                    Address::from_bytes(&self.evm_address).map_err(|_| ViewError::Unknown)?;
+                address.render_eth_address(message, page)
+            }
+            _ => Err(ViewError::NoData),
+        }
+    }
+
+    fn accept(&mut self, out: &mut [u8]) -> (usize, u16) {
+        let pkey = match self.addr_ui.pkey(None) {
+            Ok(pkey) => pkey,
+            Err(e) => return (0, e as _),
+        };
+
+        let pkey_bytes = pkey.as_ref();
+        let mut tx = 0;
+
+        out[tx] = pkey_bytes.len() as u8;
+        tx += 1;
+        out[tx..][..pkey_bytes.len()].copy_from_slice(pkey_bytes);
+        tx += pkey_bytes.len();
+
+        match self.addr_ui.hash(&pkey) {
+            Ok(hash) => {
+                out[tx..][..hash.len()].copy_from_slice(&hash[..]);
+                tx += hash.len();
+            }
+            Err(e) => return (0, e as _),
+        }
+
+        out[tx..][..ADDRESS_LEN].copy_from_slice(&self.evm_address);
+        tx += ADDRESS_LEN;
+
+        let addr = arrayref::array_mut_ref![out, tx, AddrUI::MAX_ADDR_SIZE];
+        match self.addr_ui.addr(addr) {
+            Ok(len) => tx += len,
+            Err(e) => return (0, e as _),
+        }
+
+        (tx, Error::Success as _)
+    }
+
+    fn reject(&mut self, _: &mut [u8]) -> (usize, u16) {
+        (0, Error::CommandNotAllowed as _)
+    }
+}
@@ -65,6 +65,13 @@ impl From<AddrUIInitError> for Error {
 impl<'ui> AddrUIInitializer<'ui> {
     /// Create a new `AddrUI` initialized
     pub fn new(ui: &'ui mut MaybeUninit<AddrUI>) -> Self {
+        //the chain code is only returned if requested
+        //SAFETY: pointers are all valid since they are coming from rust
+        unsafe {
+            let ui_with_cc = addr_of_mut!((*ui.as_mut_ptr()).with_cc);
+            ui_with_cc.write(false);
+        }
+
         Self {
             ui,
             path_init: false,
@@ -90,13 +97,19 @@ impl<'ui> AddrUIInitializer<'ui> {
     pub fn hash_initializer(
     ) -> impl FnOnce(&crypto::PublicKey, &mut [u8; Ripemd160::DIGEST_LEN]) -> Result<(), AddrUIInitError>
     {
-        |key, hash| {
-            let mut tmp = [0; Sha256::DIGEST_LEN];
+        |key, hash| Self::hash_key_bytes(key.as_ref(), hash)
+    }
 
-            Sha256::digest_into(key.as_ref(), &mut tmp)
-                .and_then(|_| Ripemd160::digest_into(&tmp, hash))
-                .map_err(|_| AddrUIInitError::HashInitError)
-        }
+    /// Computes `ripemd160(sha256(key))`, which is the payload of the bech32 address
+    pub fn hash_key_bytes(
+        key: &[u8],
+        hash: &mut [u8; Ripemd160::DIGEST_LEN],
+    ) -> Result<(), AddrUIInitError> {
+        let mut tmp = [0; Sha256::DIGEST_LEN];
+
+        Sha256::digest_into(key, &mut tmp)
+            .and_then(|_| Ripemd160::digest_into(&tmp, hash))
+            .map_err(|_| AddrUIInitError::HashInitError)
     }
 
     /// Initialie the path with the given one
@@ -114,6 +127,20 @@ impl<'ui> AddrUIInitializer<'ui> {
         self
     }
 
+    /// Set whether the chain code is returned along the key
+    pub fn with_chaincode(&mut self, with_cc: bool) -> &mut Self {
+        //get ui *mut
+        let ui = self.ui.as_mut_ptr();
+
+        //SAFETY: pointers are all valid since they are coming from rust
+        unsafe {
+            let ui_with_cc = addr_of_mut!((*ui).with_cc);
+            ui_with_cc.write(with_cc);
+        }
+
+        self
+    }
+
     /// Initialie the HRP with the given slice
     pub fn with_hrp(&mut self, hrp: &[u8]) -> Result<&mut Self, AddrUIInitError> {
         if hrp.len() > ASCII_HRP_MAX_SIZE {
@@ -193,6 +220,7 @@ pub struct AddrUI {
     //includes checksum
     chain_id_with_checksum: [u8; CHAIN_ID_LEN + CHAIN_ID_CHECKSUM_SIZE],
     hrp: [u8; ASCII_HRP_MAX_SIZE + 1], //+1 to null terminate just in case
+    with_cc: bool,
 }
 
 impl AddrUI {
@@ -303,7 +331,14 @@ impl Viewable for AddrUI {
     }
 
     fn accept(&mut self, out: &mut [u8]) -> (usize, u16) {
-        let pkey = match self.pkey(None) {
+        let mut chain_code = [0; CHAIN_CODE_LEN];
+        let cc = if self.with_cc {
+            Some(&mut chain_code)
+        } else {
+            None
+        };
+
+        let pkey = match self.pkey(cc) {
             Ok(pkey) => pkey,
             Err(e) => return (0, e as _),
         };
@@ -324,6 +359,13 @@ impl Viewable for AddrUI {
             Err(e) => return (0, e as _),
         }
 
+        //the chain code has a fixed size so it goes
+        // before the address, which does not
+        if self.with_cc {
+            out[tx..][..CHAIN_CODE_LEN].copy_from_slice(&chain_code[..]);
+            tx += CHAIN_CODE_LEN;
+        }
+
         let addr = arrayref::array_mut_ref![out, tx, AddrUI::MAX_ADDR_SIZE];
         match self.addr(addr) {
             Ok(len) => tx += len,
@@ -424,6 +466,56 @@ mod tests {
         assert_eq!(message, &expected_message)
     }
 
+    #[test]
+    pub fn known_key_address() {
+        // same vector as the zemu `get address` test
+        let pkey =
+            hex::decode("02c6f477ff8e7136de982f898f6bfe93136bbe8dada6c17d0cd369acce90036ac4")
+                .unwrap();
+
+        let mut hash = [0; Ripemd160::DIGEST_LEN];
+        AddrUIInitializer::hash_key_bytes(&pkey, &mut hash).unwrap();
+        assert_eq!(
+            hex::encode(hash),
+            "5fc15d9650ae2c0608bcda45e5c8d3ec48486fc5"
+        );
+
+        let mut tmp = [0; bech32::estimate_size(ASCII_HRP_MAX_SIZE, Ripemd160::DIGEST_LEN)];
+        let len = bech32::encode("avax", hash, &mut tmp, bech32::Variant::Bech32).unwrap();
+        assert_eq!(&tmp[..len], b"avax1tlq4m9js4ckqvz9umfz7tjxna3yysm79r2jz8e");
+
+        let len = bech32::encode("fuji", hash, &mut tmp, bech32::Variant::Bech32).unwrap();
+        assert_eq!(&tmp[..len], b"fuji1tlq4m9js4ckqvz9umfz7tjxna3yysm790ckatx");
+    }
+
+    #[test]
+    pub fn accept_with_chain_code() {
+        let chain_id = GetPublicKey::default_chainid();
+
+        let mut ui = AddrUI::new(path(), chain_id, GetPublicKey::DEFAULT_HRP);
+        let mut plain = [0; 256];
+        let (plain_len, code) = ui.accept(&mut plain);
+        assert_eq!(code, Error::Success as u16);
+
+        let mut loc = MaybeUninit::uninit();
+        GetPublicKey::initialize_ui(GetPublicKey::DEFAULT_HRP, chain_id, path(), true, &mut loc)
+            .unwrap();
+        let mut ui = unsafe { loc.assume_init() };
+        let mut out = [0; 256];
+        let (len, code) = ui.accept(&mut out);
+        assert_eq!(code, Error::Success as u16);
+
+        let mut expected_cc = [0; CHAIN_CODE_LEN];
+        let key = ui.pkey(Some(&mut expected_cc)).unwrap();
+
+        // the chain code follows the key and its hash
+        let at = 1 + key.as_ref().len() + Ripemd160::DIGEST_LEN;
+        assert_eq!(len, plain_len + CHAIN_CODE_LEN);
+        assert_eq!(&out[..at], &plain[..at]);
+        assert_eq!(&out[at..at + CHAIN_CODE_LEN], &expected_cc[..]);
+        assert_eq!(&out[at + CHAIN_CODE_LEN..len], &plain[at..plain_len]);
+    }
+
     #[test]
     pub fn p_chain() {
         test_chain_alias(Some("P"), None)
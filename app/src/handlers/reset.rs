@@ -0,0 +1,53 @@
+/*******************************************************************************
+*   (c) 2022 Zondax AG
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use crate::constants::ApduError;
+use crate::dispatcher::ApduHandler;
+#[cfg(any(feature = "avm", feature = "pvm"))]
+use crate::handlers::avax;
+#[cfg(feature = "evm")]
+use crate::handlers::eth;
+use crate::utils::ApduBufferRead;
+
+/// Abort any signing session still in progress
+///
+/// Every signing flow releases the resources it holds,
+/// so a new session can be started without getting `Busy`
+pub struct Reset;
+
+impl ApduHandler for Reset {
+    #[inline(never)]
+    fn handle<'apdu>(_: &mut u32, tx: &mut u32, _: ApduBufferRead<'apdu>) -> Result<(), ApduError> {
+        crate::sys::zemu_log_stack("Reset\x00");
+        *tx = 0;
+
+        //each flow only cleans up what it is holding
+        #[cfg(any(feature = "avm", feature = "pvm"))]
+        {
+            avax::signing::cleanup_globals()?;
+            avax::sign_hash::cleanup_globals()?;
+            avax::message::cleanup_globals()?;
+            avax::warp::cleanup_globals()?;
+        }
+
+        #[cfg(feature = "evm")]
+        {
+            eth::signing::cleanup_globals()?;
+            eth::personal_msg::cleanup_globals()?;
+        }
+
+        Ok(())
+    }
+}
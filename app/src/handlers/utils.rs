@@ -16,6 +16,13 @@
 use core::convert::TryFrom;
 use zemu_sys::ViewError;
 
+use bolos::crypto::bip32::BIP32Path;
+
+use crate::{
+    constants::{ApduError as Error, MAX_BIP32_PATH_DEPTH},
+    handlers::resources::{BUFFERAccessors, PATHAccessors, BUFFER, PATH},
+};
+
 #[repr(u8)]
 pub enum ZPacketType {
     Init = 0,
@@ -61,6 +68,63 @@ impl ZPacketType {
     }
 }
 
+/// Refuses to start a signing session while another one holds
+/// the path or the buffer, it has to be completed or reset first
+pub fn check_not_busy(
+    path: impl Into<PATHAccessors>,
+    buffer: impl Into<BUFFERAccessors>,
+) -> Result<(), Error> {
+    if unsafe { PATH.is_locked_by_other(path) || BUFFER.is_locked_by_other(buffer) } {
+        return Err(Error::Busy);
+    }
+    Ok(())
+}
+
+/// Holds `PATH` for a signing session until its review is shown,
+/// releasing it when dropped so a session failing before that
+/// doesn't leave the other ones `Busy`
+pub struct PathGuard {
+    accessor: PATHAccessors,
+}
+
+impl PathGuard {
+    /// Locks `PATH` for `accessor` and stores `path` in it
+    pub fn lock(
+        accessor: impl Into<PATHAccessors>,
+        path: BIP32Path<MAX_BIP32_PATH_DEPTH>,
+    ) -> Result<Self, Error> {
+        let accessor = accessor.into();
+        unsafe {
+            PATH.lock(accessor)?.replace(path);
+        }
+
+        Ok(Self { accessor })
+    }
+
+    /// The review is shown, the session releases `PATH` once completed
+    pub fn keep(self) {
+        core::mem::forget(self)
+    }
+}
+
+impl Drop for PathGuard {
+    fn drop(&mut self) {
+        unsafe {
+            if let Ok(path) = PATH.acquire(self.accessor) {
+                path.take();
+
+                let _ = PATH.release(self.accessor);
+            }
+        }
+    }
+}
+
+/// Number of items of a review as the UI expects it,
+/// erroring instead of wrapping around when it does not fit
+pub fn ui_items(items: usize) -> Result<u8, ViewError> {
+    u8::try_from(items).map_err(|_| ViewError::Unknown)
+}
+
 #[inline(never)]
 pub fn handle_ui_message(item: &[u8], out: &mut [u8], page: u8) -> Result<u8, ViewError> {
     crate::sys::zemu_log_stack("handle_ui_message\x00");
@@ -85,3 +149,121 @@ pub fn handle_ui_message(item: &[u8], out: &mut [u8], page: u8) -> Result<u8, Vi
         Ok(1)
     }
 }
+
+/// Renders the `page` of the hex encoding of `data`, preceded by `prefix`,
+/// splitting it in the same pages as [`handle_ui_message`] would
+///
+/// Only the requested window is encoded, so `data` can be of any length
+#[inline(never)]
+pub fn handle_ui_message_hex(
+    prefix: &[u8],
+    data: &[u8],
+    out: &mut [u8],
+    page: u8,
+) -> Result<u8, ViewError> {
+    crate::sys::zemu_log_stack("handle_ui_message_hex\x00");
+    let m_len = out.len().saturating_sub(1); //null byte terminator
+    if m_len < 1 {
+        return Err(ViewError::Unknown);
+    }
+
+    let total = prefix.len() + data.len() * 2;
+    let n_pages = core::cmp::max(1, (total + m_len - 1) / m_len);
+    let n_pages = u8::try_from(n_pages).map_err(|_| ViewError::Unknown)?;
+    if page >= n_pages {
+        return Err(ViewError::Unknown);
+    }
+
+    let start = page as usize * m_len;
+    let end = core::cmp::min(start + m_len, total);
+
+    for (i, o) in (start..end).zip(out.iter_mut()) {
+        *o = match i.checked_sub(prefix.len()) {
+            None => prefix[i],
+            Some(nibble) => {
+                let mut hex = [0; 2];
+                crate::utils::hex_encode([data[nibble / 2]], &mut hex)
+                    .map_err(|_| ViewError::Unknown)?;
+                hex[nibble % 2]
+            }
+        };
+    }
+    out[end - start] = 0; //null terminate
+
+    Ok(n_pages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{prelude::v1::*, vec};
+
+    const HASH: [u8; 32] = [
+        0x79, 0x35, 0x13, 0x5a, 0x92, 0x7b, 0x17, 0x46, 0xb5, 0x4e, 0x73, 0xc2, 0xb3, 0x53, 0xda,
+        0xee, 0x96, 0xba, 0x6a, 0xad, 0x6d, 0xd4, 0x56, 0x83, 0xb3, 0x60, 0x75, 0xb8, 0x09, 0x26,
+        0x08, 0xfe,
+    ];
+
+    fn render_pages(prefix: &[u8], data: &[u8], m_len: usize) -> Vec<String> {
+        let mut out = vec![0; m_len + 1];
+        let n_pages = handle_ui_message_hex(prefix, data, &mut out, 0).unwrap();
+
+        (0..n_pages)
+            .map(|page| {
+                let mut out = vec![0; m_len + 1];
+                let pages = handle_ui_message_hex(prefix, data, &mut out, page).unwrap();
+                assert_eq!(pages, n_pages);
+
+                let len = out.iter().position(|b| *b == 0).unwrap();
+                String::from_utf8(out[..len].to_vec()).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hex_page_boundaries() {
+        // 66 characters in pages of 32
+        let pages = render_pages(b"0x", &HASH, 32);
+        assert_eq!(
+            pages,
+            [
+                "0x7935135a927b1746b54e73c2b353da",
+                "ee96ba6aad6dd45683b36075b8092608",
+                "fe",
+            ]
+        );
+
+        // the last page is not left empty when the encoding fills it
+        let pages = render_pages(&[], &HASH, 32);
+        assert_eq!(pages.len(), 2);
+        assert!(pages.iter().all(|p| p.len() == 32));
+
+        let mut out = [0; 33];
+        assert!(handle_ui_message_hex(&[], &HASH, &mut out, 2).is_err());
+
+        // nothing to encode still has a page
+        assert_eq!(render_pages(b"0x", &[], 32), ["0x"]);
+    }
+
+    #[test]
+    fn hex_pages_match_full_encoding() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut full = vec![0; data.len() * 2];
+        crate::utils::hex_encode(&data, &mut full).unwrap();
+
+        for m_len in [1, 7, 20, 33, 64, 100] {
+            let pages = render_pages(b"0x", &data[..100], m_len);
+            let expected = std::format!("0x{}", std::str::from_utf8(&full[..200]).unwrap());
+            assert_eq!(pages.concat(), expected);
+
+            // every page but the last one is full
+            let (last, full_pages) = pages.split_last().unwrap();
+            assert!(full_pages.iter().all(|p| p.len() == m_len));
+            assert!(!last.is_empty() && last.len() <= m_len);
+        }
+
+        // more pages than the ui can index
+        let mut out = [0; 2];
+        assert!(handle_ui_message_hex(&[], &data, &mut out, 0).is_err());
+    }
+}
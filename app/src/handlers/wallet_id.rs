@@ -25,9 +25,9 @@ use crate::{
     },
     crypto,
     dispatcher::ApduHandler,
-    handlers::handle_ui_message,
+    handlers::handle_ui_message_hex,
     sys::{self, PIC},
-    utils::{hex_encode, ApduBufferRead, ApduPanic},
+    utils::{ApduBufferRead, ApduPanic},
 };
 
 pub struct WalletId;
@@ -151,10 +151,7 @@ impl Viewable for WalletIdUI {
             title[..title_content.len()].copy_from_slice(title_content);
 
             //TODO: use proper encoding
-            let mut mex = [0; WalletId::LEN * 2];
-            let len = hex_encode(self.id, &mut mex).map_err(|_| ViewError::Unknown)?;
-
-            handle_ui_message(&mex[..len], message, page)
+            handle_ui_message_hex(&[], &self.id[..], message, page)
         } else {
             Err(ViewError::NoData)
         }
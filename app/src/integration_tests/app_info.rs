@@ -0,0 +1,55 @@
+/*******************************************************************************
+*   (c) 2022 Zondax AG
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use super::prelude::*;
+
+use crate::handlers::app_info::GetAppInfo;
+use constants::{version::*, INS_GET_APP_INFO as INS};
+
+#[test]
+fn app_info() {
+    let mut flags = 0u32;
+    let mut tx = 0u32;
+    let rx = 5;
+    let mut buffer = [0u8; 260];
+
+    buffer[..3].copy_from_slice(&[CLA, INS, 0]);
+
+    handle_apdu(&mut flags, &mut tx, rx, &mut buffer);
+
+    //[M, N, P], flags, result code
+    assert_eq!(tx, 3 + 4 + 2);
+    assert_error_code!(tx, buffer, ApduError::Success);
+
+    assert_eq!(buffer[0], APPVERSION_M);
+    assert_eq!(buffer[1], APPVERSION_N);
+    assert_eq!(buffer[2], APPVERSION_P);
+
+    let app_flags = u32::from_be_bytes(buffer[3..7].try_into().unwrap());
+    assert_eq!(app_flags, GetAppInfo::flags());
+
+    // all chains are always supported
+    let chains = GetAppInfo::X_CHAIN | GetAppInfo::P_CHAIN | GetAppInfo::C_CHAIN;
+    assert_eq!(app_flags & chains, chains);
+
+    // expert mode is always on in tests
+    assert_ne!(app_flags & GetAppInfo::EXPERT_MODE, 0);
+
+    assert_eq!(app_flags & GetAppInfo::ERC20 != 0, cfg!(feature = "erc20"));
+    assert_eq!(
+        app_flags & GetAppInfo::ERC721 != 0,
+        cfg!(feature = "erc721")
+    );
+}
@@ -0,0 +1,66 @@
+/*******************************************************************************
+*   (c) 2022 Zondax AG
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use super::prelude::*;
+
+use bolos::{
+    bech32,
+    hash::{Hasher, Keccak, Ripemd160, Sha256},
+};
+use k256::{
+    elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint},
+    EncodedPoint, PublicKey,
+};
+
+use constants::INS_GET_C_CHAIN_ADDRESSES as INS;
+
+#[test]
+fn same_key() {
+    let mut flags = 0u32;
+    let mut tx = 0u32;
+    let rx = 5;
+    let mut buffer = [0u8; 260];
+
+    buffer[..3].copy_from_slice(&[CLA, INS, 0]);
+    prepare_buffer::<5>(&mut buffer, &[44, 60, 0, 0, 0], Some(&[]), None);
+
+    let out = handle_apdu(&mut flags, &mut tx, rx, &mut buffer);
+    assert_error_code!(tx, out, ApduError::Success);
+
+    let pk_len = out[0] as usize;
+    let (pk, rest) = out[1..].split_at(pk_len);
+    let (hash, rest) = rest.split_at(Ripemd160::DIGEST_LEN);
+    let (evm_address, rest) = rest.split_at(20);
+    let addr = std::str::from_utf8(&rest[..rest.len() - 2]).expect("address to be ascii");
+
+    //the compressed key hashes to the bech32 address
+    let expected_hash = Ripemd160::digest(&Sha256::digest(pk).unwrap()).unwrap();
+    assert_eq!(hash, &expected_hash[..]);
+
+    let mut bech32_addr = [0; 64];
+    let len = bech32::encode("avax", hash, &mut bech32_addr[..], bech32::Variant::Bech32).unwrap();
+    assert_eq!(
+        addr,
+        format!("C-{}", std::str::from_utf8(&bech32_addr[..len]).unwrap())
+    );
+
+    //and, uncompressed, to the EVM address
+    let point = EncodedPoint::from_bytes(pk).expect("valid point");
+    let uncompressed = PublicKey::from_encoded_point(&point)
+        .expect("point on the curve")
+        .to_encoded_point(false);
+    let pk_hash = Keccak::<32>::digest(&uncompressed.as_bytes()[1..]).unwrap();
+    assert_eq!(evm_address, &pk_hash[12..]);
+}
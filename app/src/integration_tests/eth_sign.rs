@@ -0,0 +1,135 @@
+/*******************************************************************************
+*   (c) 2022 Zondax AG
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use super::prelude::*;
+
+use constants::{APDU_INDEX_LEN, INS_ETH_SIGN as INS, INS_RESET, INS_SIGN_ETH_MSG};
+use serial_test::serial;
+
+const TX: &str =
+    "ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080";
+
+fn packet(p1: u8, data: &[u8]) -> ([u8; 260], u32) {
+    packet_for(CLA_ETH, INS, p1, data)
+}
+
+fn packet_for(cla: u8, ins: u8, p1: u8, data: &[u8]) -> ([u8; 260], u32) {
+    let mut buffer = [0u8; 260];
+    buffer[..4].copy_from_slice(&[cla, ins, p1, 0]);
+    buffer[APDU_INDEX_LEN] = data.len() as u8;
+    buffer[APDU_INDEX_LEN + 1..][..data.len()].copy_from_slice(data);
+
+    (buffer, 5 + data.len() as u32)
+}
+
+fn eth_path() -> Vec<u8> {
+    let mut path = Vec::from([5u8]);
+    for n in [0x8000_0000 + 44, 0x8000_0000 + 60, 0x8000_0000, 0, 0u32] {
+        path.extend_from_slice(&n.to_be_bytes());
+    }
+    path
+}
+
+#[test]
+#[serial]
+fn eth_sign_more_than_declared() {
+    let tx_data = hex::decode(TX).unwrap();
+
+    let mut init = eth_path();
+    init.extend_from_slice(&tx_data[..10]);
+
+    let mut flags = 0u32;
+    let mut tx = 0u32;
+
+    let (mut buffer, rx) = packet(0x00, &init);
+    let out = handle_apdu(&mut flags, &mut tx, rx, &mut buffer);
+    assert_error_code!(tx, out, ApduError::Success);
+
+    //the rest of the transaction followed by some trailing garbage
+    let mut next = tx_data[10..].to_vec();
+    next.extend_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+
+    let (mut buffer, rx) = packet(0x80, &next);
+    let out = handle_apdu(&mut flags, &mut tx, rx, &mut buffer);
+    assert_error_code!(tx, out, ApduError::DataInvalid);
+}
+
+#[test]
+#[serial]
+fn eth_sign_too_large() {
+    let mut flags = 0u32;
+    let mut tx = 0u32;
+
+    //a list declaring 0xFFFF bytes, more than the app can buffer
+    let mut init = eth_path();
+    init.extend_from_slice(&[0xf9, 0xff, 0xff, 0x01, 0x85]);
+
+    let (mut buffer, rx) = packet(0x00, &init);
+    let out = handle_apdu(&mut flags, &mut tx, rx, &mut buffer);
+    assert_error_code!(tx, out, ApduError::TxTooLarge);
+
+    //nothing was kept, a transaction that fits can be sent right away
+    let mut init = eth_path();
+    init.extend_from_slice(&hex::decode(TX).unwrap()[..10]);
+
+    let (mut buffer, rx) = packet(0x00, &init);
+    let out = handle_apdu(&mut flags, &mut tx, rx, &mut buffer);
+    assert_error_code!(tx, out, ApduError::Success);
+
+    let (mut buffer, rx) = packet_for(CLA, INS_RESET, 0x00, &[]);
+    handle_apdu(&mut flags, &mut tx, rx, &mut buffer);
+}
+
+#[test]
+#[serial]
+fn eth_sign_interrupted_then_restarted() {
+    let tx_data = hex::decode(TX).unwrap();
+
+    let mut flags = 0u32;
+    let mut tx = 0u32;
+
+    //a personal message whose upload is never completed
+    let mut msg = eth_path();
+    msg.extend_from_slice(&32u32.to_be_bytes());
+    msg.extend_from_slice(b"never finished");
+
+    let (mut buffer, rx) = packet_for(CLA_ETH, INS_SIGN_ETH_MSG, 0x00, &msg);
+    let out = handle_apdu(&mut flags, &mut tx, rx, &mut buffer);
+    assert_error_code!(tx, out, ApduError::Success);
+
+    let mut init = eth_path();
+    init.extend_from_slice(&tx_data[..10]);
+
+    let (mut buffer, rx) = packet(0x00, &init);
+    let out = handle_apdu(&mut flags, &mut tx, rx, &mut buffer);
+    assert_error_code!(tx, out, ApduError::Busy);
+
+    let (mut buffer, rx) = packet_for(CLA, INS_RESET, 0x00, &[]);
+    let out = handle_apdu(&mut flags, &mut tx, rx, &mut buffer);
+    assert_error_code!(tx, out, ApduError::Success);
+
+    //the transaction can now be sent from the start
+    let (mut buffer, rx) = packet(0x00, &init);
+    let out = handle_apdu(&mut flags, &mut tx, rx, &mut buffer);
+    assert_error_code!(tx, out, ApduError::Success);
+
+    //restarting our own session doesn't need a reset
+    let (mut buffer, rx) = packet(0x00, &init);
+    let out = handle_apdu(&mut flags, &mut tx, rx, &mut buffer);
+    assert_error_code!(tx, out, ApduError::Success);
+
+    let (mut buffer, rx) = packet_for(CLA, INS_RESET, 0x00, &[]);
+    handle_apdu(&mut flags, &mut tx, rx, &mut buffer);
+}
@@ -15,9 +15,20 @@
 ********************************************************************************/
 #![allow(unused_imports, dead_code)]
 
+mod app_info;
+#[cfg(feature = "evm")]
+mod c_chain_addresses;
+#[cfg(feature = "evm")]
 mod eth_public_key;
+#[cfg(feature = "evm")]
+mod eth_sign;
+#[cfg(any(feature = "avm", feature = "pvm"))]
 mod extended_public_key;
+#[cfg(any(feature = "avm", feature = "pvm"))]
 mod public_key;
+#[cfg(all(any(feature = "avm", feature = "pvm"), feature = "evm"))]
+mod reset;
+#[cfg(any(feature = "avm", feature = "pvm"))]
 mod sign;
 mod version;
 mod wallet_id;
@@ -0,0 +1,141 @@
+/*******************************************************************************
+*   (c) 2022 Zondax AG
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use super::prelude::*;
+
+use constants::{APDU_INDEX_LEN, INS_ETH_SIGN, INS_RESET as INS, INS_SIGN};
+use serial_test::serial;
+
+fn send(cla: u8, ins: u8, p1: u8, data: &[u8]) -> ApduError {
+    let mut flags = 0u32;
+    let mut tx = 0u32;
+    let mut buffer = [0u8; 260];
+
+    buffer[..4].copy_from_slice(&[cla, ins, p1, 0]);
+    buffer[APDU_INDEX_LEN] = data.len() as u8;
+    buffer[APDU_INDEX_LEN + 1..][..data.len()].copy_from_slice(data);
+
+    let out = handle_apdu(&mut flags, &mut tx, 5 + data.len() as u32, &mut buffer);
+    let pos = tx as usize;
+    (&out[pos - 2..pos]).try_into().unwrap()
+}
+
+#[test]
+#[serial]
+fn reset_without_session() {
+    assert_eq!(send(CLA, INS, 0, &[]), ApduError::Success);
+    assert_eq!(send(CLA, INS, 0, &[]), ApduError::Success);
+}
+
+#[test]
+#[serial]
+fn reset_frees_resources() {
+    let tx_data = hex::decode("ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+
+    let mut init = Vec::from([5u8]);
+    for n in [0x8000_0000 + 44, 0x8000_0000 + 60, 0x8000_0000, 0, 0u32] {
+        init.extend_from_slice(&n.to_be_bytes());
+    }
+    init.extend_from_slice(&tx_data[..10]);
+
+    assert_eq!(send(CLA_ETH, INS_ETH_SIGN, 0x00, &init), ApduError::Success);
+    assert_eq!(send(CLA, INS, 0, &[]), ApduError::Success);
+
+    //the stream was dropped, so the rest of it is refused
+    assert_eq!(
+        send(CLA_ETH, INS_ETH_SIGN, 0x80, &tx_data[10..]),
+        ApduError::ExecutionError
+    );
+
+    assert_eq!(send(CLA_ETH, INS_ETH_SIGN, 0x00, &init), ApduError::Success);
+    assert_eq!(send(CLA, INS, 0, &[]), ApduError::Success);
+}
+
+#[test]
+#[serial]
+fn failed_sign_releases_path() {
+    let mut root = Vec::from([3u8]);
+    for n in [0x8000_0000 + 44, 0x8000_0000 + 9000, 0x8000_0000u32] {
+        root.extend_from_slice(&n.to_be_bytes());
+    }
+
+    let mut init = Vec::from([5u8]);
+    for n in [0x8000_0000 + 44, 0x8000_0000 + 60, 0x8000_0000, 0, 0u32] {
+        init.extend_from_slice(&n.to_be_bytes());
+    }
+    init.extend_from_slice(&[0xed, 0x01]);
+
+    //no change paths, followed by something that isn't a transaction
+    assert_eq!(send(CLA, INS_SIGN, 0x00, &root), ApduError::Success);
+    assert_eq!(
+        send(CLA, INS_SIGN, 0x02, &[0x00, 0xde, 0xad]),
+        ApduError::DataInvalid
+    );
+
+    //nothing was kept, another session starts right away
+    assert_eq!(send(CLA_ETH, INS_ETH_SIGN, 0x00, &init), ApduError::Success);
+
+    //which isn't taken over in turn
+    assert_eq!(send(CLA, INS_SIGN, 0x00, &root), ApduError::Busy);
+    assert_eq!(send(CLA, INS, 0, &[]), ApduError::Success);
+}
+
+#[test]
+#[serial]
+fn failed_eth_sign_releases_resources() {
+    let mut init = Vec::from([5u8]);
+    for n in [0x8000_0000 + 44, 0x8000_0000 + 60, 0x8000_0000, 0, 0u32] {
+        init.extend_from_slice(&n.to_be_bytes());
+    }
+    //a complete rlp list that isn't a transaction
+    init.extend_from_slice(&[0xc1, 0x80]);
+
+    let mut root = Vec::from([3u8]);
+    for n in [0x8000_0000 + 44, 0x8000_0000 + 9000, 0x8000_0000u32] {
+        root.extend_from_slice(&n.to_be_bytes());
+    }
+
+    assert_ne!(send(CLA_ETH, INS_ETH_SIGN, 0x00, &init), ApduError::Success);
+
+    //nothing was kept, another session starts right away
+    assert_eq!(send(CLA, INS_SIGN, 0x00, &root), ApduError::Success);
+    assert_eq!(send(CLA, INS, 0, &[]), ApduError::Success);
+}
+
+#[test]
+#[serial]
+fn reset_frees_avax_upload() {
+    let mut root = Vec::from([3u8]);
+    for n in [0x8000_0000 + 44, 0x8000_0000 + 9000, 0x8000_0000u32] {
+        root.extend_from_slice(&n.to_be_bytes());
+    }
+
+    let mut init = Vec::from([5u8]);
+    for n in [0x8000_0000 + 44, 0x8000_0000 + 60, 0x8000_0000, 0, 0u32] {
+        init.extend_from_slice(&n.to_be_bytes());
+    }
+    init.extend_from_slice(&[0xed, 0x01]);
+
+    //an avax transaction whose upload is never completed
+    assert_eq!(send(CLA, INS_SIGN, 0x00, &root), ApduError::Success);
+    assert_eq!(send(CLA, INS_SIGN, 0x01, &[0x00, 0x00]), ApduError::Success);
+    assert_eq!(send(CLA_ETH, INS_ETH_SIGN, 0x00, &init), ApduError::Busy);
+
+    assert_eq!(send(CLA, INS, 0, &[]), ApduError::Success);
+
+    //the upload buffer was released too
+    assert_eq!(send(CLA_ETH, INS_ETH_SIGN, 0x00, &init), ApduError::Success);
+    assert_eq!(send(CLA, INS, 0, &[]), ApduError::Success);
+}
@@ -23,6 +23,12 @@
 
 extern crate no_std_compat as std;
 
+#[cfg(not(any(feature = "avm", feature = "pvm", feature = "evm")))]
+compile_error!("at least one of the `avm`, `pvm` or `evm` features must be enabled");
+
+#[cfg(all(feature = "host", not(any(unix, windows))))]
+compile_error!("the `host` feature only exposes the parser to off-device builds");
+
 mod constants;
 mod dispatcher;
 mod handlers;
@@ -40,6 +46,12 @@ use utils::ApduPanic;
 use handlers::ZPacketType as PacketType;
 mod crypto;
 
+#[cfg(feature = "host")]
+pub use parser::{
+    host::{get_tx_rlp_len, parse_eth_transaction, parse_transaction, render_all},
+    DisplayableItem, EthTransaction, ParserError, Transaction,
+};
+
 cfg_if::cfg_if! {
     if #[cfg(fuzzing)] {
         pub use dispatcher::handle_apdu;
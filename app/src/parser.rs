@@ -19,6 +19,7 @@ use zemu_sys::ViewError;
 
 mod address;
 mod asset_id;
+mod asset_info;
 mod avm_output;
 mod constants;
 mod coreth;
@@ -31,27 +32,38 @@ mod object_list;
 mod operations;
 mod outputs;
 mod pvm_output;
+mod signer;
 mod subnet_auth;
 mod subnet_id;
 mod transactions;
 mod utils;
 mod utxo_id;
 mod validator;
+mod warp;
 
 #[cfg(test)]
 mod snapshots_common;
 
+#[cfg(any(test, feature = "host"))]
+pub mod host;
+
 pub use address::*;
-pub use asset_id::AssetId;
+pub use asset_id::{AssetId, ASSET_ID_LEN, MAX_ASSET_AMOUNT_LEN, MAX_ASSET_LABEL_LEN};
+pub use asset_info::{AssetDenomination, AssetInfo};
 pub use avm_output::AvmOutput;
 pub use constants::*;
 pub use coreth::{
-    bytes_to_u64, data::EthData, export_tx::ExportTx, import_tx::ImportTx, native::EthTransaction,
-    PersonalMsg,
+    bytes_to_u64,
+    data::EthData,
+    export_tx::ExportTx,
+    import_tx::ImportTx,
+    native::{EthHeader, EthTransaction},
+    parse_rlp_item, ChainConfig, ChainTable, DomainInfo, DomainName, Legacy, NativeCurrency,
+    PersonalMsg, TrustedName,
 };
 pub use error::ParserError;
 pub use initial_state::{FxId, InitialState};
-pub use inputs::{Input, SECPTransferInput, TransferableInput};
+pub use inputs::{Input, SECPTransferInput, TransferableInput, UtxoList};
 pub use message::{AvaxMessage, Message};
 pub use network_info::*;
 pub use object_list::ObjectList;
@@ -61,16 +73,21 @@ pub use outputs::{
     SECPTransferOutput, TransferableOutput,
 };
 pub use pvm_output::PvmOutput;
+pub use signer::*;
 pub use subnet_auth::SubnetAuth;
 pub use subnet_id::*;
 pub use transactions::*;
 pub use utils::*;
 pub use utxo_id::UtxoId;
 pub use validator::*;
+pub use warp::{WarpMessage, WarpPayload};
 
 #[cfg(feature = "erc721")]
 pub use coreth::{data::ERC721Info, nft_info::NftInfo};
 
+#[cfg(feature = "erc20")]
+pub use coreth::{ERC20Info, TokenInfo, TokenTable};
+
 ///This trait defines the interface useful in the UI context
 /// so that all the different OperationTypes or other items can handle their own UI
 pub trait DisplayableItem {
@@ -18,22 +18,108 @@ use nom::bytes::complete::take;
 
 use crate::{
     handlers::handle_ui_message,
-    parser::{cb58_output_len, DisplayableItem, ParserError, CB58_CHECKSUM_LEN},
-    utils::bs58_encode,
+    parser::{
+        cb58_output_len, u64_to_fp_str, AssetDenomination, DisplayableItem, ParserError,
+        MAX_ASSET_DENOMINATION, NANO_AVAX_DECIMAL_DIGITS,
+    },
+    utils::cb58_encode,
 };
 
 use zemu_sys::ViewError;
 
 pub const ASSET_ID_LEN: usize = 32;
 
+/// Max len of the label rendered for an asset,
+/// either its name or its CB58 encoded id
+pub const MAX_ASSET_LABEL_LEN: usize = cb58_output_len::<ASSET_ID_LEN>();
+
+/// Max len of an amount formatted with the asset denomination,
+/// the decimals, '0.' and a null terminator
+pub const MAX_ASSET_AMOUNT_LEN: usize = MAX_ASSET_DENOMINATION as usize + 3;
+
+// AVAX asset id on mainnet, fuji and local networks
+const AVAX_ASSET_IDS: &[[u8; ASSET_ID_LEN]; 3] = &[
+    [
+        0x21, 0xe6, 0x73, 0x17, 0xcb, 0xc4, 0xbe, 0x2a, 0xeb, 0x00, 0x67, 0x7a, 0xd6, 0x46, 0x27,
+        0x78, 0xa8, 0xf5, 0x22, 0x74, 0xb9, 0xd6, 0x05, 0xdf, 0x25, 0x91, 0xb2, 0x30, 0x27, 0xa8,
+        0x7d, 0xff,
+    ],
+    [
+        0x3d, 0x9b, 0xda, 0xc0, 0xed, 0x1d, 0x76, 0x13, 0x30, 0xcf, 0x68, 0x0e, 0xfd, 0xeb, 0x1a,
+        0x42, 0x15, 0x9e, 0xb3, 0x87, 0xd6, 0xd2, 0x95, 0x0c, 0x96, 0xf7, 0xd2, 0x8f, 0x61, 0xbb,
+        0xe2, 0xaa,
+    ],
+    [
+        0xdb, 0xcf, 0x89, 0x0f, 0x77, 0xf4, 0x9b, 0x96, 0x85, 0x76, 0x48, 0xb7, 0x2b, 0x77, 0xf9,
+        0xf8, 0x29, 0x37, 0xf2, 0x8a, 0x68, 0x70, 0x4a, 0xf0, 0x5d, 0xa0, 0xdc, 0x12, 0xba, 0x53,
+        0xf2, 0xdb,
+    ],
+];
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(any(test, feature = "derive-debug"), derive(Debug))]
 pub struct AssetId<'b>(&'b [u8; ASSET_ID_LEN]);
 
 impl<'b> AssetId<'b> {
+    pub fn new(id: &'b [u8; ASSET_ID_LEN]) -> Self {
+        Self(id)
+    }
+
     pub fn id(&self) -> &[u8; ASSET_ID_LEN] {
         self.0
     }
+
+    /// Returns if this is the AVAX asset of a known network
+    pub fn is_avax(&self) -> bool {
+        use bolos::PIC;
+
+        let avax_ids = PIC::new(AVAX_ASSET_IDS).into_inner();
+        avax_ids.iter().any(|id| id == self.0)
+    }
+
+    /// Returns the name of the asset if it is a well-known one
+    pub fn known_name(&self) -> Option<&'static [u8]> {
+        use bolos::pic_str;
+
+        if self.is_avax() {
+            return Some(pic_str!(b"AVAX"!));
+        }
+
+        None
+    }
+
+    /// Writes the asset name if known, otherwise
+    /// its CB58 encoded id
+    pub fn write_label(&self, out: &mut [u8]) -> Result<usize, ViewError> {
+        if let Some(name) = self.known_name() {
+            let dst = out.get_mut(..name.len()).ok_or(ViewError::Unknown)?;
+            dst.copy_from_slice(name);
+            return Ok(name.len());
+        }
+
+        cb58_encode(self.0, out).map_err(|_| ViewError::Unknown)
+    }
+
+    /// Returns the decimal places of the asset, if known,
+    /// either because it is AVAX or it was provided by the host
+    pub fn denomination(&self) -> Option<u8> {
+        if self.known_name().is_some() {
+            return Some(NANO_AVAX_DECIMAL_DIGITS as u8);
+        }
+
+        AssetDenomination::lookup(self.0)
+    }
+
+    /// Formats `amount` with the asset denomination, amounts
+    /// of assets with an unknown one are shown in their base unit
+    pub fn format_amount<'o>(
+        &self,
+        amount: u64,
+        out: &'o mut [u8],
+    ) -> Result<&'o mut [u8], ParserError> {
+        let decimals = self.denomination().unwrap_or_default();
+        u64_to_fp_str(amount, decimals as usize, out)
+    }
 }
 
 impl<'b> AssetId<'b> {
@@ -68,10 +154,7 @@ impl<'a> DisplayableItem for AssetId<'a> {
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
-        use bolos::{
-            hash::{Hasher, Sha256},
-            pic_str, PIC,
-        };
+        use bolos::{pic_str, PIC};
 
         if item_n != 0 {
             return Err(ViewError::NoData);
@@ -79,20 +162,82 @@ impl<'a> DisplayableItem for AssetId<'a> {
 
         let title_content = pic_str!(b"AssetId");
         title[..title_content.len()].copy_from_slice(title_content);
-        let mut data = [0; ASSET_ID_LEN + CB58_CHECKSUM_LEN];
 
-        data[..ASSET_ID_LEN].copy_from_slice(&self.0[..]);
+        let mut label = [0; MAX_ASSET_LABEL_LEN];
+        let len = self.write_label(&mut label[..])?;
+
+        handle_ui_message(&label[..len], message, page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    fn render(id: &[u8; ASSET_ID_LEN]) -> std::string::String {
+        let mut title = [0; 32];
+        let mut message = [0; 100];
+        AssetId(id)
+            .render_item(0, &mut title, &mut message, 0)
+            .unwrap();
+
+        let len = message.iter().position(|b| *b == 0).unwrap();
+        std::string::String::from_utf8(message[..len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn render_avax_asset_id() {
+        let mainnet = &AVAX_ASSET_IDS[0];
+        assert_eq!(AssetId(mainnet).known_name(), Some(&b"AVAX"[..]));
+        assert_eq!(render(mainnet), "AVAX");
+
+        let fuji = &AVAX_ASSET_IDS[1];
+        assert_eq!(render(fuji), "AVAX");
+    }
+
+    #[test]
+    fn render_unknown_asset_id() {
+        let id = [0; ASSET_ID_LEN];
+        assert!(AssetId(&id).known_name().is_none());
+        assert_eq!(render(&id), "11111111111111111111111111111111LpoYY");
+    }
+
+    fn format(asset: &AssetId, amount: u64) -> std::string::String {
+        let mut out = [0; MAX_ASSET_AMOUNT_LEN];
+        let amount = asset.format_amount(amount, &mut out[..]).unwrap();
+        std::string::String::from_utf8(amount.to_vec()).unwrap()
+    }
+
+    #[test]
+    #[serial]
+    fn custom_asset_amounts() {
+        use crate::parser::{AssetInfo, FromBytes};
+
+        let id = [0x22; ASSET_ID_LEN];
+        let asset = AssetId(&id);
 
-        let checksum = Sha256::digest(&data[..ASSET_ID_LEN]).map_err(|_| ViewError::Unknown)?;
+        // unknown denomination
+        assert_eq!(asset.denomination(), None);
+        assert_eq!(format(&asset, 1_234_567), "1234567");
 
-        // prepare the data to be encoded by appending last 4-byte
-        data[ASSET_ID_LEN..].copy_from_slice(&checksum[(Sha256::DIGEST_LEN - CB58_CHECKSUM_LEN)..]);
+        let mut info = [0x22; ASSET_ID_LEN + 1];
+        info[ASSET_ID_LEN] = 6;
+        let (_, info) = AssetInfo::from_bytes(&info).unwrap();
+        AssetDenomination::set_info(Some(info));
 
-        const MAX_SIZE: usize = cb58_output_len::<ASSET_ID_LEN>();
-        let mut encoded = [0; MAX_SIZE];
+        assert_eq!(asset.denomination(), Some(6));
+        assert_eq!(format(&asset, 1_234_567), "1.234567");
+        assert_eq!(format(&asset, 500), "0.0005");
 
-        let len = bs58_encode(data, &mut encoded[..]).map_err(|_| ViewError::Unknown)?;
+        // it only applies to the provided asset
+        let other = [0x33; ASSET_ID_LEN];
+        assert_eq!(format(&AssetId(&other), 1_234_567), "1234567");
+        assert_eq!(
+            format(&AssetId(&AVAX_ASSET_IDS[0]), 1_234_567),
+            "0.001234567"
+        );
 
-        handle_ui_message(&encoded[..len], message, page)
+        AssetDenomination::set_info(None);
     }
 }
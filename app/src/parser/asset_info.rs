@@ -0,0 +1,177 @@
+/*******************************************************************************
+*   (c) 2022 Zondax AG
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use core::{mem::MaybeUninit, ptr::addr_of_mut};
+use nom::{bytes::complete::take, number::complete::be_u8};
+use zemu_sys::ViewError;
+
+use crate::{
+    handlers::{handle_ui_message, resources::ASSET_INFO},
+    parser::{
+        u8_to_str, AssetId, DisplayableItem, FromBytes, ParserError, ASSET_ID_LEN,
+        MAX_ASSET_DENOMINATION, MAX_ASSET_LABEL_LEN,
+    },
+};
+
+/// The denomination of a custom X-chain asset, as defined
+/// in its `CreateAssetTx`, provided by the host
+///
+/// Nothing vouches for it, so it is reviewed with the transaction
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+#[cfg_attr(test, derive(Debug))]
+pub struct AssetInfo {
+    id: [u8; ASSET_ID_LEN],
+    denomination: u8,
+}
+
+impl AssetInfo {
+    pub fn id(&self) -> &[u8; ASSET_ID_LEN] {
+        &self.id
+    }
+
+    pub fn denomination(&self) -> u8 {
+        self.denomination
+    }
+}
+
+impl DisplayableItem for AssetInfo {
+    fn num_items(&self) -> usize {
+        1
+    }
+
+    #[inline(never)]
+    fn render_item(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+        use lexical_core::Number;
+
+        if item_n != 0 {
+            return Err(ViewError::NoData);
+        }
+
+        let label = pic_str!(b"Denomination");
+        title[..label.len()].copy_from_slice(label);
+
+        // <denomination> for <asset>
+        let sep = pic_str!(b" for ");
+        let mut content = [0; u8::FORMATTED_SIZE_DECIMAL + 5 + MAX_ASSET_LABEL_LEN];
+
+        let num_len = u8_to_str(self.denomination, &mut content[..])
+            .map_err(|_| ViewError::Unknown)?
+            .len();
+        let mut len = num_len + sep.len();
+        content[num_len..len].copy_from_slice(sep);
+        len += AssetId::new(&self.id).write_label(&mut content[len..])?;
+
+        handle_ui_message(&content[..len], message, page)
+    }
+}
+
+impl<'b> FromBytes<'b> for AssetInfo {
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("AssetInfo::from_bytes_into\x00");
+
+        let (rem, id) = take(ASSET_ID_LEN)(input)?;
+        let (rem, denomination) = be_u8(rem)?;
+        if denomination > MAX_ASSET_DENOMINATION {
+            return Err(ParserError::ValueOutOfRange.into());
+        }
+
+        let out = out.as_mut_ptr();
+        unsafe {
+            let id_out = &mut *addr_of_mut!((*out).id);
+            id_out.copy_from_slice(id);
+
+            addr_of_mut!((*out).denomination).write(denomination);
+        }
+
+        Ok(rem)
+    }
+}
+
+/// Gives the parser access to the provided asset denomination
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct AssetDenomination;
+
+impl AssetDenomination {
+    /// Returns the provided denomination, if it is the one of asset `id`
+    pub fn lookup(id: &[u8; ASSET_ID_LEN]) -> Option<u8> {
+        match unsafe { ASSET_INFO.acquire(Self) } {
+            Ok(Some(info)) if info.id() == id => Some(info.denomination()),
+            _ => None,
+        }
+    }
+
+    /// Returns the provided information, to review it with the transaction
+    pub fn provided() -> Option<AssetInfo> {
+        match unsafe { ASSET_INFO.acquire(Self) } {
+            Ok(info) => *info,
+            _ => None,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn set_info(info: Option<AssetInfo>) {
+        unsafe {
+            *ASSET_INFO.lock(Self).unwrap() = info;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_asset_info() {
+        let mut data = [0x22; ASSET_ID_LEN + 1];
+        data[ASSET_ID_LEN] = 6;
+
+        let (rem, info) = AssetInfo::from_bytes(&data).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(info.id(), &[0x22; ASSET_ID_LEN]);
+        assert_eq!(info.denomination(), 6);
+
+        data[ASSET_ID_LEN] = MAX_ASSET_DENOMINATION + 1;
+        assert!(AssetInfo::from_bytes(&data).is_err());
+    }
+
+    #[test]
+    fn render_asset_info() {
+        let mut data = [0; ASSET_ID_LEN + 1];
+        data[ASSET_ID_LEN] = 6;
+        let (_, info) = AssetInfo::from_bytes(&data).unwrap();
+
+        let mut title = [0; 32];
+        let mut message = [0; 100];
+        info.render_item(0, &mut title, &mut message, 0).unwrap();
+
+        let len = message.iter().position(|b| *b == 0).unwrap();
+        assert_eq!(
+            &message[..len],
+            b"6 for 11111111111111111111111111111111LpoYY"
+        );
+    }
+}
@@ -19,6 +19,29 @@ pub const NETWORK_ID_MAINNET: u32 = 1;
 pub const NETWORK_ID_FUJI: u32 = 5;
 pub const NETWORK_ID_LOCAL: u32 = 12345;
 
+// X-chain blockchain ids
+pub const X_CHAIN_ID_MAINNET: [u8; 32] = [
+    0xed, 0x5f, 0x38, 0x34, 0x1e, 0x43, 0x6e, 0x5d, 0x46, 0xe2, 0xbb, 0x00, 0xb4, 0x5d, 0x62, 0xae,
+    0x97, 0xd1, 0xb0, 0x50, 0xc6, 0x4b, 0xc6, 0x34, 0xae, 0x10, 0x62, 0x67, 0x39, 0xe3, 0x5c, 0x4b,
+];
+pub const X_CHAIN_ID_FUJI: [u8; 32] = [
+    0xab, 0x68, 0xeb, 0x1e, 0xe1, 0x42, 0xa0, 0x5c, 0xfe, 0x76, 0x8c, 0x36, 0xe1, 0x1f, 0x0b, 0x59,
+    0x6d, 0xb5, 0xa3, 0xc6, 0xc7, 0x7a, 0xab, 0xe6, 0x65, 0xda, 0xd9, 0xe6, 0x38, 0xca, 0x94, 0xf7,
+];
+
+// C-chain blockchain ids
+pub const C_CHAIN_ID_MAINNET: [u8; 32] = [
+    0x04, 0x27, 0xd4, 0xb2, 0x2a, 0x2a, 0x78, 0xbc, 0xdd, 0xd4, 0x56, 0x74, 0x2c, 0xaf, 0x91, 0xb5,
+    0x6b, 0xad, 0xbf, 0xf9, 0x85, 0xee, 0x19, 0xae, 0xf1, 0x45, 0x73, 0xe7, 0x34, 0x3f, 0xd6, 0x52,
+];
+pub const C_CHAIN_ID_FUJI: [u8; 32] = [
+    0x7f, 0xc9, 0x3d, 0x85, 0xc6, 0xd6, 0x2c, 0x5b, 0x2a, 0xc0, 0xb5, 0x19, 0xc8, 0x70, 0x10, 0xea,
+    0x52, 0x94, 0x01, 0x2d, 0x1e, 0x40, 0x70, 0x30, 0xd6, 0xac, 0xd0, 0x02, 0x1c, 0xac, 0x10, 0xd5,
+];
+
+// the P-chain has the same blockchain id on every network
+pub const P_CHAIN_ID: [u8; 32] = [0; 32];
+
 // hrp
 pub const HRP_MAINNET: &str = "avax";
 pub const HRP_TESTNET: &str = "fuji";
@@ -34,6 +57,11 @@ pub const PVM_ADD_VALIDATOR: u32 = 0x0000000c;
 pub const PVM_ADD_SUBNET_VALIDATOR: u32 = 0x0000000d;
 pub const PVM_ADD_DELEGATOR: u32 = 0x0000000e;
 pub const PVM_CREATE_CHAIN: u32 = 0x0000000f;
+pub const PVM_REMOVE_SUBNET_VALIDATOR: u32 = 0x00000017;
+pub const PVM_TRANSFORM_SUBNET: u32 = 0x00000018;
+pub const PVM_ADD_PERMISSIONLESS_VALIDATOR: u32 = 0x00000019;
+pub const PVM_ADD_PERMISSIONLESS_DELEGATOR: u32 = 0x0000001a;
+pub const PVM_BASE_TX: u32 = 0x00000022;
 
 // avm transaction types
 pub const AVM_CREATE_ASSET_TX: u32 = 0x00000001;
@@ -41,11 +69,16 @@ pub const AVM_OPERATION_TX: u32 = 0x00000002;
 pub const AVM_IMPORT_TX: u32 = 0x00000003;
 pub const AVM_EXPORT_TX: u32 = 0x00000004;
 
+// warp message payload types
+pub const WARP_HASH_PAYLOAD: u32 = 0x00000000;
+pub const WARP_ADDRESSED_CALL: u32 = 0x00000001;
+
 // evm transaction types
 pub const EVM_IMPORT_TX: u32 = 0x00000000;
 pub const EVM_EXPORT_TX: u32 = 0x00000001;
 pub const EIP1559_TX: u8 = 0x02;
 pub const EIP2930_TX: u8 = 0x01;
+pub const EIP7702_TX: u8 = 0x04;
 pub const ETH_ARG_LEN: usize = 32;
 // The number of bytes to be shown
 // when rendering the contract data
@@ -55,12 +88,37 @@ pub const DEPLOY_DATA_PREVIEW_LEN: usize = 30;
 // taken from app-ethereum where its value is 70
 // but we reduce it to 50 to save some bytes
 pub const COLLECTION_NAME_MAX_LEN: usize = 50;
+// longest ERC20 ticker shown by app-ethereum
+pub const TICKER_MAX_LEN: usize = 10;
+// ERC20 tokens that can be provided ahead of a transaction
+pub const ERC20_INFO_SLOTS: usize = 4;
+// EVM chains whose native currency can be provided ahead of a transaction
+pub const CHAIN_CONFIG_SLOTS: usize = 4;
+// decimal places of the native currency of an EVM chain
+pub const MAX_NATIVE_DECIMALS: u8 = 36;
+// longest domain name shown by app-ethereum
+pub const DOMAIN_NAME_MAX_LEN: usize = 30;
+// structure type and version of the trusted names of app-ethereum
+pub const TRUSTED_NAME_TYPE: u8 = 0x03;
+pub const TRUSTED_NAME_VERSION: u8 = 0x02;
+// type of trusted names given to accounts, the only one shown
+pub const TRUSTED_NAME_ACCOUNT: u8 = 0x01;
+// decimal places of an X-chain asset, as limited by avalanchego
+pub const MAX_ASSET_DENOMINATION: u8 = 32;
+// longest source address of a warp addressed call
+pub const WARP_MAX_ADDRESS_LEN: usize = 32;
+// upper bound for the payload of a NFT
+pub const NFT_MAX_PAYLOAD_LEN: usize = 1024;
+// items are indexed with a u8
+pub const MAX_UI_ITEMS: usize = u8::MAX as usize;
 
 //Avax units
 pub const NANO_AVAX_DECIMAL_DIGITS: usize = 9;
 pub const WEI_NAVAX_DIGITS: usize = 9;
 pub const WEI_AVAX_DIGITS: usize = 18;
 pub const DELEGATION_FEE_DIGITS: usize = 4;
+// delegation shares are given in parts per million
+pub const DELEGATION_SHARES_DENOMINATOR: u32 = 1_000_000;
 
 // data formatting constants
 
@@ -68,6 +126,11 @@ pub const DELEGATION_FEE_DIGITS: usize = 4;
 // yyyy-mm-dd hh:mm:ss UTC
 pub const FORMATTED_STR_DATE_LEN: usize = 23;
 
+// staking period bounds of the primary network, in seconds
+pub const MAX_STAKE_DURATION: i64 = 365 * 24 * 60 * 60;
+pub const MIN_STAKE_DURATION_MAINNET: i64 = 14 * 24 * 60 * 60;
+pub const MIN_STAKE_DURATION_TESTNET: i64 = 24 * 60 * 60;
+
 // other constants
 pub const CB58_CHECKSUM_LEN: usize = 4;
 pub const U32_SIZE: usize = std::mem::size_of::<u32>();
@@ -17,7 +17,9 @@
 pub mod inputs;
 pub mod outputs;
 
+pub mod chain_config;
 pub mod data;
+pub mod domain_info;
 pub mod export_tx;
 pub mod import_tx;
 pub mod native;
@@ -25,8 +27,16 @@ pub mod native;
 #[cfg(feature = "erc721")]
 pub mod nft_info;
 
+#[cfg(feature = "erc20")]
+pub mod token_info;
+
+pub use chain_config::*;
 pub use data::*;
+pub use domain_info::*;
 pub use native::*;
 
 #[cfg(feature = "erc721")]
 pub use nft_info::*;
+
+#[cfg(feature = "erc20")]
+pub use token_info::*;
@@ -0,0 +1,256 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use bolos::{pic_str, PIC};
+use core::{mem::MaybeUninit, ptr::addr_of_mut};
+use nom::{
+    bytes::complete::take,
+    number::complete::{be_u64, be_u8},
+};
+use zemu_sys::ViewError;
+
+use super::render_u256;
+use crate::{
+    handlers::resources::CHAIN_CONFIG,
+    parser::{
+        FromBytes, ParserError, CHAIN_CONFIG_SLOTS, MAX_NATIVE_DECIMALS, TICKER_MAX_LEN,
+        WEI_AVAX_DIGITS,
+    },
+};
+
+/// The native currency of an EVM chain, provided by the host
+///
+/// The signature that follows it is verified by the handler
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+#[cfg_attr(test, derive(Debug))]
+pub struct ChainConfig {
+    chain_id: u64,
+    symbol: [u8; TICKER_MAX_LEN],
+    symbol_len: u8,
+    decimals: u8,
+}
+
+impl ChainConfig {
+    pub fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    pub fn symbol(&self) -> &[u8] {
+        &self.symbol[..self.symbol_len as usize]
+    }
+
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+}
+
+impl<'b> FromBytes<'b> for ChainConfig {
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("ChainConfig::from_bytes_into\x00");
+
+        let (rem, chain_id) = be_u64(input)?;
+
+        let (rem, symbol_len) = be_u8(rem)?;
+        let symbol_len = symbol_len as usize;
+        if symbol_len == 0 || symbol_len > TICKER_MAX_LEN {
+            return Err(ParserError::ValueOutOfRange.into());
+        }
+
+        let (rem, symbol) = take(symbol_len)(rem)?;
+        if !symbol.is_ascii() {
+            return Err(ParserError::InvalidAsciiValue.into());
+        }
+
+        let (rem, decimals) = be_u8(rem)?;
+        if decimals > MAX_NATIVE_DECIMALS {
+            return Err(ParserError::ValueOutOfRange.into());
+        }
+
+        let out = out.as_mut_ptr();
+        unsafe {
+            let symbol_out = &mut *addr_of_mut!((*out).symbol);
+            symbol_out.fill(0);
+            symbol_out[..symbol.len()].copy_from_slice(symbol);
+
+            addr_of_mut!((*out).chain_id).write(chain_id);
+            addr_of_mut!((*out).symbol_len).write(symbol_len as u8);
+            addr_of_mut!((*out).decimals).write(decimals);
+        }
+
+        Ok(rem)
+    }
+}
+
+/// The chain configurations provided by the host, up to `CHAIN_CONFIG_SLOTS`
+///
+/// Once full, the configuration that was stored first is evicted
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct ChainTable {
+    slots: [Option<ChainConfig>; CHAIN_CONFIG_SLOTS],
+    // the slot to evict next
+    oldest: usize,
+}
+
+impl ChainTable {
+    pub const fn new() -> Self {
+        Self {
+            slots: [None; CHAIN_CONFIG_SLOTS],
+            oldest: 0,
+        }
+    }
+
+    /// Stores `config`, replacing the one of the same chain if present
+    pub fn insert(&mut self, config: ChainConfig) {
+        let at = self
+            .slots
+            .iter()
+            .position(|s| matches!(s, Some(c) if c.chain_id == config.chain_id))
+            .or_else(|| self.slots.iter().position(Option::is_none))
+            .unwrap_or_else(|| {
+                let at = self.oldest;
+                self.oldest = (at + 1) % CHAIN_CONFIG_SLOTS;
+                at
+            });
+
+        self.slots[at] = Some(config);
+    }
+
+    pub fn find(&self, chain_id: u64) -> Option<&ChainConfig> {
+        self.slots.iter().flatten().find(|c| c.chain_id == chain_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.iter().flatten().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for ChainTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Gives the EVM parsers access to the provided chain configurations
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct NativeCurrency;
+
+impl NativeCurrency {
+    /// Returns the provided configuration of `chain_id`
+    pub fn lookup(chain_id: u64) -> Option<&'static ChainConfig> {
+        match unsafe { CHAIN_CONFIG.acquire(Self) } {
+            Ok(table) => table.find(chain_id),
+            _ => None,
+        }
+    }
+
+    /// Renders `value`, given in the smallest unit, in the native
+    /// currency of `chain_id` or in AVAX if none was provided
+    pub fn render_value(
+        chain_id: u64,
+        value: &[u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        let avax = pic_str!(b"AVAX");
+        let (symbol, decimals) = match Self::lookup(chain_id) {
+            Some(config) => (config.symbol(), config.decimals() as usize),
+            None => (&avax[..], WEI_AVAX_DIGITS),
+        };
+
+        let (prefix, message) = message.split_at_mut(symbol.len() + 1);
+        prefix[..symbol.len()].copy_from_slice(symbol);
+        prefix[symbol.len()] = b' ';
+
+        render_u256(value, decimals, message, page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(chain_id: u64) -> ChainConfig {
+        let mut data = [0; 8 + 1 + 3 + 1];
+        data[..8].copy_from_slice(&chain_id.to_be_bytes());
+        data[8] = 3;
+        data[9..12].copy_from_slice(b"TKN");
+        data[12] = 18;
+
+        ChainConfig::from_bytes(&data).unwrap().1
+    }
+
+    #[test]
+    fn parse_chain_config() {
+        let c = config(43114);
+        assert_eq!(c.chain_id(), 43114);
+        assert_eq!(c.symbol(), b"TKN");
+        assert_eq!(c.decimals(), 18);
+
+        // no symbol
+        let data = [0, 0, 0, 0, 0, 0, 0xa8, 0x6a, 0, 18];
+        assert!(ChainConfig::from_bytes(&data).is_err());
+
+        // too many decimals
+        let data = [
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0xa8,
+            0x6a,
+            1,
+            b'A',
+            MAX_NATIVE_DECIMALS + 1,
+        ];
+        assert!(ChainConfig::from_bytes(&data).is_err());
+    }
+
+    #[test]
+    fn evicts_oldest_config() {
+        let mut table = ChainTable::new();
+        for n in 0..CHAIN_CONFIG_SLOTS as u64 {
+            table.insert(config(n));
+        }
+        assert_eq!(table.len(), CHAIN_CONFIG_SLOTS);
+
+        // providing a stored chain again does not evict any
+        table.insert(config(0));
+        assert_eq!(table.len(), CHAIN_CONFIG_SLOTS);
+
+        table.insert(config(0xff));
+        assert!(table.find(0).is_none());
+        assert!(table.find(1).is_some());
+        assert!(table.find(0xff).is_some());
+
+        table.clear();
+        assert!(table.is_empty());
+    }
+}
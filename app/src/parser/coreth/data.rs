@@ -34,6 +34,12 @@ mod erc721;
 #[cfg(feature = "erc721")]
 pub use erc721::{ERC721Info, ERC721};
 
+// ERC1155 relies on the same NFT information as ERC721
+#[cfg(feature = "erc721")]
+mod erc1155;
+#[cfg(feature = "erc721")]
+pub use erc1155::ERC1155;
+
 use super::native::parse_rlp_item;
 pub use asset_call::AssetCall;
 pub use contract_call::ContractCall;
@@ -51,9 +57,23 @@ pub enum EthData<'b> {
     Erc20(ERC20<'b>),
     #[cfg(feature = "erc721")]
     Erc721(ERC721<'b>),
+    #[cfg(feature = "erc721")]
+    Erc1155(ERC1155<'b>),
 }
 
 impl<'b> EthData<'b> {
+    /// The recipient encoded in the calldata of
+    /// ERC-20 and ERC-721 transfers
+    pub fn recipient(&self) -> Option<Address<'b>> {
+        match self {
+            #[cfg(feature = "erc20")]
+            Self::Erc20(erc20) => erc20.recipient(),
+            #[cfg(feature = "erc721")]
+            Self::Erc721(erc721) => erc721.recipient(),
+            _ => None,
+        }
+    }
+
     pub fn parse_into(
         to: &Option<Address<'b>>,
         input: &'b [u8],
@@ -81,7 +101,7 @@ impl<'b> EthData<'b> {
                 if AssetCall::is_asset_call(to, data) {
                     Self::parse_asset_call(data, out)?
                 } else {
-                    // chain contract parsing, prioritizing ERC-721
+                    // chain contract parsing, prioritizing NFTs(ERC-721/ERC-1155)
                     // if it fails try ERC-20, otherwise default to
                     // a generic contract call
                     cfg_if::cfg_if! {
@@ -152,6 +172,12 @@ impl<'b> EthData<'b> {
             |erc721| ERC721::parse_into(contract_address, data, erc721),
             out,
         )
+        .or_else(|_| {
+            Self::init_as_erc_1155(
+                |erc1155| ERC1155::parse_into(contract_address, data, erc1155),
+                out,
+            )
+        })
     }
 
     fn parse_contract_call(data: &'b [u8], out: &mut MaybeUninit<Self>) -> Result<(), ParserError> {
@@ -173,6 +199,8 @@ impl<'b> DisplayableItem for EthData<'b> {
             Self::Erc20(d) => d.num_items(),
             #[cfg(feature = "erc721")]
             Self::Erc721(d) => d.num_items(),
+            #[cfg(feature = "erc721")]
+            Self::Erc1155(d) => d.num_items(),
             Self::ContractCall(d) => d.num_items(),
         }
     }
@@ -192,6 +220,8 @@ impl<'b> DisplayableItem for EthData<'b> {
             Self::Erc20(d) => d.render_item(item_n, title, message, page),
             #[cfg(feature = "erc721")]
             Self::Erc721(d) => d.render_item(item_n, title, message, page),
+            #[cfg(feature = "erc721")]
+            Self::Erc1155(d) => d.render_item(item_n, title, message, page),
             Self::ContractCall(d) => d.render_item(item_n, title, message, page),
         }
     }
@@ -16,12 +16,12 @@
 
 use core::{mem::MaybeUninit, ptr::addr_of_mut};
 
-use nom::{bytes::complete::take, number::complete::be_u32};
+use nom::number::complete::be_u32;
 use zemu_sys::ViewError;
 
 use crate::{
     handlers::handle_ui_message,
-    parser::{DisplayableItem, ParserError, DEPLOY_DATA_PREVIEW_LEN, ETH_ARG_LEN},
+    parser::{DisplayableItem, ParserError, DEPLOY_DATA_PREVIEW_LEN},
     utils::hex_encode,
 };
 
@@ -42,14 +42,11 @@ impl<'b> ContractCall<'b> {
         // each argument should be 32-bytes len.
 
         // get selector
-        let (mut rem, _) = be_u32(data)?;
-
-        // check for arguments
-        // if this error means an argument
-        // is malformed
-        while !rem.is_empty() {
-            (rem, _) = take(ETH_ARG_LEN)(rem)?;
-        }
+        //
+        // the arguments are not checked, the data is shown
+        // raw even when it can not be decoded as words, for example
+        // after a known selector with the wrong arguments
+        let _ = be_u32(data)?;
         // safe writes
         unsafe {
             addr_of_mut!((*out).0).write(data);
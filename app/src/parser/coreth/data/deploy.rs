@@ -20,7 +20,7 @@ use zemu_sys::ViewError;
 
 use crate::{
     handlers::handle_ui_message,
-    parser::{DisplayableItem, ParserError, DEPLOY_DATA_PREVIEW_LEN},
+    parser::{u64_to_str, DisplayableItem, ParserError, DEPLOY_DATA_PREVIEW_LEN},
     utils::hex_encode,
 };
 
@@ -45,6 +45,48 @@ impl<'b> Deploy<'b> {
         }
         Ok(())
     }
+
+    /// Size in bytes of the contract init code
+    pub fn code_len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Renders the page signaling a contract creation,
+    /// as there is no recipient address to show
+    pub fn render_creation(
+        &self,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+        use lexical_core::Number;
+
+        let label = pic_str!(b"Contract creation");
+        title[..label.len()].copy_from_slice(label);
+
+        // Init code: N bytes
+        let prefix = pic_str!(b"Init code: "!);
+        let suffix = pic_str!(b" bytes");
+
+        let mut num = [0; u64::FORMATTED_SIZE_DECIMAL];
+        let num =
+            u64_to_str(self.code_len() as u64, &mut num[..]).map_err(|_| ViewError::Unknown)?;
+
+        let mut output = [0; u64::FORMATTED_SIZE_DECIMAL + 17];
+        let mut sz = 0;
+
+        let mut append = |data: &[u8]| {
+            output[sz..sz + data.len()].copy_from_slice(data);
+            sz += data.len();
+        };
+
+        append(prefix);
+        append(num);
+        append(suffix);
+
+        handle_ui_message(&output[..sz], message, page)
+    }
 }
 
 impl<'b> DisplayableItem for Deploy<'b> {
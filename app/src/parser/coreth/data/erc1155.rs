@@ -0,0 +1,564 @@
+/*******************************************************************************
+*   (c) 2022 Zondax AG
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use core::{mem::MaybeUninit, ptr::addr_of_mut};
+
+use bolos::{pic_str, PIC};
+use nom::{bytes::complete::take, number::complete::be_u32};
+use zemu_sys::ViewError;
+
+use crate::{
+    handlers::{
+        eth::{u256, BorrowedU256},
+        handle_ui_message,
+    },
+    parser::{
+        u64_to_str, Address, DisplayableItem, ERC721Info, FromBytes, ParserError, ADDRESS_LEN,
+        ETH_ARG_LEN,
+    },
+    utils::ApduPanic,
+};
+
+// limits the number of items so every
+// tokenID/amount pair can be indexed by the UI
+const MAX_BATCH_ITEMS: usize = 32;
+
+// reads an ABI offset/length argument, which is
+// a big-endian uint256 that has to fit in an u32
+fn parse_abi_len(input: &[u8]) -> Result<(&[u8], usize), nom::Err<ParserError>> {
+    let (rem, word) = take(ETH_ARG_LEN)(input)?;
+
+    let (padding, len) = word.split_at(ETH_ARG_LEN - 4);
+    if padding.iter().any(|v| *v != 0) {
+        return Err(ParserError::ValueOutOfRange.into());
+    }
+    let (_, len) = be_u32(len)?;
+
+    Ok((rem, len as usize))
+}
+
+// parses the from and to addresses every transfer starts with
+fn parse_from_to<'b>(
+    input: &'b [u8],
+    from: &mut MaybeUninit<Address<'b>>,
+    to: &mut MaybeUninit<Address<'b>>,
+) -> Result<&'b [u8], nom::Err<ParserError>> {
+    let (rem, address) = take(ETH_ARG_LEN)(input)?;
+    //the first N bytes are for padding and are zeros
+    let _ = Address::from_bytes_into(&address[ETH_ARG_LEN - ADDRESS_LEN..], from)?;
+
+    let (rem, address) = take(ETH_ARG_LEN)(rem)?;
+    let _ = Address::from_bytes_into(&address[ETH_ARG_LEN - ADDRESS_LEN..], to)?;
+
+    // do not waste gas
+    let to = unsafe { &*to.as_ptr() };
+    let from = unsafe { &*from.as_ptr() };
+    if to == from {
+        return Err(ParserError::InvalidAddress.into());
+    }
+
+    Ok(rem)
+}
+
+fn render_u256(value: &BorrowedU256, message: &mut [u8], page: u8) -> Result<u8, ViewError> {
+    let mut bytes = [0; u256::FORMATTED_SIZE_DECIMAL + 1];
+    let bytes = value.as_u256().to_lexical(&mut bytes);
+
+    handle_ui_message(bytes, message, page)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "derive-debug"), derive(Debug))]
+pub struct SafeTransferFrom<'b> {
+    from: Address<'b>,
+    to: Address<'b>,
+    token_id: BorrowedU256<'b>,
+    amount: BorrowedU256<'b>,
+}
+
+impl<'b> SafeTransferFrom<'b> {
+    pub const SELECTOR: u32 = u32::from_be_bytes([0xf2, 0x42, 0x43, 0x2a]);
+}
+
+impl<'b> FromBytes<'b> for SafeTransferFrom<'b> {
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("ERC1155SafeTransferFrom::from_bytes_into\x00");
+
+        // get out pointer
+        let out = out.as_mut_ptr();
+
+        let from = unsafe { &mut *addr_of_mut!((*out).from).cast() };
+        let to = unsafe { &mut *addr_of_mut!((*out).to).cast() };
+        let rem = parse_from_to(input, from, to)?;
+
+        let (rem, token_id) = take(ETH_ARG_LEN)(rem)?;
+        let token_id = BorrowedU256::new(token_id).ok_or(ParserError::InvalidEthMessage)?;
+
+        let (rem, amount) = take(ETH_ARG_LEN)(rem)?;
+        let amount = BorrowedU256::new(amount).ok_or(ParserError::InvalidEthMessage)?;
+
+        unsafe {
+            addr_of_mut!((*out).token_id).write(token_id);
+            addr_of_mut!((*out).amount).write(amount);
+        }
+
+        // the data argument is passed as is to the receiver
+        // so it is not shown
+        Ok(&rem[rem.len()..])
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "derive-debug"), derive(Debug))]
+pub struct SafeBatchTransferFrom<'b> {
+    from: Address<'b>,
+    to: Address<'b>,
+    // the raw uint256 words of each array
+    token_ids: &'b [u8],
+    amounts: &'b [u8],
+}
+
+impl<'b> SafeBatchTransferFrom<'b> {
+    pub const SELECTOR: u32 = u32::from_be_bytes([0x2e, 0xb2, 0xc2, 0xd6]);
+
+    pub fn num_tokens(&self) -> usize {
+        self.token_ids.len() / ETH_ARG_LEN
+    }
+
+    // returns the tokenID and amount at idx
+    pub fn get_token(&self, idx: usize) -> Option<(BorrowedU256<'b>, BorrowedU256<'b>)> {
+        let range = idx * ETH_ARG_LEN..(idx + 1) * ETH_ARG_LEN;
+        let token_id = BorrowedU256::new(self.token_ids.get(range.clone())?)?;
+        let amount = BorrowedU256::new(self.amounts.get(range)?)?;
+
+        Some((token_id, amount))
+    }
+
+    // gets the words of the array found at `offset` of the arguments
+    fn parse_array(args: &'b [u8], offset: usize) -> Result<&'b [u8], nom::Err<ParserError>> {
+        let array = args.get(offset..).ok_or(ParserError::UnexpectedBufferEnd)?;
+        let (rem, len) = parse_abi_len(array)?;
+
+        if len == 0 || len > MAX_BATCH_ITEMS {
+            return Err(ParserError::ValueOutOfRange.into());
+        }
+
+        let (_, words) = take(len * ETH_ARG_LEN)(rem)?;
+        Ok(words)
+    }
+}
+
+impl<'b> FromBytes<'b> for SafeBatchTransferFrom<'b> {
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("ERC1155SafeBatchTransferFrom::from_bytes_into\x00");
+
+        // get out pointer
+        let out = out.as_mut_ptr();
+
+        let from = unsafe { &mut *addr_of_mut!((*out).from).cast() };
+        let to = unsafe { &mut *addr_of_mut!((*out).to).cast() };
+        let rem = parse_from_to(input, from, to)?;
+
+        // dynamic arguments are encoded as offsets
+        // relative to the start of the arguments
+        let (rem, ids_offset) = parse_abi_len(rem)?;
+        let (_, amounts_offset) = parse_abi_len(rem)?;
+
+        let token_ids = Self::parse_array(input, ids_offset)?;
+        let amounts = Self::parse_array(input, amounts_offset)?;
+
+        // every tokenID should come with its amount
+        if token_ids.len() != amounts.len() {
+            return Err(ParserError::InvalidEthMessage.into());
+        }
+
+        unsafe {
+            addr_of_mut!((*out).token_ids).write(token_ids);
+            addr_of_mut!((*out).amounts).write(amounts);
+        }
+
+        Ok(&input[input.len()..])
+    }
+}
+
+/// Represents a ERC1155-like contract call
+///
+/// Only transfers are supported, namely the following Solidity signatures:
+/**
+```solidity
+    function safeTransferFrom(address _from, address _to, uint256 _id, uint256 _value, bytes calldata _data) external;
+    function safeBatchTransferFrom(address _from, address _to, uint256[] calldata _ids, uint256[] calldata _values, bytes calldata _data) external;
+```
+*/
+/// As with ERC721, the collection is the one provided
+/// by the NFT information APDU.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "derive-debug"), derive(Debug))]
+#[avalanche_app_derive::enum_init]
+pub enum ERC1155<'b> {
+    SafeTransferFrom(SafeTransferFrom<'b>),
+    SafeBatchTransferFrom(SafeBatchTransferFrom<'b>),
+}
+
+impl ERC1155__Type {
+    pub fn from_selector(selector: u32) -> Option<Self> {
+        match selector {
+            SafeTransferFrom::SELECTOR => Some(Self::SafeTransferFrom),
+            SafeBatchTransferFrom::SELECTOR => Some(Self::SafeBatchTransferFrom),
+            _ => None,
+        }
+    }
+}
+
+impl<'b> ERC1155<'b> {
+    pub fn method_name(&self) -> &'static [u8] {
+        match self {
+            ERC1155::SafeTransferFrom(_) => pic_str!(b"safeTransferFrom"!),
+            ERC1155::SafeBatchTransferFrom(_) => pic_str!(b"safeBatchTransferFrom"!),
+        }
+    }
+
+    pub fn parse_into(
+        contract_address: &Address<'b>,
+        data: &'b [u8],
+        output: &mut MaybeUninit<Self>,
+    ) -> Result<(), ParserError> {
+        // Check the required information to parse this data was provided
+        let nft_info = ERC721Info::get_nft_info()?;
+
+        if contract_address != &nft_info.address() {
+            return Err(ParserError::InvalidContractAddress);
+        }
+
+        // get selector
+        let (rem, selector) = be_u32(data)?;
+
+        let ty = ERC1155__Type::from_selector(selector).ok_or(ParserError::InvalidEthSelector)?;
+
+        match ty {
+            ERC1155__Type::SafeTransferFrom => Self::init_as_safe_transfer_from(
+                |item| SafeTransferFrom::from_bytes_into(rem, item),
+                output,
+            ),
+            ERC1155__Type::SafeBatchTransferFrom => Self::init_as_safe_batch_transfer_from(
+                |item| SafeBatchTransferFrom::from_bytes_into(rem, item),
+                output,
+            ),
+        }?;
+
+        Ok(())
+    }
+
+    fn render_from_to(
+        from: &Address<'_>,
+        to: &Address<'_>,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        match item_n {
+            0 => {
+                let label = pic_str!(b"From");
+                title[..label.len()].copy_from_slice(label);
+
+                // should not panic as address was check
+                from.render_eth_address(message, page)
+            }
+            1 => {
+                let label = pic_str!(b"To");
+                title[..label.len()].copy_from_slice(label);
+
+                to.render_eth_address(message, page)
+            }
+            _ => Err(ViewError::NoData),
+        }
+    }
+
+    fn render_safe_transfer_from(
+        this: &SafeTransferFrom<'_>,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        match item_n {
+            0 => {
+                let label = pic_str!(b"TokenID");
+                title[..label.len()].copy_from_slice(label);
+
+                render_u256(&this.token_id, message, page)
+            }
+            1 => {
+                let label = pic_str!(b"Amount");
+                title[..label.len()].copy_from_slice(label);
+
+                render_u256(&this.amount, message, page)
+            }
+            x => Self::render_from_to(&this.from, &this.to, x - 2, title, message, page),
+        }
+    }
+
+    fn render_safe_batch_transfer_from(
+        this: &SafeBatchTransferFrom<'_>,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use lexical_core::Number;
+
+        let tokens_items = this.num_tokens() as u8 * 2;
+
+        match item_n {
+            0 => {
+                let label = pic_str!(b"Transfer");
+                title[..label.len()].copy_from_slice(label);
+
+                let items = pic_str!(b" items"!);
+                let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 6];
+                let num = u64_to_str(this.num_tokens() as u64, &mut buffer[..])
+                    .map_err(|_| ViewError::Unknown)?;
+                let len = num.len();
+                buffer[len..len + items.len()].copy_from_slice(items);
+
+                handle_ui_message(&buffer[..len + items.len()], message, page)
+            }
+            // each token is shown as a tokenID/amount pair
+            x @ 1.. if x <= tokens_items => {
+                let idx = (x - 1) / 2;
+                let (token_id, amount) = this.get_token(idx as usize).ok_or(ViewError::NoData)?;
+
+                if (x - 1) % 2 == 0 {
+                    let label = pic_str!(b"TokenID");
+                    title[..label.len()].copy_from_slice(label);
+
+                    render_u256(&token_id, message, page)
+                } else {
+                    let label = pic_str!(b"Amount");
+                    title[..label.len()].copy_from_slice(label);
+
+                    render_u256(&amount, message, page)
+                }
+            }
+            x => Self::render_from_to(
+                &this.from,
+                &this.to,
+                x - tokens_items - 1,
+                title,
+                message,
+                page,
+            ),
+        }
+    }
+}
+
+impl<'b> DisplayableItem for ERC1155<'b> {
+    fn num_items(&self) -> usize {
+        // method + collection + from + to
+        4 + match self {
+            // tokenID + amount
+            ERC1155::SafeTransferFrom(_) => 2,
+            // number of items + tokenID/amount pairs
+            ERC1155::SafeBatchTransferFrom(t) => 1 + t.num_tokens() * 2,
+        }
+    }
+
+    fn render_item(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        match item_n {
+            0 => {
+                let title_content = pic_str!(b"NFT Transfer");
+                title[..title_content.len()].copy_from_slice(title_content);
+
+                handle_ui_message(self.method_name(), message, page)
+            }
+            1 => {
+                // valid as at this point it was checked
+                let nft_info = ERC721Info::get_nft_info().apdu_unwrap();
+
+                let label = pic_str!(b"Collection Name");
+                title[..label.len()].copy_from_slice(label);
+
+                nft_info.render_collection_name(message, page)
+            }
+            _x @ 2.. => match &self {
+                ERC1155::SafeTransferFrom(call) => {
+                    Self::render_safe_transfer_from(call, item_n - 2, title, message, page)
+                }
+                ERC1155::SafeBatchTransferFrom(call) => {
+                    Self::render_safe_batch_transfer_from(call, item_n - 2, title, message, page)
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::NftInfo;
+    use serial_test::serial;
+    use std::{format, prelude::v1::*, vec};
+
+    const CONTRACT: &str = "34bc797f40df0445c8429d485232874b15561728";
+    const FROM: &str = "77944eed8d4a00c8bd413f77744751a4d04ea34a";
+    const TO: &str = "5d4994bccdd28afbbc6388fbcaaec69dd44c0456";
+
+    fn set_nft_info(contract: &[u8]) {
+        let mut raw = vec![1, 1, 5];
+        raw.extend_from_slice(b"Lucid");
+        raw.extend_from_slice(contract);
+        raw.extend_from_slice(&1u64.to_be_bytes());
+
+        let (_, info) = NftInfo::from_bytes(&raw).unwrap();
+        ERC721Info::set_info(info).unwrap();
+    }
+
+    fn word(value: u64) -> Vec<u8> {
+        let mut word = vec![0; ETH_ARG_LEN - 8];
+        word.extend_from_slice(&value.to_be_bytes());
+        word
+    }
+
+    fn address(hex_address: &str) -> Vec<u8> {
+        let mut word = vec![0; ETH_ARG_LEN - ADDRESS_LEN];
+        word.extend_from_slice(&hex::decode(hex_address).unwrap());
+        word
+    }
+
+    fn calldata(selector: u32, args: &[Vec<u8>]) -> Vec<u8> {
+        let mut data = selector.to_be_bytes().to_vec();
+        args.iter().for_each(|arg| data.extend_from_slice(arg));
+        data
+    }
+
+    // from, to, ids, amounts and an empty data
+    fn batch_calldata(ids: &[u64], amounts: &[u64]) -> Vec<u8> {
+        let ids_offset = 5 * ETH_ARG_LEN;
+        let amounts_offset = ids_offset + (1 + ids.len()) * ETH_ARG_LEN;
+        let data_offset = amounts_offset + (1 + amounts.len()) * ETH_ARG_LEN;
+
+        let mut args = vec![
+            address(FROM),
+            address(TO),
+            word(ids_offset as u64),
+            word(amounts_offset as u64),
+            word(data_offset as u64),
+            word(ids.len() as u64),
+        ];
+        args.extend(ids.iter().map(|id| word(*id)));
+        args.push(word(amounts.len() as u64));
+        args.extend(amounts.iter().map(|amount| word(*amount)));
+        args.push(word(0));
+
+        calldata(SafeBatchTransferFrom::SELECTOR, &args)
+    }
+
+    fn parse(data: &[u8]) -> Result<ERC1155, ParserError> {
+        let contract = hex::decode(CONTRACT).unwrap();
+        let (_, contract) = Address::from_bytes(&contract).unwrap();
+
+        let mut erc1155 = MaybeUninit::uninit();
+        ERC1155::parse_into(&contract, data, &mut erc1155)?;
+        Ok(unsafe { erc1155.assume_init() })
+    }
+
+    fn render(erc1155: &ERC1155, item_n: u8) -> (String, String) {
+        let mut title = [0; 32];
+        let mut message = [0; 64];
+        erc1155
+            .render_item(item_n, &mut title, &mut message, 0)
+            .unwrap();
+
+        let to_string = |buf: &[u8]| {
+            let len = buf.iter().position(|b| *b == 0).unwrap();
+            String::from_utf8(buf[..len].to_vec()).unwrap()
+        };
+        (to_string(&title), to_string(&message))
+    }
+
+    #[test]
+    #[serial]
+    fn erc1155_safe_transfer_from() {
+        set_nft_info(&hex::decode(CONTRACT).unwrap());
+
+        let data = calldata(
+            SafeTransferFrom::SELECTOR,
+            &[
+                address(FROM),
+                address(TO),
+                word(0x201),
+                word(10),
+                word(5 * ETH_ARG_LEN as u64),
+                word(0),
+            ],
+        );
+        let erc1155 = parse(&data).unwrap();
+
+        assert_eq!(erc1155.num_items(), 6);
+        let items: Vec<_> = (0..6).map(|i| render(&erc1155, i)).collect();
+        assert_eq!(items[0], ("NFT Transfer".into(), "safeTransferFrom".into()));
+        assert_eq!(items[1], ("Collection Name".into(), "Lucid".into()));
+        assert_eq!(items[2], ("TokenID".into(), "513".into()));
+        assert_eq!(items[3], ("Amount".into(), "10".into()));
+        assert_eq!(items[4], ("From".into(), format!("0x{}", FROM)));
+        assert_eq!(items[5], ("To".into(), format!("0x{}", TO)));
+    }
+
+    #[test]
+    #[serial]
+    fn erc1155_safe_batch_transfer_from() {
+        set_nft_info(&hex::decode(CONTRACT).unwrap());
+
+        let erc1155 = parse(&batch_calldata(&[1, 2, 3], &[5, 6, 7])).unwrap();
+
+        // method, collection, number of items, 3 tokenID/amount pairs, from and to
+        assert_eq!(erc1155.num_items(), 11);
+        let items: Vec<_> = (0..11).map(|i| render(&erc1155, i)).collect();
+        assert_eq!(
+            items[0],
+            ("NFT Transfer".into(), "safeBatchTransferFrom".into())
+        );
+        assert_eq!(items[2], ("Transfer".into(), "3 items".into()));
+        assert_eq!(items[3], ("TokenID".into(), "1".into()));
+        assert_eq!(items[4], ("Amount".into(), "5".into()));
+        assert_eq!(items[7], ("TokenID".into(), "3".into()));
+        assert_eq!(items[8], ("Amount".into(), "7".into()));
+        assert_eq!(items[9], ("From".into(), format!("0x{}", FROM)));
+        assert_eq!(items[10], ("To".into(), format!("0x{}", TO)));
+    }
+
+    #[test]
+    #[serial]
+    fn erc1155_batch_length_mismatch() {
+        set_nft_info(&hex::decode(CONTRACT).unwrap());
+
+        let data = batch_calldata(&[1, 2, 3], &[5, 6]);
+        assert_eq!(parse(&data).unwrap_err(), ParserError::InvalidEthMessage);
+    }
+}
@@ -25,7 +25,11 @@ use crate::{
         eth::{u256, BorrowedU256},
         handle_ui_message,
     },
-    parser::{Address, DisplayableItem, FromBytes, ParserError, ADDRESS_LEN, ETH_ARG_LEN},
+    parser::{
+        Address, DisplayableItem, ERC20Info, FromBytes, ParserError, TokenInfo, ADDRESS_LEN,
+        ETH_ARG_LEN, TICKER_MAX_LEN,
+    },
+    utils::format_amount,
 };
 
 /// Represents a ERC20-like contract call
@@ -69,6 +73,26 @@ pub enum ERC20<'b> {
     },
 }
 
+// length of the note following a raw amount
+const UNKNOWN_LEN: usize = b" (decimals unknown)".len();
+
+// reads an address argument, left padded with zeros
+// to the size of a word
+fn parse_address_arg<'b>(
+    input: &'b [u8],
+    out: &mut MaybeUninit<Address<'b>>,
+) -> Result<&'b [u8], nom::Err<ParserError>> {
+    let (rem, word) = take(ETH_ARG_LEN)(input)?;
+
+    let (padding, address) = word.split_at(ETH_ARG_LEN - ADDRESS_LEN);
+    if padding.iter().any(|v| *v != 0) {
+        return Err(ParserError::InvalidAddress.into());
+    }
+    _ = Address::from_bytes_into(address, out)?;
+
+    Ok(rem)
+}
+
 impl<'b> Transfer<'b> {
     pub const SELECTOR: u32 = u32::from_be_bytes([0xa9, 0x05, 0x9c, 0xbb]);
 }
@@ -84,9 +108,7 @@ impl<'b> FromBytes<'b> for Transfer<'b> {
         let out = out.as_mut_ptr();
 
         let to = unsafe { &mut *addr_of_mut!((*out).to).cast() };
-        let (rem, address) = take(ETH_ARG_LEN)(input)?;
-        //the first N bytes are for padding and are zeros
-        let _ = Address::from_bytes_into(&address[ETH_ARG_LEN - ADDRESS_LEN..], to)?;
+        let rem = parse_address_arg(input, to)?;
 
         // value
         let (rem, value) = take(ETH_ARG_LEN)(rem)?;
@@ -115,14 +137,10 @@ impl<'b> FromBytes<'b> for TransferFrom<'b> {
         let out = out.as_mut_ptr();
 
         let from = unsafe { &mut *addr_of_mut!((*out).from).cast() };
-        let (rem, address) = take(ETH_ARG_LEN)(input)?;
-        //the first N bytes are for padding and are zeros
-        let _ = Address::from_bytes_into(&address[ETH_ARG_LEN - ADDRESS_LEN..], from)?;
+        let rem = parse_address_arg(input, from)?;
 
         let to = unsafe { &mut *addr_of_mut!((*out).to).cast() };
-        let (rem, address) = take(ETH_ARG_LEN)(rem)?;
-        //the first N bytes are for padding and are zeros
-        let _ = Address::from_bytes_into(&address[ETH_ARG_LEN - ADDRESS_LEN..], to)?;
+        let rem = parse_address_arg(rem, to)?;
 
         // do not waste gas
         let to = unsafe { &*to.as_ptr() };
@@ -158,9 +176,7 @@ impl<'b> FromBytes<'b> for Approve<'b> {
         let out = out.as_mut_ptr();
 
         let spender = unsafe { &mut *addr_of_mut!((*out).spender).cast() };
-        let (rem, address) = take(ETH_ARG_LEN)(input)?;
-        //the first N bytes are for padding and are zeros
-        let _ = Address::from_bytes_into(&address[ETH_ARG_LEN - ADDRESS_LEN..], spender)?;
+        let rem = parse_address_arg(input, spender)?;
 
         // value
         let (rem, value) = take(ETH_ARG_LEN)(rem)?;
@@ -186,6 +202,14 @@ impl ERC20__Type {
 }
 
 impl<'b> ERC20<'b> {
+    /// The account receiving the tokens, if any is moved
+    pub fn recipient(&self) -> Option<Address<'b>> {
+        match self {
+            ERC20::Transfer { to, .. } | ERC20::TransferFrom { to, .. } => Some(*to),
+            ERC20::Approve { .. } => None,
+        }
+    }
+
     pub fn method_name(&self) -> &'static [u8] {
         match self {
             ERC20::Transfer { .. } => pic_str!(b"transfer"!),
@@ -206,7 +230,7 @@ impl<'b> ERC20<'b> {
 
         let ty = ERC20__Type::from_selector(selector).ok_or(ParserError::InvalidEthSelector)?;
 
-        match ty {
+        let rem = match ty {
             ERC20__Type::Transfer => {
                 Self::init_as_transfer(|item| Transfer::from_bytes_into(rem, item), output)
             }
@@ -218,11 +242,98 @@ impl<'b> ERC20<'b> {
             }
         }?;
 
+        // other methods can share the selector, so the arguments
+        // have to match exactly for the call to be shown as ERC20
+        if !rem.is_empty() {
+            return Err(ParserError::UnexpectedData);
+        }
+
         Ok(())
     }
 
+    // the amount in units of the token when it was provided,
+    // otherwise the raw value, noting the decimals are unknown
+    fn render_amount(
+        value: &BorrowedU256<'_>,
+        token: Option<&TokenInfo>,
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        let info = token.filter(|info| (info.decimals as usize) < u256::FORMATTED_SIZE_DECIMAL);
+        let decimals = info.map(|info| info.decimals as usize).unwrap_or(0);
+
+        let mut bytes = [0; u256::FORMATTED_SIZE_DECIMAL + 2];
+        let amount = format_amount(value.as_u256(), decimals, &mut bytes)
+            .map_err(|_| ViewError::Unknown)?
+            .as_bytes();
+
+        let mut out = [0; TICKER_MAX_LEN + 1 + u256::FORMATTED_SIZE_DECIMAL + 2 + UNKNOWN_LEN];
+        let len = match info {
+            Some(info) => {
+                let ticker = info.ticker();
+                out[..ticker.len()].copy_from_slice(ticker);
+                out[ticker.len()] = b' ';
+                let len = ticker.len() + 1 + amount.len();
+                out[ticker.len() + 1..len].copy_from_slice(amount);
+                len
+            }
+            None => {
+                let note = pic_str!(b" (decimals unknown)"!);
+                out[..amount.len()].copy_from_slice(amount);
+                let len = amount.len() + note.len();
+                out[amount.len()..len].copy_from_slice(note);
+                len
+            }
+        };
+
+        handle_ui_message(&out[..len], message, page)
+    }
+
+    /// Renders `item_n` of a call to the token at `contract`,
+    /// in units of the token if it was provided
+    pub fn render_item_for(
+        &self,
+        contract: &Address<'_>,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        self.render_item_with(ERC20Info::lookup(contract), item_n, title, message, page)
+    }
+
+    fn render_item_with(
+        &self,
+        token: Option<&TokenInfo>,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        match item_n {
+            0 => {
+                let title_content = pic_str!(b"ERC-20");
+                title[..title_content.len()].copy_from_slice(title_content);
+
+                handle_ui_message(self.method_name(), message, page)
+            }
+            _x @ 1.. => match &self {
+                ERC20::Transfer { .. } => {
+                    self.render_transfer(token, item_n - 1, title, message, page)
+                }
+                ERC20::TransferFrom { .. } => {
+                    self.render_transfer_from(token, item_n - 1, title, message, page)
+                }
+                ERC20::Approve { .. } => {
+                    self.render_approve(token, item_n - 1, title, message, page)
+                }
+            },
+        }
+    }
+
     fn render_transfer(
         &self,
+        token: Option<&TokenInfo>,
         item_n: u8,
         title: &mut [u8],
         message: &mut [u8],
@@ -245,10 +356,7 @@ impl<'b> ERC20<'b> {
                 let label = pic_str!(b"Amount");
                 title[..label.len()].copy_from_slice(label);
 
-                let mut bytes = [0; u256::FORMATTED_SIZE_DECIMAL + 1];
-                let bytes = value.as_u256().to_lexical(&mut bytes);
-
-                handle_ui_message(bytes, message, page)
+                Self::render_amount(value, token, message, page)
             }
             _ => Err(ViewError::NoData),
         }
@@ -256,6 +364,7 @@ impl<'b> ERC20<'b> {
 
     fn render_transfer_from(
         &self,
+        token: Option<&TokenInfo>,
         item_n: u8,
         title: &mut [u8],
         message: &mut [u8],
@@ -285,10 +394,7 @@ impl<'b> ERC20<'b> {
                 let label = pic_str!(b"Amount");
                 title[..label.len()].copy_from_slice(label);
 
-                let mut bytes = [0; u256::FORMATTED_SIZE_DECIMAL + 1];
-                let bytes = value.as_u256().to_lexical(&mut bytes);
-
-                handle_ui_message(bytes, message, page)
+                Self::render_amount(value, token, message, page)
             }
             _ => Err(ViewError::NoData),
         }
@@ -296,6 +402,7 @@ impl<'b> ERC20<'b> {
 
     fn render_approve(
         &self,
+        token: Option<&TokenInfo>,
         item_n: u8,
         title: &mut [u8],
         message: &mut [u8],
@@ -318,10 +425,7 @@ impl<'b> ERC20<'b> {
                 let label = pic_str!(b"Amount");
                 title[..label.len()].copy_from_slice(label);
 
-                let mut bytes = [0; u256::FORMATTED_SIZE_DECIMAL + 1];
-                let bytes = value.as_u256().to_lexical(&mut bytes);
-
-                handle_ui_message(bytes, message, page)
+                Self::render_amount(value, token, message, page)
             }
             _ => Err(ViewError::NoData),
         }
@@ -344,20 +448,110 @@ impl<'b> DisplayableItem for ERC20<'b> {
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
-        match item_n {
-            0 => {
-                let title_content = pic_str!(b"ERC-20");
-                title[..title_content.len()].copy_from_slice(title_content);
+        self.render_item_with(None, item_n, title, message, page)
+    }
+}
 
-                handle_ui_message(self.method_name(), message, page)
-            }
-            _x @ 1.. => match &self {
-                ERC20::Transfer { .. } => self.render_transfer(item_n - 1, title, message, page),
-                ERC20::TransferFrom { .. } => {
-                    self.render_transfer_from(item_n - 1, title, message, page)
-                }
-                ERC20::Approve { .. } => self.render_approve(item_n - 1, title, message, page),
-            },
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::EthData;
+    use std::{prelude::v1::*, vec};
+
+    const CONTRACT: [u8; ADDRESS_LEN] = [0x11; ADDRESS_LEN];
+
+    fn word(fill: u8) -> [u8; ETH_ARG_LEN] {
+        let mut word = [0; ETH_ARG_LEN];
+        word[ETH_ARG_LEN - ADDRESS_LEN..].fill(fill);
+        word
+    }
+
+    // the calldata of `selector` with `args`, as a rlp string
+    fn calldata(selector: u32, args: &[u8]) -> Vec<u8> {
+        let len = 4 + args.len();
+        let mut data = if len < 56 {
+            vec![0x80 + len as u8]
+        } else {
+            vec![0xb8, len as u8]
+        };
+        data.extend_from_slice(&selector.to_be_bytes());
+        data.extend_from_slice(args);
+        data
+    }
+
+    fn is_erc20(data: &[u8]) -> bool {
+        let contract = Address::from_bytes(&CONTRACT).unwrap().1;
+        let mut out = MaybeUninit::uninit();
+        EthData::parse_into(&Some(contract), data, &mut out).unwrap();
+
+        match unsafe { out.assume_init() } {
+            EthData::Erc20(..) => true,
+            EthData::ContractCall(..) => false,
+            _ => panic!("unexpected calldata decoding"),
         }
     }
+
+    #[test]
+    fn well_formed_calls() {
+        let args = [word(0x22), word(0x01)].concat();
+        assert!(is_erc20(&calldata(Transfer::SELECTOR, &args)));
+        assert!(is_erc20(&calldata(Approve::SELECTOR, &args)));
+
+        let args = [word(0x22), word(0x33), word(0x01)].concat();
+        assert!(is_erc20(&calldata(TransferFrom::SELECTOR, &args)));
+    }
+
+    #[test]
+    fn transfer_without_token_info() {
+        let args = [word(0x22), word(0x01)].concat();
+        let data = calldata(Transfer::SELECTOR, &args);
+        let mut erc20 = MaybeUninit::uninit();
+        ERC20::parse_into(&data[1..], &mut erc20).unwrap();
+        let erc20 = unsafe { erc20.assume_init() };
+
+        let render = |item_n| {
+            let mut title = [0; 32];
+            let mut message = [0; 128];
+            let contract = Address::from_bytes(&CONTRACT).unwrap().1;
+            erc20
+                .render_item_for(&contract, item_n, &mut title, &mut message, 0)
+                .unwrap();
+            let msg = message.iter().position(|&b| b == 0).unwrap();
+            let ttl = title.iter().position(|&b| b == 0).unwrap();
+            (title[..ttl].to_vec(), message[..msg].to_vec())
+        };
+
+        let (title, message) = render(1);
+        assert_eq!(title, b"To");
+        assert_eq!(message, [&b"0x"[..], &[b'2'; 2 * ADDRESS_LEN]].concat());
+
+        let (title, message) = render(2);
+        assert_eq!(title, b"Amount");
+        assert_eq!(message, b"1 (decimals unknown)");
+    }
+
+    #[test]
+    fn selector_collisions_fall_back() {
+        let args = [word(0x22), word(0x01)].concat();
+
+        // too few argument bytes
+        let data = calldata(Transfer::SELECTOR, &args[..40]);
+        assert!(!is_erc20(&data));
+        let data = calldata(TransferFrom::SELECTOR, &args);
+        assert!(!is_erc20(&data));
+
+        // an extra argument
+        let data = calldata(Approve::SELECTOR, &[&args[..], &word(0x01)[..]].concat());
+        assert!(!is_erc20(&data));
+
+        // trailing bytes
+        let mut long = args.clone();
+        long.push(0);
+        assert!(!is_erc20(&calldata(Transfer::SELECTOR, &long)));
+
+        // an address argument which is not zero padded
+        let mut dirty = args;
+        dirty[0] = 1;
+        assert!(!is_erc20(&calldata(Transfer::SELECTOR, &dirty)));
+    }
 }
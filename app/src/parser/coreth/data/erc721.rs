@@ -64,7 +64,8 @@ pub struct BaseTransfer<'b> {
 
 impl<'b> DisplayableItem for BaseTransfer<'b> {
     fn num_items(&self) -> usize {
-        3
+        // collection + token_id + from + to
+        4
     }
 
     fn render_item(
@@ -79,19 +80,12 @@ impl<'b> DisplayableItem for BaseTransfer<'b> {
 
         match item_n {
             0 => {
-                let label = pic_str!(b"To");
-                title[..label.len()].copy_from_slice(label);
-
-                // should not panic as address was check
-                self.to.render_eth_address(message, page)
-            }
-            1 => {
                 let label = pic_str!(b"Collection Name");
                 title[..label.len()].copy_from_slice(label);
 
                 nft_info.render_collection_name(message, page)
             }
-            2 => {
+            1 => {
                 let label = pic_str!(b"TokenID");
 
                 let res = self.asset_id.render_item(0, title, message, page);
@@ -100,6 +94,20 @@ impl<'b> DisplayableItem for BaseTransfer<'b> {
                 title[..label.len()].copy_from_slice(label);
                 res
             }
+            2 => {
+                let label = pic_str!(b"From");
+                title[..label.len()].copy_from_slice(label);
+
+                // should not panic as address was check
+                self.from.render_eth_address(message, page)
+            }
+            3 => {
+                let label = pic_str!(b"To");
+                title[..label.len()].copy_from_slice(label);
+
+                // should not panic as address was check
+                self.to.render_eth_address(message, page)
+            }
             _ => Err(ViewError::NoData),
         }
     }
@@ -324,6 +332,15 @@ impl ERC721__Type {
 }
 
 impl<'b> ERC721<'b> {
+    /// The account receiving the token, if it is transferred
+    pub fn recipient(&self) -> Option<Address<'b>> {
+        match self {
+            ERC721::TransferFrom(t) => Some(t.base.to),
+            ERC721::SafeTransferFrom(t) => Some(t.base.to),
+            ERC721::Approve(_) | ERC721::ApprovalForAll(_) => None,
+        }
+    }
+
     pub fn method_name(&self) -> &'static [u8] {
         match self {
             ERC721::TransferFrom(_) => pic_str!(b"transferFrom"!),
@@ -518,8 +535,14 @@ impl<'b> DisplayableItem for ERC721<'b> {
     ) -> Result<u8, ViewError> {
         match item_n {
             0 => {
-                let title_content = pic_str!(b"ERC-721");
-                title[..title_content.len()].copy_from_slice(title_content);
+                let transfer = pic_str!(b"NFT Transfer");
+                let erc721 = pic_str!(b"ERC-721");
+                match self {
+                    ERC721::TransferFrom(_) | ERC721::SafeTransferFrom(_) => {
+                        title[..transfer.len()].copy_from_slice(transfer)
+                    }
+                    _ => title[..erc721.len()].copy_from_slice(erc721),
+                };
 
                 handle_ui_message(self.method_name(), message, page)
             }
@@ -540,3 +563,78 @@ impl<'b> DisplayableItem for ERC721<'b> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::EthData;
+    use serial_test::serial;
+    use std::{prelude::v1::*, vec};
+
+    // safeTransferFrom(from, to, 0x201) of the Lucid collection
+    const CALLDATA: &str = "42842e0e00000000000000000000000077944eed8d4a00c8bd413f77744751a4d04ea34a0000000000000000000000005d4994bccdd28afbbc6388fbcaaec69dd44c04560000000000000000000000000000000000000000000000000000000000000201";
+    const CONTRACT: &str = "34bc797f40df0445c8429d485232874b15561728";
+    const OTHER_CONTRACT: &str = "bd3f82a81c3f74542736765ce4fd579d177b6bc5";
+
+    fn nft_info(contract: &[u8]) -> NftInfo {
+        let mut raw = vec![1, 1, 5];
+        raw.extend_from_slice(b"Lucid");
+        raw.extend_from_slice(contract);
+        raw.extend_from_slice(&1u64.to_be_bytes());
+
+        let (_, info) = NftInfo::from_bytes(&raw).unwrap();
+        info
+    }
+
+    fn render_title(erc721: &ERC721, item_n: u8) -> String {
+        let mut title = [0; 32];
+        let mut message = [0; 64];
+        erc721
+            .render_item(item_n, &mut title, &mut message, 0)
+            .unwrap();
+        let len = title.iter().position(|b| *b == 0).unwrap();
+        String::from_utf8(title[..len].to_vec()).unwrap()
+    }
+
+    #[test]
+    #[serial]
+    fn erc721_transfer_collections() {
+        let calldata = hex::decode(CALLDATA).unwrap();
+        let contract = hex::decode(CONTRACT).unwrap();
+        let other = hex::decode(OTHER_CONTRACT).unwrap();
+        ERC721Info::set_info(nft_info(&contract)).unwrap();
+
+        // matching collection, the transfer is clear-signed
+        let (_, to) = Address::from_bytes(&contract).unwrap();
+        let mut erc721 = MaybeUninit::uninit();
+        ERC721::parse_into(&to, &calldata, &mut erc721).unwrap();
+        let erc721 = unsafe { erc721.assume_init() };
+
+        assert_eq!(erc721.num_items(), 5);
+        let titles: Vec<_> = (0..5).map(|i| render_title(&erc721, i)).collect();
+        assert_eq!(
+            titles,
+            ["NFT Transfer", "Collection Name", "TokenID", "From", "To"]
+        );
+
+        let mut message = [0; 64];
+        let mut title = [0; 32];
+        erc721.render_item(1, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&message[..5], b"Lucid");
+
+        // a different contract falls back to a generic contract call
+        let (_, to) = Address::from_bytes(&other).unwrap();
+        let mut erc721 = MaybeUninit::uninit();
+        assert_eq!(
+            ERC721::parse_into(&to, &calldata, &mut erc721),
+            Err(ParserError::InvalidContractAddress)
+        );
+
+        let mut rlp = vec![0xb8, calldata.len() as u8];
+        rlp.extend_from_slice(&calldata);
+        let mut data = MaybeUninit::uninit();
+        EthData::parse_into(&Some(to), &rlp, &mut data).unwrap();
+        let data = unsafe { data.assume_init() };
+        assert!(matches!(data, EthData::ContractCall(..)));
+    }
+}
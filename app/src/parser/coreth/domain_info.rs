@@ -0,0 +1,365 @@
+/*******************************************************************************
+*   (c) 2022 Zondax AG
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use bolos::{pic_str, PIC};
+use core::{mem::MaybeUninit, ptr::addr_of_mut};
+use nom::{
+    bytes::complete::take,
+    number::complete::{be_u16, be_u8},
+};
+use zemu_sys::ViewError;
+
+use crate::{
+    handlers::{handle_ui_message, resources::DOMAIN_INFO},
+    parser::{
+        Address, FromBytes, OwnedAddress, ParserError, ADDRESS_LEN, DOMAIN_NAME_MAX_LEN,
+        TRUSTED_NAME_ACCOUNT, TRUSTED_NAME_TYPE, TRUSTED_NAME_VERSION,
+    },
+    utils::hex_encode,
+};
+
+// tags of the trusted name fields, as defined by app-ethereum
+mod tags {
+    pub const STRUCT_TYPE: u8 = 0x01;
+    pub const STRUCT_VERSION: u8 = 0x02;
+    pub const CHALLENGE: u8 = 0x12;
+    pub const SIGNER_KEY_ID: u8 = 0x13;
+    pub const SIGNER_ALGORITHM: u8 = 0x14;
+    pub const SIGNATURE: u8 = 0x15;
+    pub const NAME: u8 = 0x20;
+    pub const ADDRESS: u8 = 0x22;
+    pub const CHAIN_ID: u8 = 0x23;
+    pub const NAME_TYPE: u8 = 0x70;
+    pub const NAME_SOURCE: u8 = 0x71;
+}
+
+/// A domain name resolved to an address of a chain by the host
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+#[cfg_attr(test, derive(Debug))]
+pub struct DomainInfo {
+    address: OwnedAddress,
+    name: [u8; DOMAIN_NAME_MAX_LEN],
+    name_len: u8,
+    chain_id: u64,
+}
+
+impl DomainInfo {
+    pub fn address(&self) -> Address<'_> {
+        self.address.address()
+    }
+
+    pub fn name(&self) -> &[u8] {
+        &self.name[..self.name_len as usize]
+    }
+
+    pub fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+}
+
+/// A trusted name, in the TLV structure of app-ethereum
+///
+/// The signature has to be the last field, it covers every field before it.
+/// It is verified by the handler, along with the challenge and the signer ids
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct TrustedName<'b> {
+    pub info: DomainInfo,
+    pub challenge: u32,
+    pub key_id: u16,
+    pub algorithm_id: u8,
+    pub signed: &'b [u8],
+    pub signature: &'b [u8],
+}
+
+// a DER encoded length, of up to 2 bytes
+fn der_length(input: &[u8]) -> Result<(&[u8], usize), nom::Err<ParserError>> {
+    let (rem, first) = be_u8(input)?;
+    match first {
+        0..=0x7f => Ok((rem, first as usize)),
+        0x81 => be_u8(rem).map(|(rem, len)| (rem, len as usize)),
+        0x82 => be_u16(rem).map(|(rem, len)| (rem, len as usize)),
+        _ => Err(ParserError::ValueOutOfRange.into()),
+    }
+}
+
+// a big endian integer of at most `max` bytes
+fn be_uint(value: &[u8], max: usize) -> Result<u64, nom::Err<ParserError>> {
+    if value.is_empty() || value.len() > max {
+        return Err(ParserError::ValueOutOfRange.into());
+    }
+
+    Ok(value.iter().fold(0, |n, b| (n << 8) | *b as u64))
+}
+
+// fields can only be given once
+fn set_once<T>(field: &mut Option<T>, value: T) -> Result<(), nom::Err<ParserError>> {
+    if field.replace(value).is_some() {
+        return Err(ParserError::UnexpectedField.into());
+    }
+    Ok(())
+}
+
+impl<'b> FromBytes<'b> for TrustedName<'b> {
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("TrustedName::from_bytes_into\x00");
+
+        let mut struct_type = None;
+        let mut version = None;
+        let mut challenge = None;
+        let mut key_id = None;
+        let mut algorithm_id = None;
+        let mut name = None;
+        let mut address = None;
+        let mut chain_id = None;
+        let mut name_type = None;
+        let mut name_source = None;
+
+        let mut rem = input;
+        let (signed, signature) = loop {
+            let field = rem;
+            let (r, tag) = be_u8(rem)?;
+            let (r, len) = der_length(r)?;
+            let (r, value) = take(len)(r)?;
+            rem = r;
+
+            match tag {
+                tags::STRUCT_TYPE => set_once(&mut struct_type, be_uint(value, 1)? as u8)?,
+                tags::STRUCT_VERSION => set_once(&mut version, be_uint(value, 1)? as u8)?,
+                tags::CHALLENGE => set_once(&mut challenge, be_uint(value, 4)? as u32)?,
+                tags::SIGNER_KEY_ID => set_once(&mut key_id, be_uint(value, 2)? as u16)?,
+                tags::SIGNER_ALGORITHM => set_once(&mut algorithm_id, be_uint(value, 1)? as u8)?,
+                tags::NAME => set_once(&mut name, value)?,
+                tags::ADDRESS => set_once(&mut address, value)?,
+                tags::CHAIN_ID => set_once(&mut chain_id, be_uint(value, 8)?)?,
+                tags::NAME_TYPE => set_once(&mut name_type, be_uint(value, 1)? as u8)?,
+                // where the name was resolved, not shown
+                tags::NAME_SOURCE => set_once(&mut name_source, be_uint(value, 1)? as u8)?,
+                // the signature ends the structure
+                tags::SIGNATURE => break (&input[..input.len() - field.len()], value),
+                _ => return Err(ParserError::UnexpectedField.into()),
+            }
+        };
+
+        if struct_type != Some(TRUSTED_NAME_TYPE) || version != Some(TRUSTED_NAME_VERSION) {
+            return Err(ParserError::UnexpectedType.into());
+        }
+
+        // only names of accounts are shown along the recipient
+        if !matches!(name_type, None | Some(TRUSTED_NAME_ACCOUNT)) {
+            return Err(ParserError::UnexpectedType.into());
+        }
+
+        let name = name.ok_or(ParserError::UnexpectedBufferEnd)?;
+        if name.is_empty() || name.len() > DOMAIN_NAME_MAX_LEN {
+            return Err(ParserError::ValueOutOfRange.into());
+        }
+        if !name.is_ascii() {
+            return Err(ParserError::InvalidAsciiValue.into());
+        }
+
+        let address = address.ok_or(ParserError::UnexpectedBufferEnd)?;
+        if address.len() != ADDRESS_LEN {
+            return Err(ParserError::ValueOutOfRange.into());
+        }
+
+        let missing = || nom::Err::from(ParserError::UnexpectedBufferEnd);
+        let chain_id = chain_id.ok_or_else(missing)?;
+        let challenge = challenge.ok_or_else(missing)?;
+        let key_id = key_id.ok_or_else(missing)?;
+        let algorithm_id = algorithm_id.ok_or_else(missing)?;
+
+        let out = out.as_mut_ptr();
+        let owned = unsafe { &mut *addr_of_mut!((*out).info.address).cast() };
+        _ = OwnedAddress::from_bytes_into(address, owned)?;
+
+        unsafe {
+            let name_out = &mut *addr_of_mut!((*out).info.name);
+            name_out[..name.len()].copy_from_slice(name);
+            name_out[name.len()..].fill(0);
+
+            addr_of_mut!((*out).info.name_len).write(name.len() as u8);
+            addr_of_mut!((*out).info.chain_id).write(chain_id);
+
+            addr_of_mut!((*out).challenge).write(challenge);
+            addr_of_mut!((*out).key_id).write(key_id);
+            addr_of_mut!((*out).algorithm_id).write(algorithm_id);
+            addr_of_mut!((*out).signed).write(signed);
+            addr_of_mut!((*out).signature).write(signature);
+        }
+
+        Ok(rem)
+    }
+}
+
+/// Gives the parser access to the provided domain name
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct DomainName;
+
+impl DomainName {
+    /// Returns the provided domain name, if it resolves
+    /// to `address` on the chain `chain_id`
+    pub fn lookup(chain_id: u64, address: &Address<'_>) -> Option<&'static [u8]> {
+        match unsafe { DOMAIN_INFO.acquire(Self) } {
+            Ok(Some(info)) if info.chain_id() == chain_id && info.address() == *address => {
+                Some(info.name())
+            }
+            _ => None,
+        }
+    }
+
+    /// Renders `address` as an eth address, preceded by its
+    /// domain name when one was provided for it on `chain_id`
+    pub fn render_address(
+        chain_id: u64,
+        address: &Address<'_>,
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        let name = match Self::lookup(chain_id, address) {
+            Some(name) => name,
+            None => return address.render_eth_address(message, page),
+        };
+
+        // name, space, 0x and the hex address
+        let mut out = [0; DOMAIN_NAME_MAX_LEN + 1 + 2 + ADDRESS_LEN * 2];
+        let mut sz = name.len();
+        out[..sz].copy_from_slice(name);
+
+        let prefix = pic_str!(b" 0x"!);
+        out[sz..sz + prefix.len()].copy_from_slice(prefix);
+        sz += prefix.len();
+
+        sz += hex_encode(address.raw_address(), &mut out[sz..]).map_err(|_| ViewError::Unknown)?;
+
+        handle_ui_message(&out[..sz], message, page)
+    }
+
+    #[cfg(test)]
+    pub fn set_info(info: Option<DomainInfo>) {
+        unsafe {
+            *DOMAIN_INFO.lock(Self).unwrap() = info;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::prelude::v1::*;
+
+    // "zondax.eth" resolving to 0x11..11 on the C-chain, with a
+    // challenge of 0x01020304 and a placeholder signature
+    const INFO: &str = "010103020102120401020304130100140101200a7a6f6e6461782e657468221411111111111111111111111111111111111111112302a86a7001011503300102";
+    const C_CHAIN: u64 = 43114;
+
+    fn info() -> DomainInfo {
+        let data = hex::decode(INFO).unwrap();
+        TrustedName::from_bytes(&data).unwrap().1.info
+    }
+
+    fn render(chain_id: u64, address: &[u8; ADDRESS_LEN]) -> String {
+        let (_, address) = Address::from_bytes(&address[..]).unwrap();
+
+        let mut message = [0; 100];
+        DomainName::render_address(chain_id, &address, &mut message, 0).unwrap();
+
+        let len = message.iter().position(|b| *b == 0).unwrap();
+        String::from_utf8(message[..len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn parse_trusted_name() {
+        let data = hex::decode(INFO).unwrap();
+        let (rem, name) = TrustedName::from_bytes(&data).unwrap();
+
+        assert!(rem.is_empty());
+        assert_eq!(name.info.name(), b"zondax.eth");
+        assert_eq!(name.info.address().raw_address(), &[0x11; ADDRESS_LEN]);
+        assert_eq!(name.info.chain_id(), C_CHAIN);
+        assert_eq!(name.challenge, 0x01020304);
+        assert_eq!((name.key_id, name.algorithm_id), (0, 1));
+        assert_eq!(name.signature, &[0x30, 0x01, 0x02]);
+
+        // everything but the signature field is signed
+        assert_eq!(name.signed, &data[..data.len() - 5]);
+    }
+
+    #[test]
+    fn invalid_trusted_name() {
+        let data = hex::decode(INFO).unwrap();
+
+        // another structure type
+        let mut other = data.clone();
+        other[2] = 0x01;
+        assert!(TrustedName::from_bytes(&other).is_err());
+
+        // without signature
+        assert!(TrustedName::from_bytes(&data[..data.len() - 5]).is_err());
+
+        // a field given twice
+        let mut twice = hex::decode("230101").unwrap();
+        twice.extend_from_slice(&data);
+        assert!(TrustedName::from_bytes(&twice).is_err());
+
+        // missing the chain id
+        let mut no_chain = data[..data.len() - 12].to_vec();
+        no_chain.extend_from_slice(&data[data.len() - 8..]);
+        assert!(TrustedName::from_bytes(&no_chain).is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn matched_address() {
+        DomainName::set_info(Some(info()));
+
+        assert_eq!(
+            render(C_CHAIN, &[0x11; ADDRESS_LEN]),
+            "zondax.eth 0x1111111111111111111111111111111111111111"
+        );
+
+        DomainName::set_info(None);
+    }
+
+    #[test]
+    #[serial]
+    fn unmatched_address() {
+        DomainName::set_info(Some(info()));
+
+        assert_eq!(
+            render(C_CHAIN, &[0xdd; ADDRESS_LEN]),
+            "0xdddddddddddddddddddddddddddddddddddddddd"
+        );
+
+        // same address on another chain
+        assert_eq!(
+            render(1, &[0x11; ADDRESS_LEN]),
+            "0x1111111111111111111111111111111111111111"
+        );
+
+        // nothing provided
+        DomainName::set_info(None);
+        assert_eq!(
+            render(C_CHAIN, &[0x11; ADDRESS_LEN]),
+            "0x1111111111111111111111111111111111111111"
+        );
+    }
+}
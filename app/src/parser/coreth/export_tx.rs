@@ -252,6 +252,26 @@ impl<'b> ExportTx<'b> {
         }
     }
 
+    // the EVM account debited by each input
+    fn render_sender(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, zemu_sys::ViewError> {
+        let input = self
+            .inputs
+            .iter()
+            .nth(item_n as usize)
+            .ok_or(ViewError::NoData)?;
+
+        let title_content = pic_str!(b"From");
+        title[..title_content.len()].copy_from_slice(title_content);
+
+        input.address().render_eth_address(message, page)
+    }
+
     fn render_export_description(
         &self,
         title: &mut [u8],
@@ -289,8 +309,8 @@ impl<'b> ExportTx<'b> {
 
 impl<'b> DisplayableItem for ExportTx<'b> {
     fn num_items(&self) -> usize {
-        //description + number outputs + fee
-        1 + self.num_outputs_items() + 1
+        //description + senders + number outputs + fee
+        1 + self.inputs.iter().count() + self.num_outputs_items() + 1
     }
 
     fn render_item(
@@ -307,8 +327,13 @@ impl<'b> DisplayableItem for ExportTx<'b> {
             return self.render_export_description(title, message, page);
         }
 
+        let senders = self.inputs.iter().count() as u8;
+        if item_n <= senders {
+            return self.render_sender(item_n - 1, title, message, page);
+        }
+
         let outputs_num_items = self.num_outputs_items();
-        let new_item_n = item_n - 1;
+        let new_item_n = item_n - 1 - senders;
 
         match new_item_n {
             x @ 0.. if x < outputs_num_items as u8 => self.render_outputs(x, title, message, page),
@@ -417,4 +442,26 @@ mod tests {
         // 2_000_000 - 1_000_000
         assert_eq!(fee, 1_000_000);
     }
+
+    #[test]
+    fn render_evm_input() {
+        let (_, tx) = ExportTx::from_bytes(DATA2).unwrap();
+        // description, sender, amount, address and fee
+        assert_eq!(tx.num_items(), 5);
+
+        let mut title = [0; 32];
+        let mut message = [0; 64];
+        tx.render_item(0, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&message[..13], b"C to X Chain\0");
+
+        tx.render_item(1, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..5], b"From\0");
+        assert_eq!(
+            &message[..43],
+            b"0x8db97c7cece249c2b98bdc0226cc4c2a57bf52fc\0"
+        );
+
+        tx.render_item(4, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..4], b"Fee\0");
+    }
 }
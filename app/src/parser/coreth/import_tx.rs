@@ -27,8 +27,8 @@ use crate::{
     handlers::handle_ui_message,
     parser::{
         coreth::outputs::EVMOutput, nano_avax_to_fp_str, ChainId, DisplayableItem, FromBytes,
-        Header, ObjectList, OutputIdx, ParserError, TransferableInput, BLOCKCHAIN_ID_LEN,
-        EVM_IMPORT_TX, MAX_ADDRESS_ENCODED_LEN,
+        Header, ObjectList, OutputIdx, ParserError, TransferableInput, UtxoList, BLOCKCHAIN_ID_LEN,
+        EVM_IMPORT_TX,
     },
 };
 
@@ -77,6 +77,7 @@ impl<'b> FromBytes<'b> for ImportTx<'b> {
 
         let inputs = unsafe { &mut *addr_of_mut!((*this).inputs).cast() };
         let rem = ObjectList::<TransferableInput>::new_into(rem, inputs)?;
+        TransferableInput::check_imported(unsafe { inputs.assume_init_ref() })?;
 
         // check for the number of outputs before parsing then as now
         // it has to be checked for the outputIdx capacity which is used
@@ -103,6 +104,11 @@ impl<'b> FromBytes<'b> for ImportTx<'b> {
 impl<'b> ImportTx<'b> {
     pub const TYPE_ID: u32 = EVM_IMPORT_TX;
 
+    // all of the inputs come from the source chain
+    pub fn utxos(&self) -> UtxoList<'b> {
+        UtxoList::new(self.inputs)
+    }
+
     pub fn disable_output_if(&mut self, address: &[u8]) {
         let num_outs = self.outputs.iter().count();
         // skip filtering out outputs if there is only one
@@ -211,30 +217,8 @@ impl<'b> ImportTx<'b> {
 
         let obj = self.outputs.get_obj_if(filter).ok_or(ViewError::NoData)?;
 
-        // do a custom rendering of the first base_output_items
-        match obj_item_n {
-            0 => {
-                // render amount
-                obj.render_item(0, title, message, page)
-            }
-            // render output's address
-            1 => {
-                let address = obj.address();
-                // render encoded address with proper hrp,
-                let t = pic_str!(b"Address");
-                title[..t.len()].copy_from_slice(t);
-
-                let hrp = self.tx_header.hrp().map_err(|_| ViewError::Unknown)?;
-                let mut encoded = [0; MAX_ADDRESS_ENCODED_LEN];
-
-                let addr_len = address
-                    .encode_into(hrp, &mut encoded[..])
-                    .map_err(|_| ViewError::Unknown)?;
-
-                handle_ui_message(&encoded[..addr_len], message, page)
-            }
-            _ => Err(ViewError::NoData),
-        }
+        // the amount and the EVM address it is credited to
+        obj.render_item(obj_item_n as u8, title, message, page)
     }
 
     fn render_import_description(
@@ -392,4 +376,26 @@ mod tests {
         let fee = tx.fee().unwrap();
         assert_eq!(fee, 0);
     }
+
+    #[test]
+    fn render_evm_output() {
+        let (_, tx) = ImportTx::from_bytes(DATA).unwrap();
+
+        let mut title = [0; 32];
+        let mut message = [0; 64];
+        tx.render_item(1, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..7], b"Amount\0");
+        assert_eq!(&message[..9], b"500 AVAX\0");
+
+        tx.render_item(2, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..8], b"Address\0");
+        assert_eq!(
+            &message[..43],
+            b"0x0eb5ccb85c29009b6060decb353a38ea3b52cd20\0"
+        );
+
+        tx.render_item(3, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..6], b"From \0");
+        assert_eq!(&message[..8], b"X Chain\0");
+    }
 }
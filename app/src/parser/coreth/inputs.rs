@@ -47,6 +47,10 @@ impl<'b> EVMInput<'b> {
     pub fn asset_id(&self) -> &AssetId<'_> {
         &self.asset_id
     }
+
+    pub fn address(&self) -> &Address<'_> {
+        &self.address
+    }
 }
 
 impl<'b> FromBytes<'b> for EVMInput<'b> {
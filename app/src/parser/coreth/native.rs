@@ -13,16 +13,25 @@
 *  See the License for the specific language governing permissions and
 *  limitations under the License.
 ********************************************************************************/
-use core::ptr::addr_of_mut;
+use core::{convert::TryFrom, ptr::addr_of_mut};
 
 use nom::bytes::complete::take;
 use zemu_sys::ViewError;
 
 use crate::{
-    handlers::{eth::u256, handle_ui_message},
+    handlers::{
+        eth::{u256, BorrowedU256},
+        handle_ui_message,
+    },
     parser::{
-        intstr_to_fpstr_inplace, DisplayableItem, FromBytes, ParserError, EIP1559_TX, EIP2930_TX,
-        U64_SIZE,
+        Address, DisplayableItem, EthData, FromBytes, ParserError, ADDRESS_LEN, EIP1559_TX,
+        EIP2930_TX, EIP7702_TX, ETH_ARG_LEN, U64_SIZE, WEI_NAVAX_DIGITS,
+    },
+    utils::{
+        chain_id_allowlist::{allowed_chain_ids, strict_chain_id},
+        format_amount,
+        gas_price_threshold::gas_price_threshold,
+        DigestScheme,
     },
 };
 
@@ -37,6 +46,8 @@ mod eip1559;
 pub use eip1559::Eip1559;
 mod eip2930;
 pub use eip2930::Eip2930;
+mod eip7702;
+pub use eip7702::{Authorization, Eip7702};
 
 /// Renders an u256 in bytes.
 /// `input`: The big-indian bytes of the number to
@@ -51,12 +62,10 @@ pub fn render_u256(
     let mut u256_str = [0; u256::FORMATTED_SIZE_DECIMAL + 2];
 
     let amount = u256::pic_from_big_endian()(num);
-    amount.to_lexical(&mut u256_str);
-
     let out =
-        intstr_to_fpstr_inplace(&mut u256_str, decimal_point).map_err(|_| ViewError::Unknown)?;
+        format_amount(amount, decimal_point, &mut u256_str).map_err(|_| ViewError::Unknown)?;
 
-    handle_ui_message(out, message, page)
+    handle_ui_message(out.as_bytes(), message, page)
 }
 
 // Converts an slice of bytes in big-endian
@@ -74,12 +83,21 @@ pub fn bytes_to_u64(input: &[u8]) -> Result<u64, ParserError> {
 /// Returns the remaining bytes from data along with the bytes
 /// representation of the found item
 pub fn parse_rlp_item(data: &[u8]) -> Result<(&[u8], &[u8]), nom::Err<ParserError>> {
+    let (read, to_read) = parse_rlp_header(data)?;
+
+    take(to_read)(&data[read..])
+}
+
+// Returns the length of the header of the item data starts with,
+// which is all that has to be there, and the length of its content
+fn parse_rlp_header(data: &[u8]) -> Result<(usize, usize), ParserError> {
     let read = 0;
 
     let marker = *data.first().ok_or(ParserError::UnexpectedBufferEnd)?;
 
     let (read, to_read) = match marker {
-        _num @ 0..=0x7F => return Ok((&data[1..], &data[0..1])),
+        // the byte is its own content
+        _num @ 0..=0x7F => return Ok((0, 1)),
         sstring @ 0x80..=0xB7 => (1, sstring as u64 - 0x80),
         string @ 0xB8..=0xBF => {
             // For strings longer than 55 bytes the length is encoded
@@ -123,7 +141,251 @@ pub fn parse_rlp_item(data: &[u8]) -> Result<(&[u8], &[u8]), nom::Err<ParserErro
         }
     };
 
-    take(to_read as usize)(&data[read..])
+    // lengths that do not fit in memory can not be valid,
+    // and a plain cast would truncate them in 32-bit targets
+    let to_read = usize::try_from(to_read).map_err(|_| ParserError::ValueOutOfRange)?;
+
+    Ok((read, to_read))
+}
+
+/// Returns the remaining bytes from data along with the integer found
+///
+/// RLP integers are big endian, up to 32 bytes and canonically encoded:
+/// zero is the empty string, no leading zeros are allowed and values
+/// below 0x80 are encoded as a single byte
+pub fn parse_rlp_u256(data: &[u8]) -> Result<(&[u8], BorrowedU256<'_>), nom::Err<ParserError>> {
+    let (rem, bytes) = parse_rlp_item(data)?;
+
+    match bytes {
+        [0, ..] => return Err(ParserError::NonCanonicalInteger.into()),
+        [b] if *b < 0x80 && data[0] != *b => return Err(ParserError::NonCanonicalInteger.into()),
+        _ => {}
+    }
+
+    let num = BorrowedU256::new(bytes).ok_or(ParserError::InvalidLength)?;
+    Ok((rem, num))
+}
+
+/// Returns the remaining bytes from data along with the
+/// entries of the EIP-2930 access list found
+///
+/// The list is required, although it can be empty, and every entry
+/// has to be an address followed by a list of storage keys
+pub fn parse_access_list(data: &[u8]) -> Result<(&[u8], &[u8]), nom::Err<ParserError>> {
+    let is_list = |data: &[u8]| matches!(data.first(), Some(0xC0..));
+
+    if !is_list(data) {
+        return Err(ParserError::InvalidEthMessage.into());
+    }
+    let (rem, list) = parse_rlp_item(data)?;
+
+    let mut entries = list;
+    while !entries.is_empty() {
+        if !is_list(entries) {
+            return Err(ParserError::InvalidEthMessage.into());
+        }
+        let (next, entry) = parse_rlp_item(entries)?;
+
+        let (entry, address) = parse_rlp_item(entry)?;
+        if address.len() != ADDRESS_LEN || !is_list(entry) {
+            return Err(ParserError::InvalidEthMessage.into());
+        }
+
+        let (entry, mut keys) = parse_rlp_item(entry)?;
+        if !entry.is_empty() {
+            return Err(ParserError::UnexpectedData.into());
+        }
+
+        while !keys.is_empty() {
+            let (next_key, key) = parse_rlp_item(keys)?;
+            if key.len() != ETH_ARG_LEN {
+                return Err(ParserError::InvalidEthMessage.into());
+            }
+            keys = next_key;
+        }
+
+        entries = next;
+    }
+
+    Ok((rem, list))
+}
+
+// Kind of the fields before the calldata of a transaction,
+// each checked as it is found
+#[derive(Clone, Copy)]
+enum HeaderField {
+    ChainId,
+    Nonce,
+    Integer,
+    To,
+}
+
+impl HeaderField {
+    // Returns the remaining bytes from data along with the content of the field
+    fn parse(self, data: &[u8]) -> Result<(&[u8], &[u8]), nom::Err<ParserError>> {
+        match self {
+            Self::ChainId => {
+                let (rem, id_bytes) = parse_rlp_item(data)?;
+                if id_bytes.is_empty() {
+                    return Err(ParserError::InvalidChainId.into());
+                }
+
+                Ok((rem, id_bytes))
+            }
+            Self::Nonce => {
+                let (rem, nonce) = parse_rlp_u256(data)?;
+                // EIP-2681 bounds the nonce to 64 bits
+                if nonce.len() > U64_SIZE {
+                    return Err(ParserError::ValueOutOfRange.into());
+                }
+
+                Ok((rem, nonce.as_bytes()))
+            }
+            Self::Integer => {
+                let (rem, num) = parse_rlp_u256(data)?;
+
+                Ok((rem, num.as_bytes()))
+            }
+            Self::To => {
+                let (rem, raw_address) = parse_rlp_item(data)?;
+                // empty when the transaction creates a contract
+                if !raw_address.is_empty() && raw_address.len() != ADDRESS_LEN {
+                    return Err(ParserError::InvalidAddress.into());
+                }
+
+                Ok((rem, raw_address))
+            }
+        }
+    }
+}
+
+/// The type of an encoded transaction and where its fields before
+/// the calldata are: nonce, gas, recipient and value, after the chain
+/// id of typed transactions.
+///
+/// It is found in the first bytes of the transaction, so the rest of
+/// it does not have to be there yet, and is only good for the
+/// transaction it was found in
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct EthHeader {
+    tx_type: EthTransaction__Type,
+    // offset and length of the content of the RLP list
+    list: (usize, usize),
+    // offset and length of the content of every field
+    // before the calldata, from the start of the list
+    fields: [(usize, usize); EthHeader::MAX_FIELDS],
+    // offset of the calldata, from the start of the list
+    data: usize,
+}
+
+impl EthHeader {
+    // EIP-1559 and EIP-7702 transactions have the most
+    const MAX_FIELDS: usize = 7;
+
+    // Kind of the `n`th field of a `tx_type` transaction,
+    // none past the ones before its calldata
+    fn field(tx_type: EthTransaction__Type, n: usize) -> Option<HeaderField> {
+        type Type = EthTransaction__Type;
+
+        match (tx_type, n) {
+            // legacy transactions have their chain id last
+            (Type::Legacy, 0) => Some(HeaderField::Nonce),
+            (Type::Legacy, 3) => Some(HeaderField::To),
+            (Type::Legacy, 1..=4) => Some(HeaderField::Integer),
+            (_, 0) => Some(HeaderField::ChainId),
+            (_, 1) => Some(HeaderField::Nonce),
+            (Type::Eip2930, 4) | (Type::Eip1559, 5) | (Type::Eip7702, 5) => Some(HeaderField::To),
+            (Type::Eip2930, 2..=5) | (Type::Eip1559, 2..=6) | (Type::Eip7702, 2..=6) => {
+                Some(HeaderField::Integer)
+            }
+            _ => None,
+        }
+    }
+
+    /// Finds the header of the transaction `input` starts with
+    ///
+    /// Errors if the fields before the calldata are
+    /// not all there yet, or are not valid
+    pub fn parse(input: &[u8]) -> Result<Self, nom::Err<ParserError>> {
+        let (rem, tx_type) = EthTransaction__Type::from_bytes(input)?;
+
+        let (read, len) = parse_rlp_header(rem)?;
+        let start = input.len() - rem.len() + read;
+
+        // only the bytes of the list received so far
+        let list = &input[start..];
+        let list = &list[..core::cmp::min(len, list.len())];
+
+        let (fields, data) = Self::find_fields(tx_type, list)?;
+
+        Ok(Self {
+            tx_type,
+            list: (start, len),
+            fields,
+            data,
+        })
+    }
+
+    // Returns where the fields of a `tx_type` transaction
+    // before its calldata are in `list`, and where the calldata starts
+    #[allow(clippy::type_complexity)]
+    fn find_fields(
+        tx_type: EthTransaction__Type,
+        list: &[u8],
+    ) -> Result<([(usize, usize); Self::MAX_FIELDS], usize), nom::Err<ParserError>> {
+        let mut fields = [(0, 0); Self::MAX_FIELDS];
+
+        let mut rem = list;
+        let mut n = 0;
+        while let Some(kind) = Self::field(tx_type, n) {
+            let (next, field) = kind.parse(rem)?;
+
+            // the content is at the end of the item
+            let end = list.len() - next.len();
+            fields[n] = (end - field.len(), field.len());
+
+            rem = next;
+            n += 1;
+        }
+
+        Ok((fields, list.len() - rem.len()))
+    }
+
+    // Returns the content of the fields at `fields` in `list`,
+    // along with the bytes from the calldata on
+    #[allow(clippy::type_complexity)]
+    fn slice_fields<'b>(
+        list: &'b [u8],
+        fields: &[(usize, usize); Self::MAX_FIELDS],
+        data: usize,
+    ) -> Result<([&'b [u8]; Self::MAX_FIELDS], &'b [u8]), ParserError> {
+        let mut out: [&[u8]; Self::MAX_FIELDS] = [&[]; Self::MAX_FIELDS];
+
+        for (out, (start, len)) in out.iter_mut().zip(fields.iter()) {
+            *out = list
+                .get(*start..)
+                .and_then(|field| field.get(..*len))
+                .ok_or(ParserError::UnexpectedBufferEnd)?;
+        }
+
+        let rem = list.get(data..).ok_or(ParserError::UnexpectedBufferEnd)?;
+
+        Ok((out, rem))
+    }
+
+    /// Returns the content of the fields of a `tx_type` transaction
+    /// before its calldata, which `list` starts with, along with the
+    /// bytes from the calldata on
+    #[allow(clippy::type_complexity)]
+    fn fields_in(
+        tx_type: EthTransaction__Type,
+        list: &[u8],
+    ) -> Result<([&[u8]; Self::MAX_FIELDS], &[u8]), nom::Err<ParserError>> {
+        let (fields, data) = Self::find_fields(tx_type, list)?;
+
+        Self::slice_fields(list, &fields, data).map_err(Into::into)
+    }
 }
 
 impl From<u8> for EthTransaction__Type {
@@ -131,6 +393,7 @@ impl From<u8> for EthTransaction__Type {
         match value {
             EIP1559_TX => Self::Eip1559,
             EIP2930_TX => Self::Eip2930,
+            EIP7702_TX => Self::Eip7702,
             // legacy is constructed
             _ => Self::Legacy,
         }
@@ -148,6 +411,9 @@ impl EthTransaction__Type {
         match *tx_type {
             EIP1559_TX => Ok((&input[1..], Self::Eip1559)),
             EIP2930_TX => Ok((&input[1..], Self::Eip2930)),
+            EIP7702_TX => Ok((&input[1..], Self::Eip7702)),
+            // any other eip2718 transaction type
+            0x00..=0x7F => Err(ParserError::UnsupportedTxType),
             // legacy transaction does not have a version so just parse
             // it, if it is not valid, the parser will error anyways
             _ => Ok((input, Self::Legacy)),
@@ -165,6 +431,7 @@ pub enum EthTransaction<'b> {
     Legacy(Legacy<'b>),
     Eip1559(Eip1559<'b>),
     Eip2930(Eip2930<'b>),
+    Eip7702(Eip7702<'b>),
 }
 
 impl<'b> EthTransaction<'b> {
@@ -172,15 +439,149 @@ impl<'b> EthTransaction<'b> {
         match self {
             EthTransaction::Eip1559(_) => EIP1559_TX.into(),
             EthTransaction::Eip2930(_) => EIP2930_TX.into(),
+            EthTransaction::Eip7702(_) => EIP7702_TX.into(),
             _ => None,
         }
     }
 
+    /// EVM transactions are signed over the Keccak-256 of their RLP
+    pub fn digest_scheme(&self) -> DigestScheme {
+        DigestScheme::Keccak256
+    }
+
     pub fn chain_id(&self) -> &'b [u8] {
         match self {
             Self::Legacy(t) => t.chain_id(),
             Self::Eip1559(t) => t.chain_id(),
             Self::Eip2930(t) => t.chain_id(),
+            Self::Eip7702(t) => t.chain_id(),
+        }
+    }
+
+    /// Returns if the chain id is not one of `allowed`,
+    /// transactions without a chain id are not considered foreign
+    pub fn foreign_chain_id_with(&self, allowed: &[u64]) -> bool {
+        let chain_id = self.chain_id();
+        if chain_id.is_empty() {
+            return false;
+        }
+
+        match bytes_to_u64(chain_id) {
+            Ok(id) => !allowed.contains(&id),
+            Err(_) => true,
+        }
+    }
+
+    pub fn foreign_chain_id(&self) -> bool {
+        self.foreign_chain_id_with(allowed_chain_ids())
+    }
+
+    /// Errors if the chain id is not one of `allowed` and
+    /// foreign chains are not just warned about
+    pub fn check_chain_id_with(&self, allowed: &[u64], strict: bool) -> Result<(), ParserError> {
+        if strict && self.foreign_chain_id_with(allowed) {
+            return Err(ParserError::InvalidChainId);
+        }
+        Ok(())
+    }
+
+    pub fn data(&self) -> &EthData<'b> {
+        match self {
+            Self::Legacy(t) => &t.base.data,
+            Self::Eip1559(t) => t.data(),
+            Self::Eip2930(t) => &t.base.data,
+            Self::Eip7702(t) => t.call.data(),
+        }
+    }
+
+    /// The recipient of the transaction, none when it creates a contract
+    pub fn to(&self) -> Option<&Address<'b>> {
+        match self {
+            Self::Legacy(t) => t.base.to.as_ref(),
+            Self::Eip1559(t) => t.to(),
+            Self::Eip2930(t) => t.base.to.as_ref(),
+            Self::Eip7702(t) => t.call.to(),
+        }
+    }
+
+    /// The value sent, as a 32 bytes big-endian integer
+    pub fn value_u256(&self) -> [u8; 32] {
+        let value: &[u8] = match self {
+            Self::Legacy(t) => &t.base.value,
+            Self::Eip1559(t) => &t.value,
+            Self::Eip2930(t) => &t.base.value,
+            Self::Eip7702(t) => &t.call.value,
+        };
+
+        // RLP integers are at most 32 bytes, checked when parsing
+        let mut out = [0; 32];
+        out[32 - value.len()..].copy_from_slice(value);
+        out
+    }
+
+    /// Returns if the value, or the tokens moved by the calldata, are sent
+    /// to the zero address, which burns them, unlike an empty recipient
+    /// which creates a contract
+    pub fn zero_recipient(&self) -> bool {
+        let zero = |to: &Address| to.raw_address().iter().all(|b| *b == 0);
+
+        match self.data().recipient() {
+            Some(recipient) => zero(&recipient),
+            None => self.to().map(zero).unwrap_or_default(),
+        }
+    }
+
+    /// Returns if the value, or the tokens moved by the calldata,
+    /// are sent to `address`
+    pub fn sends_to(&self, address: &[u8; ADDRESS_LEN]) -> bool {
+        match self.data().recipient() {
+            Some(recipient) => recipient.raw_address() == address,
+            None => self
+                .to()
+                .map(|to| to.raw_address() == address)
+                .unwrap_or_default(),
+        }
+    }
+
+    // the highest price per gas the transaction could pay
+    fn gas_price(&self) -> &[u8] {
+        match self {
+            Self::Legacy(t) => &t.base.gas_price,
+            Self::Eip1559(t) => &t.max_fee,
+            Self::Eip2930(t) => &t.base.gas_price,
+            Self::Eip7702(t) => &t.call.max_fee,
+        }
+    }
+
+    /// Returns if the gas price is above `threshold` gwei,
+    /// a `threshold` of 0 disables the check
+    pub fn high_gas_price_with(&self, threshold: u64) -> bool {
+        if threshold == 0 {
+            return false;
+        }
+
+        let gas_price = u256::pic_from_big_endian()(self.gas_price());
+        let gwei = u256::exp10(WEI_NAVAX_DIGITS);
+
+        // a threshold that does not fit can not be exceeded
+        match u256::from(threshold).checked_mul(gwei) {
+            Some(threshold) => gas_price > threshold,
+            None => false,
+        }
+    }
+
+    pub fn high_gas_price(&self) -> bool {
+        self.high_gas_price_with(gas_price_threshold())
+    }
+
+    // number of pages before the warnings, which
+    // does not depend on the expert mode
+    fn summary_items(&self) -> usize {
+        match self {
+            Self::Legacy(t) => t.base.num_items_in(false),
+            Self::Eip1559(t) => t.num_items_in(false),
+            Self::Eip2930(t) => t.base.num_items_in(false),
+            Self::Eip7702(t) => t.num_items_in(false),
         }
     }
 }
@@ -189,20 +590,40 @@ impl<'b> FromBytes<'b> for EthTransaction<'b> {
     fn from_bytes_into(
         input: &'b [u8],
         out: &mut core::mem::MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        let header = EthHeader::parse(input)?;
+
+        Self::from_header_into(input, &header, out)
+    }
+}
+
+impl<'b> EthTransaction<'b> {
+    /// Parses the transaction `input` holds, from the calldata on,
+    /// as the fields before it were already found in `header`
+    ///
+    /// `header` has to be found in the first bytes of `input`
+    #[inline(never)]
+    pub fn from_header_into(
+        input: &'b [u8],
+        header: &EthHeader,
+        out: &mut core::mem::MaybeUninit<Self>,
     ) -> Result<&'b [u8], nom::Err<ParserError>> {
         // get transaction data as the eip2718 defines transactions structure as follow:
         // version || rlp[tx_fields]
         // version for eip1559 = 2,
         // for eip2930 = 1,
         // for legacy it does not have a version
-        let (rem, tx_type) = EthTransaction__Type::from_bytes(input)?;
+        let tx_type = header.tx_type;
 
-        // parse rlp[] part in order to get the transaction bytes
-        let (rem, tx_bytes) = parse_rlp_item(rem)?;
+        // the rlp[] part, which has to be complete by now
+        let (start, len) = header.list;
+        let tx_bytes = input
+            .get(start..)
+            .and_then(|list| list.get(..len))
+            .ok_or(ParserError::UnexpectedBufferEnd)?;
+        let rem = &input[start + len..];
 
-        if tx_bytes.is_empty() {
-            return Err(ParserError::UnexpectedBufferEnd.into());
-        }
+        let (fields, data) = EthHeader::slice_fields(tx_bytes, &header.fields, header.data)?;
 
         match tx_type {
             EthTransaction__Type::Legacy => {
@@ -214,7 +635,7 @@ impl<'b> FromBytes<'b> for EthTransaction<'b> {
                 // we do not have a way to verify this data. in the worst scenario
                 // the transaction would be rejected, and for this reason
                 // It is shown on the screen(partially) for the user to review.
-                _ = Legacy::from_bytes_into(tx_bytes, legacy)?;
+                _ = Legacy::from_fields_into(&fields, data, legacy)?;
 
                 //pointer is valid
                 unsafe {
@@ -230,7 +651,7 @@ impl<'b> FromBytes<'b> for EthTransaction<'b> {
                 // we do not have a way to verify this data. in the worst scenario
                 // the transaction would be rejected, and for this reason
                 // It is shown on the screen(partially) for the user to review.
-                _ = Eip1559::from_bytes_into(tx_bytes, eip)?;
+                _ = Eip1559::from_fields_into(&fields, data, eip)?;
 
                 //pointer is valid
                 unsafe {
@@ -246,25 +667,54 @@ impl<'b> FromBytes<'b> for EthTransaction<'b> {
                 // we do not have a way to verify this data. in the worst scenario
                 // the transaction would be rejected, and for this reason
                 // It is shown on the screen(partially) for the user to review.
-                _ = Eip2930::from_bytes_into(tx_bytes, eip)?;
+                _ = Eip2930::from_fields_into(&fields, data, eip)?;
 
                 //pointer is valid
                 unsafe {
                     addr_of_mut!((*out).0).write(EthTransaction__Type::Eip2930);
                 }
             }
+            EthTransaction__Type::Eip7702 => {
+                let out = out.as_mut_ptr() as *mut Eip7702__Variant;
+
+                let eip = unsafe { &mut *addr_of_mut!((*out).1).cast() };
+
+                // these transactions always call an address, along with
+                // the authorization list of the delegates the signer account
+                // takes the code of, which is checked while parsing
+                _ = Eip7702::from_fields_into(&fields, data, eip)?;
+
+                //pointer is valid
+                unsafe {
+                    addr_of_mut!((*out).0).write(EthTransaction__Type::Eip7702);
+                }
+            }
         }
+
+        // the variant was written above
+        let tx = unsafe { &*out.as_ptr() };
+        tx.check_chain_id_with(allowed_chain_ids(), strict_chain_id())?;
+
         Ok(rem)
     }
 }
 
+// Pages follow a fixed order for every transaction type: the summary of
+// the call (type, recipient, amount and fee), then the warnings and
+// last the expert fields
 impl<'b> DisplayableItem for EthTransaction<'b> {
     fn num_items(&self) -> usize {
-        match self {
+        let items = match self {
             Self::Legacy(t) => t.num_items(),
             Self::Eip1559(t) => t.num_items(),
             Self::Eip2930(t) => t.num_items(),
-        }
+            Self::Eip7702(t) => t.num_items(),
+        };
+
+        items
+            + self.foreign_chain_id() as usize
+            + self.high_gas_price() as usize
+            + self.zero_recipient() as usize
     }
 
     fn render_item(
@@ -274,17 +724,62 @@ impl<'b> DisplayableItem for EthTransaction<'b> {
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+
+        let summary = self.summary_items() as u8;
+        let item_n = match item_n.checked_sub(summary) {
+            Some(warning) => {
+                let warning = match (self.foreign_chain_id(), warning) {
+                    (true, 0) => {
+                        let label = pic_str!(b"Warning");
+                        title[..label.len()].copy_from_slice(label);
+                        let content = pic_str!(b"Foreign chain ID");
+                        return handle_ui_message(content, message, page);
+                    }
+                    (true, x) => x - 1,
+                    (false, x) => x,
+                };
+
+                let warning = match (self.high_gas_price(), warning) {
+                    (true, 0) => {
+                        let label = pic_str!(b"Warning");
+                        title[..label.len()].copy_from_slice(label);
+                        let content = pic_str!(b"High gas price");
+                        return handle_ui_message(content, message, page);
+                    }
+                    (true, x) => x - 1,
+                    (false, x) => x,
+                };
+
+                let warning = match (self.zero_recipient(), warning) {
+                    (true, 0) => {
+                        let label = pic_str!(b"Warning");
+                        title[..label.len()].copy_from_slice(label);
+                        let content = pic_str!(b"Burn / zero address");
+                        return handle_ui_message(content, message, page);
+                    }
+                    (true, x) => x - 1,
+                    (false, x) => x,
+                };
+
+                summary + warning
+            }
+            None => item_n,
+        };
+
         match self {
             Self::Legacy(t) => t.render_item(item_n, title, message, page),
             Self::Eip1559(t) => t.render_item(item_n, title, message, page),
             Self::Eip2930(t) => t.render_item(item_n, title, message, page),
+            Self::Eip7702(t) => t.render_item(item_n, title, message, page),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::prelude::v1::*;
+    use core::mem::MaybeUninit;
+    use std::{prelude::v1::*, vec};
 
     use zemu_sys::Viewable;
 
@@ -313,6 +808,411 @@ mod tests {
             (0, 0)
         }
     }
+    #[test]
+    fn unsupported_tx_type() {
+        // a blob transaction(0x03) with the payload of an eip1559 one
+        let data = hex::decode("02f878018402a8af41843b9aca00850d8c7b50e68303d090944a2962ac08962819a8a17661970e3c0db765565e8817addd0864728ae780c080a01e514f7fc78197c66589083cc8fd06376bae627a4080f5fb58d52d90c0df340da049b048717f215e622c93722ff5b1e38e1d1a4ab9e26a39183969a34a5f8dea75").unwrap();
+
+        for tx_type in [0x03, 0x05, 0x00, 0x7f] {
+            let mut data = data.clone();
+            data[0] = tx_type;
+
+            let err = EthTransaction::from_bytes(&data).unwrap_err();
+            assert_eq!(err, nom::Err::Error(ParserError::UnsupportedTxType));
+        }
+    }
+
+    #[test]
+    fn malformed_tx_errors() {
+        let data = hex::decode("ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+
+        // every truncation of the transaction is reported as such
+        for len in 0..data.len() {
+            let err = EthTransaction::from_bytes(&data[..len]).unwrap_err();
+            assert_eq!(err, nom::Err::Error(ParserError::UnexpectedBufferEnd));
+        }
+
+        // a nonce that does not fit in 256 bits
+        let data = hex::decode("f84ea10101010101010101010101010101010101010101010101010101010101010101018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+        let err = EthTransaction::from_bytes(&data).unwrap_err();
+        assert_eq!(err, nom::Err::Error(ParserError::InvalidLength));
+    }
+
+    #[test]
+    fn rlp_integers() {
+        // zero is the empty string
+        let (rem, num) = parse_rlp_u256(&[0x80, 0xff]).unwrap();
+        assert_eq!(rem, &[0xff]);
+        assert!(num.is_empty() && num.is_zero());
+
+        let (_, num) = parse_rlp_u256(&[0x05]).unwrap();
+        assert_eq!(&*num, &[0x05]);
+        assert!(!num.is_zero());
+
+        let (_, num) = parse_rlp_u256(&[0x81, 0x80]).unwrap();
+        assert_eq!(&*num, &[0x80]);
+
+        let mut max = vec![0xa0];
+        max.extend_from_slice(&[0xff; 32]);
+        let (_, num) = parse_rlp_u256(&max).unwrap();
+        assert_eq!(num.len(), 32);
+
+        let mut too_long = vec![0xa1];
+        too_long.extend_from_slice(&[0xff; 33]);
+        let err = parse_rlp_u256(&too_long).unwrap_err();
+        assert_eq!(err, nom::Err::Error(ParserError::InvalidLength));
+
+        // not canonical: zero as a byte, leading zeros
+        // and small values wrapped in a string
+        for data in [&[0x00][..], &[0x82, 0x00, 0x01], &[0x81, 0x01]] {
+            let err = parse_rlp_u256(data).unwrap_err();
+            assert_eq!(err, nom::Err::Error(ParserError::NonCanonicalInteger));
+        }
+    }
+
+    #[test]
+    fn rlp_integer_fields() {
+        // zero nonce
+        let data = hex::decode("ed808504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+        match tx {
+            EthTransaction::Legacy(t) => assert!(t.base.nonce.is_empty()),
+            _ => unreachable!(),
+        }
+
+        // a 32 bytes value
+        let data = hex::decode("f846018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952a0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff8082a86a8080").unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+        match tx {
+            EthTransaction::Legacy(t) => {
+                assert_eq!(&*t.base.nonce, &[0x01]);
+                assert_eq!(&*t.base.value, &[0xff; 32][..]);
+            }
+            _ => unreachable!(),
+        }
+
+        // a nonce with a leading zero
+        let data = hex::decode("ef8200018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+        let err = EthTransaction::from_bytes(&data).unwrap_err();
+        assert_eq!(err, nom::Err::Error(ParserError::NonCanonicalInteger));
+    }
+
+    #[test]
+    // the vectors are read from disk, which miri does not allow
+    #[cfg_attr(miri, ignore)]
+    fn header_in_first_bytes() {
+        use crate::parser::snapshots_common::{with_leaked, ReducedPage};
+
+        fn pages(tx: EthTransaction<'static>) -> Vec<String> {
+            let mut driver = zuit::MockDriver::<_, 18, 1024>::new(tx);
+            driver.drive();
+
+            driver
+                .out_ui()
+                .iter()
+                .flat_map(|item| item.iter().map(|page| ReducedPage::from(page).to_string()))
+                .collect()
+        }
+
+        insta::glob!("eth_testvectors/*.json", |path| {
+            let file = std::fs::File::open(path)
+                .unwrap_or_else(|e| panic!("Unable to open file {:?}: {:?}", path, e));
+            let input: Vec<u8> = serde_json::from_reader(file)
+                .unwrap_or_else(|e| panic!("Unable to read file {:?} as json: {:?}", path, e));
+
+            let test = |data: &'static [u8]| {
+                let (_, tx) = EthTransaction::from_bytes(data).expect("parse tx from data");
+
+                // found before the calldata is received
+                let received = (0..data.len())
+                    .find(|len| EthHeader::parse(&data[..*len]).is_ok())
+                    .expect("header found in the first bytes");
+                let header = EthHeader::parse(&data[..received]).unwrap();
+                assert!(received < data.len());
+                assert_eq!(header, EthHeader::parse(data).unwrap());
+
+                let mut early = MaybeUninit::uninit();
+                EthTransaction::from_header_into(data, &header, &mut early)
+                    .expect("parse tx from its header");
+                let early = unsafe { early.assume_init() };
+
+                assert_eq!(early, tx);
+                assert_eq!(pages(early), pages(tx));
+            };
+
+            unsafe { with_leaked(input, test) };
+        });
+    }
+
+    #[test]
+    fn legacy_tx_type() {
+        let data = hex::decode("ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+
+        let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+        assert!(matches!(tx, EthTransaction::Legacy(..)));
+        assert!(tx.raw_tx_type().is_none());
+    }
+
+    #[test]
+    fn high_gas_price_warning() {
+        let inner_items = |tx: &EthTransaction| match tx {
+            EthTransaction::Legacy(t) => t.num_items(),
+            _ => unreachable!(),
+        };
+
+        // legacy transfer paying 21 gwei per gas
+        let data = "ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080";
+        let data = hex::decode(data).unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+
+        assert!(tx.high_gas_price_with(20));
+        assert!(!tx.high_gas_price_with(21));
+        assert!(!tx.high_gas_price_with(u64::MAX));
+        // disabled
+        assert!(!tx.high_gas_price_with(0));
+
+        // below the default threshold
+        assert!(!tx.high_gas_price());
+        assert_eq!(DisplayableItem::num_items(&tx), inner_items(&tx));
+
+        // 10000 gwei per gas, above the default threshold
+        // and for chain id 1, which is also warned about
+        let data = "ed808609184e72a00082271094cccccccccccccccccccccccccccccccccccccccc88c40900000000000080018080";
+        let data = hex::decode(data).unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+
+        assert!(tx.high_gas_price());
+        assert_eq!(DisplayableItem::num_items(&tx), inner_items(&tx) + 2);
+
+        // the transaction comes first
+        let mut title = [0; 32];
+        let mut message = [0; 64];
+        DisplayableItem::render_item(&tx, 0, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..9], b"Transfer\0");
+
+        // the warnings follow the summary
+        let summary = tx.summary_items() as u8;
+        let mut title = [0; 32];
+        DisplayableItem::render_item(&tx, summary + 1, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..8], b"Warning\0");
+        assert_eq!(&message[..15], b"High gas price\0");
+    }
+
+    #[test]
+    fn foreign_chain_id() {
+        let inner_items = |tx: &EthTransaction| match tx {
+            EthTransaction::Legacy(t) => t.num_items(),
+            _ => unreachable!(),
+        };
+
+        // legacy transfer in mainnet
+        let data = "ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080";
+        let data = hex::decode(data).unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+
+        assert!(!tx.foreign_chain_id());
+        assert!(tx.foreign_chain_id_with(&[43113]));
+        assert_eq!(tx.check_chain_id_with(&[43114], true), Ok(()));
+        assert_eq!(DisplayableItem::num_items(&tx), inner_items(&tx));
+
+        // the same transfer for chain id 1
+        let data = "eb018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a2487400080018080";
+        let data = hex::decode(data).unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+
+        // warned about unless strict
+        assert!(tx.foreign_chain_id());
+        assert!(!tx.foreign_chain_id_with(&[43114, 1]));
+        assert_eq!(tx.check_chain_id_with(&[43114, 43113], false), Ok(()));
+        assert_eq!(DisplayableItem::num_items(&tx), inner_items(&tx) + 1);
+
+        let mut title = [0; 32];
+        let mut message = [0; 64];
+        DisplayableItem::render_item(&tx, 0, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..9], b"Transfer\0");
+
+        let summary = tx.summary_items() as u8;
+        let mut title = [0; 32];
+        DisplayableItem::render_item(&tx, summary, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..8], b"Warning\0");
+        assert_eq!(&message[..17], b"Foreign chain ID\0");
+
+        // rejected when strict
+        assert_eq!(
+            tx.check_chain_id_with(&[43114, 43113], true),
+            Err(ParserError::InvalidChainId)
+        );
+    }
+
+    #[test]
+    fn zero_recipient() {
+        let render = |tx: &EthTransaction, item_n: u8| {
+            let mut title = [0; 32];
+            let mut message = [0; 128];
+            DisplayableItem::render_item(tx, item_n, &mut title, &mut message, 0).unwrap();
+            let str_of = |buf: &[u8]| {
+                let len = buf.iter().position(|&b| b == 0).unwrap();
+                String::from_utf8(buf[..len].to_vec()).unwrap()
+            };
+            (str_of(&title), str_of(&message))
+        };
+
+        // 1 AVAX to the zero address
+        let data = "02e982a86a800101825208940000000000000000000000000000000000000000880de0b6b3a764000080c0";
+        let data = hex::decode(data).unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+
+        assert!(tx.zero_recipient());
+        assert_eq!(render(&tx, 0).0, "Transfer");
+        assert_eq!(
+            render(&tx, 1),
+            (
+                "To".to_string(),
+                "0x0000000000000000000000000000000000000000".to_string()
+            )
+        );
+        let summary = tx.summary_items() as u8;
+        assert_eq!(
+            render(&tx, summary),
+            ("Warning".to_string(), "Burn / zero address".to_string())
+        );
+
+        // the same transfer to another address
+        let data = "02e982a86a8001018252089428ee52a8f3d6e5d15f8b131996950d7f296c7952880de0b6b3a764000080c0";
+        let data = hex::decode(data).unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+
+        assert!(!tx.zero_recipient());
+        assert_eq!(
+            render(&tx, 1),
+            (
+                "To".to_string(),
+                "0x28ee52a8f3d6e5d15f8b131996950d7f296c7952".to_string()
+            )
+        );
+        assert_ne!(render(&tx, tx.summary_items() as u8).0, "Warning");
+
+        // no recipient, it deploys the init code
+        let data = "02d282a86a8001018252088080856080604052c0";
+        let data = hex::decode(data).unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+
+        assert!(!tx.zero_recipient());
+        assert_eq!(render(&tx, 0).0, "Contract creation");
+        assert_ne!(render(&tx, tx.summary_items() as u8).0, "Warning");
+    }
+
+    #[test]
+    #[cfg(feature = "erc20")]
+    fn zero_token_recipient() {
+        let notices = |tx: &EthTransaction| {
+            (tx.summary_items()..DisplayableItem::num_items(tx))
+                .filter_map(|item_n| {
+                    let mut title = [0; 32];
+                    let mut message = [0; 128];
+                    DisplayableItem::render_item(tx, item_n as u8, &mut title, &mut message, 0)
+                        .ok()?;
+                    let len = message.iter().position(|&b| b == 0)?;
+                    String::from_utf8(message[..len].to_vec()).ok()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // ERC-20 transfer of USDT to 0x0000000000000000000000000000000000000000
+        let data = "02f871018347eae184773594008517bfac7c008303291894dac17f958d2ee523a2206206994597c13d831ec780b844a9059cbb000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000006e0456cd0c0";
+        let data = hex::decode(data).unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+
+        assert!(tx.zero_recipient());
+        assert!(notices(&tx).contains(&"Burn / zero address".to_string()));
+
+        // the same transfer to 0xbb98f2a83d78310342da3e63278ce7515d52619d
+        let data = "02f871018347eae184773594008517bfac7c008303291894dac17f958d2ee523a2206206994597c13d831ec780b844a9059cbb000000000000000000000000bb98f2a83d78310342da3e63278ce7515d52619d00000000000000000000000000000000000000000000000000000006e0456cd0c0";
+        let data = hex::decode(data).unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+
+        assert!(!tx.zero_recipient());
+        assert!(!notices(&tx).contains(&"Burn / zero address".to_string()));
+    }
+
+    #[test]
+    fn u256_value() {
+        // 2^200 wei to 0x28ee52a8f3d6e5d15f8b131996950d7f296c7952
+        let data = "f840018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c79529a01000000000000000000000000000000000000000000000000008082a86a8080";
+        let data = hex::decode(data).unwrap();
+        let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+
+        let mut expected = [0; 32];
+        expected[6] = 1;
+        assert_eq!(tx.value_u256(), expected);
+
+        let mut title = [0; 32];
+        let mut message = [0; 128];
+        DisplayableItem::render_item(&tx, 0, &mut title, &mut message, 0).unwrap();
+
+        let amount = b"AVAX 1606938044258990275541962092341162602522202.993782792835301376\0";
+        assert_eq!(&title[..9], b"Transfer\0");
+        assert_eq!(&message[..amount.len()], &amount[..]);
+    }
+
+    #[test]
+    fn display_order() {
+        let title_of = |tx: &EthTransaction, item_n: u8| {
+            let mut title = [0; 32];
+            let mut message = [0; 128];
+            DisplayableItem::render_item(tx, item_n, &mut title, &mut message, 0).unwrap();
+            let len = title.iter().position(|&b| b == 0).unwrap();
+            String::from_utf8(title[..len].to_vec()).unwrap()
+        };
+
+        // the same transfer as legacy, eip2930 and eip1559 transactions
+        let mainnet = [
+            "ee018505d21dba008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952880de0b6b3a76400008082a86a8080",
+            "01ed82a86a018505d21dba008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952880de0b6b3a764000080c0",
+            "02f382a86a018505d21dba008505d21dba008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952880de0b6b3a764000080c0",
+        ];
+        // and for chain id 1
+        let foreign = [
+            "ec018505d21dba008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952880de0b6b3a764000080018080",
+            "01eb01018505d21dba008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952880de0b6b3a764000080c0",
+            "02f101018505d21dba008505d21dba008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952880de0b6b3a764000080c0",
+        ];
+
+        // the pages out of expert mode, the prices per gas of each type included
+        let summaries: [&[&str]; 3] = [
+            &["Transfer", "To", "Fee(GWEI)", "Gas price"],
+            &["Transfer", "To", "Fee(GWEI)", "Gas price"],
+            &[
+                "Transfer",
+                "To",
+                "Fee(GWEI)",
+                "Max fee/gas",
+                "Priority fee/gas",
+            ],
+        ];
+
+        for ((mainnet, foreign), summary) in mainnet.iter().zip(foreign.iter()).zip(summaries) {
+            let data = hex::decode(mainnet).unwrap();
+            let (_, tx) = EthTransaction::from_bytes(&data).unwrap();
+            let foreign_data = hex::decode(foreign).unwrap();
+            let (_, foreign_tx) = EthTransaction::from_bytes(&foreign_data).unwrap();
+
+            assert_eq!(tx.summary_items(), summary.len());
+            assert_eq!(foreign_tx.summary_items(), summary.len());
+            for (item_n, expected) in summary.iter().enumerate() {
+                assert_eq!(&title_of(&tx, item_n as u8), expected);
+                assert_eq!(&title_of(&foreign_tx, item_n as u8), expected);
+            }
+
+            // then the warning and the expert pages, in mainnet
+            // the expert pages take the place of the warning
+            let n = summary.len() as u8;
+            assert_eq!(title_of(&foreign_tx, n), "Warning");
+            assert_eq!(title_of(&tx, n), "Value(wei)");
+            assert_eq!(title_of(&foreign_tx, n + 1), "Value(wei)");
+            assert_eq!(title_of(&tx, n + 1), "Nonce");
+        }
+    }
 
     #[test]
     #[cfg(feature = "full")]
@@ -325,7 +1225,10 @@ mod tests {
     // we can just avoid having it run in miri directly
     #[cfg_attr(miri, ignore)]
     fn tx_eth_ui() {
-        use crate::parser::snapshots_common::{with_leaked, ReducedPage};
+        use crate::parser::{
+            host::render_all,
+            snapshots_common::{with_leaked, ReducedPage},
+        };
 
         insta::glob!("eth_testvectors/*.json", |path| {
             let file = std::fs::File::open(path)
@@ -334,7 +1237,8 @@ mod tests {
                 .unwrap_or_else(|e| panic!("Unable to read file {:?} as json: {:?}", path, e));
 
             let test = |data| {
-                let (_, tx) = EthTransaction::from_bytes(data).expect("parse tx from data");
+                let (_, mut tx) = EthTransaction::from_bytes(data).expect("parse tx from data");
+                let pages = render_all::<_, 18, 1024>(&mut tx);
 
                 let mut driver = zuit::MockDriver::<_, 18, 1024>::new(tx);
                 driver.drive();
@@ -345,6 +1249,7 @@ mod tests {
                     .iter()
                     .flat_map(|item| item.iter().map(ReducedPage::from))
                     .collect::<Vec<_>>();
+                assert_eq!(pages, reduced.len());
 
                 insta::assert_debug_snapshot!(reduced);
             };
@@ -18,17 +18,17 @@ use bolos::{pic_str, PIC};
 use core::{mem::MaybeUninit, ptr::addr_of_mut};
 use zemu_sys::ViewError;
 
-use super::parse_rlp_item;
+use super::{EthHeader, EthTransaction__Type};
 use crate::{
     handlers::{
         eth::{u256, BorrowedU256},
         handle_ui_message,
     },
     parser::{
-        intstr_to_fpstr_inplace, Address, DisplayableItem, EthData, FromBytes, ParserError,
-        ADDRESS_LEN, WEI_AVAX_DIGITS, WEI_NAVAX_DIGITS,
+        Address, DisplayableItem, DomainName, EthData, FromBytes, NativeCurrency, ParserError,
+        ADDRESS_LEN, WEI_NAVAX_DIGITS,
     },
-    utils::ApduPanic,
+    utils::{format_amount, is_app_mode_expert, ApduPanic},
 };
 
 use super::render_u256;
@@ -42,6 +42,8 @@ pub struct BaseLegacy<'b> {
     pub to: Option<Address<'b>>,
     pub value: BorrowedU256<'b>,
     pub data: EthData<'b>,
+    // set by the enclosing transaction, zero when it has none
+    pub chain_id: u64,
 }
 impl<'b> BaseLegacy<'b> {
     #[inline(never)]
@@ -68,11 +70,7 @@ impl<'b> BaseLegacy<'b> {
                 let label = pic_str!(b"Transfer");
                 title[..label.len()].copy_from_slice(label);
 
-                let curr = pic_str!(b"AVAX "!);
-                let (prefix, message) = message.split_at_mut(curr.len());
-                prefix.copy_from_slice(curr);
-
-                render_u256(&self.value, WEI_AVAX_DIGITS, message, page)
+                NativeCurrency::render_value(self.chain_id, &self.value, message, page)
             }
 
             1 => {
@@ -80,10 +78,8 @@ impl<'b> BaseLegacy<'b> {
                 title[..label.len()].copy_from_slice(label);
 
                 // should not panic as address was check
-                self.to
-                    .as_ref()
-                    .apdu_unwrap()
-                    .render_eth_address(message, page)
+                let to = self.to.as_ref().apdu_unwrap();
+                DomainName::render_address(self.chain_id, to, message, page)
             }
             2 => {
                 let label = pic_str!(b"Fee(GWEI)");
@@ -106,13 +102,10 @@ impl<'b> BaseLegacy<'b> {
     ) -> Result<u8, ViewError> {
         let render_funding = !self.value.is_empty();
         match item_n {
-            0 => {
-                let label = pic_str!(b"Contract");
-                title[..label.len()].copy_from_slice(label);
-
-                let content = pic_str!(b"Creation");
-                handle_ui_message(&content[..], message, page)
-            }
+            0 => match &self.data {
+                EthData::Deploy(d) => d.render_creation(title, message, page),
+                _ => Err(ViewError::NoData),
+            },
 
             1 => {
                 let label = pic_str!(b"Gas Limit");
@@ -130,7 +123,7 @@ impl<'b> BaseLegacy<'b> {
             x @ 2.. if !render_funding && x == 2 || render_funding && x == 3 => {
                 self.data.render_item(0, title, message, page)
             }
-            x @ 3.. if x as usize == self.num_items() - 1 => {
+            x @ 3.. if x as usize == self.data_num_items() - 1 => {
                 let label = pic_str!(b"Maximum Fee(GWEI)");
                 title[..label.len()].copy_from_slice(label);
 
@@ -148,7 +141,7 @@ impl<'b> BaseLegacy<'b> {
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
-        let render_fee = self.num_items() as u8 - 1;
+        let render_fee = self.data_num_items() as u8 - 1;
 
         match item_n {
             x @ 0.. if x < render_fee => self.data.render_item(item_n, title, message, page),
@@ -183,21 +176,15 @@ impl<'b> BaseLegacy<'b> {
                 let label = pic_str!(b"Transfer");
                 title[..label.len()].copy_from_slice(label);
 
-                let curr = pic_str!(b"AVAX "!);
-                let (prefix, message) = message.split_at_mut(curr.len());
-                prefix.copy_from_slice(curr);
-
-                render_u256(&self.value, WEI_AVAX_DIGITS, message, page)
+                NativeCurrency::render_value(self.chain_id, &self.value, message, page)
             }
             2 => {
                 let label = pic_str!(b"To");
                 title[..label.len()].copy_from_slice(label);
 
                 // should not panic as address was check
-                self.to
-                    .as_ref()
-                    .apdu_unwrap()
-                    .render_eth_address(message, page)
+                let to = self.to.as_ref().apdu_unwrap();
+                DomainName::render_address(self.chain_id, to, message, page)
             }
             3 => self.data.render_item(0, title, message, page),
             4 => {
@@ -228,7 +215,11 @@ impl<'b> BaseLegacy<'b> {
         let num_items = erc20.num_items() as u8;
 
         match item_n {
-            item_n @ 0.. if item_n < num_items => erc20.render_item(item_n, title, message, page),
+            item_n @ 0.. if item_n < num_items => {
+                // should not panic as address was check
+                let contract = self.to.as_ref().apdu_unwrap();
+                erc20.render_item_for(contract, item_n, title, message, page)
+            }
             x @ 0.. if x == num_items => {
                 let label = pic_str!(b"Contract");
                 title[..label.len()].copy_from_slice(label);
@@ -252,22 +243,20 @@ impl<'b> BaseLegacy<'b> {
 
     #[inline(never)]
     #[cfg(feature = "erc721")]
-    fn render_erc721_call(
+    fn render_nft_call(
         &self,
         item_n: u8,
         title: &mut [u8],
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
-        let erc721 = match self.data {
-            EthData::Erc721(erc721) => erc721,
-            _ => unsafe { core::hint::unreachable_unchecked() },
-        };
-
-        let num_items = erc721.num_items() as u8;
+        // either an ERC721 or ERC1155 call
+        let num_items = self.data.num_items() as u8;
 
         match item_n {
-            item_n @ 0.. if item_n < num_items => erc721.render_item(item_n, title, message, page),
+            item_n @ 0.. if item_n < num_items => {
+                self.data.render_item(item_n, title, message, page)
+            }
             x @ 0.. if x == num_items => {
                 let label = pic_str!(b"Contract");
                 title[..label.len()].copy_from_slice(label);
@@ -289,17 +278,98 @@ impl<'b> BaseLegacy<'b> {
         }
     }
 
+    // number of items of the transaction without
+    // the gas price and the expert pages
+    fn data_num_items(&self) -> usize {
+        // The type of the data field defines how a transaction
+        // info is displayed.
+        match self.data {
+            // description, gas limit, funding contract(if value != zero), maximun fee and data.items
+            EthData::Deploy(d) => 1 + 1 + 1 + d.num_items() + !self.value.is_empty() as usize,
+            // render a simple Transfer, to, fee
+            EthData::None => 1 + 1 + 1,
+            // asset items + fee
+            EthData::AssetCall(d) => d.num_items() + 1,
+            // description amount, address, fee and contract_data
+            EthData::ContractCall(d) => 1 + 1 + 1 + 1 + d.num_items(),
+            // address, fee
+            #[cfg(feature = "erc20")]
+            EthData::Erc20(d) => 1 + 1 + d.num_items(),
+            // contract address, fee
+            #[cfg(feature = "erc721")]
+            EthData::Erc721(d) => 1 + 1 + d.num_items(),
+            #[cfg(feature = "erc721")]
+            EthData::Erc1155(d) => 1 + 1 + d.num_items(),
+        }
+    }
+
+    // transfers also show their exact value in wei
+    fn wei_value_items(&self) -> usize {
+        matches!(self.data, EthData::None) as usize
+    }
+
+    // number of items with the gas price, shown after the fee in every
+    // review, and the expert pages, the value in wei and the nonce
+    pub fn num_items_in(&self, expert: bool) -> usize {
+        self.data_num_items()
+            + 1
+            + if expert {
+                self.wei_value_items() + 1
+            } else {
+                0
+            }
+    }
+
+    fn render_gas_price(
+        &self,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        let label = pic_str!(b"Gas price");
+        title[..label.len()].copy_from_slice(label);
+
+        render_u256(&self.gas_price, WEI_NAVAX_DIGITS, message, page)
+    }
+
+    // the expert pages, shown after the gas price
+    fn render_expert_item(
+        &self,
+        mut item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        if self.wei_value_items() == 1 {
+            if item_n == 0 {
+                let label = pic_str!(b"Value(wei)");
+                title[..label.len()].copy_from_slice(label);
+
+                return render_u256(&self.value, 0, message, page);
+            }
+            item_n -= 1;
+        }
+
+        match item_n {
+            0 => {
+                let label = pic_str!(b"Nonce");
+                title[..label.len()].copy_from_slice(label);
+
+                render_u256(&self.nonce, 0, message, page)
+            }
+            _ => Err(ViewError::NoData),
+        }
+    }
+
     #[inline(never)]
     fn render_fee(&self, message: &mut [u8], page: u8) -> Result<u8, ViewError> {
         let mut bytes = [0; u256::FORMATTED_SIZE_DECIMAL + 2];
 
         let fee = self.fee().map_err(|_| ViewError::Unknown)?;
-        fee.to_lexical(&mut bytes);
-
-        let out = intstr_to_fpstr_inplace(&mut bytes, WEI_NAVAX_DIGITS)
-            .map_err(|_| ViewError::Unknown)?;
+        let out =
+            format_amount(fee, WEI_NAVAX_DIGITS, &mut bytes).map_err(|_| ViewError::Unknown)?;
 
-        handle_ui_message(out, message, page)
+        handle_ui_message(out.as_bytes(), message, page)
     }
 }
 
@@ -307,26 +377,40 @@ impl<'b> FromBytes<'b> for BaseLegacy<'b> {
     fn from_bytes_into(
         input: &'b [u8],
         out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        // same fields as a legacy transaction up to its chain id
+        let (fields, rem) = EthHeader::fields_in(EthTransaction__Type::Legacy, input)?;
+
+        Self::from_fields_into(&fields, rem, out)
+    }
+}
+
+impl<'b> BaseLegacy<'b> {
+    /// Parses the calldata in `input`, the content of the fields
+    /// before it being in `fields`: nonce, gas price, gas limit, to and value
+    pub(super) fn from_fields_into(
+        fields: &[&'b [u8]],
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
     ) -> Result<&'b [u8], nom::Err<ParserError>> {
         crate::sys::zemu_log_stack("EthBase::from_bytes_into\x00");
 
         // get out pointer
         let out = out.as_mut_ptr();
 
-        // nonce
-        let (rem, nonce) = parse_rlp_item(input)?;
-        let nonce = BorrowedU256::new(nonce).ok_or(ParserError::InvalidEthMessage)?;
-
-        // gas price"
-        let (rem, gas_price) = parse_rlp_item(rem)?;
-        let gas_price = BorrowedU256::new(gas_price).ok_or(ParserError::InvalidEthMessage)?;
-
-        // gase limit
-        let (rem, gas_limit) = parse_rlp_item(rem)?;
-        let gas_limit = BorrowedU256::new(gas_limit).ok_or(ParserError::InvalidEthMessage)?;
+        let num = |n: usize| {
+            fields
+                .get(n)
+                .and_then(|field| BorrowedU256::new(*field))
+                .ok_or(ParserError::InvalidLength)
+        };
 
-        // to
-        let (rem, raw_address) = parse_rlp_item(rem)?;
+        // every field was checked when found
+        let nonce = num(0)?;
+        let gas_price = num(1)?;
+        let gas_limit = num(2)?;
+        let raw_address = *fields.get(3).ok_or(ParserError::InvalidLength)?;
+        let value = num(4)?;
 
         let address = match raw_address.len() {
             0 => None,
@@ -338,12 +422,8 @@ impl<'b> FromBytes<'b> for BaseLegacy<'b> {
             _ => return Err(ParserError::InvalidAddress.into()),
         };
 
-        // value
-        let (rem, value_bytes) = parse_rlp_item(rem)?;
-        let value = BorrowedU256::new(value_bytes).ok_or(ParserError::InvalidEthMessage)?;
-
         let data_out = unsafe { &mut *addr_of_mut!((*out).data).cast() };
-        let rem = EthData::parse_into(&address, rem, data_out)?;
+        let rem = EthData::parse_into(&address, input, data_out)?;
 
         // If this is an asset call transaction, checks that there is not
         // value being sent, which would be definately loss
@@ -358,6 +438,7 @@ impl<'b> FromBytes<'b> for BaseLegacy<'b> {
             addr_of_mut!((*out).gas_limit).write(gas_limit);
             addr_of_mut!((*out).to).write(address);
             addr_of_mut!((*out).value).write(value);
+            addr_of_mut!((*out).chain_id).write(0);
         }
 
         Ok(rem)
@@ -366,24 +447,7 @@ impl<'b> FromBytes<'b> for BaseLegacy<'b> {
 
 impl<'b> DisplayableItem for BaseLegacy<'b> {
     fn num_items(&self) -> usize {
-        // The type of the data field defines how a transaction
-        // info is displayed.
-        match self.data {
-            // description, gas limit, funding contract(if value != zero), maximun fee and data.items
-            EthData::Deploy(d) => 1 + 1 + 1 + d.num_items() + !self.value.is_empty() as usize,
-            // render a simple Transfer, to, fee
-            EthData::None => 1 + 1 + 1,
-            // asset items + fee
-            EthData::AssetCall(d) => d.num_items() + 1,
-            // description amount, address, fee and contract_data
-            EthData::ContractCall(d) => 1 + 1 + 1 + 1 + d.num_items(),
-            // address, fee
-            #[cfg(feature = "erc20")]
-            EthData::Erc20(d) => 1 + 1 + d.num_items(),
-            // contract address, fee
-            #[cfg(feature = "erc721")]
-            EthData::Erc721(d) => 1 + 1 + d.num_items(),
-        }
+        self.num_items_in(is_app_mode_expert())
     }
 
     fn render_item(
@@ -393,6 +457,14 @@ impl<'b> DisplayableItem for BaseLegacy<'b> {
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
+        let data_items = self.data_num_items() as u8;
+        if item_n == data_items {
+            return self.render_gas_price(title, message, page);
+        }
+        if item_n > data_items {
+            return self.render_expert_item(item_n - data_items - 1, title, message, page);
+        }
+
         match self.data {
             EthData::None => self.render_transfer(item_n, title, message, page),
             EthData::Deploy(..) => self.render_deploy(item_n, title, message, page),
@@ -401,7 +473,9 @@ impl<'b> DisplayableItem for BaseLegacy<'b> {
             #[cfg(feature = "erc20")]
             EthData::Erc20(..) => self.render_erc20_call(item_n, title, message, page),
             #[cfg(feature = "erc721")]
-            EthData::Erc721(..) => self.render_erc721_call(item_n, title, message, page),
+            EthData::Erc721(..) | EthData::Erc1155(..) => {
+                self.render_nft_call(item_n, title, message, page)
+            }
         }
     }
 }
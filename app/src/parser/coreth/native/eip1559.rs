@@ -18,17 +18,17 @@ use bolos::{pic_str, PIC};
 use core::{mem::MaybeUninit, ptr::addr_of_mut};
 use zemu_sys::ViewError;
 
-use super::{parse_rlp_item, render_u256};
+use super::{parse_access_list, parse_rlp_item, render_u256, EthHeader, EthTransaction__Type};
 use crate::{
     handlers::{
         eth::{u256, BorrowedU256},
-        handle_ui_message,
+        handle_ui_message, handle_ui_message_hex,
     },
     parser::{
-        intstr_to_fpstr_inplace, Address, DisplayableItem, EthData, FromBytes, ParserError,
+        Address, DisplayableItem, DomainName, EthData, FromBytes, NativeCurrency, ParserError,
         ADDRESS_LEN, WEI_AVAX_DIGITS, WEI_NAVAX_DIGITS,
     },
-    utils::ApduPanic,
+    utils::{format_amount, is_app_mode_expert, ApduPanic},
 };
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -54,6 +54,19 @@ impl<'b> Eip1559<'b> {
     pub fn chain_id(&self) -> &'b [u8] {
         self.chain_id
     }
+
+    // zero, a chain without provided currency, if it does not fit an u64
+    fn chain_id_value(&self) -> u64 {
+        super::bytes_to_u64(self.chain_id).unwrap_or_default()
+    }
+
+    pub fn data(&self) -> &EthData<'b> {
+        &self.data
+    }
+
+    pub fn to(&self) -> Option<&Address<'b>> {
+        self.to.as_ref()
+    }
 }
 
 impl<'b> FromBytes<'b> for Eip1559<'b> {
@@ -61,35 +74,53 @@ impl<'b> FromBytes<'b> for Eip1559<'b> {
         input: &'b [u8],
         out: &mut MaybeUninit<Self>,
     ) -> Result<&'b [u8], nom::Err<ParserError>> {
-        crate::sys::zemu_log_stack("Eip1559::from_bytes_into\x00");
+        let (fields, rem) = EthHeader::fields_in(EthTransaction__Type::Eip1559, input)?;
 
-        // get out pointer
-        let out = out.as_mut_ptr();
+        Self::from_fields_into(&fields, rem, out)
+    }
+}
 
-        // chainID
-        let (rem, id_bytes) = parse_rlp_item(input)?;
-        if id_bytes.is_empty() {
-            return Err(ParserError::InvalidChainId.into());
-        }
+impl<'b> Eip1559<'b> {
+    /// Parses the calldata in `input` and the fields following it,
+    /// the content of the ones before it being in `fields`
+    pub(super) fn from_fields_into(
+        fields: &[&'b [u8]],
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("Eip1559::from_bytes_into\x00");
 
-        // nonce
-        let (rem, nonce) = parse_rlp_item(rem)?;
-        let nonce = BorrowedU256::new(nonce).ok_or(ParserError::InvalidEthMessage)?;
+        let rem = Self::parse_fields_into(fields, input, out)?;
+        if !rem.is_empty() {
+            return Err(ParserError::UnexpectedData.into());
+        }
 
-        // max_priority_fee
-        let (rem, priority_fee) = parse_rlp_item(rem)?;
-        let priority_fee = BorrowedU256::new(priority_fee).ok_or(ParserError::InvalidEthMessage)?;
+        Ok(rem)
+    }
 
-        // max_fee
-        let (rem, max_fee) = parse_rlp_item(rem)?;
-        let max_fee = BorrowedU256::new(max_fee).ok_or(ParserError::InvalidEthMessage)?;
+    // parses every field up to the access list, an EIP-7702
+    // transaction has the same ones before its authorization list
+    pub(super) fn parse_fields_into(
+        fields: &[&'b [u8]],
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        // get out pointer
+        let out = out.as_mut_ptr();
 
-        // gas limit
-        let (rem, gas_limit) = parse_rlp_item(rem)?;
-        let gas_limit = BorrowedU256::new(gas_limit).ok_or(ParserError::InvalidEthMessage)?;
+        let field = |n: usize| fields.get(n).copied().ok_or(ParserError::InvalidLength);
+        let num = |n: usize| {
+            field(n).and_then(|f| BorrowedU256::new(f).ok_or(ParserError::InvalidLength))
+        };
 
-        // to
-        let (rem, raw_address) = parse_rlp_item(rem)?;
+        // every field was checked when found
+        let id_bytes = field(0)?;
+        let nonce = num(1)?;
+        let priority_fee = num(2)?;
+        let max_fee = num(3)?;
+        let gas_limit = num(4)?;
+        let raw_address = field(5)?;
+        let value = num(6)?;
 
         let address = match raw_address.len() {
             0 => None,
@@ -101,13 +132,9 @@ impl<'b> FromBytes<'b> for Eip1559<'b> {
             _ => return Err(ParserError::InvalidAddress.into()),
         };
 
-        // value
-        let (rem, value_bytes) = parse_rlp_item(rem)?;
-        let value = BorrowedU256::new(value_bytes).ok_or(ParserError::InvalidEthMessage)?;
-
         // EthData
         let data_out = unsafe { &mut *addr_of_mut!((*out).data).cast() };
-        let rem = EthData::parse_into(&address, rem, data_out)?;
+        let rem = EthData::parse_into(&address, input, data_out)?;
 
         // If this is an asset call transaction, checks that there is not
         // value being sent, which would be definately loss
@@ -116,11 +143,11 @@ impl<'b> FromBytes<'b> for Eip1559<'b> {
             return Err(ParserError::InvalidAssetCall.into());
         }
 
-        // check for erc721 call and chainID
+        // check for nft calls and chainID
         #[cfg(feature = "erc721")]
         {
             let data = unsafe { &*data_out.as_ptr() };
-            if matches!(data, EthData::Erc721(..)) {
+            if matches!(data, EthData::Erc721(..) | EthData::Erc1155(..)) {
                 let chain_id = super::bytes_to_u64(id_bytes)?;
                 let contract_chain_id = crate::parser::ERC721Info::get_nft_info()?.chain_id;
                 if chain_id != contract_chain_id {
@@ -129,12 +156,8 @@ impl<'b> FromBytes<'b> for Eip1559<'b> {
             }
         }
 
-        // access list
-        let (rem, access_list) = parse_rlp_item(rem)?;
-
-        if !rem.is_empty() {
-            return Err(ParserError::UnexpectedData.into());
-        }
+        // access list, which is required even if empty
+        let (rem, access_list) = parse_access_list(rem)?;
 
         unsafe {
             addr_of_mut!((*out).nonce).write(nonce);
@@ -180,11 +203,7 @@ impl<'b> Eip1559<'b> {
                 let label = pic_str!(b"Transfer");
                 title[..label.len()].copy_from_slice(label);
 
-                let curr = pic_str!(b"AVAX "!);
-                let (prefix, message) = message.split_at_mut(curr.len());
-                prefix.copy_from_slice(curr);
-
-                render_u256(&self.value, WEI_AVAX_DIGITS, message, page)
+                NativeCurrency::render_value(self.chain_id_value(), &self.value, message, page)
             }
 
             1 => {
@@ -192,10 +211,8 @@ impl<'b> Eip1559<'b> {
                 title[..label.len()].copy_from_slice(label);
 
                 // should not panic as address was check
-                self.to
-                    .as_ref()
-                    .apdu_unwrap()
-                    .render_eth_address(message, page)
+                let to = self.to.as_ref().apdu_unwrap();
+                DomainName::render_address(self.chain_id_value(), to, message, page)
             }
             2 => {
                 let label = pic_str!(b"Fee(GWEI)");
@@ -217,13 +234,10 @@ impl<'b> Eip1559<'b> {
     ) -> Result<u8, ViewError> {
         let render_funding = !self.value.is_empty();
         match item_n {
-            0 => {
-                let label = pic_str!(b"Contract");
-                title[..label.len()].copy_from_slice(label);
-
-                let content = pic_str!(b"Creation");
-                handle_ui_message(&content[..], message, page)
-            }
+            0 => match &self.data {
+                EthData::Deploy(d) => d.render_creation(title, message, page),
+                _ => Err(ViewError::NoData),
+            },
 
             1 => {
                 let label = pic_str!(b"Gas Limit");
@@ -240,7 +254,7 @@ impl<'b> Eip1559<'b> {
             x @ 2.. if !render_funding && x == 2 || render_funding && x == 3 => {
                 self.data.render_item(0, title, message, page)
             }
-            x @ 3.. if x as usize == self.num_items() - 1 => {
+            x @ 3.. if x as usize == self.data_num_items() - 1 => {
                 let label = pic_str!(b"Maximum Fee(GWEI)");
                 title[..label.len()].copy_from_slice(label);
 
@@ -258,7 +272,7 @@ impl<'b> Eip1559<'b> {
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
-        let render_fee = self.num_items() as u8 - 1;
+        let render_fee = self.data_num_items() as u8 - 1;
 
         match item_n {
             x @ 0.. if x < render_fee => self.data.render_item(item_n, title, message, page),
@@ -293,11 +307,7 @@ impl<'b> Eip1559<'b> {
                 let label = pic_str!(b"Transfer");
                 title[..label.len()].copy_from_slice(label);
 
-                let curr = pic_str!(b"AVAX "!);
-                let (prefix, message) = message.split_at_mut(curr.len());
-                prefix.copy_from_slice(curr);
-
-                render_u256(&self.value, WEI_AVAX_DIGITS, message, page)
+                NativeCurrency::render_value(self.chain_id_value(), &self.value, message, page)
             }
 
             2 => {
@@ -305,10 +315,8 @@ impl<'b> Eip1559<'b> {
                 title[..label.len()].copy_from_slice(label);
 
                 // should not panic as address was check
-                self.to
-                    .as_ref()
-                    .apdu_unwrap()
-                    .render_eth_address(message, page)
+                let to = self.to.as_ref().apdu_unwrap();
+                DomainName::render_address(self.chain_id_value(), to, message, page)
             }
             3 => self.data.render_item(0, title, message, page),
             4 => {
@@ -339,7 +347,11 @@ impl<'b> Eip1559<'b> {
         let num_items = erc20.num_items() as u8;
 
         match item_n {
-            item_n @ 0.. if item_n < num_items => erc20.render_item(item_n, title, message, page),
+            item_n @ 0.. if item_n < num_items => {
+                // should not panic as address was check
+                let contract = self.to.as_ref().apdu_unwrap();
+                erc20.render_item_for(contract, item_n, title, message, page)
+            }
             x @ 0.. if x == num_items => {
                 let label = pic_str!(b"Contract");
                 title[..label.len()].copy_from_slice(label);
@@ -363,22 +375,20 @@ impl<'b> Eip1559<'b> {
 
     #[inline(never)]
     #[cfg(feature = "erc721")]
-    fn render_erc721_call(
+    fn render_nft_call(
         &self,
         item_n: u8,
         title: &mut [u8],
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
-        let erc721 = match self.data {
-            EthData::Erc721(erc721) => erc721,
-            _ => unsafe { core::hint::unreachable_unchecked() },
-        };
-
-        let num_items = erc721.num_items() as u8;
+        // either an ERC721 or ERC1155 call
+        let num_items = self.data.num_items() as u8;
 
         match item_n {
-            item_n @ 0.. if item_n < num_items => erc721.render_item(item_n, title, message, page),
+            item_n @ 0.. if item_n < num_items => {
+                self.data.render_item(item_n, title, message, page)
+            }
             x @ 0.. if x == num_items => {
                 let label = pic_str!(b"Contract");
                 title[..label.len()].copy_from_slice(label);
@@ -400,21 +410,9 @@ impl<'b> Eip1559<'b> {
         }
     }
 
-    fn render_fee(&self, message: &mut [u8], page: u8) -> Result<u8, ViewError> {
-        let mut bytes = [0; u256::FORMATTED_SIZE_DECIMAL + 2];
-
-        let fee = self.fee().map_err(|_| ViewError::Unknown)?;
-        fee.to_lexical(&mut bytes);
-
-        let out = intstr_to_fpstr_inplace(&mut bytes, WEI_NAVAX_DIGITS)
-            .map_err(|_| ViewError::Unknown)?;
-
-        handle_ui_message(out, message, page)
-    }
-}
-
-impl<'b> DisplayableItem for Eip1559<'b> {
-    fn num_items(&self) -> usize {
+    // number of items of the transaction without
+    // the fees per gas and the expert pages
+    fn data_num_items(&self) -> usize {
         // The type of the data field defines how a transaction
         // info is displayed.
         match self.data {
@@ -432,9 +430,223 @@ impl<'b> DisplayableItem for Eip1559<'b> {
             // address, fee
             #[cfg(feature = "erc721")]
             EthData::Erc721(d) => 1 + 1 + d.num_items(),
+            #[cfg(feature = "erc721")]
+            EthData::Erc1155(d) => 1 + 1 + d.num_items(),
+        }
+    }
+
+    // the exact value in wei, only for plain transfers
+    fn wei_value_items(&self) -> usize {
+        matches!(self.data, EthData::None) as usize
+    }
+
+    // number of items with both fees per gas, shown after the fee in every
+    // review, and the expert pages, the value in wei, the nonce and the access list
+    pub fn num_items_in(&self, expert: bool) -> usize {
+        self.data_num_items()
+            + 2
+            + if expert {
+                self.wei_value_items() + 1 + 1 + self.access_list_items()
+            } else {
+                0
+            }
+    }
+
+    // the address and storage keys of every entry,
+    // which were checked when parsing
+    fn access_entries(&self) -> impl Iterator<Item = (&'b [u8], &'b [u8])> {
+        let mut list = self.access_list;
+        core::iter::from_fn(move || {
+            let (rem, entry) = parse_rlp_item(list).ok()?;
+            list = rem;
+
+            let (entry, address) = parse_rlp_item(entry).ok()?;
+            let (_, keys) = parse_rlp_item(entry).ok()?;
+            Some((address, keys))
+        })
+    }
+
+    // the address, the number of storage keys and each key of every entry
+    fn access_list_items(&self) -> usize {
+        self.access_entries()
+            .map(|(_, keys)| 2 + count_rlp_items(keys))
+            .sum()
+    }
+
+    fn render_count(
+        count: usize,
+        singular: &[u8],
+        plural: &[u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use crate::parser::u64_to_str;
+        use lexical_core::Number;
+
+        let suffix = if count == 1 { singular } else { plural };
+
+        let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 8];
+        let len = u64_to_str(count as u64, &mut buffer)
+            .map_err(|_| ViewError::Unknown)?
+            .len();
+        buffer[len..len + suffix.len()].copy_from_slice(suffix);
+
+        handle_ui_message(&buffer[..len + suffix.len()], message, page)
+    }
+
+    fn render_access_list(&self, message: &mut [u8], page: u8) -> Result<u8, ViewError> {
+        if self.access_list.is_empty() {
+            return handle_ui_message(pic_str!(b"none"!), message, page);
+        }
+
+        Self::render_count(
+            count_rlp_items(self.access_list),
+            pic_str!(b" entry"!),
+            pic_str!(b" entries"!),
+            message,
+            page,
+        )
+    }
+
+    // the pages of each entry, its address followed
+    // by the number of storage keys and the keys
+    fn render_access_entry(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        let prefix = pic_str!(b"0x"!);
+        let mut item_n = item_n as usize;
+
+        for (address, keys) in self.access_entries() {
+            let num_keys = count_rlp_items(keys);
+
+            match item_n {
+                0 => {
+                    let label = pic_str!(b"Access address");
+                    title[..label.len()].copy_from_slice(label);
+
+                    return handle_ui_message_hex(prefix, address, message, page);
+                }
+                1 => {
+                    let label = pic_str!(b"Storage keys");
+                    title[..label.len()].copy_from_slice(label);
+
+                    return Self::render_count(
+                        num_keys,
+                        pic_str!(b" key"!),
+                        pic_str!(b" keys"!),
+                        message,
+                        page,
+                    );
+                }
+                n if n < 2 + num_keys => {
+                    let label = pic_str!(b"Storage key");
+                    title[..label.len()].copy_from_slice(label);
+
+                    let mut keys = keys;
+                    for _ in 2..n {
+                        keys = parse_rlp_item(keys).map_err(|_| ViewError::Unknown)?.0;
+                    }
+                    let (_, key) = parse_rlp_item(keys).map_err(|_| ViewError::Unknown)?;
+
+                    return handle_ui_message_hex(prefix, key, message, page);
+                }
+                _ => item_n -= 2 + num_keys,
+            }
+        }
+
+        Err(ViewError::NoData)
+    }
+
+    // the maximum and priority fees per gas
+    fn render_fee_per_gas(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        match item_n {
+            0 => {
+                let label = pic_str!(b"Max fee/gas");
+                title[..label.len()].copy_from_slice(label);
+
+                render_u256(&self.max_fee, WEI_NAVAX_DIGITS, message, page)
+            }
+            1 => {
+                let label = pic_str!(b"Priority fee/gas");
+                title[..label.len()].copy_from_slice(label);
+
+                render_u256(&self.priority_fee, WEI_NAVAX_DIGITS, message, page)
+            }
+            _ => Err(ViewError::NoData),
+        }
+    }
+
+    // the expert pages, shown after the fees per gas
+    fn render_expert_item(
+        &self,
+        mut item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        if self.wei_value_items() == 1 {
+            if item_n == 0 {
+                let label = pic_str!(b"Value(wei)");
+                title[..label.len()].copy_from_slice(label);
+
+                return render_u256(&self.value, 0, message, page);
+            }
+            item_n -= 1;
+        }
+
+        match item_n {
+            0 => {
+                let label = pic_str!(b"Nonce");
+                title[..label.len()].copy_from_slice(label);
+
+                render_u256(&self.nonce, 0, message, page)
+            }
+            1 => {
+                let label = pic_str!(b"Access list");
+                title[..label.len()].copy_from_slice(label);
+
+                self.render_access_list(message, page)
+            }
+            _ => self.render_access_entry(item_n - 2, title, message, page),
         }
     }
 
+    fn render_fee(&self, message: &mut [u8], page: u8) -> Result<u8, ViewError> {
+        let mut bytes = [0; u256::FORMATTED_SIZE_DECIMAL + 2];
+
+        let fee = self.fee().map_err(|_| ViewError::Unknown)?;
+        let out =
+            format_amount(fee, WEI_NAVAX_DIGITS, &mut bytes).map_err(|_| ViewError::Unknown)?;
+
+        handle_ui_message(out.as_bytes(), message, page)
+    }
+}
+
+// items of a rlp list, checked when parsing
+fn count_rlp_items(mut list: &[u8]) -> usize {
+    let mut count = 0;
+    while let Ok((rem, _)) = parse_rlp_item(list) {
+        count += 1;
+        list = rem;
+    }
+    count
+}
+
+impl<'b> DisplayableItem for Eip1559<'b> {
+    fn num_items(&self) -> usize {
+        self.num_items_in(is_app_mode_expert())
+    }
+
     fn render_item(
         &self,
         item_n: u8,
@@ -442,6 +654,14 @@ impl<'b> DisplayableItem for Eip1559<'b> {
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
+        let data_items = self.data_num_items() as u8;
+        if item_n >= data_items + 2 {
+            return self.render_expert_item(item_n - data_items - 2, title, message, page);
+        }
+        if item_n >= data_items {
+            return self.render_fee_per_gas(item_n - data_items, title, message, page);
+        }
+
         match self.data {
             EthData::None => self.render_transfer(item_n, title, message, page),
             EthData::Deploy(..) => self.render_deploy(item_n, title, message, page),
@@ -450,7 +670,9 @@ impl<'b> DisplayableItem for Eip1559<'b> {
             #[cfg(feature = "erc20")]
             EthData::Erc20(..) => self.render_erc20_call(item_n, title, message, page),
             #[cfg(feature = "erc721")]
-            EthData::Erc721(..) => self.render_erc721_call(item_n, title, message, page),
+            EthData::Erc721(..) | EthData::Erc1155(..) => {
+                self.render_nft_call(item_n, title, message, page)
+            }
         }
     }
 }
@@ -477,4 +699,203 @@ mod tests {
 
         assert_eq!(0, tx.value.len());
     }
+
+    #[test]
+    fn fee_per_gas_pages() {
+        let data = "02f871018347eae184773594008517bfac7c008303291894dac17f958d2ee523a2206206994597c13d831ec780b844a9059cbb000000000000000000000000bb98f2a83d78310342da3e63278ce7515d52619d00000000000000000000000000000000000000000000000000000006e0456cd0c0";
+        let data = hex::decode(data).unwrap();
+
+        let (_, tx_bytes) = parse_rlp_item(&data[1..]).unwrap();
+        let (_, tx) = Eip1559::from_bytes(tx_bytes).unwrap();
+
+        let render = |item_n: u8| {
+            let mut title = [0; 32];
+            let mut message = [0; 64];
+            tx.render_item(item_n, &mut title, &mut message, 0).unwrap();
+            (title, message)
+        };
+
+        // both fees are shown, in gwei, right after the fee
+        // and out of expert mode too
+        let data_items = tx.data_num_items() as u8;
+        assert_eq!(tx.num_items_in(false), data_items as usize + 2);
+
+        let (title, _) = render(data_items - 1);
+        assert_eq!(&title[..10], b"Fee(GWEI)\0");
+
+        let (title, message) = render(data_items);
+        assert_eq!(&title[..12], b"Max fee/gas\0");
+        assert_eq!(&message[..4], b"102\0");
+
+        let (title, message) = render(data_items + 1);
+        assert_eq!(&title[..17], b"Priority fee/gas\0");
+        assert_eq!(&message[..2], b"2\0");
+
+        // there is no gas price for EIP-1559 transactions
+        for item_n in 0..tx.num_items() as u8 {
+            let (title, _) = render(item_n);
+            assert_ne!(&title[..9], b"Gas price");
+        }
+    }
+
+    #[test]
+    fn expert_pages() {
+        let data = "02f871018347eae184773594008517bfac7c008303291894dac17f958d2ee523a2206206994597c13d831ec780b844a9059cbb000000000000000000000000bb98f2a83d78310342da3e63278ce7515d52619d00000000000000000000000000000000000000000000000000000006e0456cd0c0";
+        let data = hex::decode(data).unwrap();
+
+        let (_, tx_bytes) = parse_rlp_item(&data[1..]).unwrap();
+        let (_, tx) = Eip1559::from_bytes(tx_bytes).unwrap();
+
+        // the nonce and the access list are hidden
+        // in simple mode, the fees per gas are not
+        let simple = tx.num_items_in(false);
+        assert_eq!(tx.num_items_in(true), simple + 2);
+
+        let mut title = [0; 32];
+        let mut message = [0; 64];
+        tx.render_item(simple as u8, &mut title, &mut message, 0)
+            .unwrap();
+        assert_eq!(&title[..6], b"Nonce\0");
+        assert_eq!(&message[..8], b"4713185\0");
+    }
+
+    #[test]
+    fn access_list() {
+        use std::prelude::v1::*;
+
+        // a transfer with an empty access list
+        let data = hex::decode(
+            "02e6018001018402625a0094010203040000000000000000000000000000000285012345678980c0",
+        )
+        .unwrap();
+        let (_, tx_bytes) = parse_rlp_item(&data[1..]).unwrap();
+        let fields = &tx_bytes[..tx_bytes.len() - 1];
+
+        let parse = |access_list: &[u8]| {
+            let mut tx_bytes = fields.to_vec();
+            tx_bytes.extend_from_slice(access_list);
+            Eip1559::from_bytes(&tx_bytes).map(|(_, tx)| {
+                let mut title = [0; 32];
+                let mut message = [0; 64];
+                let count = tx.data_num_items() + tx.wei_value_items() + 3;
+                tx.render_item(count as u8, &mut title, &mut message, 0)
+                    .unwrap();
+                (title, message)
+            })
+        };
+
+        let (title, message) = parse(&[0xc0]).unwrap();
+        assert_eq!(&title[..12], b"Access list\0");
+        assert_eq!(&message[..5], b"none\0");
+
+        // one address with a single storage key
+        let mut entry = vec![0xf7, 0x94];
+        entry.extend_from_slice(&[0x11; ADDRESS_LEN]);
+        entry.extend_from_slice(&[0xe1, 0xa0]);
+        entry.extend_from_slice(&[0x22; 32]);
+        let mut list = vec![0xf8, 0x38];
+        list.extend_from_slice(&entry);
+
+        let (_, message) = parse(&list).unwrap();
+        assert_eq!(&message[..8], b"1 entry\0");
+
+        list.extend_from_slice(&[0xd6, 0x94]);
+        list.extend_from_slice(&[0x33; ADDRESS_LEN]);
+        list.push(0xc0);
+        list[1] += 0x17;
+        let (_, message) = parse(&list).unwrap();
+        assert_eq!(&message[..10], b"2 entries\0");
+
+        // missing
+        assert!(parse(&[]).is_err());
+        // not a list
+        assert!(parse(&[0x80]).is_err());
+        // truncated
+        assert!(parse(&list[..list.len() - 1]).is_err());
+        // malformed entry, with a short address
+        assert!(parse(&[0xc4, 0xc3, 0x81, 0x11, 0xc0]).is_err());
+    }
+
+    #[test]
+    fn access_list_storage_keys() {
+        use std::prelude::v1::*;
+
+        let data = hex::decode(
+            "02e6018001018402625a0094010203040000000000000000000000000000000285012345678980c0",
+        )
+        .unwrap();
+        let (_, tx_bytes) = parse_rlp_item(&data[1..]).unwrap();
+        let mut tx_bytes = tx_bytes[..tx_bytes.len() - 1].to_vec();
+
+        // one address with two storage keys
+        tx_bytes.extend_from_slice(&[0xf8, 0x5b, 0xf8, 0x59, 0x94]);
+        tx_bytes.extend_from_slice(&[0x11; ADDRESS_LEN]);
+        tx_bytes.extend_from_slice(&[0xf8, 0x42, 0xa0]);
+        tx_bytes.extend_from_slice(&[0x22; 32]);
+        tx_bytes.push(0xa0);
+        tx_bytes.extend_from_slice(&[0x33; 32]);
+
+        let (_, tx) = Eip1559::from_bytes(&tx_bytes).unwrap();
+        assert_eq!(tx.access_list_items(), 4);
+
+        let pages = |item_n: usize| {
+            let mut title = [0; 32];
+            let mut message = [0; 20];
+            let n = tx
+                .render_item(item_n as u8, &mut title, &mut message, 0)
+                .unwrap();
+
+            let mut text = String::new();
+            for page in 0..n {
+                tx.render_item(item_n as u8, &mut title, &mut message, page)
+                    .unwrap();
+                let len = message.iter().position(|b| *b == 0).unwrap();
+                text.push_str(core::str::from_utf8(&message[..len]).unwrap());
+            }
+            let len = title.iter().position(|b| *b == 0).unwrap();
+            (String::from_utf8(title[..len].to_vec()).unwrap(), text)
+        };
+
+        // after the number of entries
+        let first = tx.data_num_items() + tx.wei_value_items() + 4;
+        assert_eq!(tx.num_items_in(true), first + 4);
+
+        let expected = [
+            (
+                "Access address",
+                std::format!("0x{}", "11".repeat(ADDRESS_LEN)),
+            ),
+            ("Storage keys", "2 keys".to_string()),
+            ("Storage key", std::format!("0x{}", "22".repeat(32))),
+            ("Storage key", std::format!("0x{}", "33".repeat(32))),
+        ];
+        for (n, (title, message)) in expected.iter().enumerate() {
+            assert_eq!(pages(first + n), (title.to_string(), message.clone()));
+        }
+    }
+
+    #[test]
+    fn contract_creation_page() {
+        let render_first = |data: &str| {
+            let data = hex::decode(data).unwrap();
+            let (_, tx_bytes) = parse_rlp_item(&data[1..]).unwrap();
+            let (_, tx) = Eip1559::from_bytes(tx_bytes).unwrap();
+
+            let mut title = [0; 32];
+            let mut message = [0; 64];
+            tx.render_item(0, &mut title, &mut message, 0).unwrap();
+            (title, message)
+        };
+
+        // empty `to` with 5 bytes of init code
+        let (title, message) = render_first("02d10180010183625a008080856080604052c0");
+        assert_eq!(&title[..18], b"Contract creation\0");
+        assert_eq!(&message[..19], b"Init code: 5 bytes\0");
+
+        // a transfer shows its recipient instead
+        let (title, _) = render_first(
+            "02e6018001018402625a0094010203040000000000000000000000000000000285012345678980c0",
+        );
+        assert_ne!(&title[..17], b"Contract creation");
+    }
 }
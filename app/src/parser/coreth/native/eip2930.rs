@@ -16,8 +16,8 @@
 use core::{mem::MaybeUninit, ptr::addr_of_mut};
 use zemu_sys::ViewError;
 
-use super::parse_rlp_item;
 use super::BaseLegacy;
+use super::{parse_access_list, EthHeader, EthTransaction__Type};
 use crate::parser::{DisplayableItem, FromBytes, ParserError};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -46,29 +46,44 @@ impl<'b> FromBytes<'b> for Eip2930<'b> {
     fn from_bytes_into(
         input: &'b [u8],
         out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        let (fields, rem) = EthHeader::fields_in(EthTransaction__Type::Eip2930, input)?;
+
+        Self::from_fields_into(&fields, rem, out)
+    }
+}
+
+impl<'b> Eip2930<'b> {
+    /// Parses the calldata in `input` and the fields following it,
+    /// the content of the ones before it being in `fields`
+    pub(super) fn from_fields_into(
+        fields: &[&'b [u8]],
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
     ) -> Result<&'b [u8], nom::Err<ParserError>> {
         crate::sys::zemu_log_stack("Eip2930::from_bytes_into\x00");
 
         // get out pointer
         let out = out.as_mut_ptr();
 
-        // chainID
-        let (rem, id_bytes) = parse_rlp_item(input)?;
-        if id_bytes.is_empty() {
-            return Err(ParserError::InvalidChainId.into());
-        }
+        // chainID, checked when found, followed by the base fields
+        let (id_bytes, base_fields) = fields.split_first().ok_or(ParserError::InvalidChainId)?;
+        let id_bytes = *id_bytes;
 
         let data_out = unsafe { &mut *addr_of_mut!((*out).base).cast() };
-        let rem = BaseLegacy::from_bytes_into(rem, data_out)?;
+        let rem = BaseLegacy::from_fields_into(base_fields, input, data_out)?;
 
         // access list
-        let (rem, access_list) = parse_rlp_item(rem)?;
+        let (rem, access_list) = parse_access_list(rem)?;
 
-        // check for erc721 call and chainID
+        // check for nft calls and chainID
         #[cfg(feature = "erc721")]
         {
             let base = unsafe { &*data_out.as_ptr() };
-            if matches!(base.data, crate::parser::EthData::Erc721(..)) {
+            if matches!(
+                base.data,
+                crate::parser::EthData::Erc721(..) | crate::parser::EthData::Erc1155(..)
+            ) {
                 let chain_id = super::bytes_to_u64(id_bytes)?;
                 let contract_chain_id = crate::parser::ERC721Info::get_nft_info()?.chain_id;
                 if chain_id != contract_chain_id {
@@ -77,8 +92,11 @@ impl<'b> FromBytes<'b> for Eip2930<'b> {
             }
         }
 
+        // chains that do not fit an u64 have no provided currency
+        let chain_id = super::bytes_to_u64(id_bytes).unwrap_or_default();
         unsafe {
             addr_of_mut!((*out).chain_id).write(id_bytes);
+            addr_of_mut!((*out).base.chain_id).write(chain_id);
             addr_of_mut!((*out).access_list).write(access_list);
         }
 
@@ -106,6 +124,7 @@ impl<'b> DisplayableItem for Eip2930<'b> {
 mod tests {
     use crate::parser::EthData;
 
+    use super::super::parse_rlp_item;
     use super::*;
 
     #[test]
@@ -137,4 +156,47 @@ mod tests {
         assert!(matches!(tx.base.data, EthData::ContractCall(..)));
         assert_eq!(&tx.base.to.unwrap().raw_address()[..], &address);
     }
+
+    #[test]
+    fn nonce_page() {
+        // a transfer with nonce 257
+        let data = "01ef82a86a8201018505d21dba008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952880de0b6b3a764000080c0";
+        let data = hex::decode(data).unwrap();
+
+        let (_, bytes) = parse_rlp_item(&data[1..]).unwrap();
+        let (_, tx) = Eip2930::from_bytes(bytes).unwrap();
+
+        let render = |item_n: usize| {
+            let mut title = [0; 32];
+            let mut message = [0; 64];
+            tx.base
+                .render_item(item_n as u8, &mut title, &mut message, 0)
+                .unwrap();
+            (title, message)
+        };
+
+        // hidden in simple mode, unlike the gas price
+        let simple = tx.base.num_items_in(false);
+        for item_n in 0..simple {
+            assert_ne!(&render(item_n).0[..6], b"Nonce\0");
+        }
+        assert_eq!(&render(simple - 1).0[..10], b"Gas price\0");
+
+        // shown in decimal after the value in wei
+        assert_eq!(tx.base.num_items_in(true), simple + 2);
+        let (title, message) = render(simple + 1);
+        assert_eq!(&title[..6], b"Nonce\0");
+        assert_eq!(&message[..4], b"257\0");
+    }
+
+    #[test]
+    fn nonce_over_64_bits() {
+        // the same transfer with a 9 bytes nonce
+        let data = "01f782a86a89010101010101010101018505d21dba008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952880de0b6b3a764000080c0";
+        let data = hex::decode(data).unwrap();
+
+        let (_, bytes) = parse_rlp_item(&data[1..]).unwrap();
+        let err = Eip2930::from_bytes(bytes).unwrap_err();
+        assert_eq!(err, nom::Err::Error(ParserError::ValueOutOfRange));
+    }
 }
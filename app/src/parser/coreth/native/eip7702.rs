@@ -0,0 +1,355 @@
+/*******************************************************************************
+*   (c) 2022 Zondax AG
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use bolos::{pic_str, PIC};
+use core::{mem::MaybeUninit, ptr::addr_of_mut};
+use zemu_sys::ViewError;
+
+use super::{
+    parse_rlp_item, parse_rlp_u256, render_u256, Eip1559, EthHeader, EthTransaction__Type,
+};
+use crate::{
+    handlers::{eth::BorrowedU256, handle_ui_message},
+    parser::{Address, DisplayableItem, FromBytes, ParserError, ADDRESS_LEN, U64_SIZE},
+    utils::is_app_mode_expert,
+};
+
+/// An EIP-7702 transaction, an EIP-1559 call that also sets the code
+/// of the signing account to the ones of the delegates authorized
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct Eip7702<'b> {
+    pub call: Eip1559<'b>,
+    authorization_list: &'b [u8],
+}
+
+/// An entry of the authorization list, its nonce and
+/// signature are checked when parsing but not kept
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct Authorization<'b> {
+    // zero for any chain
+    pub chain_id: BorrowedU256<'b>,
+    pub address: Address<'b>,
+}
+
+impl<'b> Authorization<'b> {
+    fn from_tuple(tuple: &'b [u8]) -> Result<Self, nom::Err<ParserError>> {
+        let (rem, chain_id) = parse_rlp_u256(tuple)?;
+
+        let (rem, raw_address) = parse_rlp_item(rem)?;
+        if raw_address.len() != ADDRESS_LEN {
+            return Err(ParserError::InvalidAuthorization.into());
+        }
+        let mut address = MaybeUninit::uninit();
+        _ = Address::from_bytes_into(raw_address, &mut address)?;
+
+        let (rem, nonce) = parse_rlp_u256(rem)?;
+        if nonce.len() > U64_SIZE {
+            return Err(ParserError::InvalidAuthorization.into());
+        }
+
+        // signature of the authority
+        let (rem, y_parity) = parse_rlp_u256(rem)?;
+        if !matches!(&y_parity[..], [] | [1]) {
+            return Err(ParserError::InvalidAuthorization.into());
+        }
+        let (rem, _r) = parse_rlp_u256(rem)?;
+        let (rem, _s) = parse_rlp_u256(rem)?;
+
+        if !rem.is_empty() {
+            return Err(ParserError::InvalidAuthorization.into());
+        }
+
+        Ok(Self {
+            chain_id,
+            address: unsafe { address.assume_init() },
+        })
+    }
+}
+
+/// Returns the remaining bytes from data along with
+/// the tuples of the authorization list found
+///
+/// The list can not be empty and every tuple has to be
+/// `[chain_id, address, nonce, y_parity, r, s]`
+pub fn parse_authorization_list(data: &[u8]) -> Result<(&[u8], &[u8]), nom::Err<ParserError>> {
+    let is_list = |data: &[u8]| matches!(data.first(), Some(0xC0..));
+
+    if !is_list(data) {
+        return Err(ParserError::InvalidEthMessage.into());
+    }
+    let (rem, list) = parse_rlp_item(data)?;
+    if list.is_empty() {
+        return Err(ParserError::InvalidAuthorization.into());
+    }
+
+    let mut tuples = list;
+    while !tuples.is_empty() {
+        if !is_list(tuples) {
+            return Err(ParserError::InvalidAuthorization.into());
+        }
+        let (next, tuple) = parse_rlp_item(tuples)?;
+        Authorization::from_tuple(tuple)?;
+
+        tuples = next;
+    }
+
+    Ok((rem, list))
+}
+
+impl<'b> Eip7702<'b> {
+    pub fn chain_id(&self) -> &'b [u8] {
+        self.call.chain_id()
+    }
+
+    // tuples were checked when parsing
+    fn num_authorizations(&self) -> usize {
+        let mut count = 0;
+        let mut list = self.authorization_list;
+        while let Ok((rem, _)) = parse_rlp_item(list) {
+            count += 1;
+            list = rem;
+        }
+        count
+    }
+
+    pub fn authorization(&self, n: usize) -> Option<Authorization<'b>> {
+        let mut list = self.authorization_list;
+        for _ in 0..n {
+            list = parse_rlp_item(list).ok()?.0;
+        }
+
+        let (_, tuple) = parse_rlp_item(list).ok()?;
+        Authorization::from_tuple(tuple).ok()
+    }
+
+    // the warning and the delegate and chain id of each authorization
+    fn delegation_items(&self) -> usize {
+        1 + 2 * self.num_authorizations()
+    }
+
+    pub fn num_items_in(&self, expert: bool) -> usize {
+        self.delegation_items() + self.call.num_items_in(expert)
+    }
+
+    fn render_authorization(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        let auth = self
+            .authorization(item_n as usize / 2)
+            .ok_or(ViewError::NoData)?;
+
+        if item_n % 2 == 0 {
+            let label = pic_str!(b"Delegate");
+            title[..label.len()].copy_from_slice(label);
+
+            return auth.address.render_eth_address(message, page);
+        }
+
+        let label = pic_str!(b"Delegate chain ID");
+        title[..label.len()].copy_from_slice(label);
+
+        if auth.chain_id.is_zero() {
+            return handle_ui_message(pic_str!(b"Any"!), message, page);
+        }
+        render_u256(&auth.chain_id, 0, message, page)
+    }
+}
+
+impl<'b> FromBytes<'b> for Eip7702<'b> {
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        let (fields, rem) = EthHeader::fields_in(EthTransaction__Type::Eip7702, input)?;
+
+        Self::from_fields_into(&fields, rem, out)
+    }
+}
+
+impl<'b> Eip7702<'b> {
+    /// Parses the calldata in `input` and the fields following it,
+    /// the content of the ones before it being in `fields`
+    pub(super) fn from_fields_into(
+        fields: &[&'b [u8]],
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("Eip7702::from_bytes_into\x00");
+
+        // get out pointer
+        let out = out.as_mut_ptr();
+
+        let call_out = unsafe { &mut *addr_of_mut!((*out).call).cast() };
+        let rem = Eip1559::parse_fields_into(fields, input, call_out)?;
+
+        // the destination is required, this transaction can not deploy
+        let call = unsafe { &*call_out.as_ptr() };
+        if call.to().is_none() {
+            return Err(ParserError::InvalidAddress.into());
+        }
+
+        let (rem, authorization_list) = parse_authorization_list(rem)?;
+
+        if !rem.is_empty() {
+            return Err(ParserError::UnexpectedData.into());
+        }
+
+        unsafe {
+            addr_of_mut!((*out).authorization_list).write(authorization_list);
+        }
+
+        Ok(rem)
+    }
+}
+
+impl<'b> DisplayableItem for Eip7702<'b> {
+    fn num_items(&self) -> usize {
+        self.num_items_in(is_app_mode_expert())
+    }
+
+    fn render_item(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        let delegation_items = self.delegation_items();
+
+        match item_n as usize {
+            // the code of the account changes, which is
+            // not obvious from the call that follows
+            0 => {
+                let label = pic_str!(b"Warning");
+                title[..label.len()].copy_from_slice(label);
+                let content = pic_str!(b"Set account code");
+
+                handle_ui_message(content, message, page)
+            }
+            x if x < delegation_items => {
+                self.render_authorization(item_n - 1, title, message, page)
+            }
+            _ => self
+                .call
+                .render_item(item_n - delegation_items as u8, title, message, page),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{prelude::v1::*, vec};
+
+    use super::*;
+    use crate::parser::coreth::native::EthTransaction;
+
+    const SET_CODE_TX: &str = "04f8e782a86a018505d21dba008505d21dba0082c35094cccccccccccccccccccccccccccccccccccccccc8080c0f8baf85c82a86a9463c7e0d2a7b1ab1118a7a8f6a8b3a1399d55b26a8001a01111111111111111111111111111111111111111111111111111111111111111a02222222222222222222222222222222222222222222222222222222222222222f85a809428ee52a8f3d6e5d15f8b131996950d7f296c79520580a01111111111111111111111111111111111111111111111111111111111111111a02222222222222222222222222222222222222222222222222222222222222222";
+
+    fn render(tx: &EthTransaction, item_n: u8) -> (String, String) {
+        let mut title = [0; 32];
+        let mut message = [0; 128];
+        DisplayableItem::render_item(tx, item_n, &mut title, &mut message, 0).unwrap();
+
+        let str_of = |buf: &[u8]| {
+            let len = buf.iter().position(|&b| b == 0).unwrap();
+            String::from_utf8(buf[..len].to_vec()).unwrap()
+        };
+        (str_of(&title), str_of(&message))
+    }
+
+    #[test]
+    fn parse_eip7702() {
+        let data = hex::decode(SET_CODE_TX).unwrap();
+        let (rem, tx) = EthTransaction::from_bytes(&data).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(tx.raw_tx_type(), Some(0x04));
+
+        let set_code = match tx {
+            EthTransaction::Eip7702(t) => t,
+            _ => panic!("not an eip7702 transaction"),
+        };
+        assert_eq!(set_code.num_authorizations(), 2);
+        assert!(set_code.authorization(1).unwrap().chain_id.is_zero());
+        assert!(set_code.authorization(2).is_none());
+
+        let pages = vec![
+            ("Warning", "Set account code"),
+            ("Delegate", "0x63c7e0d2a7b1ab1118a7a8f6a8b3a1399d55b26a"),
+            ("Delegate chain ID", "43114"),
+            ("Delegate", "0x28ee52a8f3d6e5d15f8b131996950d7f296c7952"),
+            ("Delegate chain ID", "Any"),
+            ("Transfer", "AVAX 0"),
+        ];
+        for (item_n, (title, message)) in pages.into_iter().enumerate() {
+            let (t, m) = render(&tx, item_n as u8);
+            assert_eq!((t.as_str(), m.as_str()), (title, message));
+        }
+        // along both fees per gas
+        assert_eq!(set_code.num_items_in(false), 5 + 5);
+    }
+
+    #[test]
+    fn set_code_envelope() {
+        use bolos::hash::{Hasher, Keccak};
+
+        // the signed hash covers the transaction type too
+        let data = hex::decode(SET_CODE_TX).unwrap();
+        let (rem, _) = EthTransaction::from_bytes(&data).unwrap();
+        let signed = &data[..data.len() - rem.len()];
+        assert_eq!(signed[0], 0x04);
+
+        let expected = "e0e8b8f58d28b4e0ad80fb393934252d08f40a01644610bdba702e96a2e60db7";
+        let hash = Keccak::<32>::digest(signed).unwrap();
+        assert_eq!(hex::encode(hash), expected);
+    }
+
+    #[test]
+    fn malformed_authorizations() {
+        let parse = |data: &str| {
+            let data = hex::decode(data).unwrap();
+            EthTransaction::from_bytes(&data).map(|_| ()).unwrap_err()
+        };
+
+        let invalid = nom::Err::Error(ParserError::InvalidAuthorization);
+        // empty list
+        assert_eq!(parse("04ec82a86a018505d21dba008505d21dba0082c35094cccccccccccccccccccccccccccccccccccccccc8080c0c0"), invalid);
+        // entry that is not a tuple
+        assert_eq!(parse("04ef82a86a018505d21dba008505d21dba0082c35094cccccccccccccccccccccccccccccccccccccccc8080c0c382a86a"), invalid);
+        // 19 bytes address
+        assert_eq!(parse("04f88a82a86a018505d21dba008505d21dba0082c35094cccccccccccccccccccccccccccccccccccccccc8080c0f85df85b82a86a9363c7e0d2a7b1ab1118a7a8f6a8b3a1399d55b28001a01111111111111111111111111111111111111111111111111111111111111111a02222222222222222222222222222222222222222222222222222222222222222"), invalid);
+        // y parity of 2
+        assert_eq!(parse("04f88b82a86a018505d21dba008505d21dba0082c35094cccccccccccccccccccccccccccccccccccccccc8080c0f85ef85c82a86a9463c7e0d2a7b1ab1118a7a8f6a8b3a1399d55b26a8002a01111111111111111111111111111111111111111111111111111111111111111a02222222222222222222222222222222222222222222222222222222222222222"), invalid);
+        // nonce above u64
+        assert_eq!(parse("04f89482a86a018505d21dba008505d21dba0082c35094cccccccccccccccccccccccccccccccccccccccc8080c0f867f86582a86a9463c7e0d2a7b1ab1118a7a8f6a8b3a1399d55b26a8901010101010101010101a01111111111111111111111111111111111111111111111111111111111111111a02222222222222222222222222222222222222222222222222222222222222222"), invalid);
+        // an extra field
+        assert_eq!(parse("04f88c82a86a018505d21dba008505d21dba0082c35094cccccccccccccccccccccccccccccccccccccccc8080c0f85ff85d82a86a9463c7e0d2a7b1ab1118a7a8f6a8b3a1399d55b26a8001a01111111111111111111111111111111111111111111111111111111111111111a0222222222222222222222222222222222222222222222222222222222222222280"), invalid);
+
+        // missing the s component of the signature
+        parse("04f86a82a86a018505d21dba008505d21dba0082c35094cccccccccccccccccccccccccccccccccccccccc8080c0f83df83b82a86a9463c7e0d2a7b1ab1118a7a8f6a8b3a1399d55b26a8001a01111111111111111111111111111111111111111111111111111111111111111");
+
+        // can not deploy a contract
+        assert_eq!(
+            parse("04f87c82a86a018505d21dba008505d21dba0082c3508080856080604052c0f85ef85c82a86a9463c7e0d2a7b1ab1118a7a8f6a8b3a1399d55b26a8001a01111111111111111111111111111111111111111111111111111111111111111a02222222222222222222222222222222222222222222222222222222222222222"),
+            nom::Err::Error(ParserError::InvalidAddress)
+        );
+    }
+}
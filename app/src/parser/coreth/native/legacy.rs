@@ -18,9 +18,11 @@ use core::{mem::MaybeUninit, ptr::addr_of_mut};
 use zemu_sys::ViewError;
 
 use super::parse_rlp_item;
-use super::BaseLegacy;
+use super::{BaseLegacy, EthHeader, EthTransaction__Type};
+use crate::handlers::handle_ui_message;
 use crate::parser::U64_SIZE;
 use crate::parser::{DisplayableItem, FromBytes, ParserError};
+use crate::utils::pre_eip155_toggle::pre_eip155_enabled;
 
 const MAX_CHAIN_LEN: usize = U64_SIZE as usize;
 
@@ -39,12 +41,32 @@ impl<'b> Legacy<'b> {
     pub fn chain_id(&self) -> &'b [u8] {
         self.chain_id
     }
+
+    // legacy transactions without a chain id are
+    // not protected against replay attacks(EIP155)
+    pub fn replay_protected(&self) -> bool {
+        !self.chain_id.is_empty()
+    }
 }
 
 impl<'b> FromBytes<'b> for Legacy<'b> {
     fn from_bytes_into(
         input: &'b [u8],
         out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        let (fields, rem) = EthHeader::fields_in(EthTransaction__Type::Legacy, input)?;
+
+        Self::from_fields_into(&fields, rem, out)
+    }
+}
+
+impl<'b> Legacy<'b> {
+    /// Parses the calldata in `input` and the fields following it,
+    /// the content of the ones before it being in `fields`
+    pub(super) fn from_fields_into(
+        fields: &[&'b [u8]],
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
     ) -> Result<&'b [u8], nom::Err<ParserError>> {
         crate::sys::zemu_log_stack("Legacy::from_bytes_into\x00");
 
@@ -52,12 +74,17 @@ impl<'b> FromBytes<'b> for Legacy<'b> {
         let out = out.as_mut_ptr();
 
         let data_out = unsafe { &mut *addr_of_mut!((*out).base).cast() };
-        let rem = BaseLegacy::from_bytes_into(input, data_out)?;
+        let rem = BaseLegacy::from_fields_into(fields, input, data_out)?;
 
         // two cases:
-        // - legacy no EIP155 compliant which should be supported
+        // - legacy no EIP155 compliant which is only supported if enabled by the user
         // - legacy EIP155 in which case should come with empty r and s values
         if rem.is_empty() {
+            if !pre_eip155_enabled() {
+                crate::sys::zemu_log_stack("Legacy::missing_chain_id\x00");
+                return Err(ParserError::MissingChainId.into());
+            }
+
             unsafe {
                 // write an empty chain-id as it is used to compute the right V component
                 // when transaction is signed
@@ -76,11 +103,14 @@ impl<'b> FromBytes<'b> for Legacy<'b> {
         let (rem, r) = parse_rlp_item(rem)?;
         let (rem, s) = parse_rlp_item(rem)?;
 
-        // check for erc721 call and chainID
+        // check for nft calls and chainID
         #[cfg(feature = "erc721")]
         {
             let base = unsafe { &*data_out.as_ptr() };
-            if matches!(base.data, crate::parser::EthData::Erc721(..)) {
+            if matches!(
+                base.data,
+                crate::parser::EthData::Erc721(..) | crate::parser::EthData::Erc1155(..)
+            ) {
                 let chain_id = super::bytes_to_u64(id_bytes)?;
                 let contract_chain_id = crate::parser::ERC721Info::get_nft_info()?.chain_id;
                 if chain_id != contract_chain_id {
@@ -104,8 +134,10 @@ impl<'b> FromBytes<'b> for Legacy<'b> {
             return Err(ParserError::InvalidChainId.into());
         }
 
+        let chain_id = super::bytes_to_u64(id_bytes)?;
         unsafe {
             addr_of_mut!((*out).chain_id).write(id_bytes);
+            addr_of_mut!((*out).base.chain_id).write(chain_id);
         }
 
         Ok(rem)
@@ -114,7 +146,7 @@ impl<'b> FromBytes<'b> for Legacy<'b> {
 
 impl<'b> DisplayableItem for Legacy<'b> {
     fn num_items(&self) -> usize {
-        self.base.num_items()
+        self.base.num_items() + (!self.replay_protected()) as usize
     }
 
     fn render_item(
@@ -124,7 +156,23 @@ impl<'b> DisplayableItem for Legacy<'b> {
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
-        self.base.render_item(item_n, title, message, page)
+        use bolos::{pic_str, PIC};
+
+        // warn about the missing replay protection
+        // right after the summary of the transaction
+        let summary = self.base.num_items_in(false) as u8;
+        if self.replay_protected() || item_n < summary {
+            return self.base.render_item(item_n, title, message, page);
+        }
+
+        if item_n == summary {
+            let label = pic_str!(b"Warning");
+            title[..label.len()].copy_from_slice(label);
+            let content = pic_str!(b"No replay protection");
+            return handle_ui_message(content, message, page);
+        }
+
+        self.base.render_item(item_n - 1, title, message, page)
     }
 }
 
@@ -139,7 +187,151 @@ mod tests {
         let data = hex::decode(data).unwrap();
 
         let (_, bytes) = parse_rlp_item(&data).unwrap();
-        let (_, _tx) = Legacy::from_bytes(bytes).unwrap();
+        let (_, tx) = Legacy::from_bytes(bytes).unwrap();
+
+        // 43114
+        assert_eq!(tx.chain_id(), &[0xa8, 0x6a]);
+        assert!(tx.replay_protected());
+        assert_eq!(tx.num_items(), tx.base.num_items());
+    }
+
+    #[test]
+    fn gas_price_page() {
+        let data = "ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080";
+        let data = hex::decode(data).unwrap();
+
+        let (_, bytes) = parse_rlp_item(&data).unwrap();
+        let (_, tx) = Legacy::from_bytes(bytes).unwrap();
+
+        // only one price for legacy transactions, right after
+        // the fee and out of expert mode too
+        let summary = tx.base.num_items_in(false) as u8;
+        let mut title = [0; 32];
+        let mut message = [0; 64];
+        for item_n in 0..tx.num_items() as u8 {
+            title.iter_mut().for_each(|v| *v = 0);
+            message.iter_mut().for_each(|v| *v = 0);
+            tx.render_item(item_n, &mut title, &mut message, 0).unwrap();
+
+            if item_n == summary - 1 {
+                assert_eq!(&title[..10], b"Gas price\0");
+                assert_eq!(&message[..3], b"21\0");
+            } else {
+                assert_ne!(&title[..9], b"Gas price");
+            }
+        }
+    }
+
+    #[test]
+    fn expert_pages() {
+        let data = "ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080";
+        let data = hex::decode(data).unwrap();
+
+        let (_, bytes) = parse_rlp_item(&data).unwrap();
+        let (_, tx) = Legacy::from_bytes(bytes).unwrap();
+
+        // simple mode only shows the transfer, recipient, fee and gas price
+        let simple = tx.base.num_items_in(false);
+        let expert = tx.base.num_items_in(true);
+        assert_eq!(simple, 4);
+        assert_eq!(expert, simple + 2);
+
+        let mut title = [0; 32];
+        let mut message = [0; 64];
+        tx.base
+            .render_item(simple as u8 + 1, &mut title, &mut message, 0)
+            .unwrap();
+        assert_eq!(&title[..6], b"Nonce\0");
+        assert_eq!(&message[..2], b"1\0");
+    }
+
+    #[test]
+    fn transfer_value_pages() {
+        let render = |data: &str| {
+            let data = hex::decode(data).unwrap();
+            let (_, bytes) = parse_rlp_item(&data).unwrap();
+            let (_, tx) = Legacy::from_bytes(bytes).unwrap();
+
+            let mut amount = [0; 100];
+            let mut wei = [0; 100];
+            let mut title = [0; 32];
+            tx.render_item(0, &mut title, &mut amount, 0).unwrap();
+            assert_eq!(&title[..9], b"Transfer\0");
+
+            let wei_item = tx.base.num_items_in(false) as u8;
+            tx.render_item(wei_item, &mut title, &mut wei, 0).unwrap();
+            assert_eq!(&title[..11], b"Value(wei)\0");
+            (amount, wei)
+        };
+
+        // 1 wei
+        let (amount, wei) = render(
+            "e6018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952018082a86a8080",
+        );
+        assert_eq!(&amount[..26], b"AVAX 0.000000000000000001\0");
+        assert_eq!(&wei[..2], b"1\0");
+
+        // 1 AVAX
+        let (amount, wei) = render("ee018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952880de0b6b3a76400008082a86a8080");
+        assert_eq!(&amount[..7], b"AVAX 1\0");
+        assert_eq!(&wei[..20], b"1000000000000000000\0");
+
+        // u256::MAX
+        let (amount, wei) = render("f846018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952a0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff8082a86a8080");
+        let expected_amount =
+            b"AVAX 115792089237316195423570985008687907853269984665640564039457.584007913129639935\0";
+        let expected_wei =
+            b"115792089237316195423570985008687907853269984665640564039457584007913129639935\0";
+        assert_eq!(&amount[..expected_amount.len()], &expected_amount[..]);
+        assert_eq!(&wei[..expected_wei.len()], &expected_wei[..]);
+    }
+
+    // same as above but without the chain_id, r and s fields
+    const PRE_EIP155: &str =
+        "e8018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a2487400080";
+
+    #[test]
+    fn reject_pre_eip155_tx() {
+        let data = hex::decode(PRE_EIP155).unwrap();
+
+        let (_, bytes) = parse_rlp_item(&data).unwrap();
+        let err = Legacy::from_bytes(bytes).unwrap_err();
+
+        assert_eq!(err, nom::Err::Error(ParserError::MissingChainId));
+    }
+
+    #[test]
+    fn pre_eip155_warning() {
+        let data = hex::decode(PRE_EIP155).unwrap();
+
+        // the toggle is off in tests, so build the tx by hand
+        let (_, bytes) = parse_rlp_item(&data).unwrap();
+        let (_, base) = BaseLegacy::from_bytes(bytes).unwrap();
+        let tx = Legacy {
+            base,
+            chain_id: &[],
+        };
+
+        assert!(!tx.replay_protected());
+        assert_eq!(tx.num_items(), base.num_items() + 1);
+
+        // shown after the summary, before the expert pages
+        let summary = base.num_items_in(false) as u8;
+        let mut title = [0; 32];
+        let mut message = [0; 64];
+        tx.render_item(summary - 2, &mut title, &mut message, 0)
+            .unwrap();
+        assert_eq!(&title[..10], b"Fee(GWEI)\0");
+
+        tx.render_item(summary - 1, &mut title, &mut message, 0)
+            .unwrap();
+        assert_eq!(&title[..10], b"Gas price\0");
+
+        tx.render_item(summary, &mut title, &mut message, 0)
+            .unwrap();
+        let expected = b"No replay protection";
+        assert_eq!(&title[..7], b"Warning");
+        assert_eq!(&message[..expected.len()], &expected[..]);
     }
 
     #[test]
@@ -23,8 +23,8 @@ use zemu_sys::ViewError;
 use crate::{
     handlers::handle_ui_message,
     parser::{
-        error::ParserError, nano_avax_to_fp_str, Address, AssetId, DisplayableItem, FromBytes,
-        Output, OutputType, SECPTransferOutput,
+        error::ParserError, Address, AssetId, DisplayableItem, FromBytes, Output, OutputType,
+        SECPTransferOutput, MAX_ASSET_AMOUNT_LEN, MAX_ASSET_LABEL_LEN,
     },
 };
 
@@ -161,20 +161,34 @@ impl<'b> DisplayableItem for EVMOutput<'b> {
         page: u8,
     ) -> Result<u8, ViewError> {
         use bolos::{pic_str, PIC};
-        use lexical_core::Number;
-
-        let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 2];
 
         match item_n as usize {
             0 => {
                 let title_content = pic_str!(b"Amount");
                 title[..title_content.len()].copy_from_slice(title_content);
-                let buffer = nano_avax_to_fp_str(self.amount, &mut buffer)
+
+                let mut amount_buf = [0; MAX_ASSET_AMOUNT_LEN];
+                let amount = self
+                    .asset_id
+                    .format_amount(self.amount, &mut amount_buf[..])
                     .map_err(|_| ViewError::Unknown)?;
 
-                handle_ui_message(buffer, message, page)
+                let mut buffer = [0; MAX_ASSET_AMOUNT_LEN + 1 + MAX_ASSET_LABEL_LEN];
+                let mut len = amount.len();
+                buffer[..len].copy_from_slice(amount);
+                buffer[len] = b' ';
+                len += 1;
+                len += self.asset_id.write_label(&mut buffer[len..])?;
+
+                handle_ui_message(&buffer[..len], message, page)
+            }
+            // the account credited on the C-chain
+            1 => {
+                let title_content = pic_str!(b"Address");
+                title[..title_content.len()].copy_from_slice(title_content);
+
+                self.address.render_eth_address(message, page)
             }
-            1 => self.address.render_item(0, title, message, page),
             _ => Err(ViewError::NoData),
         }
     }
@@ -0,0 +1,208 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use core::{mem::MaybeUninit, ptr::addr_of_mut};
+use nom::{
+    bytes::complete::take,
+    number::complete::{be_u32, be_u8},
+};
+
+use crate::{
+    handlers::resources::ERC20_INFO,
+    parser::{
+        Address, FromBytes, OwnedAddress, ParserError, ADDRESS_LEN, ERC20_INFO_SLOTS,
+        TICKER_MAX_LEN,
+    },
+};
+
+/// ERC20 token information, as listed in the crypto asset list
+///
+/// The signature that follows it is verified by the handler
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+#[cfg_attr(test, derive(Debug))]
+pub struct TokenInfo {
+    contract_address: OwnedAddress,
+    ticker: [u8; TICKER_MAX_LEN],
+    ticker_len: u8,
+    pub decimals: u32,
+    pub chain_id: u32,
+}
+
+impl TokenInfo {
+    pub fn address(&self) -> Address<'_> {
+        self.contract_address.address()
+    }
+
+    pub fn ticker(&self) -> &[u8] {
+        &self.ticker[..self.ticker_len as usize]
+    }
+}
+
+impl<'b> FromBytes<'b> for TokenInfo {
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("TokenInfo::from_bytes_into\x00");
+
+        let (rem, ticker_len) = be_u8(input)?;
+        let ticker_len = ticker_len as usize;
+        if ticker_len > TICKER_MAX_LEN {
+            return Err(ParserError::ValueOutOfRange.into());
+        }
+
+        let (rem, ticker) = take(ticker_len)(rem)?;
+        if !ticker.is_ascii() {
+            return Err(ParserError::InvalidAsciiValue.into());
+        }
+
+        let (rem, address) = take(ADDRESS_LEN)(rem)?;
+        let (rem, decimals) = be_u32(rem)?;
+        let (rem, chain_id) = be_u32(rem)?;
+
+        let out = out.as_mut_ptr();
+        let owned = unsafe { &mut *addr_of_mut!((*out).contract_address).cast() };
+        _ = OwnedAddress::from_bytes_into(address, owned)?;
+
+        unsafe {
+            let ticker_out = &mut *addr_of_mut!((*out).ticker);
+            ticker_out[..ticker.len()].copy_from_slice(ticker);
+
+            addr_of_mut!((*out).ticker_len).write(ticker_len as u8);
+            addr_of_mut!((*out).decimals).write(decimals);
+            addr_of_mut!((*out).chain_id).write(chain_id);
+        }
+
+        Ok(rem)
+    }
+}
+
+/// The ERC20 tokens provided by the host, up to `ERC20_INFO_SLOTS`
+///
+/// Once full, the token that was stored first is evicted
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct TokenTable {
+    slots: [Option<TokenInfo>; ERC20_INFO_SLOTS],
+    // the slot to evict next
+    oldest: usize,
+}
+
+impl TokenTable {
+    pub const fn new() -> Self {
+        Self {
+            slots: [None; ERC20_INFO_SLOTS],
+            oldest: 0,
+        }
+    }
+
+    /// Stores `info`, replacing the token of the same contract if present
+    pub fn insert(&mut self, info: TokenInfo) {
+        let at = self
+            .slots
+            .iter()
+            .position(|s| matches!(s, Some(t) if t.address() == info.address()))
+            .or_else(|| self.slots.iter().position(Option::is_none))
+            .unwrap_or_else(|| {
+                let at = self.oldest;
+                self.oldest = (at + 1) % ERC20_INFO_SLOTS;
+                at
+            });
+
+        self.slots[at] = Some(info);
+    }
+
+    pub fn find(&self, address: &Address<'_>) -> Option<&TokenInfo> {
+        self.slots
+            .iter()
+            .flatten()
+            .find(|t| t.address() == *address)
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.iter().flatten().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for TokenTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Gives the ERC20 parser access to the provided tokens
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct ERC20Info;
+
+impl ERC20Info {
+    /// Returns the provided information of the token at `address`
+    pub fn lookup(address: &Address<'_>) -> Option<&'static TokenInfo> {
+        match unsafe { ERC20_INFO.acquire(Self) } {
+            Ok(table) => table.find(address),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(address: u8) -> TokenInfo {
+        let mut data = [0; 1 + 3 + ADDRESS_LEN + 4 + 4];
+        data[0] = 3;
+        data[1..4].copy_from_slice(b"TKN");
+        data[4..4 + ADDRESS_LEN].fill(address);
+        data[4 + ADDRESS_LEN + 3] = 18;
+
+        TokenInfo::from_bytes(&data).unwrap().1
+    }
+
+    #[test]
+    fn evicts_oldest_token() {
+        let mut table = TokenTable::new();
+        for n in 0..ERC20_INFO_SLOTS as u8 {
+            table.insert(info(n));
+        }
+        assert_eq!(table.len(), ERC20_INFO_SLOTS);
+
+        // providing a stored token again does not evict any
+        table.insert(info(0));
+        assert_eq!(table.len(), ERC20_INFO_SLOTS);
+
+        let first = info(0);
+        let second = info(1);
+        table.insert(info(0xff));
+        assert!(table.find(&first.address()).is_none());
+        assert!(table.find(&second.address()).is_some());
+        assert!(table.find(&info(0xff).address()).is_some());
+
+        table.insert(info(0xfe));
+        assert!(table.find(&second.address()).is_none());
+
+        table.clear();
+        assert!(table.is_empty());
+    }
+}
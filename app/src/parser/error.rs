@@ -35,6 +35,7 @@ pub enum ParserError {
     InvalidAddress,
     InvalidPath,
     TooManyOutputs,
+    TooManyItems,
     InvalidAvaxMessage,
     UnexpectedData,
     InvalidEthMessage,
@@ -42,6 +43,20 @@ pub enum ParserError {
     InvalidAssetCall,
     NftInfoNotProvided,
     InvalidContractAddress,
+    MissingChainId,
+    UnsupportedTxType,
+    InvalidOutputOrder,
+    InvalidStakingPeriod,
+    // a field longer than its type allows
+    InvalidLength,
+    // an RLP integer with leading zeros or not minimally encoded
+    NonCanonicalInteger,
+    // a malformed EIP-7702 authorization tuple
+    InvalidAuthorization,
+    // an imported input with a zero tx id or spending a utxo twice
+    InvalidUtxoId,
+    // address indices repeated or not in increasing order
+    InvalidIndexOrder,
 }
 
 impl From<ErrorKind> for ParserError {
@@ -56,6 +71,19 @@ impl From<ErrorKind> for ParserError {
     }
 }
 
+// Parsing errors are reported with distinct status words, these are
+// the errors the host can act upon, any other is just invalid data
+impl From<ParserError> for crate::constants::ApduError {
+    fn from(err: ParserError) -> Self {
+        match err {
+            ParserError::UnexpectedBufferEnd => Self::DataTruncated,
+            ParserError::InvalidLength => Self::DataInvalidLength,
+            ParserError::UnsupportedTxType => Self::DataUnsupported,
+            _ => Self::DataInvalid,
+        }
+    }
+}
+
 impl<I> nom::error::ParseError<I> for ParserError {
     fn from_error_kind(_input: I, kind: ErrorKind) -> Self {
         Self::from(kind)
@@ -0,0 +1,172 @@
+/*******************************************************************************
+*   (c) 2021 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+//! Entry points to parse transactions off-device,
+//! useful to run the parser against a corpus of transactions.
+//!
+//! These still build against the off-device mocks of `bolos` and `zemu_sys`,
+//! which the parser uses for `PIC`, `pic_str!` and `ViewError`:
+//! enabling the `host` feature doesn't drop those dependencies.
+use core::mem::MaybeUninit;
+
+use zemu_sys::{ViewError, Viewable};
+
+#[cfg(test)]
+use crate::parser::DisplayableItem;
+use crate::parser::{EthTransaction, FromBytes, ParserError, Transaction};
+
+#[cfg(feature = "host")]
+pub use crate::handlers::eth::get_tx_rlp_len;
+
+/// Parses an EIP2718 or legacy ethereum transaction
+pub fn parse_eth_transaction(input: &[u8]) -> Result<EthTransaction<'_>, ParserError> {
+    let mut tx = MaybeUninit::uninit();
+    EthTransaction::from_bytes_into(input, &mut tx)?;
+
+    //safe: initialized
+    Ok(unsafe { tx.assume_init() })
+}
+
+/// Parses a X, P or C-chain atomic transaction,
+/// including the codec prefix
+pub fn parse_transaction(input: &[u8]) -> Result<Transaction<'_>, ParserError> {
+    let mut tx = MaybeUninit::uninit();
+    Transaction::new_into(input, &mut tx)?;
+
+    //safe: initialized
+    Ok(unsafe { tx.assume_init() })
+}
+
+/// Renders every page of every item of `ui` with a title of `T` bytes
+/// and a message of `M` bytes, panicking on the first mismatch between
+/// `num_items` and `render_item`: a page failing to render, a title
+/// or message not nul terminated within its buffer, or an item
+/// reporting a different number of pages from one page to the next.
+///
+/// Returns the total number of pages
+pub fn render_all<V: Viewable, const T: usize, const M: usize>(ui: &mut V) -> usize {
+    let num_items = ui
+        .num_items()
+        .unwrap_or_else(|_| panic!("unable to get the number of items"));
+
+    render_pages::<T, M>(
+        num_items,
+        |item, title, message, page| ui.render_item(item, title, message, page),
+        |_, _, _, _| {},
+    )
+}
+
+/// Renders every page of `item` as [`render_all`] does, returning
+/// the message of the first item titled `title`, with its pages joined
+#[cfg(test)]
+pub fn find_message<D: DisplayableItem, const T: usize, const M: usize>(
+    item: &D,
+    title: &str,
+) -> Option<std::string::String> {
+    use std::string::String;
+
+    let text = |bytes: &[u8]| {
+        let len = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+        String::from_utf8_lossy(&bytes[..len]).into_owned()
+    };
+
+    let mut found: Option<(u8, String)> = None;
+    render_pages::<T, M>(
+        item.num_items() as u8,
+        |item_n, t, m, page| item.render_item(item_n, t, m, page),
+        |item_n, page, t, m| match found {
+            Some((at, ref mut message)) if at == item_n => message.push_str(&text(m)),
+            None if page == 0 && text(t) == title => found = Some((item_n, text(m))),
+            _ => {}
+        },
+    );
+
+    found.map(|(_, message)| message)
+}
+
+fn render_pages<const T: usize, const M: usize>(
+    num_items: u8,
+    mut render: impl FnMut(u8, &mut [u8], &mut [u8], u8) -> Result<u8, ViewError>,
+    mut on_page: impl FnMut(u8, u8, &[u8; T], &[u8; M]),
+) -> usize {
+    let mut total = 0;
+    for item in 0..num_items {
+        let mut num_pages = None;
+        let mut page = 0;
+
+        while num_pages.map_or(true, |n| page < n) {
+            let mut title = [0; T];
+            let mut message = [0; M];
+
+            let pages = render(item, &mut title, &mut message, page)
+                .unwrap_or_else(|_| panic!("item {} failed to render page {}", item, page));
+
+            assert!(pages > 0, "item {} has no pages", item);
+            assert_eq!(
+                *num_pages.get_or_insert(pages),
+                pages,
+                "item {} changed its number of pages at page {}",
+                item,
+                page
+            );
+            assert!(
+                title.contains(&0) && message.contains(&0),
+                "item {} page {} is not nul terminated",
+                item,
+                page
+            );
+
+            on_page(item, page, &title, &message);
+            page += 1;
+        }
+
+        total += page as usize;
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use std::prelude::v1::*;
+
+    use super::*;
+
+    #[test]
+    fn host_parse_eth_transaction() {
+        let data = hex::decode("ed018504e3b292008252089428ee52a8f3d6e5d15f8b131996950d7f296c7952872bd72a248740008082a86a8080").unwrap();
+
+        let tx = parse_eth_transaction(&data).unwrap();
+        assert!(matches!(tx, EthTransaction::Legacy(..)));
+        assert!(tx.num_items() > 0);
+    }
+
+    #[test]
+    fn host_parse_transaction() {
+        let data: Vec<u8> =
+            serde_json::from_str(include_str!("testvectors/simple_transfer.json")).unwrap();
+
+        let tx = parse_transaction(&data).unwrap();
+        assert!(matches!(tx, Transaction::Transfer(..)));
+        assert!(tx.num_items() > 0);
+
+        let mut data = data;
+        data[1] = 1;
+        assert_eq!(
+            parse_transaction(&data).unwrap_err(),
+            ParserError::InvalidCodec
+        );
+    }
+}
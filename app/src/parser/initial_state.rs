@@ -15,8 +15,12 @@
 ********************************************************************************/
 use core::{convert::TryFrom, mem::MaybeUninit, ptr::addr_of_mut};
 use nom::number::complete::be_u32;
+use zemu_sys::ViewError;
 
-use crate::parser::{AvmOutput, FromBytes, ObjectList, ParserError};
+use crate::{
+    handlers::handle_ui_message,
+    parser::{u64_to_str, AvmOutput, DisplayableItem, FromBytes, ObjectList, ParserError},
+};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(test, derive(Debug))]
@@ -45,6 +49,16 @@ pub struct InitialState<'b> {
     outputs: ObjectList<'b, AvmOutput<'b>>,
 }
 
+impl<'b> InitialState<'b> {
+    /// Returns the number of addresses across
+    /// all the outputs of this state
+    pub fn num_holders(&self) -> usize {
+        let mut holders = 0;
+        self.outputs.iterate_with(|o| holders += o.num_addresses());
+        holders
+    }
+}
+
 impl<'b> FromBytes<'b> for InitialState<'b> {
     fn from_bytes_into(
         input: &'b [u8],
@@ -69,3 +83,41 @@ impl<'b> FromBytes<'b> for InitialState<'b> {
         Ok(rem)
     }
 }
+
+impl<'b> DisplayableItem for InitialState<'b> {
+    fn num_items(&self) -> usize {
+        1
+    }
+
+    fn render_item(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+        use lexical_core::Number;
+
+        if item_n != 0 {
+            return Err(ViewError::NoData);
+        }
+
+        let label = pic_str!(b"Initial holders");
+        title[..label.len()].copy_from_slice(label);
+
+        // "<holders> (<fx>)"
+        let fx = match self.id {
+            FxId::SECP256KAsset => pic_str!(b" (SECP256K1)"!),
+            FxId::NftAsset => pic_str!(b" (NFT)"!),
+        };
+
+        let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 12];
+        let len = u64_to_str(self.num_holders() as u64, &mut buffer[..])
+            .map_err(|_| ViewError::Unknown)?
+            .len();
+        buffer[len..len + fx.len()].copy_from_slice(fx);
+
+        handle_ui_message(&buffer[..len + fx.len()], message, page)
+    }
+}
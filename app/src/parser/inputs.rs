@@ -19,26 +19,31 @@ pub use secp_transfer_input::SECPTransferInput;
 
 use core::{mem::MaybeUninit, ptr::addr_of_mut};
 use nom::{
-    bytes::complete::{tag, take},
+    bytes::complete::tag,
     number::complete::{be_u32, be_u64},
-    sequence::tuple,
 };
 use zemu_sys::ViewError;
 
 use crate::{
-    handlers::handle_ui_message,
-    parser::{error::ParserError, AssetId, DisplayableItem, FromBytes},
-    utils::hex_encode,
+    handlers::{handle_ui_message, handle_ui_message_hex},
+    parser::{
+        cb58_output_len, error::ParserError, u32_to_str, u64_to_str, AssetId, DisplayableItem,
+        FromBytes, ObjectList, UtxoId, MAX_ASSET_AMOUNT_LEN, MAX_ASSET_LABEL_LEN, U32_SIZE,
+    },
+    utils::cb58_encode,
 };
 
-const TX_ID_LEN: usize = 32;
+use super::utxo_id::TX_ID_LEN;
+
+// address indices listed in the utxo page of an input,
+// any other index is elided
+const MAX_UTXO_INDICES: usize = 8;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(C)]
 #[cfg_attr(test, derive(Debug))]
 pub struct TransferableInput<'b> {
-    tx_id: &'b [u8; TX_ID_LEN],
-    utxo_index: u32,
+    utxo_id: UtxoId<'b>,
     asset_id: AssetId<'b>,
     // it is set if this TransferableInput
     // contains a stakeable_locked input.
@@ -64,6 +69,101 @@ impl<'b> TransferableInput<'b> {
     pub fn locktime(&self) -> Option<u64> {
         self.locktime
     }
+
+    pub fn tx_id(&self) -> &'b [u8; TX_ID_LEN] {
+        self.utxo_id.tx_id()
+    }
+
+    pub fn utxo_index(&self) -> u32 {
+        self.utxo_id.out_idx()
+    }
+
+    pub fn asset_id(&self) -> &AssetId<'b> {
+        &self.asset_id
+    }
+
+    pub fn address_indices(&self) -> &'b [[u8; U32_SIZE]] {
+        self.input.address_indices()
+    }
+
+    // Checks the utxos referenced by imported inputs, these can not
+    // be looked up on the source chain, so only their structure:
+    // the tx id is a hash, thus never zero, and no utxo is spent twice
+    pub fn check_imported(inputs: &ObjectList<'b, Self>) -> Result<(), ParserError> {
+        for (i, input) in inputs.iter().enumerate() {
+            if input.tx_id() == &[0; TX_ID_LEN] {
+                return Err(ParserError::InvalidUtxoId);
+            }
+
+            let spent_twice = inputs
+                .iter()
+                .skip(i + 1)
+                .any(|other| other.utxo_id == input.utxo_id);
+            if spent_twice {
+                return Err(ParserError::InvalidUtxoId);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Renders the utxo spent by this input in one page as
+    // "<tx id>:<output index>, <amount> <asset>, indices <i>,<j>"
+    #[inline(never)]
+    pub fn render_utxo(&self, message: &mut [u8], page: u8) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+        use lexical_core::Number;
+
+        let mut label = [0; MAX_ASSET_LABEL_LEN];
+        let label_len = self.asset_id.write_label(&mut label[..])?;
+
+        let mut buffer = [0; cb58_output_len::<TX_ID_LEN>()
+            + MAX_ASSET_LABEL_LEN
+            + (u32::FORMATTED_SIZE_DECIMAL + 1) * (MAX_UTXO_INDICES + 1)
+            + MAX_ASSET_AMOUNT_LEN
+            + 32];
+        let mut len = cb58_encode(self.tx_id(), &mut buffer[..]).map_err(|_| ViewError::Unknown)?;
+
+        let mut append = |data: &[u8]| -> Result<(), ViewError> {
+            let dst = buffer
+                .get_mut(len..len + data.len())
+                .ok_or(ViewError::Unknown)?;
+            dst.copy_from_slice(data);
+            len += data.len();
+            Ok(())
+        };
+
+        let mut num = [0; MAX_ASSET_AMOUNT_LEN];
+
+        append(pic_str!(b":"!))?;
+        append(u32_to_str(self.utxo_index(), &mut num[..]).map_err(|_| ViewError::Unknown)?)?;
+
+        let amount = self.amount().ok_or(ViewError::NoData)?;
+        let amount = self
+            .asset_id
+            .format_amount(amount, &mut num[..])
+            .map_err(|_| ViewError::Unknown)?;
+
+        append(pic_str!(b", "!))?;
+        append(amount)?;
+        append(pic_str!(b" "!))?;
+        append(&label[..label_len])?;
+        append(pic_str!(b", indices "!))?;
+
+        let indices = self.address_indices();
+        for (i, index) in indices.iter().take(MAX_UTXO_INDICES).enumerate() {
+            if i > 0 {
+                append(pic_str!(b","!))?;
+            }
+            let index = u32::from_be_bytes(*index);
+            append(u32_to_str(index, &mut num[..]).map_err(|_| ViewError::Unknown)?)?;
+        }
+        if indices.len() > MAX_UTXO_INDICES {
+            append(pic_str!(b",..."!))?;
+        }
+
+        handle_ui_message(&buffer[..len], message, page)
+    }
 }
 
 impl<'b> FromBytes<'b> for TransferableInput<'b> {
@@ -74,11 +174,13 @@ impl<'b> FromBytes<'b> for TransferableInput<'b> {
     ) -> Result<&'b [u8], nom::Err<ParserError>> {
         crate::sys::zemu_log_stack("TransferableInput::from_bytes_into\x00");
 
-        let (rem, (tx_id, utxo_id)) = tuple((take(TX_ID_LEN), be_u32))(bytes)?;
-        let tx_id = arrayref::array_ref!(tx_id, 0, TX_ID_LEN);
+        let input = input.as_mut_ptr() as *mut TransferableInput;
+
+        // the utxo being spent
+        let utxo_id = unsafe { &mut *addr_of_mut!((*input).utxo_id).cast() };
+        let rem = UtxoId::from_bytes_into(bytes, utxo_id)?;
 
         // asset_id
-        let input = input.as_mut_ptr() as *mut TransferableInput;
         let asset = unsafe { &mut *addr_of_mut!((*input).asset_id).cast() };
         let mut rem = AssetId::from_bytes_into(rem, asset)?;
 
@@ -96,8 +198,6 @@ impl<'b> FromBytes<'b> for TransferableInput<'b> {
 
         //good ptr and no uninit reads
         unsafe {
-            addr_of_mut!((*input).tx_id).write(tx_id);
-            addr_of_mut!((*input).utxo_index).write(utxo_id);
             addr_of_mut!((*input).locktime).write(locktime);
         }
         Ok(rem)
@@ -128,16 +228,14 @@ impl<'b> DisplayableItem for TransferableInput<'b> {
             0 => {
                 let title_content = pic_str!(b"TransactionID");
                 title[..title_content.len()].copy_from_slice(title_content);
-                let sha = Sha256::digest(self.tx_id).map_err(|_| ViewError::Unknown)?;
-                let mut hex_buf = [0; Sha256::DIGEST_LEN * 2];
-                hex_encode(&sha[..], &mut hex_buf).map_err(|_| ViewError::Unknown)?;
+                let sha = Sha256::digest(self.tx_id()).map_err(|_| ViewError::Unknown)?;
 
-                handle_ui_message(&hex_buf, message, page)
+                handle_ui_message_hex(&[], &sha[..], message, page)
             }
             1 => {
                 let title_content = pic_str!(b"Utxo index");
                 title[..title_content.len()].copy_from_slice(title_content);
-                let buffer = itoa(self.utxo_index, &mut buffer);
+                let buffer = itoa(self.utxo_index(), &mut buffer);
 
                 handle_ui_message(buffer, message, page)
             }
@@ -153,6 +251,70 @@ impl<'b> DisplayableItem for TransferableInput<'b> {
     }
 }
 
+/// The utxos a transaction spends: its inputs and,
+/// for imports, the inputs from the source chain.
+///
+/// Shown in expert mode, one page per input
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct UtxoList<'b> {
+    inputs: ObjectList<'b, TransferableInput<'b>>,
+    imported: Option<ObjectList<'b, TransferableInput<'b>>>,
+}
+
+impl<'b> UtxoList<'b> {
+    pub fn new(inputs: ObjectList<'b, TransferableInput<'b>>) -> Self {
+        Self {
+            inputs,
+            imported: None,
+        }
+    }
+
+    pub fn with_imported(mut self, imported: ObjectList<'b, TransferableInput<'b>>) -> Self {
+        self.imported = Some(imported);
+        self
+    }
+
+    fn get(&self, idx: usize) -> Option<TransferableInput<'b>> {
+        let local = self.inputs.iter().count();
+        if idx < local {
+            return self.inputs.iter().nth(idx);
+        }
+        self.imported?.iter().nth(idx - local)
+    }
+}
+
+impl<'b> DisplayableItem for UtxoList<'b> {
+    fn num_items(&self) -> usize {
+        let imported = self.imported.map(|i| i.iter().count()).unwrap_or_default();
+        self.inputs.iter().count() + imported
+    }
+
+    #[inline(never)]
+    fn render_item(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+        use lexical_core::Number;
+
+        let input = self.get(item_n as usize).ok_or(ViewError::NoData)?;
+
+        // Input N, counting from 1
+        let label = pic_str!(b"Input "!);
+        title[..label.len()].copy_from_slice(label);
+
+        let mut num = [0; u64::FORMATTED_SIZE_DECIMAL];
+        let num = u64_to_str(item_n as u64 + 1, &mut num[..]).map_err(|_| ViewError::Unknown)?;
+        title[label.len()..label.len() + num.len()].copy_from_slice(num);
+
+        input.render_utxo(message, page)
+    }
+}
+
 // Important: do not change the repr attribute,
 // as this type is use as the tag field
 // for the Input enum which has the same representation
@@ -200,6 +362,11 @@ impl<'b> Input<'b> {
         let Self::SECPTransfer(input) = self;
         Some(input.amount)
     }
+
+    pub fn address_indices(&self) -> &'b [[u8; U32_SIZE]] {
+        let Self::SECPTransfer(input) = self;
+        input.address_indices
+    }
 }
 
 impl<'b> FromBytes<'b> for Input<'b> {
@@ -255,6 +422,7 @@ impl<'a> DisplayableItem for Input<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::prelude::v1::*;
 
     // TransferableInput { tx_id: [7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7],
     // utxo_index: 2,
@@ -280,17 +448,42 @@ mod tests {
     #[test]
     fn parse_transferable_input() {
         let t = TransferableInput::from_bytes(DATA).unwrap().1;
-        assert_eq!(t.tx_id, &[7; TX_ID_LEN]);
-        assert_eq!(t.utxo_index, 2);
+        assert_eq!(t.tx_id(), &[7; TX_ID_LEN]);
+        assert_eq!(t.utxo_index(), 2);
         assert!(matches!(t.input, Input::SECPTransfer(..)));
     }
 
     #[test]
     fn parse_transferable_locked_input() {
         let t = TransferableInput::from_bytes(LOCKED_DATA).unwrap().1;
-        assert_eq!(t.tx_id, &[7; TX_ID_LEN]);
-        assert_eq!(t.utxo_index, 2);
+        assert_eq!(t.tx_id(), &[7; TX_ID_LEN]);
+        assert_eq!(t.utxo_index(), 2);
         assert!(matches!(t.input, Input::SECPTransfer(..)));
         assert_eq!(t.locktime.unwrap(), 8);
     }
+
+    #[test]
+    fn malformed_imported_utxos() {
+        // tx id cut short
+        let err = TransferableInput::from_bytes(&DATA[..TX_ID_LEN - 4]).unwrap_err();
+        assert_eq!(err, nom::Err::Error(ParserError::UnexpectedBufferEnd));
+
+        let check = |inputs: &[&[u8]]| {
+            let mut data = (inputs.len() as u32).to_be_bytes().to_vec();
+            inputs.iter().for_each(|i| data.extend_from_slice(i));
+            let (_, list) = ObjectList::<TransferableInput>::new(&data).unwrap();
+            TransferableInput::check_imported(&list)
+        };
+
+        let mut other = DATA.to_vec();
+        other[TX_ID_LEN + 3] = 3;
+        assert_eq!(check(&[DATA, &other]), Ok(()));
+
+        // the same utxo twice
+        assert_eq!(check(&[DATA, DATA]), Err(ParserError::InvalidUtxoId));
+
+        let mut zero = DATA.to_vec();
+        zero[..TX_ID_LEN].fill(0);
+        assert_eq!(check(&[&zero]), Err(ParserError::InvalidUtxoId));
+    }
 }
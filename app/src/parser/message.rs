@@ -22,7 +22,7 @@ use nom::{
 use zemu_sys::ViewError;
 
 use crate::{
-    handlers::handle_ui_message,
+    handlers::{handle_ui_message, handle_ui_message_hex},
     parser::{error::ParserError, DisplayableItem, FromBytes},
     utils::ApduPanic,
 };
@@ -45,41 +45,45 @@ impl<'b> Message<'b> {
     }
 
     fn render_msg(&self, message: &mut [u8], page: u8) -> Result<u8, ViewError> {
-        let suffix = pic_str!(b"...");
-        // message plus suffix and
-        let mut render_msg = [0u8; MAX_ASCII_LEN + 4]; // plus suffix
-
-        let msg = self.msg();
-
-        // look for special characters [\b..=\r]
-        // which the eth app maps to a space b' '
-        let msg_iter = msg.iter().map(|c| {
-            if (*c >= 0x08) && (*c <= b'\r') {
-                b' '
-            } else {
-                *c
-            }
-        });
+        render_ascii(self.msg(), message, page)
+    }
+}
 
-        let mut copy_len = if msg.len() > MAX_ASCII_LEN {
-            render_msg[MAX_ASCII_LEN..].copy_from_slice(&suffix[..]);
-            MAX_ASCII_LEN
+// renders an ascii message the way the eth app does,
+// truncated to `MAX_ASCII_LEN`
+fn render_ascii(msg: &[u8], message: &mut [u8], page: u8) -> Result<u8, ViewError> {
+    let suffix = pic_str!(b"...");
+    // message plus suffix and
+    let mut render_msg = [0u8; MAX_ASCII_LEN + 4]; // plus suffix
+
+    // look for special characters [\b..=\r]
+    // which the eth app maps to a space b' '
+    let msg_iter = msg.iter().map(|c| {
+        if (*c >= 0x08) && (*c <= b'\r') {
+            b' '
         } else {
-            msg.len()
-        };
-
-        render_msg
-            .iter_mut()
-            .take(copy_len)
-            .zip(msg_iter)
-            .for_each(|(r, m)| *r = m);
-
-        if copy_len >= MAX_ASCII_LEN {
-            copy_len += suffix.len()
+            *c
         }
-
-        handle_ui_message(&render_msg[..copy_len], message, page)
+    });
+
+    let mut copy_len = if msg.len() > MAX_ASCII_LEN {
+        render_msg[MAX_ASCII_LEN..].copy_from_slice(&suffix[..]);
+        MAX_ASCII_LEN
+    } else {
+        msg.len()
+    };
+
+    render_msg
+        .iter_mut()
+        .take(copy_len)
+        .zip(msg_iter)
+        .for_each(|(r, m)| *r = m);
+
+    if copy_len >= MAX_ASCII_LEN {
+        copy_len += suffix.len()
     }
+
+    handle_ui_message(&render_msg[..copy_len], message, page)
 }
 
 impl<'b> FromBytes<'b> for Message<'b> {
@@ -132,11 +136,13 @@ impl<'b> DisplayableItem for Message<'b> {
     }
 }
 
+/// An Avalanche signed message, hashed with SHA-256 as
+/// `"\x1AAvalanche Signed Message:\n" || len || message`
 #[derive(Clone, Copy, PartialEq)]
 #[repr(C)]
 #[cfg_attr(test, derive(Debug))]
 pub struct AvaxMessage<'b> {
-    data: Message<'b>,
+    msg: &'b [u8],
 }
 
 impl<'b> AvaxMessage<'b> {
@@ -146,8 +152,18 @@ impl<'b> AvaxMessage<'b> {
         Ok(unsafe { this.assume_init() })
     }
 
-    pub fn msg(&self) -> &[u8] {
-        &self.data.msg()
+    pub fn msg(&self) -> &'b [u8] {
+        self.msg
+    }
+
+    // ascii messages are shown as text, as the device
+    // has no glyphs for other characters, anything else as hex
+    fn render_msg(&self, message: &mut [u8], page: u8) -> Result<u8, ViewError> {
+        if self.msg.is_ascii() {
+            render_ascii(self.msg, message, page)
+        } else {
+            handle_ui_message_hex(pic_str!(b"0x"!), self.msg, message, page)
+        }
     }
 }
 
@@ -165,10 +181,16 @@ impl<'b> FromBytes<'b> for AvaxMessage<'b> {
         let (rem, _) = tag(header)(input)?;
 
         // read message len
-        let out = out.as_mut_ptr();
+        let (rem, len) = be_u32(rem)?;
+        if len == 0 {
+            return Err(ParserError::InvalidAvaxMessage.into());
+        }
+        let (rem, msg) = take(len as usize)(rem)?;
 
-        let msg = unsafe { &mut *addr_of_mut!((*out).data).cast() };
-        let rem = Message::from_bytes_into(rem, msg)?;
+        let out = out.as_mut_ptr();
+        unsafe {
+            addr_of_mut!((*out).msg).write(msg);
+        }
 
         Ok(rem)
     }
@@ -177,7 +199,7 @@ impl<'b> FromBytes<'b> for AvaxMessage<'b> {
 impl<'b> DisplayableItem for AvaxMessage<'b> {
     fn num_items(&self) -> usize {
         // Description + message
-        1 + self.data.num_items()
+        2
     }
 
     fn render_item(
@@ -194,10 +216,12 @@ impl<'b> DisplayableItem for AvaxMessage<'b> {
                 let content = pic_str!("Avax Message");
                 handle_ui_message(content.as_bytes(), message, page)
             }
-            x @ 1.. => {
-                let idx = x - 1;
-                self.data.render_item(idx, title, message, page)
+            1 => {
+                let label = pic_str!(b"Message");
+                title[..label.len()].copy_from_slice(label);
+                self.render_msg(message, page)
             }
+            _ => Err(ViewError::NoData),
         }
     }
 }
@@ -209,20 +233,57 @@ mod tests {
     const DATA: &str = "An AvalancheMessage to sign";
     const HEADER: &str = "\x1AAvalanche Signed Message:\n";
 
-    fn construct_msg(msg: &str) -> std::vec::Vec<u8> {
+    fn construct_msg(msg: &[u8]) -> std::vec::Vec<u8> {
         let mut vec = std::vec![];
-        let msg_len = (DATA.len() as u32).to_be_bytes();
+        let msg_len = (msg.len() as u32).to_be_bytes();
         vec.extend_from_slice(HEADER.as_bytes());
         vec.extend_from_slice(&msg_len[..]);
-        vec.extend_from_slice(msg.as_bytes());
+        vec.extend_from_slice(msg);
         vec
     }
 
+    fn render_msg(msg: &AvaxMessage) -> std::string::String {
+        let mut title = [0; 32];
+        let mut message = [0; 128];
+        msg.render_item(1, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..8], b"Message\0");
+
+        let len = message.iter().position(|b| *b == 0).unwrap();
+        std::string::String::from_utf8(message[..len].to_vec()).unwrap()
+    }
+
     #[test]
     fn parse_avax_msg() {
-        let msg = construct_msg(DATA);
+        let msg = construct_msg(DATA.as_bytes());
         let (_, tx) = AvaxMessage::from_bytes(&msg).unwrap();
         let m = std::str::from_utf8(tx.msg()).unwrap();
         assert_eq!(m, DATA);
+        assert_eq!(render_msg(&tx), DATA);
+    }
+
+    #[test]
+    fn binary_avax_msg() {
+        let msg = construct_msg(&[0xde, 0xad, 0xbe, 0xef]);
+        let (_, tx) = AvaxMessage::from_bytes(&msg).unwrap();
+        assert_eq!(render_msg(&tx), "0xdeadbeef");
+    }
+
+    #[test]
+    fn malformed_avax_msg() {
+        // nothing to sign
+        let empty = construct_msg(&[]);
+        assert_eq!(
+            AvaxMessage::new(&empty),
+            Err(ParserError::InvalidAvaxMessage)
+        );
+
+        // shorter than declared
+        let msg = construct_msg(DATA.as_bytes());
+        assert!(AvaxMessage::new(&msg[..msg.len() - 1]).is_err());
+
+        // personal_sign header
+        let mut eth = msg.clone();
+        eth[0] = 0x19;
+        assert!(AvaxMessage::new(&eth).is_err());
     }
 }
@@ -40,6 +40,24 @@ impl NetworkId {
             Self::Local => PIC::new(HRP_LOCAL).into_inner(),
         }
     }
+
+    pub fn name(&self) -> &'static [u8] {
+        use bolos::{pic_str, PIC};
+
+        match self {
+            Self::Mainnet => pic_str!(b"Mainnet"!),
+            Self::Fuji => pic_str!(b"Fuji"!),
+            Self::Local => pic_str!(b"Local"!),
+        }
+    }
+
+    /// The shortest staking period allowed on the primary network
+    pub fn min_stake_duration(&self) -> i64 {
+        match self {
+            Self::Mainnet => MIN_STAKE_DURATION_MAINNET,
+            Self::Fuji | Self::Local => MIN_STAKE_DURATION_TESTNET,
+        }
+    }
 }
 
 impl TryFrom<u32> for NetworkId {
@@ -81,6 +99,40 @@ impl TryFrom<&[u8; BLOCKCHAIN_ID_LEN]> for ChainId {
     }
 }
 
+/// The name of the primary network chain `blockchain_id` is on `network_id`,
+/// `None` for the chains of other subnets, which are shown in CB58
+pub fn blockchain_id_name(
+    network_id: NetworkId,
+    blockchain_id: &[u8; BLOCKCHAIN_ID_LEN],
+) -> Option<&'static str> {
+    use bolos::{pic_str, PIC};
+
+    if blockchain_id == PIC::new(&P_CHAIN_ID).into_inner() {
+        return Some(pic_str!("P-Chain"));
+    }
+
+    let (x_chain, c_chain) = match network_id {
+        NetworkId::Mainnet => (
+            PIC::new(&X_CHAIN_ID_MAINNET).into_inner(),
+            PIC::new(&C_CHAIN_ID_MAINNET).into_inner(),
+        ),
+        NetworkId::Fuji => (
+            PIC::new(&X_CHAIN_ID_FUJI).into_inner(),
+            PIC::new(&C_CHAIN_ID_FUJI).into_inner(),
+        ),
+        // the chains of local networks are not fixed
+        NetworkId::Local => return None,
+    };
+
+    if blockchain_id == x_chain {
+        Some(pic_str!("X-Chain"))
+    } else if blockchain_id == c_chain {
+        Some(pic_str!("C-Chain"))
+    } else {
+        None
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(test, derive(Debug))]
 pub struct NetworkInfo {
@@ -100,3 +152,33 @@ impl TryFrom<(u32, &[u8; BLOCKCHAIN_ID_LEN])> for NetworkInfo {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn primary_network_names() {
+        let names = [
+            (NetworkId::Mainnet, &P_CHAIN_ID, Some("P-Chain")),
+            (NetworkId::Mainnet, &X_CHAIN_ID_MAINNET, Some("X-Chain")),
+            (NetworkId::Mainnet, &C_CHAIN_ID_MAINNET, Some("C-Chain")),
+            (NetworkId::Fuji, &X_CHAIN_ID_FUJI, Some("X-Chain")),
+            (NetworkId::Fuji, &C_CHAIN_ID_FUJI, Some("C-Chain")),
+            // the chains of another network
+            (NetworkId::Mainnet, &C_CHAIN_ID_FUJI, None),
+            (NetworkId::Fuji, &X_CHAIN_ID_MAINNET, None),
+        ];
+
+        for (network, chain, name) in names {
+            assert_eq!(blockchain_id_name(network, chain), name);
+        }
+    }
+
+    #[test]
+    fn unknown_subnet_chain() {
+        let subnet_chain = [0x42; BLOCKCHAIN_ID_LEN];
+        assert_eq!(blockchain_id_name(NetworkId::Mainnet, &subnet_chain), None);
+        assert_eq!(blockchain_id_name(NetworkId::Fuji, &subnet_chain), None);
+    }
+}
@@ -129,9 +129,9 @@ impl<'b> Operation<'b> {
         use bolos::{pic_str, PIC};
 
         match self {
-            Operation::SECPMint(_) => pic_str!("SECPMintOperation"),
+            Operation::SECPMint(_) => pic_str!("Mint tokens"),
             Operation::NFTTransfer(_) => pic_str!("NFTTransferOperation"),
-            Operation::NFTMint(_) => pic_str!("NFTMintOperation"),
+            Operation::NFTMint(_) => pic_str!("Mint NFT"),
         }
     }
 }
@@ -249,8 +249,8 @@ mod tests {
         0x00, 0x00, 0x00, 0x05, // op:
         0x00, 0x00, 0x00, 0x0d, // number of address indices:
         0x00, 0x00, 0x00, 0x02, // address index 0:
-        0x00, 0x00, 0x00, 0x07, // address index 1:
-        0x00, 0x00, 0x00, 0x03, // groupID:
+        0x00, 0x00, 0x00, 0x03, // address index 1:
+        0x00, 0x00, 0x00, 0x07, // groupID:
         0x00, 0x00, 0x30, 0x39, // length of payload:
         0x00, 0x00, 0x00, 0x03, // payload:
         0x43, 0x11, 0x00, // locktime:
@@ -14,8 +14,11 @@
 *  limitations under the License.
 ********************************************************************************/
 use crate::{
-    parser::{DisplayableItem, FromBytes, NFTTransferOutput, ParserError, U32_SIZE},
-    utils::ApduPanic,
+    handlers::handle_ui_message,
+    parser::{
+        u32_to_str, u64_to_str, DisplayableItem, FromBytes, ObjectList, ParserError,
+        SECPOutputOwners, NFT_MAX_PAYLOAD_LEN, U32_SIZE,
+    },
 };
 
 use zemu_sys::ViewError;
@@ -24,25 +27,72 @@ use core::{mem::MaybeUninit, ptr::addr_of_mut};
 use nom::{
     bytes::complete::{tag, take},
     number::complete::be_u32,
+    sequence::tuple,
 };
 
+/// Owners of a minted NFT
+///
+/// The owners are serialized without their type id
+/// in the outputs of the operation
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+#[repr(transparent)]
+pub struct MintOwners<'b>(pub SECPOutputOwners<'b>);
+
+impl<'b> FromBytes<'b> for MintOwners<'b> {
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        let owners = unsafe { &mut *addr_of_mut!((*out.as_mut_ptr()).0).cast() };
+        SECPOutputOwners::into_without_type(input, owners)
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(test, derive(Debug))]
 #[repr(C)]
 pub struct NFTMintOperation<'b> {
     pub address_indices: &'b [[u8; U32_SIZE]],
-    // It turns out that the fields this operation contains
-    // are the same as the ones in the NFTTransferOutput
-    // type, which makes sense considering the notion
-    // of inheritance the avax design follows.
-    // This makes this, type very similar to the NFTTransferOperation, for
-    // which the documentations says that it "extends" an untyped
-    // nft_transfer_output.
-    nft_output: NFTTransferOutput<'b>,
+    pub group_id: u32,
+    pub payload: &'b [u8],
+    pub outputs: ObjectList<'b, MintOwners<'b>>,
 }
 
 impl<'b> NFTMintOperation<'b> {
     pub const TYPE_ID: u32 = 0x0c;
+
+    fn owners_items(&self) -> usize {
+        let mut items = 0;
+        self.outputs.iterate_with(|o| items += o.0.num_items());
+        items
+    }
+
+    fn render_owners(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        let mut count = 0usize;
+        let mut obj_item_n = 0;
+
+        // gets the output that contains item_n
+        // and its corresponding index
+        let filter = |o: &MintOwners<'b>| -> bool {
+            let n = o.0.num_items();
+            if (item_n as usize) < count + n {
+                obj_item_n = item_n as usize - count;
+                return true;
+            }
+            count += n;
+            false
+        };
+
+        let owners = self.outputs.get_obj_if(filter).ok_or(ViewError::NoData)?;
+        owners.0.render_item(obj_item_n as u8, title, message, page)
+    }
 }
 
 impl<'b> FromBytes<'b> for NFTMintOperation<'b> {
@@ -57,18 +107,29 @@ impl<'b> FromBytes<'b> for NFTMintOperation<'b> {
         let (rem, _) = tag(Self::TYPE_ID.to_be_bytes())(input)?;
 
         let (rem, num_indices) = be_u32(rem)?;
-        let (rem, indices) = take(num_indices as usize * U32_SIZE)(rem)?;
-        let indices = bytemuck::try_cast_slice(indices).apdu_unwrap();
+        let indices_len = (num_indices as usize)
+            .checked_mul(U32_SIZE)
+            .ok_or(ParserError::ValueOutOfRange)?;
+        let (rem, indices) = take(indices_len)(rem)?;
+        let indices =
+            bytemuck::try_cast_slice(indices).map_err(|_| ParserError::InvalidAddressLength)?;
+
+        let (rem, (group_id, payload_len)) = tuple((be_u32, be_u32))(rem)?;
+        if payload_len as usize > NFT_MAX_PAYLOAD_LEN {
+            return Err(ParserError::ValueOutOfRange.into());
+        }
+        let (rem, payload) = take(payload_len as usize)(rem)?;
 
         let out = out.as_mut_ptr();
 
-        let output = unsafe { &mut *addr_of_mut!((*out).nft_output).cast() };
-        // parse without type checking
-        let rem = NFTTransferOutput::into_without_type(rem, output)?;
+        let outputs = unsafe { &mut *addr_of_mut!((*out).outputs).cast() };
+        let rem = ObjectList::<MintOwners>::new_into(rem, outputs)?;
 
         //good ptr and no uninit reads
         unsafe {
             addr_of_mut!((*out).address_indices).write(indices);
+            addr_of_mut!((*out).group_id).write(group_id);
+            addr_of_mut!((*out).payload).write(payload);
         }
 
         Ok(rem)
@@ -77,7 +138,8 @@ impl<'b> FromBytes<'b> for NFTMintOperation<'b> {
 
 impl<'a> DisplayableItem for NFTMintOperation<'a> {
     fn num_items(&self) -> usize {
-        self.nft_output.num_items()
+        // group_id, payload size and the owners
+        1 + 1 + self.owners_items()
     }
 
     #[inline(never)]
@@ -88,7 +150,34 @@ impl<'a> DisplayableItem for NFTMintOperation<'a> {
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
-        self.nft_output.render_item(item_n, title, message, page)
+        use bolos::{pic_str, PIC};
+        use lexical_core::Number;
+
+        match item_n {
+            0 => {
+                let label = pic_str!(b"GroupID");
+                title[..label.len()].copy_from_slice(label);
+
+                let mut buffer = [0; u32::FORMATTED_SIZE_DECIMAL + 2];
+                let num = u32_to_str(self.group_id, &mut buffer).map_err(|_| ViewError::Unknown)?;
+
+                handle_ui_message(num, message, page)
+            }
+            1 => {
+                let label = pic_str!(b"Payload size");
+                title[..label.len()].copy_from_slice(label);
+
+                let suffix = pic_str!(b" bytes");
+                let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 6];
+                let len = u64_to_str(self.payload.len() as u64, &mut buffer)
+                    .map_err(|_| ViewError::Unknown)?
+                    .len();
+                buffer[len..len + suffix.len()].copy_from_slice(suffix);
+
+                handle_ui_message(&buffer[..len + suffix.len()], message, page)
+            }
+            x => self.render_owners(x - 2, title, message, page),
+        }
     }
 }
 
@@ -96,25 +185,57 @@ impl<'a> DisplayableItem for NFTMintOperation<'a> {
 mod tests {
     use super::*;
 
+    const DATA: &[u8] = &[
+        // Type ID
+        0x00, 0x00, 0x00, 0x0c, // number of address indices:
+        0x00, 0x00, 0x00, 0x02, // address index 0:
+        0x00, 0x00, 0x00, 0x03, // address index 1:
+        0x00, 0x00, 0x00, 0x07, // groupID:
+        0x00, 0x00, 0x30, 0x39, // length of payload:
+        0x00, 0x00, 0x00, 0x03, // payload:
+        0x43, 0x11, 0x00, // number of outputs:
+        0x00, 0x00, 0x00, 0x01, // outputs[0] locktime:
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xd4, 0x31, // threshold:
+        0x00, 0x00, 0x00, 0x01, // number of addresses:
+        0x00, 0x00, 0x00, 0x02, // addrs[0]:
+        0x51, 0x02, 0x5c, 0x61, 0xfb, 0xcf, 0xc0, 0x78, 0xf6, 0x93, 0x34, 0xf8, 0x34, 0xbe, 0x6d,
+        0xd2, 0x6d, 0x55, 0xa9, 0x55, // addrs[1]:
+        0xc3, 0x34, 0x41, 0x28, 0xe0, 0x60, 0x12, 0x8e, 0xde, 0x35, 0x23, 0xa2, 0x4a, 0x46, 0x1c,
+        0x89, 0x43, 0xab, 0x08, 0x59,
+    ];
+
     #[test]
     fn parse_nft_mint_operation() {
-        let raw_input = [
-            // Type ID
-            0x00, 0x00, 0x00, 0x0c, // number of address indices:
-            0x00, 0x00, 0x00, 0x02, // address index 0:
-            0x00, 0x00, 0x00, 0x03, // address index 1:
-            0x00, 0x00, 0x00, 0x07, // groupID:
-            0x00, 0x00, 0x30, 0x39, // length of payload:
-            0x00, 0x00, 0x00, 0x03, // payload:
-            0x43, 0x11, 0x00, // transferable output:
-            0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 1, 22, 54, 119, 75, 103, 131, 141, 236,
-            22, 225, 106, 182, 207, 172, 178, 27, 136, 195, 168, 97,
-        ];
-
-        let nft_mint_operation = NFTMintOperation::from_bytes(&raw_input).unwrap().1;
+        let (rem, nft_mint_operation) = NFTMintOperation::from_bytes(DATA).unwrap();
+        assert!(rem.is_empty());
 
         let address_bytes: &[[u8; 4]] = &[3_u32.to_be_bytes(), 7_u32.to_be_bytes()];
 
         assert_eq!(nft_mint_operation.address_indices, address_bytes);
+        assert_eq!(nft_mint_operation.group_id, 12345);
+        assert_eq!(nft_mint_operation.payload, &[0x43, 0x11, 0x00]);
+
+        let owners = nft_mint_operation.outputs.iter().next().unwrap().0;
+        assert_eq!(owners.locktime, 0xd431);
+        assert_eq!(owners.threshold, 1);
+        assert_eq!(owners.addresses.len(), 2);
+    }
+
+    #[test]
+    fn nft_mint_operation_items() {
+        let (_, op) = NFTMintOperation::from_bytes(DATA).unwrap();
+
+        // group id, payload size, locktime and both owners
+        assert_eq!(op.num_items(), 5);
+
+        let mut title = [0; 18];
+        let mut message = [0; 32];
+        op.render_item(1, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..13], b"Payload size\0");
+        assert_eq!(&message[..8], b"3 bytes\0");
+
+        let mut title = [0; 18];
+        op.render_item(4, &mut title, &mut message, 0).unwrap();
+        assert_eq!(&title[..14], b"Owner address\0");
     }
 }
@@ -16,10 +16,7 @@
 
 use crate::parser::NFTTransferOutput;
 
-use crate::{
-    parser::{DisplayableItem, FromBytes, ParserError, U32_SIZE},
-    utils::ApduPanic,
-};
+use crate::parser::{DisplayableItem, FromBytes, ParserError, U32_SIZE};
 
 use core::{mem::MaybeUninit, ptr::addr_of_mut};
 use nom::{
@@ -57,8 +54,18 @@ impl<'b> FromBytes<'b> for NFTTransferOperation<'b> {
         let (rem, _) = tag(Self::TYPE_ID.to_be_bytes())(input)?;
 
         let (rem, num_indices) = be_u32(rem)?;
-        let (rem, indices) = take(num_indices as usize * U32_SIZE)(rem)?;
-        let indices = bytemuck::try_cast_slice(indices).apdu_unwrap();
+        let indices_len = (num_indices as usize)
+            .checked_mul(U32_SIZE)
+            .ok_or(ParserError::ValueOutOfRange)?;
+        let (rem, indices) = take(indices_len)(rem)?;
+        let indices = bytemuck::try_cast_slice::<_, [u8; U32_SIZE]>(indices)
+            .map_err(|_| ParserError::InvalidAddressLength)?;
+
+        // indices are sorted and unique, being big endian
+        // comparing the bytes is comparing the numbers
+        if indices.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(ParserError::InvalidIndexOrder.into());
+        }
 
         let out = out.as_mut_ptr();
         let nft_transfer_output = unsafe { &mut *addr_of_mut!((*out).nft_transfer_output).cast() };
@@ -101,8 +108,8 @@ mod tests {
             // Type ID
             0x00, 0x00, 0x00, 0x0d, // number of address indices:
             0x00, 0x00, 0x00, 0x02, // address index 0:
-            0x00, 0x00, 0x00, 0x07, // address index 1:
-            0x00, 0x00, 0x00, 0x03, // groupID:
+            0x00, 0x00, 0x00, 0x03, // address index 1:
+            0x00, 0x00, 0x00, 0x07, // groupID:
             0x00, 0x00, 0x30, 0x39, // length of payload:
             0x00, 0x00, 0x00, 0x03, // payload:
             0x43, 0x11, 0x00, // locktime:
@@ -117,8 +124,49 @@ mod tests {
 
         let nft_transfer_operation = NFTTransferOperation::from_bytes(&raw_input).unwrap().1;
 
-        let address_bytes: &[[u8; 4]] = &[7_u32.to_be_bytes(), 3_u32.to_be_bytes()];
+        let address_bytes: &[[u8; 4]] = &[3_u32.to_be_bytes(), 7_u32.to_be_bytes()];
 
         assert_eq!(nft_transfer_operation.address_indices, address_bytes);
     }
+
+    #[test]
+    fn address_indices_order() {
+        let parse = |indices: &[u32]| {
+            let mut data = NFTTransferOperation::TYPE_ID.to_be_bytes().to_vec();
+            data.extend_from_slice(&(indices.len() as u32).to_be_bytes());
+            indices
+                .iter()
+                .for_each(|i| data.extend_from_slice(&i.to_be_bytes()));
+            // group 0, empty payload, locktime 0 and 1 of 1 owners
+            data.extend_from_slice(&[0; 16]);
+            data.extend_from_slice(&[0, 0, 0, 1, 0, 0, 0, 1]);
+            data.extend_from_slice(&[0x11; 20]);
+
+            NFTTransferOperation::from_bytes(&data).map(|(_, op)| op.address_indices.len())
+        };
+        let err = Err(nom::Err::Error(ParserError::InvalidIndexOrder));
+
+        assert_eq!(parse(&[]), Ok(0));
+        assert_eq!(parse(&[0, 1, 256]), Ok(3));
+        // repeated
+        assert_eq!(parse(&[1, 1]), err);
+        assert_eq!(parse(&[0, 2, 2, 3]), err);
+        // out of order
+        assert_eq!(parse(&[3, 1]), err);
+        assert_eq!(parse(&[1, 256, 2]), err);
+    }
+
+    #[test]
+    fn truncated_address_indices() {
+        let raw_input = [
+            // Type ID
+            0x00, 0x00, 0x00, 0x0d, // number of address indices:
+            0x00, 0x00, 0x00, 0x02, // address index 0:
+            0x00, 0x00, 0x00, 0x07, // address index 1, truncated:
+            0x00, 0x00,
+        ];
+
+        let err = NFTTransferOperation::from_bytes(&raw_input).unwrap_err();
+        assert_eq!(err, nom::Err::Error(ParserError::UnexpectedBufferEnd));
+    }
 }
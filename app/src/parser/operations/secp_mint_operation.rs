@@ -14,12 +14,12 @@
 *  limitations under the License.
 ********************************************************************************/
 
-use crate::parser::{DisplayableItem, SECPMintOutput, SECPTransferOutput};
-
 use crate::{
     handlers::handle_ui_message,
-    parser::{FromBytes, ParserError},
-    utils::ApduPanic,
+    parser::{
+        u64_to_str, DisplayableItem, FromBytes, ParserError, SECPMintOutput, SECPTransferOutput,
+        U32_SIZE,
+    },
 };
 use zemu_sys::ViewError;
 
@@ -29,8 +29,10 @@ use nom::{
     number::complete::be_u32,
 };
 
-const U32_SIZE: usize = std::mem::size_of::<u32>();
-
+/// Mints new units of a fungible asset
+///
+/// Neither output carries an asset id in its encoding,
+/// both belong to the asset of the enclosing `TransferableOp`
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(test, derive(Debug))]
 pub struct SECPMintOperation<'b> {
@@ -40,6 +42,27 @@ pub struct SECPMintOperation<'b> {
 }
 impl<'b> SECPMintOperation<'b> {
     pub const TYPE_ID: u32 = 8;
+
+    fn render_amount(
+        &self,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+        use lexical_core::Number;
+
+        let label = pic_str!(b"Amount");
+        title[..label.len()].copy_from_slice(label);
+
+        // the asset denomination is unknown here,
+        // so show the amount in its smallest unit
+        let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 2];
+        let amount = u64_to_str(self.transfer_output.amount, &mut buffer[..])
+            .map_err(|_| ViewError::Unknown)?;
+
+        handle_ui_message(amount, message, page)
+    }
 }
 
 impl<'b> FromBytes<'b> for SECPMintOperation<'b> {
@@ -54,9 +77,12 @@ impl<'b> FromBytes<'b> for SECPMintOperation<'b> {
         let (rem, _) = tag(Self::TYPE_ID.to_be_bytes())(input)?;
 
         let (rem, num_indices) = be_u32(rem)?;
-
-        let (rem, indices) = take(num_indices as usize * U32_SIZE)(rem)?;
-        let indices = bytemuck::try_cast_slice(indices).apdu_unwrap();
+        let indices_len = (num_indices as usize)
+            .checked_mul(U32_SIZE)
+            .ok_or(ParserError::ValueOutOfRange)?;
+        let (rem, indices) = take(indices_len)(rem)?;
+        let indices =
+            bytemuck::try_cast_slice(indices).map_err(|_| ParserError::InvalidAddressLength)?;
 
         let out = out.as_mut_ptr();
         let mint_output = unsafe { &mut *addr_of_mut!((*out).mint_output).cast() };
@@ -76,9 +102,8 @@ impl<'b> FromBytes<'b> for SECPMintOperation<'b> {
 
 impl<'b> DisplayableItem for SECPMintOperation<'b> {
     fn num_items(&self) -> usize {
-        // operation description
-        // and the transfer to the new mint-output owners
-        1 + self.transfer_output.num_items()
+        // minted amount and the new owners of it
+        1 + self.transfer_output.num_addresses()
     }
 
     fn render_item(
@@ -90,28 +115,20 @@ impl<'b> DisplayableItem for SECPMintOperation<'b> {
     ) -> Result<u8, ViewError> {
         use bolos::{pic_str, PIC};
 
-        if item_n == 0 {
-            let title_content = pic_str!(b"SECPMint");
-            title[..title_content.len()].copy_from_slice(title_content);
-
-            return handle_ui_message(pic_str!(b"Operation"), message, page);
-        }
-
-        let item_n = item_n as usize - 1;
-
         match item_n as usize {
-            0 => {
-                let res = self.transfer_output.render_item(0, title, message, page);
+            0 => self.render_amount(title, message, page),
+            x @ 1.. if x < self.num_items() => {
+                let addr = self
+                    .transfer_output
+                    .get_address_at(x - 1)
+                    .ok_or(ViewError::NoData)?;
+                let ret = addr.render_item(0, title, message, page);
+
+                let label = pic_str!(b"Owner address");
                 title.iter_mut().for_each(|v| *v = 0);
-                // this operation consumes mint and transfer new ones to
-                // list of owners so change this title.
-                let title_content = pic_str!(b"Transfer: ");
-                title[..title_content.len()].copy_from_slice(title_content);
-                res
+                title[..label.len()].copy_from_slice(label);
+                ret
             }
-            x @ 1.. if x < self.transfer_output.num_addresses() + 1 => self
-                .transfer_output
-                .render_item(x as _, title, message, page),
             _ => Err(ViewError::NoData),
         }
     }
@@ -142,5 +159,7 @@ mod tests {
         ];
 
         assert_eq!(secp_mint_operation.address_indices, address_bytes);
+        assert_eq!(secp_mint_operation.transfer_output.amount, 10000);
+        assert_eq!(secp_mint_operation.num_items(), 2);
     }
 }
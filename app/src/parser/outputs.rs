@@ -15,7 +15,9 @@
 ********************************************************************************/
 use core::ops::Deref;
 
-use crate::parser::{Address, AssetId, DisplayableItem, FromBytes, ParserError};
+use crate::parser::{
+    Address, AssetId, DisplayableItem, FromBytes, ParserError, MAX_ASSET_LABEL_LEN,
+};
 use crate::sys::ViewError;
 use crate::utils::ApduPanic;
 use core::{mem::MaybeUninit, ptr::addr_of_mut};
@@ -121,7 +123,11 @@ where
     fn num_items(&self) -> usize {
         // the asset_id is not part of the summary we need from objects of this type,
         // but could give to higher level objects information to display such information.
-        self.output.num_items()
+        // Except for nfts, where the asset is shown before the output.
+        match *self.output {
+            Output::NFTTransfer(_) => 1 + self.output.num_items(),
+            _ => self.output.num_items(),
+        }
     }
 
     fn render_item(
@@ -131,7 +137,19 @@ where
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
-        self.output.render_item(item_n as _, title, message, page)
+        match (&*self.output, item_n) {
+            // label the amount with the asset name or id
+            (Output::SECPTransfer(out), 0) => {
+                let mut asset = [0; MAX_ASSET_LABEL_LEN];
+                let len = self.asset_id.write_label(&mut asset[..])?;
+                let decimals = self.asset_id.denomination().unwrap_or_default();
+
+                out.render_amount(&asset[..len], decimals as usize, title, message, page)
+            }
+            (Output::NFTTransfer(_), 0) => self.asset_id.render_item(0, title, message, page),
+            (Output::NFTTransfer(_), x) => self.output.render_item(x - 1, title, message, page),
+            _ => self.output.render_item(item_n as _, title, message, page),
+        }
     }
 }
 
@@ -358,6 +376,27 @@ mod tests {
         assert_eq!(output.output.locktime.unwrap(), 8);
     }
 
+    #[test]
+    fn locked_output_of_unknown_asset() {
+        let mut data = LOCKED_OUTPUT.to_vec();
+        data[36..44].copy_from_slice(&1622496480u64.to_be_bytes());
+        let output = TransferableOutput::<PvmOutput>::from_bytes(&data)
+            .unwrap()
+            .1;
+
+        let mut title = [0; 18];
+        let mut message = [0; 200];
+        output.render_lock(&mut title, &mut message, 0).unwrap();
+
+        // the amount is in base units, not in AVAX
+        let len = message.iter().position(|b| *b == 0).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&message[..len]).unwrap(),
+            "2000000000000 46WPtSJH9Mc1rNbRaWo6k2v6Bm8dc1FuCFxS2n6gFo7L5vAy5 \
+             until 2021-05-31 21:28:00 UTC"
+        );
+    }
+
     #[test]
     fn parse_transferable_output_avm_output() {
         let t = TransferableOutput::<AvmOutput>::from_bytes(DATA).unwrap().1;
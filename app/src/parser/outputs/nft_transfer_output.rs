@@ -23,17 +23,17 @@ use zemu_sys::ViewError;
 
 use crate::{
     handlers::handle_ui_message,
-    parser::{u32_to_str, Address, DisplayableItem, FromBytes, ParserError, ADDRESS_LEN},
+    parser::{
+        u32_to_str, Address, DisplayableItem, FromBytes, ParserError, ADDRESS_LEN,
+        NFT_MAX_PAYLOAD_LEN,
+    },
     utils::hex_encode,
 };
 
-const MAX_PAYLOAD_LEN: usize = 1024;
-
 // avax-team requested to display at least X
 // characters that correspond to the payload.
-// lets set that limit to 50 characters,
-// as the payload can be either an ascii string
-// or raw bytes.
+// lets set that limit to 50 characters
+// for binary payloads, text is shown whole.
 const SHOW_PAYLOAD_LEN: usize = 50;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -59,6 +59,25 @@ impl<'b> NFTTransferOutput<'b> {
         Some(unsafe { addr.assume_init() })
     }
 
+    /// The group of the asset this NFT belongs to
+    pub fn group_id(&self) -> u32 {
+        self.group_id
+    }
+
+    pub fn payload(&self) -> &'b [u8] {
+        self.payload
+    }
+
+    /// Returns the payload if it is printable UTF-8 text
+    pub fn payload_text(&self) -> Option<&'b [u8]> {
+        let text = core::str::from_utf8(self.payload).ok()?;
+        if text.chars().any(char::is_control) {
+            return None;
+        }
+
+        Some(self.payload)
+    }
+
     pub fn num_addresses(&self) -> usize {
         self.addresses.len()
     }
@@ -69,7 +88,7 @@ impl<'b> NFTTransferOutput<'b> {
     ) -> Result<&'b [u8], nom::Err<ParserError>> {
         let (rem, (group_id, payload_len)) = tuple((be_u32, be_u32))(input)?;
 
-        if payload_len as usize > MAX_PAYLOAD_LEN {
+        if payload_len as usize > NFT_MAX_PAYLOAD_LEN {
             return Err(ParserError::ValueOutOfRange.into());
         }
 
@@ -116,8 +135,8 @@ impl<'b> FromBytes<'b> for NFTTransferOutput<'b> {
 
 impl<'a> DisplayableItem for NFTTransferOutput<'a> {
     fn num_items(&self) -> usize {
-        // group_id, payload and addresses
-        1 + 1 + self.num_addresses()
+        // group, payload, payload size and addresses
+        1 + 1 + 1 + self.num_addresses()
     }
 
     #[inline(never)]
@@ -131,49 +150,48 @@ impl<'a> DisplayableItem for NFTTransferOutput<'a> {
         use bolos::{pic_str, PIC};
         use lexical_core::Number;
 
-        let mut buffer = [0; u32::FORMATTED_SIZE_DECIMAL + 2];
-
         match item_n as usize {
             0 => {
-                let title_content = pic_str!(b"GroupID: ");
+                let title_content = pic_str!(b"NFT");
                 title[..title_content.len()].copy_from_slice(title_content);
 
-                let group_id =
-                    u32_to_str(self.group_id, &mut buffer[..]).map_err(|_| ViewError::Unknown)?;
+                let prefix = pic_str!(b"Group "!);
+                let mut buffer = [0; 6 + u32::FORMATTED_SIZE_DECIMAL + 2];
+                buffer[..prefix.len()].copy_from_slice(prefix);
+                let len = u32_to_str(self.group_id, &mut buffer[prefix.len()..])
+                    .map_err(|_| ViewError::Unknown)?
+                    .len();
 
-                handle_ui_message(group_id, message, page)
+                handle_ui_message(&buffer[..prefix.len() + len], message, page)
             }
             1 => {
-                let title_content = pic_str!(b"Payload: ");
+                let title_content = pic_str!(b"Payload");
                 title[..title_content.len()].copy_from_slice(title_content);
 
+                // metadata like JSON or an URI is shown whole
+                if let Some(text) = self.payload_text() {
+                    return handle_ui_message(text, message, page);
+                }
+
                 let suffix = pic_str!(b"...");
                 let prefix = pic_str!(b"0x"!);
                 let mut show_suffix = false;
                 let mut buf = [0; SHOW_PAYLOAD_LEN + 4 + 2]; // suffix and preffix
                 let mut len = self.payload.len();
 
-                if self.payload.is_ascii() {
-                    if len > SHOW_PAYLOAD_LEN {
-                        show_suffix = true;
-                        len = SHOW_PAYLOAD_LEN;
-                    }
-                    buf[..len].copy_from_slice(&self.payload[..len]);
-                } else {
-                    // hex string for non ascii payloads.
-                    if len * 2 > SHOW_PAYLOAD_LEN {
-                        show_suffix = true;
-                        len = SHOW_PAYLOAD_LEN / 2;
-                    }
-
-                    let prefix_len = prefix.len();
-                    buf[..prefix_len].copy_from_slice(&prefix[..]);
-
-                    len = hex_encode(&self.payload[..len], &mut buf[prefix_len..])
-                        .map_err(|_| ViewError::Unknown)?
-                        + prefix_len;
+                // hex string for binary payloads.
+                if len * 2 > SHOW_PAYLOAD_LEN {
+                    show_suffix = true;
+                    len = SHOW_PAYLOAD_LEN / 2;
                 }
 
+                let prefix_len = prefix.len();
+                buf[..prefix_len].copy_from_slice(&prefix[..]);
+
+                len = hex_encode(&self.payload[..len], &mut buf[prefix_len..])
+                    .map_err(|_| ViewError::Unknown)?
+                    + prefix_len;
+
                 // add suffix indicating the payload being shown is just
                 // a fraction of the total.
                 if show_suffix {
@@ -182,8 +200,21 @@ impl<'a> DisplayableItem for NFTTransferOutput<'a> {
                 }
                 handle_ui_message(&buf[..len], message, page)
             }
-            x @ 2.. if x < self.num_addresses() + 2 => {
-                let idx = x - 2;
+            2 => {
+                let title_content = pic_str!(b"Payload size");
+                title[..title_content.len()].copy_from_slice(title_content);
+
+                let suffix = pic_str!(b" bytes");
+                let mut buffer = [0; u32::FORMATTED_SIZE_DECIMAL + 6];
+                let len = u32_to_str(self.payload.len() as u32, &mut buffer)
+                    .map_err(|_| ViewError::Unknown)?
+                    .len();
+                buffer[len..len + suffix.len()].copy_from_slice(suffix);
+
+                handle_ui_message(&buffer[..len + suffix.len()], message, page)
+            }
+            x @ 3.. if x < self.num_addresses() + 3 => {
+                let idx = x - 3;
                 if let Some(addr) = self.get_address_at(idx as usize) {
                     let res = addr.render_item(0, title, message, page);
                     // render Owner instead of Address
@@ -221,4 +252,120 @@ mod tests {
         assert_eq!(out.payload, "nft_transfer_payload".as_bytes());
         assert_eq!(out.addresses[0][..], DATA[(DATA.len() - 20)..]);
     }
+
+    fn render(out: &NFTTransferOutput, item_n: u8) -> (std::string::String, std::string::String) {
+        let mut title = [0; 18];
+        let mut message = [0; 64];
+        out.render_item(item_n, &mut title, &mut message, 0)
+            .unwrap();
+
+        let text = |buf: &[u8]| {
+            let len = buf.iter().position(|b| *b == 0).unwrap();
+            std::string::String::from_utf8(buf[..len].to_vec()).unwrap()
+        };
+        (text(&title), text(&message))
+    }
+
+    #[test]
+    fn nft_transf_outputs_in_groups() {
+        // the same output, in group 11 with a binary payload
+        let mut other = DATA[..12].to_vec();
+        other[4..8].copy_from_slice(&11u32.to_be_bytes());
+        other[8..12].copy_from_slice(&2u32.to_be_bytes());
+        other.extend_from_slice(&[0xca, 0xfe]);
+        other.extend_from_slice(&DATA[32..]);
+
+        let mut data = DATA.to_vec();
+        data.extend_from_slice(&other);
+
+        let (rem, first) = NFTTransferOutput::from_bytes(&data).unwrap();
+        let (rem, second) = NFTTransferOutput::from_bytes(rem).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(first.group_id(), 10);
+        assert_eq!(second.group_id(), 11);
+        assert_eq!(second.payload(), &[0xca, 0xfe]);
+
+        assert_eq!(first.num_items(), 4);
+        assert_eq!(render(&first, 0), ("NFT".into(), "Group 10".into()));
+        assert_eq!(
+            render(&first, 1),
+            ("Payload".into(), "nft_transfer_payload".into())
+        );
+        assert_eq!(
+            render(&first, 2),
+            ("Payload size".into(), "20 bytes".into())
+        );
+
+        assert_eq!(render(&second, 0), ("NFT".into(), "Group 11".into()));
+        assert_eq!(render(&second, 1), ("Payload".into(), "0xcafe".into()));
+        assert_eq!(
+            render(&second, 2),
+            ("Payload size".into(), "2 bytes".into())
+        );
+    }
+
+    // replaces the payload of DATA
+    fn with_payload(payload: &[u8]) -> std::vec::Vec<u8> {
+        let mut data = DATA[..12].to_vec();
+        data[8..12].copy_from_slice(&(payload.len() as u32).to_be_bytes());
+        data.extend_from_slice(payload);
+        data.extend_from_slice(&DATA[32..]);
+        data
+    }
+
+    #[test]
+    fn nft_transf_output_json_payload() {
+        let json = br#"{"name":"Avalanche Summit","image":"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"}"#;
+        let data = with_payload(json);
+        let (_, out) = NFTTransferOutput::from_bytes(&data).unwrap();
+        assert_eq!(out.payload_text(), Some(&json[..]));
+
+        // paginated instead of cut
+        let mut title = [0; 18];
+        let mut message = [0; 33];
+        let pages = out.render_item(1, &mut title, &mut message, 0).unwrap();
+        assert_eq!(pages as usize, (json.len() + 31) / 32);
+
+        let mut shown = std::vec::Vec::new();
+        for page in 0..pages {
+            out.render_item(1, &mut title, &mut message, page).unwrap();
+            let len = message.iter().position(|b| *b == 0).unwrap();
+            shown.extend_from_slice(&message[..len]);
+        }
+        assert_eq!(&shown[..], &json[..]);
+
+        // utf-8 text is shown as is
+        let data = with_payload("NFT número 7".as_bytes());
+        let (_, out) = NFTTransferOutput::from_bytes(&data).unwrap();
+        assert_eq!(render(&out, 1), ("Payload".into(), "NFT número 7".into()));
+    }
+
+    #[test]
+    fn nft_transf_output_binary_payload() {
+        let data = with_payload(&[0xff; 30]);
+        let (_, out) = NFTTransferOutput::from_bytes(&data).unwrap();
+        assert!(out.payload_text().is_none());
+
+        let expected = std::format!("0x{}...", "ff".repeat(25));
+        assert_eq!(render(&out, 1), ("Payload".into(), expected));
+
+        // control characters are not printable
+        let data = with_payload(b"line\nbreak");
+        let (_, out) = NFTTransferOutput::from_bytes(&data).unwrap();
+        assert_eq!(
+            render(&out, 1),
+            ("Payload".into(), "0x6c696e650a627265616b".into())
+        );
+    }
+
+    #[test]
+    fn nft_transf_output_truncated_payload() {
+        // declares a payload longer than what follows
+        let mut data = DATA[..12].to_vec();
+        data[8..12].copy_from_slice(&40u32.to_be_bytes());
+        data.extend_from_slice(&DATA[12..32]);
+
+        let err = NFTTransferOutput::from_bytes(&data).unwrap_err();
+        assert_eq!(err, nom::Err::Error(ParserError::UnexpectedBufferEnd));
+    }
 }
@@ -50,33 +50,74 @@ impl<'b> SECPOutputOwners<'b> {
     pub fn num_addresses(&self) -> usize {
         self.addresses.len()
     }
-}
-impl<'b> FromBytes<'b> for SECPOutputOwners<'b> {
-    #[inline(never)]
-    fn from_bytes_into(
+
+    /// Whether the threshold is worth showing, as with only one owner it is always "1 of 1"
+    pub fn is_multisig(&self) -> bool {
+        self.addresses.len() > 1
+    }
+
+    /// Number of items to show the threshold, when multisig, and each address
+    pub fn num_owner_items(&self) -> usize {
+        self.is_multisig() as usize + self.addresses.len()
+    }
+
+    // Renders the threshold as "M of N"
+    pub fn render_threshold(
+        &self,
+        label: &[u8],
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+        use lexical_core::Number;
+
+        title[..label.len()].copy_from_slice(label);
+
+        let of = pic_str!(b" of "!);
+        let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL * 2 + 4];
+
+        let mut len = u64_to_str(self.threshold as u64, &mut buffer[..])
+            .map_err(|_| ViewError::Unknown)?
+            .len();
+        buffer[len..len + of.len()].copy_from_slice(of);
+        len += of.len();
+        len += u64_to_str(self.addresses.len() as u64, &mut buffer[len..])
+            .map_err(|_| ViewError::Unknown)?
+            .len();
+
+        handle_ui_message(&buffer[..len], message, page)
+    }
+
+    /// Parses the owners when the type id is implied by the context,
+    /// like in the outputs of a NFT mint operation
+    pub fn into_without_type(
         input: &'b [u8],
         out: &mut MaybeUninit<Self>,
     ) -> Result<&'b [u8], nom::Err<ParserError>> {
-        crate::sys::zemu_log_stack("SECPOutputOwners::from_bytes_into\x00");
-        // get owners type and check
-        let (rem, _) = tag(Self::TYPE_ID.to_be_bytes())(input)?;
-
-        let (rem, (locktime, threshold, addr_len)) = tuple((be_u64, be_u32, be_u32))(rem)?;
+        let (rem, (locktime, threshold, addr_len)) = tuple((be_u64, be_u32, be_u32))(input)?;
 
-        let (rem, addresses) = take(addr_len as usize * ADDRESS_LEN)(rem)?;
         // Check for invariants
         // owner list of address must contain at least one address
         if addr_len == 0 {
             return Err(ParserError::InvalidAddressLength.into());
         }
 
-        let addresses =
+        // every declared address has to be there
+        let (rem, addresses) = take(addr_len as usize * ADDRESS_LEN)(rem)?;
+        let addresses: &[[u8; ADDRESS_LEN]] =
             bytemuck::try_cast_slice(addresses).map_err(|_| ParserError::InvalidAddressLength)?;
 
-        if threshold as usize > addresses.len() {
+        // at least one and at most all of the owners have to sign
+        if threshold == 0 || threshold as usize > addresses.len() {
             return Err(ParserError::InvalidThreshold.into());
         }
 
+        // and they are listed once, sorted as the P-chain requires
+        if addresses.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(ParserError::InvalidAddress.into());
+        }
+
         //good ptr and no uninit reads
         let out = out.as_mut_ptr();
         unsafe {
@@ -89,6 +130,20 @@ impl<'b> FromBytes<'b> for SECPOutputOwners<'b> {
     }
 }
 
+impl<'b> FromBytes<'b> for SECPOutputOwners<'b> {
+    #[inline(never)]
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("SECPOutputOwners::from_bytes_into\x00");
+        // get owners type and check
+        let (rem, _) = tag(Self::TYPE_ID.to_be_bytes())(input)?;
+
+        Self::into_without_type(rem, out)
+    }
+}
+
 impl<'a> DisplayableItem for SECPOutputOwners<'a> {
     fn num_items(&self) -> usize {
         // show an item for each address in the list
@@ -148,6 +203,7 @@ impl<'a> DisplayableItem for SECPOutputOwners<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::prelude::v1::*;
 
     const DATA: &[u8] = &[
         0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 1, 0, 0, 0, 1, 22, 54, 119, 75, 103, 131,
@@ -161,4 +217,30 @@ mod tests {
         assert_eq!(owner.threshold, 1);
         assert_eq!(owner.addresses.len(), 1);
     }
+
+    #[test]
+    fn malformed_owners() {
+        let parse = |threshold: u32, addresses: &[[u8; ADDRESS_LEN]], declared: u32| {
+            let mut data = DATA[..12].to_vec();
+            data.extend_from_slice(&threshold.to_be_bytes());
+            data.extend_from_slice(&declared.to_be_bytes());
+            addresses.iter().for_each(|a| data.extend_from_slice(a));
+            SECPOutputOwners::from_bytes(&data).map(|(_, owners)| owners.addresses.len())
+        };
+        let err = |e| Err(nom::Err::Error(e));
+
+        let (a, b) = ([0x11; ADDRESS_LEN], [0x22; ADDRESS_LEN]);
+        assert_eq!(parse(2, &[a, b], 2), Ok(2));
+
+        // 2 addresses declared, only the bytes of 1
+        assert_eq!(parse(1, &[a], 2), err(ParserError::UnexpectedBufferEnd));
+        // no owners
+        assert_eq!(parse(0, &[], 0), err(ParserError::InvalidAddressLength));
+        // nobody or more owners than listed have to sign
+        assert_eq!(parse(0, &[a, b], 2), err(ParserError::InvalidThreshold));
+        assert_eq!(parse(3, &[a, b], 2), err(ParserError::InvalidThreshold));
+        // unsorted or repeated owners
+        assert_eq!(parse(1, &[b, a], 2), err(ParserError::InvalidAddress));
+        assert_eq!(parse(1, &[a, a], 2), err(ParserError::InvalidAddress));
+    }
 }
@@ -23,10 +23,13 @@ use zemu_sys::ViewError;
 
 use crate::{
     handlers::handle_ui_message,
-    parser::{nano_avax_to_fp_str, Address, DisplayableItem, FromBytes, ParserError, ADDRESS_LEN},
+    parser::{
+        u64_to_fp_str, Address, DisplayableItem, FromBytes, ParserError, ADDRESS_LEN,
+        MAX_ASSET_AMOUNT_LEN, MAX_ASSET_LABEL_LEN, NANO_AVAX_DECIMAL_DIGITS,
+    },
 };
 
-const AVAX_TO_LEN: usize = 9; //b" AVAX to "
+const TO_LEN: usize = 4; //b" to "
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(test, derive(Debug))]
@@ -51,6 +54,45 @@ impl<'b> SECPTransferOutput<'b> {
     pub fn num_addresses(&self) -> usize {
         self.addresses.len()
     }
+
+    // Renders the amount as "<amount> <asset> to ",
+    // with `decimals` places
+    pub fn render_amount(
+        &self,
+        asset: &[u8],
+        decimals: usize,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+
+        if asset.len() > MAX_ASSET_LABEL_LEN {
+            return Err(ViewError::Unknown);
+        }
+
+        let title_content = pic_str!(b"Amount");
+        title[..title_content.len()].copy_from_slice(title_content);
+
+        let mut buffer = [0; MAX_ASSET_AMOUNT_LEN + 1 + MAX_ASSET_LABEL_LEN + TO_LEN];
+
+        // write the amount
+        let mut len = u64_to_fp_str(self.amount, decimals, &mut buffer[..])
+            .map_err(|_| ViewError::Unknown)?
+            .len();
+
+        // write the asset
+        buffer[len] = b' ';
+        len += 1;
+        buffer[len..len + asset.len()].copy_from_slice(asset);
+        len += asset.len();
+
+        let to = pic_str!(b" to "!);
+        buffer[len..len + to.len()].copy_from_slice(to);
+        len += to.len();
+
+        handle_ui_message(&buffer[..len], message, page)
+    }
 }
 
 impl<'b> FromBytes<'b> for SECPTransferOutput<'b> {
@@ -107,27 +149,17 @@ impl<'a> DisplayableItem for SECPTransferOutput<'a> {
         page: u8,
     ) -> Result<u8, ViewError> {
         use bolos::{pic_str, PIC};
-        use lexical_core::Number;
 
-        let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 2 + AVAX_TO_LEN];
         let addr_item_n = self.num_items() - self.addresses.len();
 
         match item_n as usize {
-            0 => {
-                let title_content = pic_str!(b"Amount");
-                title[..title_content.len()].copy_from_slice(title_content);
-
-                let avax_to = pic_str!(b" AVAX to ");
-
-                // write the amount
-                let len = nano_avax_to_fp_str(self.amount, &mut buffer[..])
-                    .map_err(|_| ViewError::Unknown)?
-                    .len();
-
-                // write avax
-                buffer[len..(len + avax_to.len())].copy_from_slice(avax_to);
-                handle_ui_message(&buffer[..(len + avax_to.len())], message, page)
-            }
+            0 => self.render_amount(
+                pic_str!(b"AVAX"!),
+                NANO_AVAX_DECIMAL_DIGITS,
+                title,
+                message,
+                page,
+            ),
 
             x @ 1.. if x >= addr_item_n => {
                 let idx = x - addr_item_n;
@@ -16,9 +16,10 @@
 use crate::{
     handlers::handle_ui_message,
     parser::{
-        error::ParserError, nano_avax_to_fp_str, timestamp_to_str_date, Address, DisplayableItem,
-        FromBytes, Output, OutputType, SECPOutputOwners, SECPTransferOutput,
-        FORMATTED_STR_DATE_LEN,
+        error::ParserError, timestamp_to_str_date, u64_to_fp_str, Address, DisplayableItem,
+        FromBytes, Output, OutputType, SECPOutputOwners, SECPTransferOutput, TransferableOutput,
+        FORMATTED_STR_DATE_LEN, MAX_ASSET_AMOUNT_LEN, MAX_ASSET_LABEL_LEN,
+        NANO_AVAX_DECIMAL_DIGITS,
     },
 };
 use core::ops::Deref;
@@ -34,7 +35,7 @@ use zemu_sys::ViewError;
 // buffer on which we write other information so that we need
 // its length to initialize such buffer and having the length defined as a constant and the
 // literal inlined can lead to len mismatch which can cause overlapping.
-const AVAX_UNTIL: &[u8; 12] = b" AVAX until ";
+const UNTIL: &[u8; 7] = b" until ";
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(C)]
@@ -63,6 +64,11 @@ impl<'b> PvmOutput<'b> {
         self.locktime.is_some()
     }
 
+    /// Returns if `item_n` is the page with the lock of this output
+    pub fn is_lock_item(&self, item_n: u8) -> bool {
+        self.is_locked() && item_n as usize == self.num_inner_items()
+    }
+
     pub fn num_inner_items(&self) -> usize {
         self.output.num_items()
     }
@@ -128,6 +134,80 @@ impl<'b> PvmOutput<'b> {
             _ => None,
         }
     }
+
+    // Renders the lock of the output as
+    // "<amount> <asset> until <date>", with `decimals` places
+    pub fn render_lock(
+        &self,
+        asset: &[u8],
+        decimals: usize,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+
+        if asset.len() > MAX_ASSET_LABEL_LEN {
+            return Err(ViewError::Unknown);
+        }
+
+        // legacy app displays:
+        // 'Funds locked', body: '0.5 AVAX until 2021-05-31 21:28:00 UTC'},
+        // so lets do the same thing
+        let t = pic_str!(b"Funds locked");
+        title[..t.len()].copy_from_slice(t);
+
+        let until = PIC::new(UNTIL).into_inner();
+        let mut content = [0; MAX_ASSET_AMOUNT_LEN
+            + 1
+            + MAX_ASSET_LABEL_LEN
+            + UNTIL.len()
+            + FORMATTED_STR_DATE_LEN];
+        // write the amount
+        let amount = self.amount().ok_or(ViewError::Unknown)?;
+        let mut total_len = u64_to_fp_str(amount, decimals, &mut content[..])
+            .map_err(|_| ViewError::Unknown)?
+            .len();
+        // write the asset
+        content[total_len] = b' ';
+        total_len += 1;
+        content[total_len..total_len + asset.len()].copy_from_slice(asset);
+        total_len += asset.len();
+        // write until
+        content[total_len..total_len + until.len()].copy_from_slice(until);
+        total_len += until.len();
+        // finally, write the date
+        let locktime = self.locktime.ok_or(ViewError::NoData)?;
+        let date_str = timestamp_to_str_date(locktime).map_err(|_| ViewError::Unknown)?;
+        content[total_len..]
+            .iter_mut()
+            .zip(date_str.as_slice())
+            .take(date_str.len())
+            .for_each(|(d, s)| *d = *s);
+        total_len += date_str.len();
+
+        handle_ui_message(&content[..total_len], message, page)
+    }
+}
+
+impl<'b> TransferableOutput<'b, PvmOutput<'b>> {
+    /// Renders the lock of the output with the amount
+    /// in the output asset
+    pub fn render_lock(
+        &self,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        let asset_id = self.assert_id();
+
+        let mut asset = [0; MAX_ASSET_LABEL_LEN];
+        let len = asset_id.write_label(&mut asset[..])?;
+        let decimals = asset_id.denomination().unwrap_or_default();
+
+        self.output
+            .render_lock(&asset[..len], decimals as usize, title, message, page)
+    }
 }
 
 impl<'b> DisplayableItem for PvmOutput<'b> {
@@ -150,7 +230,6 @@ impl<'b> DisplayableItem for PvmOutput<'b> {
         page: u8,
     ) -> Result<u8, ViewError> {
         use bolos::{pic_str, PIC};
-        use lexical_core::Number;
 
         let num_inner_items = self.output.num_items() as _;
         match item_n {
@@ -164,38 +243,13 @@ impl<'b> DisplayableItem for PvmOutput<'b> {
             }
             // render "locked" informations which inner output DO not know nothing about
             // only after rendering all inner_output items
-            x if x == num_inner_items && self.is_locked() => {
-                // legacy app displays:
-                // 'Funds locked', body: '0.5 AVAX until 2021-05-31 21:28:00 UTC'},
-                // so lets do the same thing
-                let t = pic_str!(b"Funds locked");
-                title[..t.len()].copy_from_slice(t);
-
-                let avax_until = PIC::new(AVAX_UNTIL).into_inner();
-                let mut content = [0; AVAX_UNTIL.len()
-                    + FORMATTED_STR_DATE_LEN
-                    + u64::FORMATTED_SIZE_DECIMAL
-                    + 2];
-                // write the amount
-                let amount = self.amount().ok_or(ViewError::Unknown)?;
-                let num_len = nano_avax_to_fp_str(amount, &mut content[..])
-                    .map_err(|_| ViewError::Unknown)?
-                    .len();
-                // write avax until
-                let mut total_len = num_len + avax_until.len();
-                content[num_len..total_len].copy_from_slice(avax_until);
-                // finally, write the date
-                let locktime = self.locktime.ok_or(ViewError::NoData)?;
-                let date_str = timestamp_to_str_date(locktime).map_err(|_| ViewError::Unknown)?;
-                content[total_len..]
-                    .iter_mut()
-                    .zip(date_str.as_slice())
-                    .take(date_str.len())
-                    .for_each(|(d, s)| *d = *s);
-                total_len += date_str.len();
-
-                handle_ui_message(&content[..total_len], message, page)
-            }
+            x if x == num_inner_items && self.is_locked() => self.render_lock(
+                pic_str!(b"AVAX"!),
+                NANO_AVAX_DECIMAL_DIGITS,
+                title,
+                message,
+                page,
+            ),
             _ => Err(ViewError::NoData),
         }
     }
@@ -0,0 +1,129 @@
+/*******************************************************************************
+*   (c) 2021 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use core::mem::MaybeUninit;
+use nom::{bytes::complete::take, number::complete::be_u32};
+
+use crate::handlers::handle_ui_message;
+use crate::parser::{DisplayableItem, FromBytes, ParserError};
+use crate::sys::ViewError;
+
+pub const BLS_PUBLIC_KEY_LEN: usize = 48;
+pub const BLS_SIGNATURE_LEN: usize = 96;
+
+/// The BLS key a permissionless validator registers with,
+/// subnets validators and delegators use the empty signer.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub enum Signer<'b> {
+    Empty,
+    ProofOfPossession {
+        public_key: &'b [u8; BLS_PUBLIC_KEY_LEN],
+        signature: &'b [u8; BLS_SIGNATURE_LEN],
+    },
+}
+
+impl<'b> Signer<'b> {
+    pub const EMPTY_TYPE_ID: u32 = 0x0000001b;
+    pub const PROOF_OF_POSSESSION_TYPE_ID: u32 = 0x0000001c;
+
+    pub fn is_present(&self) -> bool {
+        matches!(self, Self::ProofOfPossession { .. })
+    }
+}
+
+impl<'b> FromBytes<'b> for Signer<'b> {
+    #[inline(never)]
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("Signer::from_bytes_into\x00");
+
+        let (rem, type_id) = be_u32(input)?;
+
+        let (rem, signer) = match type_id {
+            Self::EMPTY_TYPE_ID => (rem, Self::Empty),
+            Self::PROOF_OF_POSSESSION_TYPE_ID => {
+                let (rem, public_key) = take(BLS_PUBLIC_KEY_LEN)(rem)?;
+                let (rem, signature) = take(BLS_SIGNATURE_LEN)(rem)?;
+                // This would not fail as previous lines ensure we take
+                // the right amount of bytes
+                let public_key = arrayref::array_ref!(public_key, 0, BLS_PUBLIC_KEY_LEN);
+                let signature = arrayref::array_ref!(signature, 0, BLS_SIGNATURE_LEN);
+                (
+                    rem,
+                    Self::ProofOfPossession {
+                        public_key,
+                        signature,
+                    },
+                )
+            }
+            _ => return Err(ParserError::InvalidTypeId.into()),
+        };
+
+        out.write(signer);
+
+        Ok(rem)
+    }
+}
+
+impl<'b> DisplayableItem for Signer<'b> {
+    fn num_items(&self) -> usize {
+        1
+    }
+
+    fn render_item(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+
+        if item_n != 0 {
+            return Err(ViewError::NoData);
+        }
+
+        let label = pic_str!(b"BLS signer");
+        title[..label.len()].copy_from_slice(label);
+
+        if self.is_present() {
+            handle_ui_message(pic_str!(b"Present"), message, page)
+        } else {
+            handle_ui_message(pic_str!(b"None"), message, page)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_empty_signer() {
+        let data = Signer::EMPTY_TYPE_ID.to_be_bytes();
+        let (rem, signer) = Signer::from_bytes(&data).unwrap();
+        assert!(rem.is_empty());
+        assert!(!signer.is_present());
+    }
+
+    #[test]
+    fn parse_unknown_signer() {
+        let data = 0x0000001du32.to_be_bytes();
+        assert!(Signer::from_bytes(&data).is_err());
+    }
+}
@@ -20,12 +20,17 @@ use nom::{
 };
 
 use crate::{
-    parser::{FromBytes, ParserError},
+    handlers::handle_ui_message,
+    parser::{u32_to_str, FromBytes, ParserError},
+    sys::ViewError,
     utils::ApduPanic,
 };
 
 const U32_SIZE: usize = std::mem::size_of::<u32>();
 
+// indices shown before the list is truncated
+const MAX_AUTH_INDICES: usize = 8;
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(test, derive(Debug))]
 pub struct SubnetAuth<'b> {
@@ -38,6 +43,40 @@ pub struct SubnetAuth<'b> {
 
 impl<'b> SubnetAuth<'b> {
     pub const TYPE_ID: u32 = 0x0000000a;
+
+    /// Renders the signature indices as "<i>,<j>", lists
+    /// longer than MAX_AUTH_INDICES end with ",..."
+    #[inline(never)]
+    pub fn render_indices(&self, message: &mut [u8], page: u8) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+        use lexical_core::Number;
+
+        let mut buffer = [0; (u32::FORMATTED_SIZE_DECIMAL + 1) * (MAX_AUTH_INDICES + 1)];
+        let mut len = 0;
+
+        let mut append = |data: &[u8]| -> Result<(), ViewError> {
+            let dst = buffer
+                .get_mut(len..len + data.len())
+                .ok_or(ViewError::Unknown)?;
+            dst.copy_from_slice(data);
+            len += data.len();
+            Ok(())
+        };
+
+        let mut num = [0; u32::FORMATTED_SIZE_DECIMAL];
+        for (i, index) in self.sig_indices.iter().take(MAX_AUTH_INDICES).enumerate() {
+            if i > 0 {
+                append(pic_str!(b","!))?;
+            }
+            let index = u32::from_be_bytes(*index);
+            append(u32_to_str(index, &mut num[..]).map_err(|_| ViewError::Unknown)?)?;
+        }
+        if self.sig_indices.len() > MAX_AUTH_INDICES {
+            append(pic_str!(b",..."!))?;
+        }
+
+        handle_ui_message(&buffer[..len], message, page)
+    }
 }
 
 impl<'b> FromBytes<'b> for SubnetAuth<'b> {
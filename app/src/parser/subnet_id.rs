@@ -18,8 +18,8 @@ use crate::sys::ViewError;
 use core::{mem::MaybeUninit, ptr::addr_of_mut};
 use nom::bytes::complete::take;
 
-use crate::parser::{cb58_output_len, DisplayableItem, FromBytes, ParserError, CB58_CHECKSUM_LEN};
-use crate::utils::bs58_encode;
+use crate::parser::{cb58_output_len, DisplayableItem, FromBytes, ParserError};
+use crate::utils::cb58_encode;
 
 pub const SUBNET_ID_LEN: usize = 32;
 
@@ -27,6 +27,17 @@ pub const SUBNET_ID_LEN: usize = 32;
 #[cfg_attr(test, derive(Debug))]
 pub struct SubnetId<'b>(&'b [u8; SUBNET_ID_LEN]);
 
+impl<'b> SubnetId<'b> {
+    pub fn id(&self) -> &[u8; SUBNET_ID_LEN] {
+        self.0
+    }
+
+    /// The primary network is identified by the all-zeros id
+    pub fn is_primary_network(&self) -> bool {
+        self.0.iter().all(|b| *b == 0)
+    }
+}
+
 impl<'b> FromBytes<'b> for SubnetId<'b> {
     #[inline(never)]
     fn from_bytes_into(
@@ -62,7 +73,6 @@ impl<'b> DisplayableItem for SubnetId<'b> {
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, ViewError> {
-        use bolos::hash::{Hasher, Sha256};
         use bolos::{pic_str, PIC};
 
         if item_n != 0 {
@@ -72,20 +82,10 @@ impl<'b> DisplayableItem for SubnetId<'b> {
         let label = pic_str!(b"SubnetID");
         title[..label.len()].copy_from_slice(label);
 
-        let mut data = [0; SUBNET_ID_LEN + CB58_CHECKSUM_LEN];
-
-        data[..SUBNET_ID_LEN].copy_from_slice(&self.0[..]);
-
-        let checksum = Sha256::digest(&data[..SUBNET_ID_LEN]).map_err(|_| ViewError::Unknown)?;
-
-        // prepare the data to be encoded by appending last 4-byte
-        data[SUBNET_ID_LEN..]
-            .copy_from_slice(&checksum[(Sha256::DIGEST_LEN - CB58_CHECKSUM_LEN)..]);
-
         const MAX_SIZE: usize = cb58_output_len::<SUBNET_ID_LEN>();
         let mut encoded = [0; MAX_SIZE];
 
-        let len = bs58_encode(data, &mut encoded[..]).map_err(|_| ViewError::Unknown)?;
+        let len = cb58_encode(self.0, &mut encoded[..]).map_err(|_| ViewError::Unknown)?;
         handle_ui_message(&encoded[..len], message, page)
     }
 }
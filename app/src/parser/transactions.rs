@@ -26,22 +26,30 @@ mod base_import;
 mod base_tx_fields;
 mod transfer;
 mod tx_header;
+mod tx_summary;
 
 pub use base_export::BaseExport;
 pub use base_import::BaseImport;
 pub use base_tx_fields::BaseTxFields;
 pub use transfer::Transfer;
 pub use tx_header::{Header, BLOCKCHAIN_ID_LEN};
+pub use tx_summary::TxSummary;
 
+#[cfg(feature = "avm")]
 mod avm;
+#[cfg(feature = "pvm")]
 mod pvm;
 
-use crate::parser::{
-    DisplayableItem, ExportTx as EvmExport, ImportTx as EvmImport, EVM_IMPORT_TX, PVM_EXPORT_TX,
-    PVM_IMPORT_TX,
-};
+use crate::parser::{DisplayableItem, UtxoList, MAX_UI_ITEMS};
+use crate::utils::DigestScheme;
+
+#[cfg(feature = "evm")]
+use crate::parser::{ExportTx as EvmExport, ImportTx as EvmImport, EVM_EXPORT_TX, EVM_IMPORT_TX};
+
+#[cfg(feature = "avm")]
 pub use avm::{AvmExportTx, AvmImportTx, OperationTx};
-pub use pvm::{PvmExportTx, PvmImportTx};
+#[cfg(feature = "pvm")]
+pub use pvm::{PvmBaseTx, PvmExportTx, PvmImportTx};
 
 #[cfg(feature = "create-asset")]
 pub use avm::CreateAssetTx;
@@ -51,6 +59,10 @@ pub use pvm::CreateChainTx;
 
 #[cfg(feature = "add-subnet-validator")]
 pub use pvm::AddSubnetValidatorTx;
+#[cfg(feature = "remove-subnet-validator")]
+pub use pvm::RemoveSubnetValidatorTx;
+#[cfg(feature = "transform-subnet")]
+pub use pvm::TransformSubnetTx;
 
 #[cfg(feature = "create-subnet")]
 pub use pvm::CreateSubnetTx;
@@ -61,10 +73,25 @@ pub use pvm::AddDelegatorTx;
 #[cfg(feature = "add-validator")]
 pub use pvm::AddValidatorTx;
 
-use super::{
-    ChainId, FromBytes, NetworkInfo, ParserError, AVM_EXPORT_TX, AVM_IMPORT_TX, AVM_OPERATION_TX,
-    EVM_EXPORT_TX, TRANSFER_TX,
-};
+#[cfg(feature = "add-permissionless-validator")]
+pub use pvm::AddPermissionlessValidatorTx;
+
+#[cfg(feature = "add-permissionless-delegator")]
+pub use pvm::AddPermissionlessDelegatorTx;
+
+use super::{FromBytes, NetworkInfo, ParserError};
+
+#[cfg(any(feature = "evm", feature = "create-asset"))]
+use super::ChainId;
+
+#[cfg(feature = "avm")]
+use super::{AVM_EXPORT_TX, AVM_IMPORT_TX, AVM_OPERATION_TX};
+
+#[cfg(feature = "pvm")]
+use super::{PVM_BASE_TX, PVM_EXPORT_TX, PVM_IMPORT_TX};
+
+#[cfg(any(feature = "avm", feature = "pvm"))]
+use super::TRANSFER_TX;
 
 #[cfg(feature = "create-asset")]
 use super::AVM_CREATE_ASSET_TX;
@@ -78,9 +105,21 @@ use super::PVM_CREATE_SUBNET;
 #[cfg(feature = "add-subnet-validator")]
 use super::PVM_ADD_SUBNET_VALIDATOR;
 
+#[cfg(feature = "transform-subnet")]
+use super::PVM_TRANSFORM_SUBNET;
+
+#[cfg(feature = "remove-subnet-validator")]
+use super::PVM_REMOVE_SUBNET_VALIDATOR;
+
 #[cfg(feature = "add-validator")]
 use super::PVM_ADD_VALIDATOR;
 
+#[cfg(feature = "add-permissionless-validator")]
+use super::PVM_ADD_PERMISSIONLESS_VALIDATOR;
+
+#[cfg(feature = "add-permissionless-delegator")]
+use super::PVM_ADD_PERMISSIONLESS_DELEGATOR;
+
 #[cfg(feature = "add-delegator")]
 use super::PVM_ADD_DELEGATOR;
 
@@ -90,20 +129,30 @@ impl TryFrom<(u32, NetworkInfo)> for Transaction__Type {
     fn try_from(value: (u32, NetworkInfo)) -> Result<Self, Self::Error> {
         crate::sys::zemu_log_stack("TransactionType::TryFrom\x00");
         let tx_type = match value.0 {
+            #[cfg(feature = "pvm")]
             PVM_EXPORT_TX => Transaction__Type::PExport,
+            #[cfg(feature = "pvm")]
             PVM_IMPORT_TX => Transaction__Type::PImport,
+            #[cfg(feature = "pvm")]
+            PVM_BASE_TX => Transaction__Type::PTransfer,
+            #[cfg(feature = "avm")]
             AVM_EXPORT_TX => Transaction__Type::XExport,
+            #[cfg(feature = "avm")]
             AVM_IMPORT_TX => Transaction__Type::XImport,
+            #[cfg(feature = "avm")]
             AVM_OPERATION_TX => Transaction__Type::XOperation,
             // avoid collision with evm_export tx in C-chain
             // avoid collision with createAsset tx in X-chain
+            #[cfg(feature = "evm")]
             EVM_EXPORT_TX if matches!(value.1.chain_id, ChainId::CChain) => {
                 Transaction__Type::CExport
             }
             // avoid collision with normal_transfer tx in X-chain/P-chain
+            #[cfg(feature = "evm")]
             EVM_IMPORT_TX if matches!(value.1.chain_id, ChainId::CChain) => {
                 Transaction__Type::CImport
             }
+            #[cfg(any(feature = "avm", feature = "pvm"))]
             TRANSFER_TX => Transaction__Type::Transfer,
             #[cfg(feature = "create-asset")]
             AVM_CREATE_ASSET_TX if matches!(value.1.chain_id, ChainId::XChain) => {
@@ -117,8 +166,16 @@ impl TryFrom<(u32, NetworkInfo)> for Transaction__Type {
             PVM_CREATE_SUBNET => Transaction__Type::CreateSubnet,
             #[cfg(feature = "add-validator")]
             PVM_ADD_VALIDATOR => Transaction__Type::Validator,
+            #[cfg(feature = "add-permissionless-validator")]
+            PVM_ADD_PERMISSIONLESS_VALIDATOR => Transaction__Type::PermissionlessValidator,
+            #[cfg(feature = "add-permissionless-delegator")]
+            PVM_ADD_PERMISSIONLESS_DELEGATOR => Transaction__Type::PermissionlessDelegator,
             #[cfg(feature = "add-subnet-validator")]
             PVM_ADD_SUBNET_VALIDATOR => Transaction__Type::SubnetValidator,
+            #[cfg(feature = "transform-subnet")]
+            PVM_TRANSFORM_SUBNET => Transaction__Type::TransformSubnet,
+            #[cfg(feature = "remove-subnet-validator")]
+            PVM_REMOVE_SUBNET_VALIDATOR => Transaction__Type::RemoveSubnetValidator,
             _ => return Err(ParserError::InvalidTransactionType),
         };
 
@@ -130,18 +187,32 @@ impl TryFrom<(u32, NetworkInfo)> for Transaction__Type {
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(test, derive(Debug))]
 pub enum Transaction<'b> {
+    #[cfg(feature = "avm")]
     XImport(AvmImportTx<'b>),
+    #[cfg(feature = "avm")]
     XExport(AvmExportTx<'b>),
+    #[cfg(feature = "avm")]
     XOperation(OperationTx<'b>),
+    #[cfg(feature = "pvm")]
     PImport(PvmImportTx<'b>),
+    #[cfg(feature = "pvm")]
     PExport(PvmExportTx<'b>),
+    #[cfg(feature = "pvm")]
+    PTransfer(PvmBaseTx<'b>),
+    #[cfg(feature = "evm")]
     CImport(EvmImport<'b>),
+    #[cfg(feature = "evm")]
     CExport(EvmExport<'b>),
+    #[cfg(any(feature = "avm", feature = "pvm"))]
     Transfer(Transfer<'b>),
     #[cfg(feature = "create-asset")]
     XAsset(CreateAssetTx<'b>),
     #[cfg(feature = "add-validator")]
     Validator(AddValidatorTx<'b>),
+    #[cfg(feature = "add-permissionless-validator")]
+    PermissionlessValidator(AddPermissionlessValidatorTx<'b>),
+    #[cfg(feature = "add-permissionless-delegator")]
+    PermissionlessDelegator(AddPermissionlessDelegatorTx<'b>),
     #[cfg(feature = "add-delegator")]
     Delegator(AddDelegatorTx<'b>),
     #[cfg(feature = "create-chain")]
@@ -150,6 +221,10 @@ pub enum Transaction<'b> {
     CreateSubnet(CreateSubnetTx<'b>),
     #[cfg(feature = "add-subnet-validator")]
     SubnetValidator(AddSubnetValidatorTx<'b>),
+    #[cfg(feature = "transform-subnet")]
+    TransformSubnet(TransformSubnetTx<'b>),
+    #[cfg(feature = "remove-subnet-validator")]
+    RemoveSubnetValidator(RemoveSubnetValidatorTx<'b>),
 }
 
 impl<'b> Transaction<'b> {
@@ -162,6 +237,13 @@ impl<'b> Transaction<'b> {
         Ok((raw_tx_id, network_info))
     }
 
+    /// The network and chain of the serialized transaction in `input`
+    pub fn network_info(input: &'b [u8]) -> Result<NetworkInfo, ParserError> {
+        let (rem, _) = be_u16::<_, ParserError>(input)?;
+        let (_, info) = Self::peek_transaction_info(rem)?;
+        Ok(info)
+    }
+
     #[cfg(test)]
     pub fn new(input: &'b [u8]) -> Result<Self, ParserError> {
         let mut variant = MaybeUninit::uninit();
@@ -178,27 +260,144 @@ impl<'b> Transaction<'b> {
         }
         Self::parse(rem, this)?;
 
+        // reject what can not be navigated on the device
+        // instead of truncating its number of items
+        let tx = unsafe { &*this.as_ptr() };
+        if tx.num_items() > MAX_UI_ITEMS {
+            return Err(ParserError::TooManyItems);
+        }
+
         Ok(())
     }
 
+    /// Every avalanche transaction, atomic C-chain ones included,
+    /// is signed over the SHA-256 of its serialized bytes
+    pub fn digest_scheme(&self) -> DigestScheme {
+        DigestScheme::Sha256
+    }
+
     pub fn disable_output_if(&mut self, address: &[u8]) {
         match self {
+            #[cfg(feature = "avm")]
             Self::XImport(tx) => tx.disable_output_if(address),
+            #[cfg(feature = "avm")]
             Self::XExport(tx) => tx.disable_output_if(address),
+            #[cfg(feature = "avm")]
             Self::XOperation(tx) => tx.disable_output_if(address),
+            #[cfg(feature = "pvm")]
             Self::PImport(tx) => tx.disable_output_if(address),
+            #[cfg(feature = "pvm")]
             Self::PExport(tx) => tx.disable_output_if(address),
+            #[cfg(feature = "pvm")]
+            Self::PTransfer(tx) => tx.disable_output_if(address),
+            #[cfg(any(feature = "avm", feature = "pvm"))]
             Self::Transfer(tx) => tx.disable_output_if(address),
+            #[cfg(feature = "evm")]
             Self::CImport(tx) => tx.disable_output_if(address),
+            #[cfg(feature = "evm")]
             Self::CExport(tx) => tx.disable_output_if(address),
             #[cfg(feature = "add-validator")]
             Self::Validator(tx) => tx.disable_output_if(address),
+            #[cfg(feature = "add-permissionless-validator")]
+            Self::PermissionlessValidator(tx) => tx.disable_output_if(address),
+            #[cfg(feature = "add-permissionless-delegator")]
+            Self::PermissionlessDelegator(tx) => tx.disable_output_if(address),
             #[cfg(feature = "add-delegator")]
             Self::Delegator(tx) => tx.disable_output_if(address),
             _ => {}
         }
     }
 
+    // Returns None for C-chain transactions
+    pub fn summary(&'b self) -> Option<TxSummary> {
+        let summary = match self {
+            #[cfg(feature = "avm")]
+            Self::XImport(tx) => tx.summary(),
+            #[cfg(feature = "avm")]
+            Self::XExport(tx) => tx.summary(),
+            #[cfg(feature = "avm")]
+            Self::XOperation(tx) => tx.base_tx.summary(),
+            #[cfg(feature = "pvm")]
+            Self::PImport(tx) => tx.summary(),
+            #[cfg(feature = "pvm")]
+            Self::PExport(tx) => tx.summary(),
+            #[cfg(feature = "pvm")]
+            Self::PTransfer(tx) => tx.summary(),
+            #[cfg(any(feature = "avm", feature = "pvm"))]
+            Self::Transfer(tx) => tx.summary(),
+            #[cfg(feature = "evm")]
+            Self::CImport(_) | Self::CExport(_) => return None,
+            #[cfg(feature = "create-asset")]
+            Self::XAsset(tx) => tx.base_tx.summary(),
+            #[cfg(feature = "add-validator")]
+            Self::Validator(tx) => tx.summary(),
+            #[cfg(feature = "add-permissionless-validator")]
+            Self::PermissionlessValidator(tx) => tx.summary(),
+            #[cfg(feature = "add-permissionless-delegator")]
+            Self::PermissionlessDelegator(tx) => tx.summary(),
+            #[cfg(feature = "add-delegator")]
+            Self::Delegator(tx) => tx.summary(),
+            #[cfg(feature = "create-chain")]
+            Self::CreateChain(tx) => tx.base_tx.summary(),
+            #[cfg(feature = "create-subnet")]
+            Self::CreateSubnet(tx) => tx.base_tx.summary(),
+            #[cfg(feature = "add-subnet-validator")]
+            Self::SubnetValidator(tx) => tx.base_tx.summary(),
+            #[cfg(feature = "transform-subnet")]
+            Self::TransformSubnet(tx) => tx.base_tx.summary(),
+            #[cfg(feature = "remove-subnet-validator")]
+            Self::RemoveSubnetValidator(tx) => tx.base_tx.summary(),
+        };
+
+        summary.ok()
+    }
+
+    pub fn utxos(&self) -> Option<UtxoList<'b>> {
+        let utxos = match self {
+            #[cfg(feature = "avm")]
+            Self::XImport(tx) => tx.utxos(),
+            #[cfg(feature = "avm")]
+            Self::XExport(tx) => tx.utxos(),
+            #[cfg(feature = "avm")]
+            Self::XOperation(tx) => tx.base_tx.utxos(),
+            #[cfg(feature = "pvm")]
+            Self::PImport(tx) => tx.utxos(),
+            #[cfg(feature = "pvm")]
+            Self::PExport(tx) => tx.utxos(),
+            #[cfg(feature = "pvm")]
+            Self::PTransfer(tx) => tx.base_tx.utxos(),
+            #[cfg(any(feature = "avm", feature = "pvm"))]
+            Self::Transfer(tx) => tx.utxos(),
+            #[cfg(feature = "evm")]
+            Self::CImport(tx) => tx.utxos(),
+            // inputs of C-chain exports are account balances
+            #[cfg(feature = "evm")]
+            Self::CExport(_) => return None,
+            #[cfg(feature = "create-asset")]
+            Self::XAsset(tx) => tx.base_tx.utxos(),
+            #[cfg(feature = "add-validator")]
+            Self::Validator(tx) => tx.base_tx.utxos(),
+            #[cfg(feature = "add-permissionless-validator")]
+            Self::PermissionlessValidator(tx) => tx.base_tx.utxos(),
+            #[cfg(feature = "add-permissionless-delegator")]
+            Self::PermissionlessDelegator(tx) => tx.base_tx.utxos(),
+            #[cfg(feature = "add-delegator")]
+            Self::Delegator(tx) => tx.base_tx.utxos(),
+            #[cfg(feature = "create-chain")]
+            Self::CreateChain(tx) => tx.base_tx.utxos(),
+            #[cfg(feature = "create-subnet")]
+            Self::CreateSubnet(tx) => tx.base_tx.utxos(),
+            #[cfg(feature = "add-subnet-validator")]
+            Self::SubnetValidator(tx) => tx.base_tx.utxos(),
+            #[cfg(feature = "transform-subnet")]
+            Self::TransformSubnet(tx) => tx.base_tx.utxos(),
+            #[cfg(feature = "remove-subnet-validator")]
+            Self::RemoveSubnetValidator(tx) => tx.base_tx.utxos(),
+        };
+
+        Some(utxos)
+    }
+
     fn parse(
         input: &'b [u8],
         out: &mut MaybeUninit<Self>,
@@ -207,6 +406,7 @@ impl<'b> Transaction<'b> {
         let transaction_type = Transaction__Type::try_from(info)?;
 
         let rem = match transaction_type {
+            #[cfg(feature = "pvm")]
             Transaction__Type::PImport => {
                 let out = out.as_mut_ptr() as *mut PImport__Variant;
                 //valid pointer
@@ -221,6 +421,7 @@ impl<'b> Transaction<'b> {
 
                 rem
             }
+            #[cfg(feature = "pvm")]
             Transaction__Type::PExport => {
                 let out = out.as_mut_ptr() as *mut PExport__Variant;
                 //valid pointer
@@ -235,6 +436,22 @@ impl<'b> Transaction<'b> {
 
                 rem
             }
+            #[cfg(feature = "pvm")]
+            Transaction__Type::PTransfer => {
+                let out = out.as_mut_ptr() as *mut PTransfer__Variant;
+                //valid pointer
+                let data = unsafe { &mut *addr_of_mut!((*out).1).cast() };
+
+                let rem = PvmBaseTx::from_bytes_into(input, data)?;
+
+                //pointer is valid
+                unsafe {
+                    addr_of_mut!((*out).0).write(Transaction__Type::PTransfer);
+                }
+
+                rem
+            }
+            #[cfg(feature = "avm")]
             Transaction__Type::XImport => {
                 let out = out.as_mut_ptr() as *mut XImport__Variant;
                 //valid pointer
@@ -249,6 +466,7 @@ impl<'b> Transaction<'b> {
 
                 rem
             }
+            #[cfg(feature = "avm")]
             Transaction__Type::XExport => {
                 let out = out.as_mut_ptr() as *mut XExport__Variant;
                 //valid pointer
@@ -263,6 +481,7 @@ impl<'b> Transaction<'b> {
 
                 rem
             }
+            #[cfg(feature = "avm")]
             Transaction__Type::XOperation => {
                 let out = out.as_mut_ptr() as *mut XOperation__Variant;
                 //valid pointer
@@ -277,6 +496,7 @@ impl<'b> Transaction<'b> {
 
                 rem
             }
+            #[cfg(feature = "evm")]
             Transaction__Type::CExport => {
                 let out = out.as_mut_ptr() as *mut CExport__Variant;
                 //valid pointer
@@ -291,6 +511,7 @@ impl<'b> Transaction<'b> {
 
                 rem
             }
+            #[cfg(feature = "evm")]
             Transaction__Type::CImport => {
                 let out = out.as_mut_ptr() as *mut CImport__Variant;
                 //valid pointer
@@ -305,6 +526,7 @@ impl<'b> Transaction<'b> {
 
                 rem
             }
+            #[cfg(any(feature = "avm", feature = "pvm"))]
             Transaction__Type::Transfer => {
                 let out = out.as_mut_ptr() as *mut Transfer__Variant;
                 //valid pointer
@@ -379,6 +601,36 @@ impl<'b> Transaction<'b> {
 
                 rem
             }
+            #[cfg(feature = "add-permissionless-validator")]
+            Transaction__Type::PermissionlessValidator => {
+                let out = out.as_mut_ptr() as *mut PermissionlessValidator__Variant;
+                //valid pointer
+                let data = unsafe { &mut *addr_of_mut!((*out).1).cast() };
+
+                let rem = AddPermissionlessValidatorTx::from_bytes_into(input, data)?;
+
+                //pointer is valid
+                unsafe {
+                    addr_of_mut!((*out).0).write(Transaction__Type::PermissionlessValidator);
+                }
+
+                rem
+            }
+            #[cfg(feature = "add-permissionless-delegator")]
+            Transaction__Type::PermissionlessDelegator => {
+                let out = out.as_mut_ptr() as *mut PermissionlessDelegator__Variant;
+                //valid pointer
+                let data = unsafe { &mut *addr_of_mut!((*out).1).cast() };
+
+                let rem = AddPermissionlessDelegatorTx::from_bytes_into(input, data)?;
+
+                //pointer is valid
+                unsafe {
+                    addr_of_mut!((*out).0).write(Transaction__Type::PermissionlessDelegator);
+                }
+
+                rem
+            }
             #[cfg(feature = "add-delegator")]
             Transaction__Type::Delegator => {
                 let out = out.as_mut_ptr() as *mut Delegator__Variant;
@@ -407,6 +659,36 @@ impl<'b> Transaction<'b> {
                     addr_of_mut!((*out).0).write(Transaction__Type::SubnetValidator);
                 }
 
+                rem
+            }
+            #[cfg(feature = "transform-subnet")]
+            Transaction__Type::TransformSubnet => {
+                let out = out.as_mut_ptr() as *mut TransformSubnet__Variant;
+                //valid pointer
+                let data = unsafe { &mut *addr_of_mut!((*out).1).cast() };
+
+                let rem = TransformSubnetTx::from_bytes_into(input, data)?;
+
+                //pointer is valid
+                unsafe {
+                    addr_of_mut!((*out).0).write(Transaction__Type::TransformSubnet);
+                }
+
+                rem
+            }
+            #[cfg(feature = "remove-subnet-validator")]
+            Transaction__Type::RemoveSubnetValidator => {
+                let out = out.as_mut_ptr() as *mut RemoveSubnetValidator__Variant;
+                //valid pointer
+                let data = unsafe { &mut *addr_of_mut!((*out).1).cast() };
+
+                let rem = RemoveSubnetValidatorTx::from_bytes_into(input, data)?;
+
+                //pointer is valid
+                unsafe {
+                    addr_of_mut!((*out).0).write(Transaction__Type::RemoveSubnetValidator);
+                }
+
                 rem
             }
         };
@@ -416,28 +698,52 @@ impl<'b> Transaction<'b> {
     }
 
     // Returns True if transaction is one of the supported coreth transactions.
+    #[cfg(feature = "evm")]
     pub fn is_eth(&self) -> bool {
         matches!(self, Self::CExport(_)) || matches!(self, Self::CImport(_))
     }
+
+    #[cfg(not(feature = "evm"))]
+    pub fn is_eth(&self) -> bool {
+        false
+    }
 }
 
 impl<'b> DisplayableItem for Transaction<'b> {
     fn num_items(&self) -> usize {
         match self {
+            #[cfg(feature = "avm")]
             Self::XImport(tx) => tx.num_items(),
+            #[cfg(feature = "avm")]
             Self::XExport(tx) => tx.num_items(),
+            #[cfg(feature = "avm")]
             Self::XOperation(tx) => tx.num_items(),
+            #[cfg(feature = "pvm")]
             Self::PImport(tx) => tx.num_items(),
+            #[cfg(feature = "pvm")]
             Self::PExport(tx) => tx.num_items(),
+            #[cfg(feature = "pvm")]
+            Self::PTransfer(tx) => tx.num_items(),
+            #[cfg(feature = "evm")]
             Self::CImport(tx) => tx.num_items(),
+            #[cfg(feature = "evm")]
             Self::CExport(tx) => tx.num_items(),
+            #[cfg(any(feature = "avm", feature = "pvm"))]
             Self::Transfer(tx) => tx.num_items(),
             #[cfg(feature = "create-asset")]
             Self::XAsset(tx) => tx.num_items(),
             #[cfg(feature = "add-validator")]
             Self::Validator(tx) => tx.num_items(),
+            #[cfg(feature = "add-permissionless-validator")]
+            Self::PermissionlessValidator(tx) => tx.num_items(),
+            #[cfg(feature = "add-permissionless-delegator")]
+            Self::PermissionlessDelegator(tx) => tx.num_items(),
             #[cfg(feature = "add-subnet-validator")]
             Self::SubnetValidator(tx) => tx.num_items(),
+            #[cfg(feature = "transform-subnet")]
+            Self::TransformSubnet(tx) => tx.num_items(),
+            #[cfg(feature = "remove-subnet-validator")]
+            Self::RemoveSubnetValidator(tx) => tx.num_items(),
             #[cfg(feature = "add-delegator")]
             Self::Delegator(tx) => tx.num_items(),
             #[cfg(feature = "create-chain")]
@@ -455,20 +761,38 @@ impl<'b> DisplayableItem for Transaction<'b> {
         page: u8,
     ) -> Result<u8, zemu_sys::ViewError> {
         match self {
+            #[cfg(feature = "avm")]
             Self::XImport(tx) => tx.render_item(item_n, title, message, page),
+            #[cfg(feature = "avm")]
             Self::XExport(tx) => tx.render_item(item_n, title, message, page),
+            #[cfg(feature = "avm")]
             Self::XOperation(tx) => tx.render_item(item_n, title, message, page),
+            #[cfg(feature = "pvm")]
             Self::PImport(tx) => tx.render_item(item_n, title, message, page),
+            #[cfg(feature = "pvm")]
             Self::PExport(tx) => tx.render_item(item_n, title, message, page),
+            #[cfg(feature = "pvm")]
+            Self::PTransfer(tx) => tx.render_item(item_n, title, message, page),
+            #[cfg(feature = "evm")]
             Self::CImport(tx) => tx.render_item(item_n, title, message, page),
+            #[cfg(feature = "evm")]
             Self::CExport(tx) => tx.render_item(item_n, title, message, page),
+            #[cfg(any(feature = "avm", feature = "pvm"))]
             Self::Transfer(tx) => tx.render_item(item_n, title, message, page),
             #[cfg(feature = "create-asset")]
             Self::XAsset(tx) => tx.render_item(item_n, title, message, page),
             #[cfg(feature = "add-validator")]
             Self::Validator(tx) => tx.render_item(item_n, title, message, page),
+            #[cfg(feature = "add-permissionless-validator")]
+            Self::PermissionlessValidator(tx) => tx.render_item(item_n, title, message, page),
+            #[cfg(feature = "add-permissionless-delegator")]
+            Self::PermissionlessDelegator(tx) => tx.render_item(item_n, title, message, page),
             #[cfg(feature = "add-subnet-validator")]
             Self::SubnetValidator(tx) => tx.render_item(item_n, title, message, page),
+            #[cfg(feature = "transform-subnet")]
+            Self::TransformSubnet(tx) => tx.render_item(item_n, title, message, page),
+            #[cfg(feature = "remove-subnet-validator")]
+            Self::RemoveSubnetValidator(tx) => tx.render_item(item_n, title, message, page),
             #[cfg(feature = "add-delegator")]
             Self::Delegator(tx) => tx.render_item(item_n, title, message, page),
             #[cfg(feature = "create-chain")]
@@ -541,6 +865,109 @@ mod tests {
         assert!(matches!(tx, Transaction::Transfer(..)));
     }
 
+    #[test]
+    fn utxo_pages() {
+        let data = hex::decode(DATA).unwrap();
+        let tx = Transaction::new(&data).unwrap();
+
+        let utxos = tx.utxos().unwrap();
+        assert_eq!(utxos.num_items(), 2);
+
+        let expected: [(&[u8], &[u8]); 2] = [
+            (
+                b"Input 1\0",
+                b"TNvCMpSdMUiVWSaFtU1u8zMJvfV588D5mgm4RebLdmGvpmh6z:0, 0.099 AVAX, indices 0\0",
+            ),
+            (
+                b"Input 2\0",
+                b"28vZbCw7CW2fcQcsFd18cXBCHqxfzCNGs5U9WLTxoswfiao6zQ:0, 5 AVAX, indices 0\0",
+            ),
+        ];
+
+        for (i, (title_exp, message_exp)) in expected.iter().enumerate() {
+            let mut title = [0; 18];
+            let mut message = [0; 128];
+            utxos
+                .render_item(i as u8, &mut title, &mut message, 0)
+                .unwrap();
+            assert_eq!(&title[..title_exp.len()], *title_exp);
+            assert_eq!(&message[..message_exp.len()], *message_exp);
+        }
+
+        assert!(utxos
+            .render_item(2, &mut [0; 18], &mut [0; 128], 0)
+            .is_err());
+    }
+
+    #[test]
+    fn too_many_items() {
+        const HEADER: &str =
+            "00000000000000000005ab68eb1ee142a05cfe768c36e11f0b596db5a3c6c77aabe665dad9e638ca94f7";
+        const ASSET: &str = "3d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa";
+        // the inputs and memo of DATA
+        const INPUTS: &str = "000000023be4ead93aa5e6396d1f2c6e9587c7642b30d52d605f917d8a402e6823f965f2000000003d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa000000050000000005e69ec0000000010000000095aff4ba72647c2a6a41802c047a9f3a3919b35aefcf7da843d4cc34980c7102000000003d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa00000005000000012a05f200000000010000000000000000";
+
+        // 64 outputs with 3 addresses each
+        // require more items than a u8 can index
+        let mut data = hex::decode(HEADER).unwrap();
+        data.extend_from_slice(&64u32.to_be_bytes());
+        for amount in 1..=64u64 {
+            data.extend(hex::decode(ASSET).unwrap());
+            data.extend_from_slice(&7u32.to_be_bytes());
+            data.extend_from_slice(&amount.to_be_bytes());
+            // locktime, threshold and number of addresses
+            data.extend_from_slice(&0u64.to_be_bytes());
+            data.extend_from_slice(&1u32.to_be_bytes());
+            data.extend_from_slice(&3u32.to_be_bytes());
+            for addr in 1..=3u8 {
+                data.extend_from_slice(&[addr; 20]);
+            }
+        }
+        data.extend(hex::decode(INPUTS).unwrap());
+
+        assert_eq!(
+            Transaction::new(&data).err(),
+            Some(ParserError::TooManyItems)
+        );
+    }
+
+    #[test]
+    fn transaction_summary() {
+        let data = hex::decode(DATA).unwrap();
+        let change_address = hex::decode(CHANGE_ADDRESS).unwrap();
+
+        let mut tx = Transaction::new(&data).unwrap();
+
+        let summary = tx.summary().unwrap();
+        assert_eq!(summary.inputs, 2);
+        assert_eq!(summary.outputs, 2);
+        assert_eq!(summary.amount, 1_000_000_000 + 4_098_000_000);
+
+        // the change output is still counted but
+        // its amount is not
+        tx.disable_output_if(&change_address);
+
+        let summary = tx.summary().unwrap();
+        assert_eq!(summary.inputs, 2);
+        assert_eq!(summary.outputs, 2);
+        assert_eq!(summary.amount, 1_000_000_000);
+    }
+
+    #[test]
+    fn summary_only_sums_avax() {
+        const AVAX: &str = "3d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa";
+
+        // the first output moves another asset
+        let data = DATA.replacen(AVAX, &"22".repeat(32), 1);
+        let data = hex::decode(data).unwrap();
+
+        let tx = Transaction::new(&data).unwrap();
+
+        let summary = tx.summary().unwrap();
+        assert_eq!(summary.outputs, 2);
+        assert_eq!(summary.amount, 4_098_000_000);
+    }
+
     #[test]
     #[cfg(feature = "full")]
     //isolation is enabled by defalt in miri
@@ -552,7 +979,10 @@ mod tests {
     // we can just avoid having it run in miri directly
     #[cfg_attr(miri, ignore)]
     fn tx_ui() {
-        use crate::parser::snapshots_common::{with_leaked, ReducedPage};
+        use crate::parser::{
+            host::render_all,
+            snapshots_common::{with_leaked, ReducedPage},
+        };
 
         insta::glob!("testvectors/*.json", |path| {
             let file = std::fs::File::open(path)
@@ -561,7 +991,8 @@ mod tests {
                 .unwrap_or_else(|e| panic!("Unable to read file {:?} as json: {:?}", path, e));
 
             let test = |data| {
-                let tx = Transaction::new(data).expect("parse tx from data");
+                let mut tx = Transaction::new(data).expect("parse tx from data");
+                let pages = render_all::<_, 18, 1024>(&mut tx);
 
                 let mut driver = zuit::MockDriver::<_, 18, 1024>::new(tx);
                 driver.drive();
@@ -572,6 +1003,7 @@ mod tests {
                     .iter()
                     .flat_map(|item| item.iter().map(ReducedPage::from))
                     .collect::<Vec<_>>();
+                assert_eq!(pages, reduced.len());
 
                 insta::assert_debug_snapshot!(reduced);
             };
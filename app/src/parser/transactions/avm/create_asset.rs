@@ -23,11 +23,14 @@ use crate::{
     handlers::handle_ui_message,
     parser::{
         u8_to_str, AvmOutput, BaseTxFields, DisplayableItem, FromBytes, Header, InitialState,
-        ObjectList, ParserError, AVM_CREATE_ASSET_TX,
+        ObjectList, ParserError, AVM_CREATE_ASSET_TX, MAX_ASSET_DENOMINATION,
     },
 };
 
+// AVM limits
+const MIN_NAME_LEN: usize = 1;
 const MAX_NAME_LEN: usize = 128;
+const MIN_SYMBOL_LEN: usize = 1;
 const MAX_SYMBOL_LEN: usize = 4;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -82,7 +85,7 @@ impl<'b> FromBytes<'b> for CreateAssetTx<'b> {
 
         // name
         let (rem, name_len) = be_u16(rem)?;
-        if name_len > MAX_NAME_LEN as _ {
+        if !(MIN_NAME_LEN..=MAX_NAME_LEN).contains(&(name_len as usize)) {
             return Err(ParserError::ValueOutOfRange.into());
         }
         let (rem, name) = take(name_len as usize)(rem)?;
@@ -92,15 +95,22 @@ impl<'b> FromBytes<'b> for CreateAssetTx<'b> {
 
         // symbol
         let (rem, sym_len) = be_u16(rem)?;
-        if sym_len > MAX_SYMBOL_LEN as _ {
+        if !(MIN_SYMBOL_LEN..=MAX_SYMBOL_LEN).contains(&(sym_len as usize)) {
             return Err(ParserError::ValueOutOfRange.into());
         }
         let (rem, sym) = take(sym_len as usize)(rem)?;
-        if !sym.is_ascii() {
+        // only uppercase alphanumeric symbols are allowed
+        if !sym
+            .iter()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        {
             return Err(ParserError::InvalidAsciiValue.into());
         }
 
         let (rem, denomination) = be_u8(rem)?;
+        if denomination > MAX_ASSET_DENOMINATION {
+            return Err(ParserError::ValueOutOfRange.into());
+        }
 
         // initial_states
         let states = unsafe { &mut *addr_of_mut!((*out).initial_states).cast() };
@@ -117,8 +127,9 @@ impl<'b> FromBytes<'b> for CreateAssetTx<'b> {
 
 impl<'b> DisplayableItem for CreateAssetTx<'b> {
     fn num_items(&self) -> usize {
-        // description + asset_name + asset_symbol + denomination + fee
-        1 + 1 + 1 + 1 + 1
+        // description + asset_name + asset_symbol + denomination +
+        // initial_states + memo + fee
+        1 + 1 + 1 + 1 + self.initial_states.iter().count() + self.base_tx.memo_num_items() + 1
     }
 
     fn render_item(
@@ -132,6 +143,8 @@ impl<'b> DisplayableItem for CreateAssetTx<'b> {
         use lexical_core::Number;
 
         let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 2];
+        let num_states = self.initial_states.iter().count();
+        let memo_items = self.base_tx.memo_num_items();
 
         match item_n {
             0 => {
@@ -161,7 +174,26 @@ impl<'b> DisplayableItem for CreateAssetTx<'b> {
                 handle_ui_message(num, message, page)
             }
 
-            4 => {
+            x @ 4.. if (x as usize) < 4 + num_states => {
+                let state_idx = x as usize - 4;
+                let mut idx = 0;
+                let state = self
+                    .initial_states
+                    .get_obj_if(|_| {
+                        let found = idx == state_idx;
+                        idx += 1;
+                        found
+                    })
+                    .ok_or(ViewError::NoData)?;
+
+                state.render_item(0, title, message, page)
+            }
+
+            x if (x as usize) < 4 + num_states + memo_items => {
+                self.base_tx.render_memo(title, message, page)
+            }
+
+            x if x as usize == 4 + num_states + memo_items => {
                 let label = pic_str!(b"Fee(AVAX)");
                 title[..label.len()].copy_from_slice(label);
 
@@ -216,5 +248,35 @@ mod tests {
         assert_eq!(name, "Volatility Index");
         assert_eq!(symbol, "VIX");
         assert_eq!(tx.denomination, 2);
+        assert_eq!(tx.initial_states.iter().count(), 1);
+    }
+
+    #[test]
+    fn initial_holders() {
+        let (_, tx) = CreateAssetTx::from_bytes(DATA).unwrap();
+        let state = tx.initial_states.iter().next().unwrap();
+        assert_eq!(state.num_holders(), 2);
+    }
+
+    #[test]
+    fn invalid_symbol() {
+        let sym_at = DATA.windows(3).position(|w| w == b"VIX").unwrap();
+
+        let mut data = DATA.to_vec();
+        data[sym_at..sym_at + 3].copy_from_slice(b"ViX");
+        assert!(CreateAssetTx::from_bytes(&data).is_err());
+
+        let mut data = DATA.to_vec();
+        data[sym_at..sym_at + 3].copy_from_slice(b"V-X");
+        assert!(CreateAssetTx::from_bytes(&data).is_err());
+    }
+
+    #[test]
+    fn invalid_denomination() {
+        let sym_at = DATA.windows(3).position(|w| w == b"VIX").unwrap();
+
+        let mut data = DATA.to_vec();
+        data[sym_at + 3] = MAX_ASSET_DENOMINATION + 1;
+        assert!(CreateAssetTx::from_bytes(&data).is_err());
     }
 }
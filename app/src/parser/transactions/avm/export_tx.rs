@@ -22,7 +22,7 @@ use crate::{
     handlers::handle_ui_message,
     parser::{
         nano_avax_to_fp_str, AvmOutput, BaseExport, DisplayableItem, FromBytes, ParserError,
-        AVM_EXPORT_TX,
+        TxSummary, UtxoList, AVM_EXPORT_TX,
     },
 };
 
@@ -55,8 +55,8 @@ impl<'b> DisplayableItem for AvmExportTx<'b> {
         // only support SECP256k1 outputs
         // and to keep compatibility with the legacy app,
         // we show only 4 items for each output
-        // chains info, amount, address and fee which is the sum of all inputs minus all outputs
-        1 + self.0.num_outputs_items() + 1
+        // chains info, amount, address, memo and fee which is the sum of all inputs minus all outputs
+        1 + self.0.num_outputs_items() + self.0.base_tx.memo_num_items() + 1
     }
 
     fn render_item(
@@ -74,14 +74,16 @@ impl<'b> DisplayableItem for AvmExportTx<'b> {
             return self.0.render_export_description(title, message, page);
         }
 
-        let outputs_num_items = self.0.num_outputs_items();
+        let outputs_num_items = self.0.num_outputs_items() as u8;
+        let memo_items = self.0.base_tx.memo_num_items() as u8;
         let new_item_n = item_n - 1;
 
         match new_item_n {
-            x @ 0.. if x < outputs_num_items as u8 => {
-                self.0.render_outputs(x, title, message, page)
+            x @ 0.. if x < outputs_num_items => self.0.render_outputs(x, title, message, page),
+            x if x < outputs_num_items + memo_items => {
+                self.0.base_tx.render_memo(title, message, page)
             }
-            x if x == outputs_num_items as u8 => {
+            x if x == outputs_num_items + memo_items => {
                 let title_content = pic_str!(b"Fee(AVAX)");
                 title[..title_content.len()].copy_from_slice(title_content);
                 let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 2];
@@ -103,12 +105,21 @@ impl<'b> AvmExportTx<'b> {
     pub fn disable_output_if(&mut self, address: &[u8]) {
         self.0.disable_output_if(address);
     }
+
+    pub fn summary(&'b self) -> Result<TxSummary, ParserError> {
+        self.0.summary()
+    }
+
+    pub fn utxos(&self) -> UtxoList<'b> {
+        self.0.base_tx.utxos()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::parser::ChainId;
+    use std::prelude::v1::*;
 
     const DATA: &[u8] = &[
         0, 0, 0, 4, 0, 0, 0, 1, 237, 95, 56, 52, 30, 67, 110, 93, 70, 226, 187, 0, 180, 93, 98,
@@ -147,4 +158,70 @@ mod tests {
         let fee = tx.fee().unwrap();
         assert_eq!(fee, 1000);
     }
+
+    #[test]
+    fn export_outputs_summary() {
+        // DATA, spending 1500 more, with the export outputs replaced by
+        // 5 AVAX outputs to the addresses A, A, B, A and B + C
+        const HEADER: &str = "0000000400000001ed5f38341e436e5d46e2bb00b45d62ae97d1b050c64bc634ae10626739e35c4b000000010202020202020202020202020202020202020202020202020202020202020202000000070000000000001964000000000000000c00000001000000019d1f34bc3a6f2306ca079016aef85c131767f2380000000107070707070707070707070707070707070707070707070707070707070707070000000208080808080808080808080808080808080808080808080808080808080808080000000500000000000023280000000a00000004000000050000003a000000010000004f00000041000000570000005e0000007d0000017a000000046d656d6f0427d4b22a2a78bcddd456742caf91b56badbff985ee19aef14573e7343fd652";
+        const OUTPUTS: &str = "0000000521e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff000000070000000000000064000000000000000000000001000000019d1f34bc3a6f2306ca079016aef85c131767f23821e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff0000000700000000000000c8000000000000000000000001000000019d1f34bc3a6f2306ca079016aef85c131767f23821e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff00000007000000000000012c00000000000000000000000100000001111111111111111111111111111111111111111121e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff000000070000000000000190000000000000000000000001000000019d1f34bc3a6f2306ca079016aef85c131767f23821e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff0000000700000000000001f40000000000000000000000010000000211111111111111111111111111111111111111112222222222222222222222222222222222222222";
+
+        let mut data = hex::decode(HEADER).unwrap();
+        data.extend(hex::decode(OUTPUTS).unwrap());
+
+        let (rem, tx) = AvmExportTx::from_bytes(&data).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(tx.fee().unwrap(), 1000);
+
+        let total = tx.0.sum_export_outputs_amount().unwrap();
+        assert_eq!(total, 100 + 200 + 300 + 400 + 500);
+
+        let render = |expert: bool, item_n: u8| {
+            let mut title = [0; 18];
+            let mut message = [0; 100];
+            tx.0.render_outputs_with(expert, item_n, &mut title, &mut message, 0)
+                .unwrap();
+
+            let title_len = title.iter().position(|b| *b == 0).unwrap();
+            let message_len = message.iter().position(|b| *b == 0).unwrap();
+            (
+                String::from_utf8(title[..title_len].to_vec()).unwrap(),
+                String::from_utf8(message[..message_len].to_vec()).unwrap(),
+            )
+        };
+
+        let summary = (
+            String::from("Summary"),
+            String::from("Export 5 outputs, total 0.0000015 AVAX to C Chain"),
+        );
+
+        // each output follows the summary in expert mode,
+        // with its amount and then its addresses
+        assert_eq!(tx.0.num_outputs_items_with(true), 1 + 5 * 2 + 1);
+        assert_eq!(render(true, 0), summary);
+        assert_eq!(render(true, 1).0, "Amount");
+        assert_eq!(render(true, 11).0, "Address");
+
+        // otherwise, only every address once
+        let addresses = [
+            "avax1n50nf0p6du3sdjs8jqt2a7zuzvtk0u3cktdupp",
+            "avax1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg35l6ttj",
+            "avax1yg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zmzldr9",
+        ];
+        assert_eq!(tx.0.num_outputs_items_with(false), 1 + addresses.len());
+        assert_eq!(render(false, 0), summary);
+        for (idx, address) in addresses.iter().enumerate() {
+            assert_eq!(
+                render(false, idx as u8 + 1),
+                (String::from("Address"), String::from(*address))
+            );
+        }
+
+        let mut title = [0; 18];
+        let mut message = [0; 100];
+        assert!(tx
+            .0
+            .render_outputs_with(false, 4, &mut title, &mut message, 0)
+            .is_err());
+    }
 }
@@ -180,8 +180,11 @@ impl<'b> FromBytes<'b> for OperationTx<'b> {
 
 impl<'b> DisplayableItem for OperationTx<'b> {
     fn num_items(&self) -> usize {
-        // description +
-        1 + self.base_tx.base_outputs_num_items() + self.operation_items() + 1 // fee
+        // description + outputs + operations + memo + fee
+        1 + self.base_tx.base_outputs_num_items()
+            + self.operation_items()
+            + self.base_tx.memo_num_items()
+            + 1
     }
 
     fn render_item(
@@ -204,15 +207,20 @@ impl<'b> DisplayableItem for OperationTx<'b> {
         let item_n = item_n - 1;
 
         let base_items = self.base_tx.base_outputs_num_items() as u8;
+        let op_items = self.operation_items() as u8;
+        let memo_items = self.base_tx.memo_num_items() as u8;
 
         match item_n {
             x @ 0.. if x < base_items => self.render_outputs(item_n, title, message, page),
-            x if x >= base_items && x < self.num_items() as u8 - 2 => {
+            x if x < base_items + op_items => {
                 let x = item_n - base_items;
                 let (op, idx) = self.op_with_item(x).map_err(|_| ViewError::NoData)?;
                 op.render_item(idx, title, message, page)
             }
-            x if x == self.num_items() as u8 - 2 => {
+            x if x < base_items + op_items + memo_items => {
+                self.base_tx.render_memo(title, message, page)
+            }
+            x if x == base_items + op_items + memo_items => {
                 let title_content = pic_str!(b"Fee(AVAX)");
                 title[..title_content.len()].copy_from_slice(title_content);
 
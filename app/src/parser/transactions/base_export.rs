@@ -17,6 +17,7 @@ use core::ops::Deref;
 
 use bolos::{pic_str, PIC};
 use core::{convert::TryFrom, mem::MaybeUninit, ptr::addr_of_mut};
+use lexical_core::Number;
 use nom::{bytes::complete::take, number::complete::be_u32};
 use zemu_sys::ViewError;
 
@@ -24,14 +25,28 @@ use crate::{
     constants::chain_alias_lookup,
     handlers::handle_ui_message,
     parser::{
-        BaseTxFields, ChainId, DisplayableItem, FromBytes, Header, ObjectList, Output, OutputIdx,
-        ParserError, TransferableInput, TransferableOutput, BLOCKCHAIN_ID_LEN,
-        MAX_ADDRESS_ENCODED_LEN,
+        u64_to_str, Address, AssetId, BaseTxFields, ChainId, DisplayableItem, FromBytes, Header,
+        ObjectList, Output, OutputIdx, ParserError, TransferableInput, TransferableOutput,
+        TxSummary, ADDRESS_LEN, BLOCKCHAIN_ID_LEN, MAX_ADDRESS_ENCODED_LEN, MAX_ASSET_AMOUNT_LEN,
+        MAX_ASSET_LABEL_LEN,
     },
+    utils::is_app_mode_expert,
 };
 
 pub const DESTINATION_CHAIN_LEN: usize = BLOCKCHAIN_ID_LEN;
 const EXPORT_TX_DESCRIPTION_LEN: usize = 13; //X to C Chain
+const CHAIN_ALIAS_LEN: usize = 1; //X, P or C
+
+// Export <N> outputs, total <amount> <asset> to <alias> Chain
+const EXPORT_TX_SUMMARY_LEN: usize = b"Export ".len()
+    + u64::FORMATTED_SIZE_DECIMAL
+    + b" outputs, total ".len()
+    + MAX_ASSET_AMOUNT_LEN
+    + b" ".len()
+    + MAX_ASSET_LABEL_LEN
+    + b" to ".len()
+    + CHAIN_ALIAS_LEN
+    + b" Chain".len();
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(test, derive(Debug))]
@@ -93,8 +108,8 @@ where
         //good ptr and no uninit reads
         unsafe {
             addr_of_mut!((*out).destination_chain).write(destination_chain);
-            // by default all outputs are renderable
-            addr_of_mut!((*out).renderable_out).write(OutputIdx::MAX);
+            let renderable = BaseTxFields::<O>::default_renderable(&*addr_of_mut!((*out).outputs));
+            addr_of_mut!((*out).renderable_out).write(renderable);
         }
 
         Ok(rem)
@@ -120,7 +135,7 @@ where
             // The 99.99% of the outputs contain only one address(best case),
             // In the worse case we just show every output.
             if o.num_addresses() == 1 && o.contain_address(address) {
-                render ^= 1 << idx;
+                render &= !(1 << idx);
             }
             idx += 1;
         });
@@ -168,10 +183,125 @@ where
             })
     }
 
+    // exported outputs are accounted as any other output,
+    // skipping the ones that were disabled
+    pub fn summary(&'b self) -> Result<TxSummary, ParserError> {
+        let mut summary = self.base_tx.summary()?;
+
+        let renderable = self
+            .outputs
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| self.renderable_out & (1 << *idx) > 0)
+            .map(|(_, output)| output);
+
+        summary.add_outputs(
+            self.outputs.iter().count(),
+            TxSummary::avax_amount(renderable)?,
+        )?;
+        summary.add_zero_outputs(
+            BaseTxFields::<O>::zero_amount_outputs(&self.outputs).count_ones() as usize,
+        );
+        Ok(summary)
+    }
+
+    fn renderable_outputs(&'b self) -> impl Iterator<Item = TransferableOutput<'b, O>> + 'b {
+        let render = self.renderable_out;
+        self.outputs
+            .iter()
+            .enumerate()
+            .filter(move |(idx, _)| render & (1 << *idx) > 0)
+            .map(|(_, output)| output)
+    }
+
+    // Returns the asset of the outputs to review when there are
+    // several of them to summarize, exports of different assets
+    // are reviewed output by output
+    fn summary_asset(&'b self) -> Option<AssetId<'b>> {
+        let mut outputs = self.renderable_outputs();
+        let asset = *outputs.next()?.assert_id();
+
+        let mut count = 1;
+        for output in outputs {
+            if *output.assert_id() != asset {
+                return None;
+            }
+            count += 1;
+        }
+
+        if count > 1 {
+            Some(asset)
+        } else {
+            None
+        }
+    }
+
+    // Walks the addresses of the outputs to review, along with their position,
+    // stopping at the first one `f` returns true for
+    fn find_address<F>(&'b self, mut f: F) -> Option<[u8; ADDRESS_LEN]>
+    where
+        F: FnMut(usize, &[u8; ADDRESS_LEN]) -> bool,
+    {
+        let mut position = 0;
+        for output in self.renderable_outputs() {
+            let output = output.output();
+            for idx in 0..output.num_addresses() {
+                let address = output.get_address(idx)?;
+                if f(position, address.raw_address()) {
+                    return Some(*address.raw_address());
+                }
+                position += 1;
+            }
+        }
+        None
+    }
+
+    fn first_seen_at(&'b self, position: usize, address: &[u8; ADDRESS_LEN]) -> bool {
+        self.find_address(|p, a| p < position && a == address)
+            .is_none()
+    }
+
+    /// Returns the addresses the outputs to review send funds to,
+    /// counting only once the ones found in several outputs
+    pub fn num_distinct_addresses(&'b self) -> usize {
+        let mut count = 0;
+        self.find_address(|position, address| {
+            count += self.first_seen_at(position, address) as usize;
+            false
+        });
+        count
+    }
+
+    pub fn distinct_address_at(&'b self, n: usize) -> Option<[u8; ADDRESS_LEN]> {
+        let mut count = 0;
+        self.find_address(|position, address| {
+            if !self.first_seen_at(position, address) {
+                return false;
+            }
+            count += 1;
+            count == n + 1
+        })
+    }
+
+    /// Number of items of the outputs to review, several ones of the same asset
+    /// are summarized first, followed by each output in expert mode or
+    /// by their distinct addresses otherwise
+    pub fn num_outputs_items_with(&'b self, expert: bool) -> usize {
+        match (self.summary_asset(), expert) {
+            (None, _) => self.num_each_output_items(),
+            (Some(_), true) => 1 + self.num_each_output_items(),
+            (Some(_), false) => 1 + self.num_distinct_addresses(),
+        }
+    }
+
+    pub fn num_outputs_items(&'b self) -> usize {
+        self.num_outputs_items_with(is_app_mode_expert())
+    }
+
     // Default implementation similar to "num_items", this relies on the
     // inner objects, but callers might want to filter it
     // out.
-    pub fn num_outputs_items(&'b self) -> usize {
+    fn num_each_output_items(&'b self) -> usize {
         let mut items = 0;
         let mut idx = 0;
         self.outputs.iterate_with(|o| {
@@ -220,10 +350,113 @@ where
         Ok((obj, obj_item_n as u8))
     }
 
+    pub fn render_outputs_with(
+        &'b self,
+        expert: bool,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, zemu_sys::ViewError> {
+        let asset = match self.summary_asset() {
+            Some(asset) => asset,
+            None => return self.render_each_output(item_n, title, message, page),
+        };
+
+        match (item_n, expert) {
+            (0, _) => self.render_export_summary(&asset, title, message, page),
+            (x, true) => self.render_each_output(x - 1, title, message, page),
+            (x, false) => {
+                let raw = self
+                    .distinct_address_at(x as usize - 1)
+                    .ok_or(ViewError::NoData)?;
+                let (_, address) = Address::from_bytes(&raw[..]).map_err(|_| ViewError::Unknown)?;
+
+                let t = pic_str!(b"Address");
+                title[..t.len()].copy_from_slice(t);
+
+                let hrp = self.tx_header.hrp().map_err(|_| ViewError::Unknown)?;
+                let mut encoded = [0; MAX_ADDRESS_ENCODED_LEN];
+                let addr_len = address
+                    .encode_into(hrp, &mut encoded[..])
+                    .map_err(|_| ViewError::Unknown)?;
+
+                handle_ui_message(&encoded[..addr_len], message, page)
+            }
+        }
+    }
+
+    pub fn render_outputs(
+        &'b self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, zemu_sys::ViewError> {
+        self.render_outputs_with(is_app_mode_expert(), item_n, title, message, page)
+    }
+
+    // Renders "Export <N> outputs, total <amount> <asset> to <alias> Chain"
+    fn render_export_summary(
+        &'b self,
+        asset: &AssetId,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, zemu_sys::ViewError> {
+        use arrayvec::ArrayVec;
+
+        let title_content = pic_str!(b"Summary");
+        title[..title_content.len()].copy_from_slice(title_content);
+
+        let mut count = 0u64;
+        let mut total = 0u64;
+        for output in self.renderable_outputs() {
+            let amount = output.amount().ok_or(ViewError::Unknown)?;
+            total = total.checked_add(amount).ok_or(ViewError::Unknown)?;
+            count += 1;
+        }
+
+        let mut count_buf = [0; u64::FORMATTED_SIZE_DECIMAL];
+        let count = u64_to_str(count, &mut count_buf[..]).map_err(|_| ViewError::Unknown)?;
+
+        let mut amount_buf = [0; MAX_ASSET_AMOUNT_LEN];
+        let amount = asset
+            .format_amount(total, &mut amount_buf[..])
+            .map_err(|_| ViewError::Unknown)?;
+
+        let mut label_buf = [0; MAX_ASSET_LABEL_LEN];
+        let label_len = asset.write_label(&mut label_buf[..])?;
+
+        let to_alias = chain_alias_lookup(self.destination_chain)
+            .map(|a| a.as_bytes())
+            .map_err(|_| ViewError::Unknown)?;
+
+        let mut summary: ArrayVec<u8, EXPORT_TX_SUMMARY_LEN> = ArrayVec::new();
+        let parts: [&[u8]; 9] = [
+            pic_str!(b"Export "!),
+            count,
+            pic_str!(b" outputs, total "!),
+            amount,
+            pic_str!(b" "!),
+            &label_buf[..label_len],
+            pic_str!(b" to "!),
+            to_alias,
+            pic_str!(b" Chain"!),
+        ];
+        for part in parts {
+            summary
+                .try_extend_from_slice(part)
+                .map_err(|_| ViewError::Unknown)?;
+        }
+
+        handle_ui_message(&summary, message, page)
+    }
+
     // default render_item implementation that
     // relies on the DisplayableItem trait implementation
     // of the objects in the list.
-    pub fn render_outputs(
+    fn render_each_output(
         &'b self,
         item_n: u8,
         title: &mut [u8],
@@ -25,7 +25,8 @@ use crate::{
     handlers::handle_ui_message,
     parser::{
         BaseTxFields, ChainId, DisplayableItem, FromBytes, Header, ObjectList, Output, ParserError,
-        TransferableInput, TransferableOutput, BLOCKCHAIN_ID_LEN, MAX_ADDRESS_ENCODED_LEN,
+        TransferableInput, TransferableOutput, TxSummary, UtxoList, BLOCKCHAIN_ID_LEN,
+        MAX_ADDRESS_ENCODED_LEN,
     },
 };
 
@@ -83,6 +84,7 @@ where
         let inputs = unsafe { &mut *addr_of_mut!((*out).inputs).cast() };
 
         let rem = ObjectList::<TransferableInput>::new_into(rem, inputs)?;
+        TransferableInput::check_imported(unsafe { inputs.assume_init_ref() })?;
 
         //good ptr and no uninit reads
         unsafe {
@@ -129,6 +131,16 @@ where
         Ok(fee)
     }
 
+    pub fn summary(&'b self) -> Result<TxSummary, ParserError> {
+        let mut summary = self.base_tx.summary()?;
+        summary.add_inputs(self.inputs.iter().count());
+        Ok(summary)
+    }
+
+    pub fn utxos(&self) -> UtxoList<'b> {
+        self.base_tx.utxos().with_imported(self.inputs)
+    }
+
     fn sum_inputs_amount(&self) -> Result<u64, ParserError> {
         let base_inputs = self.base_tx.sum_inputs_amount()?;
 
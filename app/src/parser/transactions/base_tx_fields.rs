@@ -17,10 +17,19 @@ use core::ops::Deref;
 
 use core::{mem::MaybeUninit, ptr::addr_of_mut};
 use nom::{bytes::complete::take, number::complete::be_u32};
+use zemu_sys::ViewError;
 
-use crate::parser::{
-    DisplayableItem, FromBytes, ObjectList, Output, OutputIdx, ParserError, TransferableInput,
-    TransferableOutput,
+use crate::{
+    handlers::handle_ui_message,
+    parser::{
+        AssetId, DisplayableItem, FromBytes, ObjectList, Output, OutputIdx, ParserError,
+        TransferableInput, TransferableOutput, TxSummary, UtxoList, MAX_ASSET_AMOUNT_LEN,
+        MAX_ASSET_LABEL_LEN,
+    },
+    utils::{
+        hex_encode, output_order_toggle::strict_output_order,
+        strict_outputs_toggle::strict_outputs_enabled,
+    },
 };
 
 const MAX_MEMO_LEN: usize = 256;
@@ -69,13 +78,35 @@ where
             // The 99.99% of the outputs contain only one address(best case),
             // In the worse case we just show every output.
             if o.num_addresses() == 1 && o.contain_address(address) {
-                render ^= 1 << idx;
+                render &= !(1 << idx);
             }
             idx += 1;
         });
         self.renderable_out = render;
     }
 
+    /// Returns the bit-wise idx of the fungible outputs that move no value
+    pub fn zero_amount_outputs(outputs: &ObjectList<'b, TransferableOutput<'b, O>>) -> OutputIdx {
+        let mut idx = 0;
+        let mut zero = 0;
+        outputs.iterate_with(|o| {
+            if o.amount() == Some(0) {
+                zero |= 1 << idx;
+            }
+            idx += 1;
+        });
+        zero
+    }
+
+    /// Outputs are renderable by default, but the ones without value
+    /// are left out of the review unless the user wants a notice about them
+    pub fn default_renderable(outputs: &ObjectList<'b, TransferableOutput<'b, O>>) -> OutputIdx {
+        if strict_outputs_enabled() {
+            return OutputIdx::MAX;
+        }
+        OutputIdx::MAX & !Self::zero_amount_outputs(outputs)
+    }
+
     pub fn sum_inputs_amount(&self) -> Result<u64, ParserError> {
         self.inputs
             .iter()
@@ -94,6 +125,137 @@ where
             })
     }
 
+    /// Returns the AVAX paid as fee, what the inputs spend minus
+    /// what the outputs receive, amounts of other assets are not counted
+    pub fn avax_fee(&'b self) -> Result<u64, ParserError> {
+        let inputs = self
+            .inputs
+            .iter()
+            .filter(|input| input.asset_id().is_avax())
+            .map(|input| input.amount().ok_or(ParserError::UnexpectedError))
+            .try_fold(0u64, |acc, x| {
+                acc.checked_add(x?).ok_or(ParserError::OperationOverflows)
+            })?;
+
+        let outputs = self
+            .outputs
+            .iter()
+            .filter(|output| output.assert_id().is_avax())
+            .map(|output| (*output).amount().ok_or(ParserError::UnexpectedError))
+            .try_fold(0u64, |acc, x| {
+                acc.checked_add(x?).ok_or(ParserError::OperationOverflows)
+            })?;
+
+        inputs
+            .checked_sub(outputs)
+            .ok_or(ParserError::OperationOverflows)
+    }
+
+    // only the outputs that are going to be displayed
+    // add to the summary amount
+    pub fn summary(&'b self) -> Result<TxSummary, ParserError> {
+        let renderable = self
+            .outputs
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| self.is_renderable(*idx))
+            .map(|(_, output)| output);
+
+        let mut summary = TxSummary::default();
+        summary.add_inputs(self.inputs.iter().count());
+        summary.add_outputs(
+            self.outputs.iter().count(),
+            TxSummary::avax_amount(renderable)?,
+        )?;
+        summary.add_zero_outputs(Self::zero_amount_outputs(&self.outputs).count_ones() as usize);
+        Ok(summary)
+    }
+
+    fn is_renderable(&self, idx: usize) -> bool {
+        self.renderable_out & (1 << idx) > 0
+    }
+
+    // tells if none of the renderable outputs before idx
+    // holds the same asset
+    fn first_of_asset(&'b self, idx: usize, asset: &AssetId) -> bool {
+        !self
+            .outputs
+            .iter()
+            .take(idx)
+            .enumerate()
+            .any(|(i, o)| self.is_renderable(i) && o.assert_id() == asset)
+    }
+
+    // number of distinct assets among the renderable outputs
+    pub fn num_renderable_assets(&'b self) -> usize {
+        self.outputs
+            .iter()
+            .enumerate()
+            .filter(|(idx, o)| self.is_renderable(*idx) && self.first_of_asset(*idx, o.assert_id()))
+            .count()
+    }
+
+    // gets the asset at asset_idx, in order of appearance, along with
+    // the total amount of the renderable outputs holding it
+    pub fn asset_total_at(&'b self, asset_idx: usize) -> Result<(AssetId<'b>, u64), ParserError> {
+        let output = self
+            .outputs
+            .iter()
+            .enumerate()
+            .filter(|(idx, o)| self.is_renderable(*idx) && self.first_of_asset(*idx, o.assert_id()))
+            .nth(asset_idx)
+            .map(|(_, o)| o)
+            .ok_or(ParserError::DisplayIdxOutOfRange)?;
+        let asset = *output.assert_id();
+
+        let total = self
+            .outputs
+            .iter()
+            .enumerate()
+            .filter(|(idx, o)| self.is_renderable(*idx) && o.assert_id() == &asset)
+            .filter_map(|(_, o)| o.amount())
+            .try_fold(0u64, |acc, x| {
+                acc.checked_add(x).ok_or(ParserError::OperationOverflows)
+            })?;
+
+        Ok((asset, total))
+    }
+
+    // Renders the total of the asset at asset_idx as
+    // "<name or id>: <amount>", amounts of assets without
+    // a known denomination are shown in their base unit
+    pub fn render_asset_total(
+        &'b self,
+        asset_idx: usize,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+
+        let (asset, total) = self
+            .asset_total_at(asset_idx)
+            .map_err(|_| ViewError::NoData)?;
+
+        let label = pic_str!(b"Asset");
+        title[..label.len()].copy_from_slice(label);
+
+        let separator = pic_str!(b": "!);
+        let mut amount_buf = [0; MAX_ASSET_AMOUNT_LEN];
+        let amount = asset
+            .format_amount(total, &mut amount_buf[..])
+            .map_err(|_| ViewError::Unknown)?;
+
+        let mut buffer = [0; MAX_ASSET_LABEL_LEN + 2 + MAX_ASSET_AMOUNT_LEN];
+        let mut len = asset.write_label(&mut buffer[..])?;
+        buffer[len..len + separator.len()].copy_from_slice(separator);
+        len += separator.len();
+        buffer[len..len + amount.len()].copy_from_slice(amount);
+        len += amount.len();
+
+        handle_ui_message(&buffer[..len], message, page)
+    }
+
     pub fn outputs(&'b self) -> &ObjectList<TransferableOutput<O>> {
         &self.outputs
     }
@@ -102,6 +264,55 @@ where
         &self.inputs
     }
 
+    pub fn utxos(&self) -> UtxoList<'b> {
+        UtxoList::new(self.inputs)
+    }
+
+    // the memo page is omitted if there is no memo
+    pub fn memo_num_items(&self) -> usize {
+        if self.memo.is_empty() {
+            0
+        } else {
+            1
+        }
+    }
+
+    // Renders the memo as text if it is printable utf8,
+    // otherwise as an hex string
+    pub fn render_memo(
+        &self,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+
+        if self.memo.is_empty() {
+            return Err(ViewError::NoData);
+        }
+
+        let label = pic_str!(b"Memo");
+        title[..label.len()].copy_from_slice(label);
+
+        let printable = core::str::from_utf8(self.memo)
+            .map(|m| !m.chars().any(char::is_control))
+            .unwrap_or(false);
+
+        if printable {
+            return handle_ui_message(self.memo, message, page);
+        }
+
+        let prefix = pic_str!(b"0x"!);
+        let mut buf = [0; MAX_MEMO_LEN * 2 + 2];
+        buf[..prefix.len()].copy_from_slice(prefix);
+
+        let len = hex_encode(self.memo, &mut buf[prefix.len()..])
+            .map_err(|_| ViewError::Unknown)?
+            + prefix.len();
+
+        handle_ui_message(&buf[..len], message, page)
+    }
+
     pub fn base_outputs_num_items(&'b self) -> usize {
         let mut items = 0;
         let mut idx = 0;
@@ -159,6 +370,44 @@ where
     }
 }
 
+impl<'b, O> BaseTxFields<'b, O>
+where
+    O: FromBytes<'b> + DisplayableItem + Deref<Target = Output<'b>> + 'b,
+{
+    // Outputs have to be sorted by asset and then by their serialized
+    // bytes, as avalanchego does, any other order is not canonical.
+    // As the asset id has a fixed length, this is the same as
+    // comparing the serialized transferable outputs.
+    //
+    // Only checked if `strict`, avalanchego rejects them anyway
+    pub fn check_outputs_order_with(
+        input: &'b [u8],
+        strict: bool,
+    ) -> Result<(), nom::Err<ParserError>> {
+        if !strict {
+            return Ok(());
+        }
+
+        let (mut rem, num_outputs) = be_u32(input)?;
+        let mut prev: Option<&[u8]> = None;
+
+        for _ in 0..num_outputs {
+            let mut output = MaybeUninit::uninit();
+            let next = TransferableOutput::<O>::from_bytes_into(rem, &mut output)?;
+            let raw = &rem[..rem.len() - next.len()];
+
+            if matches!(prev, Some(prev) if prev > raw) {
+                return Err(ParserError::InvalidOutputOrder.into());
+            }
+
+            prev = Some(raw);
+            rem = next;
+        }
+
+        Ok(())
+    }
+}
+
 impl<'b, O> FromBytes<'b> for BaseTxFields<'b, O>
 where
     O: FromBytes<'b> + DisplayableItem + Deref<Target = Output<'b>> + 'b,
@@ -181,6 +430,7 @@ where
         // get outputs
         let outputs = unsafe { &mut *addr_of_mut!((*out).outputs).cast() };
         let rem = ObjectList::<TransferableOutput<O>>::new_into(input, outputs)?;
+        Self::check_outputs_order_with(&input[..input.len() - rem.len()], strict_output_order())?;
 
         // inputs
         let inputs = unsafe { &mut *addr_of_mut!((*out).inputs).cast() };
@@ -198,8 +448,8 @@ where
         //good ptr and no uninit reads
         unsafe {
             addr_of_mut!((*out).memo).write(memo);
-            // by default all outputs are renderable
-            addr_of_mut!((*out).renderable_out).write(OutputIdx::MAX);
+            let renderable = Self::default_renderable(&*addr_of_mut!((*out).outputs));
+            addr_of_mut!((*out).renderable_out).write(renderable);
         }
 
         Ok(rem)
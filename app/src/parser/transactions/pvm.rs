@@ -1,6 +1,8 @@
+mod base_tx;
 mod export_tx;
 mod import_tx;
 
+pub use base_tx::*;
 pub use export_tx::*;
 pub use import_tx::*;
 
@@ -19,11 +21,31 @@ mod add_subnet_validator;
 #[cfg(feature = "add-subnet-validator")]
 pub use add_subnet_validator::*;
 
+#[cfg(feature = "remove-subnet-validator")]
+mod remove_subnet_validator;
+#[cfg(feature = "remove-subnet-validator")]
+pub use remove_subnet_validator::*;
+
+#[cfg(feature = "transform-subnet")]
+mod transform_subnet_tx;
+#[cfg(feature = "transform-subnet")]
+pub use transform_subnet_tx::*;
+
 #[cfg(feature = "add-validator")]
 mod add_validator;
 #[cfg(feature = "add-validator")]
 pub use add_validator::*;
 
+#[cfg(feature = "add-permissionless-validator")]
+mod add_permissionless_validator;
+#[cfg(feature = "add-permissionless-validator")]
+pub use add_permissionless_validator::*;
+
+#[cfg(feature = "add-permissionless-delegator")]
+mod add_permissionless_delegator;
+#[cfg(feature = "add-permissionless-delegator")]
+pub use add_permissionless_delegator::*;
+
 #[cfg(feature = "add-delegator")]
 mod add_delegator;
 
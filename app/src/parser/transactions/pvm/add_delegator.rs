@@ -23,8 +23,8 @@ use crate::{
     handlers::handle_ui_message,
     parser::{
         nano_avax_to_fp_str, Address, BaseTxFields, DisplayableItem, FromBytes, Header, ObjectList,
-        OutputIdx, ParserError, PvmOutput, SECPOutputOwners, TransferableOutput, Validator,
-        MAX_ADDRESS_ENCODED_LEN, PVM_ADD_DELEGATOR,
+        OutputIdx, ParserError, PvmOutput, SECPOutputOwners, TransferableOutput, TxSummary,
+        Validator, MAX_ADDRESS_ENCODED_LEN, PVM_ADD_DELEGATOR,
     },
 };
 
@@ -106,11 +106,12 @@ impl<'b> FromBytes<'b> for AddDelegatorTx<'b> {
 impl<'b> DisplayableItem for AddDelegatorTx<'b> {
     fn num_items(&self) -> usize {
         // tx_info, base_tx items, validator_items(4),
-        // rewards_to, stake items and fee
+        // rewards_to, stake items, memo and fee
         1 + self.base_tx.base_outputs_num_items()
             + self.validator.num_items()
-            + self.rewards_owner.addresses.len()
+            + self.rewards_owner.num_owner_items()
             + self.num_stake_items()
+            + self.base_tx.memo_num_items()
             + 1
     }
 
@@ -189,6 +190,17 @@ impl<'b> AddDelegatorTx<'b> {
         self.renderable_out = render;
     }
 
+    // the staked amount is accounted even if it goes
+    // back to the signer, as it gets locked
+    pub fn summary(&'b self) -> Result<TxSummary, ParserError> {
+        let mut summary = self.base_tx.summary()?;
+        summary.add_outputs(
+            self.stake.iter().count(),
+            TxSummary::avax_amount(self.stake.iter())?,
+        )?;
+        Ok(summary)
+    }
+
     fn fee(&'b self) -> Result<u64, ParserError> {
         let sum_inputs = self.base_tx.sum_inputs_amount()?;
 
@@ -250,7 +262,16 @@ impl<'b> AddDelegatorTx<'b> {
         // for staking the header is Stake
         let header = pic_str!(b"Stake");
 
-        self.render_output_with_header(&obj, item_idx, title, message, page, header)
+        let res = self.render_output_with_header(&obj, item_idx, title, message, page, header);
+
+        // the stake can be locked past the staking period
+        if obj.output.is_lock_item(item_idx) {
+            let label = pic_str!(b"Output locked");
+            title.iter_mut().for_each(|v| *v = 0);
+            title[..label.len()].copy_from_slice(label);
+        }
+
+        res
     }
 
     // helper function to render any TransferableOutput<PvmOutput>,
@@ -315,6 +336,8 @@ impl<'b> AddDelegatorTx<'b> {
             // by default we call the objects impl here,
             // if it is a locked output, that info will be shown otherwise,
             // this returns an error
+            // the lock is rendered in the output asset
+            x if obj.output.is_lock_item(x) => obj.render_lock(title, message, page),
             _ => obj.render_item(item_n, title, message, page),
         }
     }
@@ -331,11 +354,23 @@ impl<'b> AddDelegatorTx<'b> {
 
     fn render_rewards_to(
         &self,
-        addr_idx: usize,
+        item_n: usize,
         title: &mut [u8],
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, zemu_sys::ViewError> {
+        // with several owners, tell how many of them control the rewards
+        let addr_idx = match (self.rewards_owner.is_multisig(), item_n) {
+            (true, 0) => {
+                let label = pic_str!(b"Reward owner");
+                return self
+                    .rewards_owner
+                    .render_threshold(label, title, message, page);
+            }
+            (true, x) => x - 1,
+            (false, x) => x,
+        };
+
         let label = pic_str!(b"Rewards to");
         title[..label.len()].copy_from_slice(label);
 
@@ -370,14 +405,16 @@ impl<'b> AddDelegatorTx<'b> {
         use lexical_core::Number;
 
         let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 2];
-        let num_addresses = self.rewards_owner.addresses.len() as u8;
+        let rewards_items = self.rewards_owner.num_owner_items() as u8;
+        let memo_items = self.base_tx.memo_num_items() as u8;
 
         match item_n {
             // render rewards
-            x @ 0.. if x < num_addresses => {
+            x @ 0.. if x < rewards_items => {
                 self.render_rewards_to(x as usize, title, message, page)
             }
-            x if x == num_addresses => {
+            x if x < rewards_items + memo_items => self.base_tx.render_memo(title, message, page),
+            x if x == rewards_items + memo_items => {
                 let label = pic_str!(b"Fee(AVAX)");
                 title[..label.len()].copy_from_slice(label);
 
@@ -469,4 +506,60 @@ mod tests {
         let (_, tx) = AddDelegatorTx::from_bytes(DATA).unwrap();
         assert_eq!(tx.validator.weight, 2000000000000);
     }
+
+    #[test]
+    fn multisig_rewards_owner() {
+        use crate::parser::ADDRESS_LEN;
+        use std::prelude::v1::*;
+
+        // the rewards owner closes the tx, make it 2 of 3 addresses
+        let owners = [
+            [0x11; ADDRESS_LEN],
+            [0x22; ADDRESS_LEN],
+            [0x33; ADDRESS_LEN],
+        ];
+        let mut data = DATA[..DATA.len() - 28].to_vec();
+        data.extend_from_slice(&2u32.to_be_bytes());
+        data.extend_from_slice(&3u32.to_be_bytes());
+        owners.iter().for_each(|a| data.extend_from_slice(a));
+
+        let (_, tx) = AddDelegatorTx::from_bytes(&data).unwrap();
+        let (_, single) = AddDelegatorTx::from_bytes(DATA).unwrap();
+        assert_eq!(tx.num_items(), single.num_items() + 3);
+
+        let text = |buf: &[u8]| {
+            let len = buf.iter().position(|b| *b == 0).unwrap();
+            String::from_utf8(buf[..len].to_vec()).unwrap()
+        };
+        let render = |item_n: usize| {
+            let mut title = [0; 18];
+            let mut message = [0; 128];
+            tx.render_item(item_n as u8, &mut title, &mut message, 0)
+                .unwrap();
+            (text(&title), text(&message))
+        };
+
+        // the threshold, each owner and the fee last
+        let first = tx.num_items() - 5;
+        assert_eq!(render(first), ("Reward owner".into(), "2 of 3".into()));
+
+        let hrp = tx.tx_header.hrp().unwrap();
+        for (i, owner) in owners.iter().enumerate() {
+            let (_, address) = Address::from_bytes(owner).unwrap();
+            let mut encoded = [0; MAX_ADDRESS_ENCODED_LEN];
+            let len = address.encode_into(hrp, &mut encoded[..]).unwrap();
+
+            let expected = String::from_utf8(encoded[..len].to_vec()).unwrap();
+            assert_eq!(render(first + 1 + i), ("Rewards to".into(), expected));
+        }
+        assert_eq!(render(first + 4).0, "Fee(AVAX)");
+
+        // more owners have to sign than there are
+        let threshold_at = DATA.len() - 28;
+        data[threshold_at..][..4].copy_from_slice(&4u32.to_be_bytes());
+        assert_eq!(
+            AddDelegatorTx::from_bytes(&data).map(|_| ()),
+            Err(nom::Err::Error(ParserError::InvalidThreshold))
+        );
+    }
 }
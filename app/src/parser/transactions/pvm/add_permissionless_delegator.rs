@@ -0,0 +1,603 @@
+/*******************************************************************************
+*   (c) 2021 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use bolos::{pic_str, PIC};
+use core::{mem::MaybeUninit, ptr::addr_of_mut};
+use nom::{bytes::complete::tag, number::complete::be_u32};
+use zemu_sys::ViewError;
+
+use crate::{
+    handlers::handle_ui_message,
+    parser::{
+        cb58_output_len, encode_node_id, nano_avax_to_fp_str, stake_asset, Address, BaseTxFields,
+        DisplayableItem, FromBytes, Header, ObjectList, OutputIdx, ParserError, PvmOutput,
+        SECPOutputOwners, SubnetId, TransferableOutput, TxSummary, Validator,
+        MAX_ADDRESS_ENCODED_LEN, MAX_NODE_ID_ENCODED_LEN, PVM_ADD_PERMISSIONLESS_DELEGATOR,
+        SUBNET_ID_LEN,
+    },
+    utils::cb58_encode,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+#[cfg_attr(test, derive(Debug))]
+pub struct AddPermissionlessDelegatorTx<'b> {
+    pub tx_header: Header<'b>,
+    pub base_tx: BaseTxFields<'b, PvmOutput<'b>>,
+    pub validator: Validator<'b>,
+    pub subnet_id: SubnetId<'b>,
+    pub stake: ObjectList<'b, TransferableOutput<'b, PvmOutput<'b>>>,
+    // a bit-wise idx that tells what stake outputs could be displayed
+    // in the ui stage.
+    // this is set during the parsing stage
+    renderable_out: OutputIdx,
+    pub rewards_owner: SECPOutputOwners<'b>,
+}
+
+impl<'b> FromBytes<'b> for AddPermissionlessDelegatorTx<'b> {
+    #[inline(never)]
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("AddPermissionlessDelegatorTx::from_bytes_into\x00");
+
+        let (rem, _) = tag(PVM_ADD_PERMISSIONLESS_DELEGATOR.to_be_bytes())(input)?;
+
+        let out = out.as_mut_ptr();
+
+        // tx header
+        let tx_header = unsafe { &mut *addr_of_mut!((*out).tx_header).cast() };
+        let rem = Header::from_bytes_into(rem, tx_header)?;
+
+        // base_tx
+        let base_tx = unsafe { &mut *addr_of_mut!((*out).base_tx).cast() };
+        let rem = BaseTxFields::<PvmOutput>::from_bytes_into(rem, base_tx)?;
+
+        // validator
+        let validator = unsafe { &mut *addr_of_mut!((*out).validator).cast() };
+        let rem = Validator::from_bytes_into(rem, validator)?;
+
+        // subnet_id
+        let subnet_id = unsafe { &mut *addr_of_mut!((*out).subnet_id).cast() };
+        let rem = SubnetId::from_bytes_into(rem, subnet_id)?;
+
+        // valid pointers read as memory was initialized
+        let (header, validator, subnet_id) = unsafe {
+            (
+                &*tx_header.as_ptr(),
+                &*validator.as_ptr(),
+                &*subnet_id.as_ptr(),
+            )
+        };
+        validator.check_staking_period(header, subnet_id)?;
+
+        // stake
+        // check for the number of stake-outputs before parsing then as now
+        // it has to be checked for the outputIdx capacity which is used
+        // to tell if an output should be rendered or not.
+        let (_, num_outputs) = be_u32(rem)?;
+        if num_outputs > OutputIdx::BITS {
+            return Err(ParserError::TooManyOutputs.into());
+        }
+        let stake = unsafe { &mut *addr_of_mut!((*out).stake).cast() };
+        let rem = ObjectList::<TransferableOutput<PvmOutput>>::new_into(rem, stake)?;
+
+        // valid pointers read as memory was initialized
+        let staked_list = unsafe { &*stake.as_ptr() };
+
+        // all the stake is locked in the same asset
+        stake_asset(staked_list, subnet_id)?;
+
+        // get locked outputs amount to check for invariant
+        let stake = Self::sum_stake_outputs_amount(staked_list)?;
+
+        // Check for invariant, the locked utxos must be equals to validators' stake
+        if validator.weight != stake {
+            return Err(ParserError::InvalidStakingAmount.into());
+        }
+
+        // rewards_owner
+        let rewards_owner = unsafe { &mut *addr_of_mut!((*out).rewards_owner).cast() };
+        let rem = SECPOutputOwners::from_bytes_into(rem, rewards_owner)?;
+        unsafe {
+            // by default all outputs are renderable
+            addr_of_mut!((*out).renderable_out).write(OutputIdx::MAX);
+        }
+
+        Ok(rem)
+    }
+}
+
+impl<'b> DisplayableItem for AddPermissionlessDelegatorTx<'b> {
+    fn num_items(&self) -> usize {
+        // tx_info, base_tx items, delegate_to, start_time,
+        // end_time, total_stake, stake items, rewards_to, memo and fee
+        1 + self.base_tx.base_outputs_num_items()
+            + 4
+            + self.num_stake_items()
+            + self.rewards_owner.num_owner_items()
+            + self.base_tx.memo_num_items()
+            + 1
+    }
+
+    fn render_item(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, zemu_sys::ViewError> {
+        if item_n == 0 {
+            let label = pic_str!(b"AddDelegator");
+            title[..label.len()].copy_from_slice(label);
+            let content = pic_str!(b"Transaction");
+            return handle_ui_message(content, message, page);
+        }
+
+        let item_n = item_n - 1;
+
+        let base_outputs_items = self.base_tx.base_outputs_num_items() as u8;
+        let stake_outputs_items = self.num_stake_items() as u8;
+
+        // where each group of items starts
+        let render_delegation_at = base_outputs_items;
+        let render_stake_outputs_at = render_delegation_at + 4;
+        let render_last_items_at = render_stake_outputs_at + stake_outputs_items;
+
+        match item_n {
+            // render base_outputs
+            x if x < render_delegation_at => self.render_base_outputs(x, title, message, page),
+
+            // render validator node and subnet
+            x if x == render_delegation_at => self.render_delegate_to(title, message, page),
+
+            // render start and end time
+            x if x < render_delegation_at + 3 => {
+                let new_idx = x - render_delegation_at;
+                self.validator.render_item(new_idx, title, message, page)
+            }
+
+            // render the total stake in the staked asset
+            x if x < render_stake_outputs_at => {
+                let asset =
+                    stake_asset(&self.stake, &self.subnet_id).map_err(|_| ViewError::NoData)?;
+                self.validator
+                    .render_total_stake(&asset, title, message, page)
+            }
+
+            // render stake items
+            x if x < render_last_items_at => {
+                let new_idx = x - render_stake_outputs_at;
+                self.render_stake_outputs(new_idx, title, message, page)
+            }
+
+            // render rewards to and fee
+            x => {
+                let new_idx = x - render_last_items_at;
+                self.render_last_items(new_idx, title, message, page)
+            }
+        }
+    }
+}
+
+impl<'b> AddPermissionlessDelegatorTx<'b> {
+    pub fn disable_output_if(&mut self, address: &[u8]) {
+        // for this stake transaction, transfer information
+        // is not important so even if there is only one
+        // output, just hide it from the UI as long as
+        // the change address match
+        self.base_tx.force_disable_output(address);
+
+        let mut idx = 0;
+        let mut render = self.renderable_out;
+
+        self.stake.iterate_with(|o| {
+            // The 99.99% of the outputs contain only one address(best case),
+            // In the worse case we just show every output.
+            if o.num_addresses() == 1 && o.contain_address(address) {
+                render ^= 1 << idx;
+            }
+            idx += 1;
+        });
+        self.renderable_out = render;
+    }
+
+    // the staked amount is accounted even if it goes
+    // back to the signer, as it gets locked
+    pub fn summary(&'b self) -> Result<TxSummary, ParserError> {
+        let mut summary = self.base_tx.summary()?;
+        summary.add_outputs(
+            self.stake.iter().count(),
+            TxSummary::avax_amount(self.stake.iter())?,
+        )?;
+        Ok(summary)
+    }
+
+    fn fee(&'b self) -> Result<u64, ParserError> {
+        let sum_inputs = self.base_tx.sum_inputs_amount()?;
+
+        let base_outputs = self.base_tx.sum_outputs_amount()?;
+        let stake_outputs = Self::sum_stake_outputs_amount(&self.stake)?;
+
+        let total_outputs = base_outputs
+            .checked_add(stake_outputs)
+            .ok_or(ParserError::OperationOverflows)?;
+
+        let fee = sum_inputs
+            .checked_sub(total_outputs)
+            .ok_or(ParserError::OperationOverflows)?;
+        Ok(fee)
+    }
+
+    fn num_stake_items(&self) -> usize {
+        let mut items = 0;
+        let mut idx = 0;
+        self.stake.iterate_with(|o| {
+            let render = self.renderable_out & (1 << idx);
+            if render > 0 {
+                items += o.num_items();
+            }
+            idx += 1;
+        });
+        items
+    }
+
+    // Delegate to:
+    //      NodeID-<cb58> on Primary Network
+    //      NodeID-<cb58> on subnet <cb58>
+    fn render_delegate_to(
+        &self,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        let label = pic_str!(b"Delegate to");
+        title[..label.len()].copy_from_slice(label);
+
+        // room for both ids plus the separator
+        const MAX_SIZE: usize = MAX_NODE_ID_ENCODED_LEN + 16 + cb58_output_len::<SUBNET_ID_LEN>();
+        let mut buffer = [0; MAX_SIZE];
+
+        let mut len = encode_node_id(self.validator.node_id, &mut buffer[..])?;
+
+        if self.subnet_id.is_primary_network() {
+            let on = pic_str!(b" on Primary Network"!);
+            buffer[len..len + on.len()].copy_from_slice(on);
+            len += on.len();
+        } else {
+            let on = pic_str!(b" on subnet "!);
+            buffer[len..len + on.len()].copy_from_slice(on);
+            len += on.len();
+            len += cb58_encode(self.subnet_id.id(), &mut buffer[len..])
+                .map_err(|_| ViewError::Unknown)?;
+        }
+
+        handle_ui_message(&buffer[..len], message, page)
+    }
+
+    fn render_base_outputs(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        let (obj, item_idx) = self
+            .base_tx
+            .base_output_with_item(item_n)
+            .map_err(|_| ViewError::NoData)?;
+
+        // for base_outputs the header is Transfer
+        let header = pic_str!(b"Transfer");
+
+        self.render_output_with_header(&obj, item_idx, title, message, page, header)
+    }
+
+    fn render_stake_outputs(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        let (obj, item_idx) = self
+            .stake_output_with_item(item_n)
+            .map_err(|_| ViewError::NoData)?;
+
+        // for stake outputs the header is Stake
+        let header = pic_str!(b"Stake");
+
+        let res = self.render_output_with_header(&obj, item_idx, title, message, page, header);
+
+        // the stake can be locked past the staking period
+        if obj.output.is_lock_item(item_idx) {
+            let label = pic_str!(b"Output locked");
+            title.iter_mut().for_each(|v| *v = 0);
+            title[..label.len()].copy_from_slice(label);
+        }
+
+        res
+    }
+
+    // helper function to render any TransferableOutput<PvmOutput>,
+    // either locked or normal(comming as part of base_tx_fields)
+    // the rendering is the same, the only difference is that
+    // locked outputs uses a Stake label as the first item
+    fn render_output_with_header(
+        &'b self,
+        &obj: &TransferableOutput<'b, PvmOutput<'b>>,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+        header: &[u8],
+    ) -> Result<u8, ViewError> {
+        // get the number of items for the obj wrapped up by PvmOutput
+        let num_inner_items = obj.output.num_inner_items() as _;
+
+        // do a custom rendering of the first base_output_items
+        match item_n {
+            0 => {
+                title[..header.len()].copy_from_slice(header);
+
+                // render using default obj impl
+                let res = obj.render_item(0, title, message, page);
+
+                // customize the label
+                title.iter_mut().for_each(|v| *v = 0);
+                title[..header.len()].copy_from_slice(header);
+
+                res
+            }
+            // address rendering, we support rendering any number of addresses
+            x @ 1.. if x < num_inner_items => {
+                // get the address index
+                let address_idx = x - 1;
+                let address = obj
+                    .output
+                    .get_address_at(address_idx as usize)
+                    .ok_or(ViewError::NoData)?;
+                // render encoded address with proper hrp,
+                let t = pic_str!(b"Address");
+                title[..t.len()].copy_from_slice(t);
+
+                let hrp = self.tx_header.hrp().map_err(|_| ViewError::Unknown)?;
+                let mut encoded = [0; MAX_ADDRESS_ENCODED_LEN];
+
+                let addr_len = address
+                    .encode_into(hrp, &mut encoded[..])
+                    .map_err(|_| ViewError::Unknown)?;
+
+                handle_ui_message(&encoded[..addr_len], message, page)
+            }
+            // by default we call the objects impl here,
+            // if it is a locked output, that info will be shown otherwise,
+            // this returns an error
+            // the lock is rendered in the output asset
+            x if obj.output.is_lock_item(x) => obj.render_lock(title, message, page),
+            _ => obj.render_item(item_n, title, message, page),
+        }
+    }
+
+    fn sum_stake_outputs_amount(
+        stake: &'b ObjectList<'b, TransferableOutput<PvmOutput<'b>>>,
+    ) -> Result<u64, ParserError> {
+        stake
+            .iter()
+            .filter_map(|output| output.amount())
+            .try_fold(0u64, |acc, x| acc.checked_add(x))
+            .ok_or(ParserError::OperationOverflows)
+    }
+
+    fn render_rewards_to(
+        &self,
+        item_n: usize,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, zemu_sys::ViewError> {
+        // with several owners, tell how many of them control the rewards
+        let addr_idx = match (self.rewards_owner.is_multisig(), item_n) {
+            (true, 0) => {
+                let label = pic_str!(b"Reward owner");
+                return self
+                    .rewards_owner
+                    .render_threshold(label, title, message, page);
+            }
+            (true, x) => x - 1,
+            (false, x) => x,
+        };
+
+        let label = pic_str!(b"Rewards to");
+        title[..label.len()].copy_from_slice(label);
+
+        // render owner addresses
+        if let Some(addr) = self.rewards_owner.addresses.get(addr_idx) {
+            let hrp = self.tx_header.hrp().map_err(|_| ViewError::Unknown)?;
+
+            let mut address = MaybeUninit::uninit();
+            Address::from_bytes_into(addr, &mut address).map_err(|_| ViewError::Unknown)?;
+
+            let mut encoded = [0; MAX_ADDRESS_ENCODED_LEN];
+            // valid read as memory was initialized
+            let address = unsafe { address.assume_init() };
+
+            let len = address
+                .encode_into(hrp, &mut encoded[..])
+                .map_err(|_| ViewError::Unknown)?;
+
+            return handle_ui_message(&encoded[..len], message, page);
+        }
+
+        Err(ViewError::NoData)
+    }
+
+    fn render_last_items(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, zemu_sys::ViewError> {
+        use lexical_core::Number;
+
+        let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 2];
+        let rewards_items = self.rewards_owner.num_owner_items() as u8;
+        let memo_items = self.base_tx.memo_num_items() as u8;
+
+        match item_n {
+            // render rewards
+            x if x < rewards_items => self.render_rewards_to(x as usize, title, message, page),
+            x if x < rewards_items + memo_items => self.base_tx.render_memo(title, message, page),
+            x if x == rewards_items + memo_items => {
+                let label = pic_str!(b"Fee(AVAX)");
+                title[..label.len()].copy_from_slice(label);
+
+                let fee = self.fee().map_err(|_| ViewError::Unknown)?;
+                let fee_buff =
+                    nano_avax_to_fp_str(fee, &mut buffer[..]).map_err(|_| ViewError::Unknown)?;
+                handle_ui_message(fee_buff, message, page)
+            }
+            _ => Err(ViewError::NoData),
+        }
+    }
+
+    // Gets the obj that contain the item_n, along with the index
+    // of the item. Returns an error otherwise
+    pub fn stake_output_with_item(
+        &'b self,
+        item_n: u8,
+    ) -> Result<(TransferableOutput<PvmOutput>, u8), ParserError> {
+        let mut count = 0usize;
+        let mut obj_item_n = 0;
+        let mut idx = 0;
+        // gets the output that contains item_n
+        // and its corresponding index
+        let filter = |o: &TransferableOutput<'b, PvmOutput>| -> bool {
+            let render = self.renderable_out & (1 << idx) > 0;
+            idx += 1;
+            if !render {
+                return false;
+            }
+
+            let n = o.num_items();
+            for index in 0..n {
+                count += 1;
+                obj_item_n = index;
+                if count == item_n as usize + 1 {
+                    return true;
+                }
+            }
+            false
+        };
+
+        let obj = self
+            .stake
+            .get_obj_if(filter)
+            .ok_or(ParserError::DisplayIdxOutOfRange)?;
+        Ok((obj, obj_item_n as u8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::prelude::v1::*;
+
+    use super::*;
+    use crate::parser::{host::find_message, MAX_STAKE_DURATION, MIN_STAKE_DURATION_MAINNET};
+
+    const END_TIME_AT: usize = 248;
+    const SUBNET_ID_AT: usize = 264;
+    const STAKE_ASSET_AT: usize = 300;
+
+    fn data() -> Vec<u8> {
+        let data: Vec<u8> = serde_json::from_str(include_str!(
+            "../../testvectors/add_permissionless_delegator.json"
+        ))
+        .unwrap();
+        // skip the codec
+        data[2..].to_vec()
+    }
+
+    fn with_end_time(mut data: Vec<u8>, end_time: i64) -> Vec<u8> {
+        data[END_TIME_AT..END_TIME_AT + 8].copy_from_slice(&end_time.to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn parse_add_permissionless_delegator_tx() {
+        let data = data();
+        let (rem, tx) = AddPermissionlessDelegatorTx::from_bytes(&data).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(tx.validator.weight, 25_000_000_000);
+        assert!(tx.subnet_id.is_primary_network());
+
+        assert_eq!(
+            find_message::<_, 100, 100>(&tx, "Delegate to").unwrap(),
+            "NodeID-6Y3kysjF9jnHnYkdS9yGAuoHyae2eNmeV on Primary Network"
+        );
+        assert_eq!(
+            find_message::<_, 100, 100>(&tx, "Total stake").unwrap(),
+            "25 AVAX"
+        );
+    }
+
+    #[test]
+    fn subnet_delegation_with_custom_asset() {
+        let mut data = data();
+        data[SUBNET_ID_AT..SUBNET_ID_AT + 32].copy_from_slice(&[1; 32]);
+        data[STAKE_ASSET_AT..STAKE_ASSET_AT + 32].copy_from_slice(&[0xee; 32]);
+        // subnets define their own minimum staking period
+        let data = with_end_time(data, 1672531200 + 60);
+
+        let (_, tx) = AddPermissionlessDelegatorTx::from_bytes(&data).unwrap();
+        assert_eq!(
+            find_message::<_, 100, 100>(&tx, "Delegate to").unwrap(),
+            "NodeID-6Y3kysjF9jnHnYkdS9yGAuoHyae2eNmeV on subnet \
+             SeLqn3UAUoRymWmwW7axrzJK7JfNaBR2cHCryA6cFscgkny8"
+        );
+        assert_eq!(
+            find_message::<_, 100, 100>(&tx, "Total stake").unwrap(),
+            "25000000000 2pEER9q8Tu5XVwfBQeU2NE883JsUTX9jbbmVg3SL1g2fG4KkQh"
+        );
+    }
+
+    #[test]
+    fn primary_network_requires_avax_stake() {
+        let mut data = data();
+        data[STAKE_ASSET_AT..STAKE_ASSET_AT + 32].copy_from_slice(&[0xee; 32]);
+
+        let err = AddPermissionlessDelegatorTx::from_bytes(&data).unwrap_err();
+        assert_eq!(err, nom::Err::Error(ParserError::InvalidStakingAmount));
+    }
+
+    #[test]
+    fn staking_period_bounds() {
+        let start = 1672531200;
+
+        // mainnet requires two weeks at least
+        let data = with_end_time(data(), start + MIN_STAKE_DURATION_MAINNET - 1);
+        let err = AddPermissionlessDelegatorTx::from_bytes(&data).unwrap_err();
+        assert_eq!(err, nom::Err::Error(ParserError::InvalidStakingPeriod));
+
+        let data = with_end_time(data(), start + MAX_STAKE_DURATION + 1);
+        let err = AddPermissionlessDelegatorTx::from_bytes(&data).unwrap_err();
+        assert_eq!(err, nom::Err::Error(ParserError::InvalidStakingPeriod));
+
+        let data = with_end_time(data(), start + MAX_STAKE_DURATION);
+        assert!(AddPermissionlessDelegatorTx::from_bytes(&data).is_ok());
+    }
+}
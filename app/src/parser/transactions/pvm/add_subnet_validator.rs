@@ -75,8 +75,8 @@ impl<'b> FromBytes<'b> for AddSubnetValidatorTx<'b> {
 impl<'b> DisplayableItem for AddSubnetValidatorTx<'b> {
     fn num_items(&self) -> usize {
         // tx_info, validator_items(4),
-        // subnet_id and fee
-        1 + self.validator.num_items() + 1 + 1
+        // subnet_id, memo and fee
+        1 + self.validator.num_items() + 1 + self.base_tx.memo_num_items() + 1
     }
 
     fn render_item(
@@ -99,6 +99,7 @@ impl<'b> DisplayableItem for AddSubnetValidatorTx<'b> {
         let item_n = item_n - 1;
 
         let validator_items = self.validator.num_items() as u8;
+        let memo_items = self.base_tx.memo_num_items() as u8;
 
         match item_n {
             // render validator info
@@ -107,8 +108,13 @@ impl<'b> DisplayableItem for AddSubnetValidatorTx<'b> {
             // render subnet_id
             x if x == validator_items => self.subnet_id.render_item(0, title, message, page),
 
+            // render memo
+            x if x < validator_items + 1 + memo_items => {
+                self.base_tx.render_memo(title, message, page)
+            }
+
             // render fee
-            x if x == validator_items + 1 => {
+            x if x == validator_items + 1 + memo_items => {
                 let label = pic_str!(b"Fee(AVAX)");
                 title[..label.len()].copy_from_slice(label);
 
@@ -21,9 +21,9 @@ use zemu_sys::ViewError;
 use crate::{
     handlers::handle_ui_message,
     parser::{
-        intstr_to_fpstr_inplace, nano_avax_to_fp_str, u64_to_str, Address, BaseTxFields,
-        DisplayableItem, FromBytes, Header, ObjectList, OutputIdx, ParserError, PvmOutput,
-        SECPOutputOwners, TransferableOutput, Validator, DELEGATION_FEE_DIGITS,
+        nano_avax_to_fp_str, shares_to_percent_str, Address, BaseTxFields, DisplayableItem,
+        FromBytes, Header, ObjectList, OutputIdx, ParserError, PvmOutput, SECPOutputOwners,
+        TransferableOutput, TxSummary, Validator, DELEGATION_SHARES_DENOMINATOR,
         MAX_ADDRESS_ENCODED_LEN, PVM_ADD_VALIDATOR,
     },
 };
@@ -99,6 +99,9 @@ impl<'b> FromBytes<'b> for AddValidatorTx<'b> {
 
         // shares
         let (rem, shares) = be_u32(rem)?;
+        if shares > DELEGATION_SHARES_DENOMINATOR {
+            return Err(ParserError::ValueOutOfRange.into());
+        }
 
         //good ptr and no uninit reads
         unsafe {
@@ -114,11 +117,12 @@ impl<'b> FromBytes<'b> for AddValidatorTx<'b> {
 impl<'b> DisplayableItem for AddValidatorTx<'b> {
     fn num_items(&self) -> usize {
         // tx_info, base_tx items, validator_items(4),
-        // fee, fee_delegation, rewards_to and stake items
+        // fee, fee_delegation, rewards_to, stake items and memo
         1 + self.base_tx.base_outputs_num_items()
             + self.validator.num_items()
-            + self.rewards_owner.addresses.len()
+            + self.rewards_owner.num_owner_items()
             + self.num_stake_items()
+            + self.base_tx.memo_num_items()
             + 1
             + 1
     }
@@ -199,6 +203,17 @@ impl<'b> AddValidatorTx<'b> {
         self.renderable_out = render;
     }
 
+    // the staked amount is accounted even if it goes
+    // back to the signer, as it gets locked
+    pub fn summary(&'b self) -> Result<TxSummary, ParserError> {
+        let mut summary = self.base_tx.summary()?;
+        summary.add_outputs(
+            self.stake.iter().count(),
+            TxSummary::avax_amount(self.stake.iter())?,
+        )?;
+        Ok(summary)
+    }
+
     fn fee(&'b self) -> Result<u64, ParserError> {
         let sum_inputs = self.base_tx.sum_inputs_amount()?;
 
@@ -260,7 +275,16 @@ impl<'b> AddValidatorTx<'b> {
         // for base_outputs the header is Transfer
         let header = pic_str!(b"Stake");
 
-        self.render_output_with_header(&obj, item_idx, title, message, page, header)
+        let res = self.render_output_with_header(&obj, item_idx, title, message, page, header);
+
+        // the stake can be locked past the staking period
+        if obj.output.is_lock_item(item_idx) {
+            let label = pic_str!(b"Output locked");
+            title.iter_mut().for_each(|v| *v = 0);
+            title[..label.len()].copy_from_slice(label);
+        }
+
+        res
     }
 
     // helper function to render any TransferableOutput<PvmOutput>,
@@ -325,6 +349,8 @@ impl<'b> AddValidatorTx<'b> {
             // by default we call the objects impl here,
             // if it is a locked output, that info will be shown otherwise,
             // this returns an error
+            // the lock is rendered in the output asset
+            x if obj.output.is_lock_item(x) => obj.render_lock(title, message, page),
             _ => obj.render_item(item_n, title, message, page),
         }
     }
@@ -341,11 +367,23 @@ impl<'b> AddValidatorTx<'b> {
 
     fn render_rewards_to(
         &self,
-        addr_idx: usize,
+        item_n: usize,
         title: &mut [u8],
         message: &mut [u8],
         page: u8,
     ) -> Result<u8, zemu_sys::ViewError> {
+        // with several owners, tell how many of them control the rewards
+        let addr_idx = match (self.rewards_owner.is_multisig(), item_n) {
+            (true, 0) => {
+                let label = pic_str!(b"Reward owner");
+                return self
+                    .rewards_owner
+                    .render_threshold(label, title, message, page);
+            }
+            (true, x) => x - 1,
+            (false, x) => x,
+        };
+
         let label = pic_str!(b"Rewards to");
         title[..label.len()].copy_from_slice(label);
 
@@ -380,24 +418,27 @@ impl<'b> AddValidatorTx<'b> {
         use lexical_core::Number;
 
         let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 2];
-        let num_addresses = self.rewards_owner.addresses.len() as u8;
+        let rewards_items = self.rewards_owner.num_owner_items() as u8;
+        let memo_items = self.base_tx.memo_num_items() as u8;
 
         match item_n {
             // render rewards
-            x @ 0.. if x < num_addresses => {
+            x @ 0.. if x < rewards_items => {
                 self.render_rewards_to(x as usize, title, message, page)
             }
-            x if x >= num_addresses && x < (num_addresses + 1) => {
-                let label = pic_str!(b"Delegate fee(%)");
+            x if x >= rewards_items && x < (rewards_items + 1) => {
+                let label = pic_str!(b"Delegate fee");
                 title[..label.len()].copy_from_slice(label);
-                u64_to_str(self.shares as _, &mut buffer[..]).map_err(|_| ViewError::Unknown)?;
 
-                let buffer = intstr_to_fpstr_inplace(&mut buffer[..], DELEGATION_FEE_DIGITS)
+                let buffer = shares_to_percent_str(self.shares, &mut buffer[..])
                     .map_err(|_| ViewError::Unknown)?;
 
                 handle_ui_message(buffer, message, page)
             }
-            x if x == (num_addresses + 1) => {
+            x if x < (rewards_items + 1 + memo_items) => {
+                self.base_tx.render_memo(title, message, page)
+            }
+            x if x == (rewards_items + 1 + memo_items) => {
                 let label = pic_str!(b"Fee(AVAX)");
                 title[..label.len()].copy_from_slice(label);
 
@@ -450,6 +491,7 @@ impl<'b> AddValidatorTx<'b> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::prelude::v1::*;
 
     const DATA: &[u8] = &[
         0x00, 0x00, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -502,6 +544,42 @@ mod tests {
         assert_eq!(tx.validator.weight, 1000000000);
     }
 
+    #[test]
+    fn stake_lock_and_staking_period() {
+        let (_, tx) = AddValidatorTx::from_bytes(DATA).unwrap();
+
+        let pages = (0..tx.num_items())
+            .map(|i| {
+                let mut title = [0; 100];
+                let mut value = [0; 100];
+                tx.render_item(i as _, &mut title, &mut value, 0).unwrap();
+                let str_of = |b: &[u8]| {
+                    let len = b.iter().position(|c| *c == 0).unwrap();
+                    String::from_utf8(b[..len].to_vec()).unwrap()
+                };
+                (str_of(&title), str_of(&value))
+            })
+            .collect::<Vec<_>>();
+        let page = |title: &str| {
+            pages
+                .iter()
+                .find(|(t, _)| t == title)
+                .map(|(_, v)| v.as_str())
+        };
+
+        assert_eq!(page("Start time"), Some("2021-03-15 20:05:27 UTC"));
+        assert_eq!(page("End time"), Some("2022-03-15 19:55:27 UTC"));
+        assert_eq!(
+            page("Output locked"),
+            Some("1 AVAX until 2021-05-31 21:28:00 UTC")
+        );
+        // the change output keeps the transfer label
+        assert_eq!(
+            page("Funds locked"),
+            Some("0.5 AVAX until 2021-05-31 21:28:00 UTC")
+        );
+    }
+
     #[test]
     fn ui_validator() {
         let (_, tx) = AddValidatorTx::from_bytes(DATA).unwrap();
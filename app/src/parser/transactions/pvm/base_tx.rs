@@ -0,0 +1,228 @@
+/*******************************************************************************
+*   (c) 2018 - 2022 Zondax AG
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use core::{mem::MaybeUninit, ptr::addr_of_mut};
+use nom::bytes::complete::tag;
+use zemu_sys::ViewError;
+
+use crate::{
+    handlers::handle_ui_message,
+    parser::{
+        nano_avax_to_fp_str, BaseTxFields, DisplayableItem, FromBytes, Header, ParserError,
+        PvmOutput, TxSummary, BLOCKCHAIN_ID_LEN, MAX_ADDRESS_ENCODED_LEN, PVM_BASE_TX,
+    },
+};
+
+/// Transfer of AVAX between P-chain addresses
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct PvmBaseTx<'b> {
+    pub tx_header: Header<'b>,
+    pub base_tx: BaseTxFields<'b, PvmOutput<'b>>,
+}
+
+impl<'b> PvmBaseTx<'b> {
+    pub fn disable_output_if(&mut self, address: &[u8]) {
+        self.base_tx.disable_output_if(address);
+    }
+
+    pub fn summary(&'b self) -> Result<TxSummary, ParserError> {
+        self.base_tx.summary()
+    }
+
+    fn fee(&'b self) -> Result<u64, ParserError> {
+        let inputs = self.base_tx.sum_inputs_amount()?;
+        let outputs = self.base_tx.sum_outputs_amount()?;
+        inputs
+            .checked_sub(outputs)
+            .ok_or(ParserError::OperationOverflows)
+    }
+
+    fn render_outputs(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+
+        let (obj, idx) = self
+            .base_tx
+            .base_output_with_item(item_n)
+            .map_err(|_| ViewError::NoData)?;
+
+        let num_inner_items = obj.output.num_inner_items() as _;
+
+        match idx {
+            0 => {
+                let res = obj.render_item(0, title, message, page);
+
+                title.iter_mut().for_each(|v| *v = 0);
+                let label = pic_str!(b"Transfer");
+                title[..label.len()].copy_from_slice(label);
+
+                res
+            }
+            x @ 1.. if x < num_inner_items => {
+                let address = obj
+                    .output
+                    .get_address_at(x as usize - 1)
+                    .ok_or(ViewError::NoData)?;
+
+                // render encoded address with proper hrp,
+                let t = pic_str!(b"Address");
+                title[..t.len()].copy_from_slice(t);
+
+                let hrp = self.tx_header.hrp().map_err(|_| ViewError::Unknown)?;
+                let mut encoded = [0; MAX_ADDRESS_ENCODED_LEN];
+
+                let addr_len = address
+                    .encode_into(hrp, &mut encoded[..])
+                    .map_err(|_| ViewError::Unknown)?;
+
+                handle_ui_message(&encoded[..addr_len], message, page)
+            }
+            // locked information of the output, if any,
+            // rendered in the output asset
+            x if obj.output.is_lock_item(x) => obj.render_lock(title, message, page),
+            _ => obj.render_item(idx, title, message, page),
+        }
+    }
+}
+
+impl<'b> FromBytes<'b> for PvmBaseTx<'b> {
+    #[inline(never)]
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("PvmBaseTx::from_bytes_into\x00");
+
+        let (rem, _) = tag(PVM_BASE_TX.to_be_bytes())(input)?;
+
+        let out = out.as_mut_ptr();
+
+        // tx header
+        let tx_header = unsafe { &mut *addr_of_mut!((*out).tx_header).cast() };
+        let rem = Header::from_bytes_into(rem, tx_header)?;
+
+        // the P-chain id is all zeros in every network
+        let header = unsafe { tx_header.assume_init_ref() };
+        if header.blockchain_id != &[0; BLOCKCHAIN_ID_LEN] {
+            return Err(ParserError::InvalidChainId.into());
+        }
+
+        // base_tx
+        let base_tx = unsafe { &mut *addr_of_mut!((*out).base_tx).cast() };
+        let rem = BaseTxFields::<PvmOutput>::from_bytes_into(rem, base_tx)?;
+        let base = unsafe { base_tx.assume_init_ref() };
+
+        // only transfers are supported as outputs
+        if base
+            .outputs()
+            .iter()
+            .any(|o| o.output.secp_transfer().is_none())
+        {
+            return Err(ParserError::UnexpectedType.into());
+        }
+
+        Ok(rem)
+    }
+}
+
+impl<'b> DisplayableItem for PvmBaseTx<'b> {
+    fn num_items(&self) -> usize {
+        // description + network + outputs + memo + fee
+        1 + 1 + self.base_tx.base_outputs_num_items() + self.base_tx.memo_num_items() + 1
+    }
+
+    fn render_item(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+        use lexical_core::Number;
+
+        if item_n == 0 {
+            let label = pic_str!(b"Transfer");
+            title[..label.len()].copy_from_slice(label);
+            return handle_ui_message(pic_str!(b"P-Chain"), message, page);
+        }
+
+        if item_n == 1 {
+            let label = pic_str!(b"Network");
+            title[..label.len()].copy_from_slice(label);
+            let network = self
+                .tx_header
+                .network_id()
+                .map_err(|_| ViewError::Unknown)?;
+            return handle_ui_message(network.name(), message, page);
+        }
+
+        let item_n = item_n - 2;
+        let outputs_items = self.base_tx.base_outputs_num_items() as u8;
+        let memo_items = self.base_tx.memo_num_items() as u8;
+
+        match item_n {
+            x @ 0.. if x < outputs_items => self.render_outputs(x, title, message, page),
+            x if x < outputs_items + memo_items => self.base_tx.render_memo(title, message, page),
+            x if x == outputs_items + memo_items => {
+                let label = pic_str!(b"Fee(AVAX)");
+                title[..label.len()].copy_from_slice(label);
+
+                let fee = self.fee().map_err(|_| ViewError::Unknown)?;
+                let mut content = [0; u64::FORMATTED_SIZE_DECIMAL + 2];
+                let fee =
+                    nano_avax_to_fp_str(fee, &mut content[..]).map_err(|_| ViewError::Unknown)?;
+
+                handle_ui_message(fee, message, page)
+            }
+            _ => Err(ViewError::NoData),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: &str = "00000022000000050000000000000000000000000000000000000000000000000000000000000000000000013d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa00000007000000003b9aca00000000000000000000000001000000017f671c730d4807c29ea19b19a23c700b198f8b51000000011c0306e58b754eeb92e7a579c59a693323cd9994a5946162726f3b680e9e4834000000003d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa00000005000000003baa0c40000000010000000000000000";
+
+    #[test]
+    fn parse_base_tx() {
+        let data = hex::decode(DATA).unwrap();
+        let (rem, tx) = PvmBaseTx::from_bytes(&data).unwrap();
+
+        assert!(rem.is_empty());
+        assert_eq!(tx.fee().unwrap(), 1_000_000);
+        // description, network, amount, address and fee
+        assert_eq!(tx.num_items(), 5);
+    }
+
+    #[test]
+    fn base_tx_wrong_chain() {
+        let mut data = hex::decode(DATA).unwrap();
+        // any chain other than the P-chain
+        data[8] = 1;
+
+        let err = PvmBaseTx::from_bytes(&data).unwrap_err();
+        assert_eq!(err, nom::Err::Error(ParserError::InvalidChainId));
+    }
+}
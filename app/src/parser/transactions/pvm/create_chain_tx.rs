@@ -21,12 +21,12 @@ use nom::{
 use zemu_sys::ViewError;
 
 use crate::{
-    handlers::handle_ui_message,
+    handlers::{handle_ui_message, handle_ui_message_hex},
     parser::{
-        cb58_output_len, nano_avax_to_fp_str, BaseTxFields, DisplayableItem, FromBytes, Header,
-        ParserError, PvmOutput, SubnetAuth, SubnetId, CB58_CHECKSUM_LEN, PVM_CREATE_CHAIN,
+        cb58_output_len, nano_avax_to_fp_str, u64_to_str, BaseTxFields, DisplayableItem, FromBytes,
+        Header, ParserError, PvmOutput, SubnetAuth, SubnetId, PVM_CREATE_CHAIN,
     },
-    utils::{bs58_encode, hex_encode, ApduPanic},
+    utils::{cb58_encode, is_app_mode_expert, ApduPanic},
 };
 
 pub const VM_ID_LEN: usize = 32;
@@ -88,8 +88,11 @@ impl<'b> FromBytes<'b> for CreateChainTx<'b> {
         let (rem, chain_name) = take(chain_name_len as usize)(rem)?;
         // chain name is a valid utf8 string according
         // to avalanche's docs
-        // double check for ascii bytes
-        if !chain_name.is_ascii() {
+        // double check for printable ascii bytes
+        if !chain_name
+            .iter()
+            .all(|c| c.is_ascii_graphic() || *c == b' ')
+        {
             return Err(ParserError::InvalidAsciiValue.into());
         }
 
@@ -124,9 +127,9 @@ impl<'b> FromBytes<'b> for CreateChainTx<'b> {
 impl<'b> DisplayableItem for CreateChainTx<'b> {
     fn num_items(&self) -> usize {
         // we need to show:
-        // tx description, SubnetID, ChainName, VMID, GenesisDataHash
-        // and fee
-        1 + 4 + 1
+        // tx description, SubnetID, ChainName, VMID,
+        // GenesisData size, its hash in expert mode, memo and fee
+        1 + 4 + is_app_mode_expert() as usize + self.base_tx.memo_num_items() + 1
     }
 
     fn render_item(
@@ -142,7 +145,17 @@ impl<'b> DisplayableItem for CreateChainTx<'b> {
         };
         use lexical_core::Number;
 
-        let mut hex_buf = [0; Sha256::DIGEST_LEN * 2];
+        // the memo follows the genesis hash if shown
+        // and the fee follows the memo if there is one
+        let item_n = match item_n {
+            5.. if !is_app_mode_expert() => item_n + 1,
+            n => n,
+        };
+        let item_n = match item_n {
+            6.. if self.base_tx.memo_num_items() == 0 => item_n + 1,
+            n => n,
+        };
+
         match item_n {
             0 => {
                 let label = pic_str!(b"CreateChain");
@@ -160,27 +173,36 @@ impl<'b> DisplayableItem for CreateChainTx<'b> {
                 let label = pic_str!(b"VMID");
                 title[..label.len()].copy_from_slice(label);
 
-                let checksum = Sha256::digest(self.vm_id).map_err(|_| ViewError::Unknown)?;
-                // prepare the data to be encoded by appending last 4-byte
-                let mut data = [0; VM_ID_LEN + CB58_CHECKSUM_LEN];
-                data[..VM_ID_LEN].copy_from_slice(&self.vm_id[..]);
-                data[VM_ID_LEN..]
-                    .copy_from_slice(&checksum[(Sha256::DIGEST_LEN - CB58_CHECKSUM_LEN)..]);
-
                 const MAX_SIZE: usize = cb58_output_len::<VM_ID_LEN>();
                 let mut encoded = [0; MAX_SIZE];
 
-                let len = bs58_encode(data, &mut encoded[..]).map_err(|_| ViewError::Unknown)?;
+                let len =
+                    cb58_encode(self.vm_id, &mut encoded[..]).map_err(|_| ViewError::Unknown)?;
                 handle_ui_message(&encoded[..len], message, page)
             }
             4 => {
-                let label = pic_str!(b"GenesisData");
+                let label = pic_str!(b"Genesis size");
                 title[..label.len()].copy_from_slice(label);
-                let sha = Sha256::digest(self.genesis_data).map_err(|_| ViewError::Unknown)?;
-                hex_encode(&sha[..], &mut hex_buf).map_err(|_| ViewError::Unknown)?;
-                handle_ui_message(&hex_buf, message, page)
+
+                let bytes = pic_str!(b" bytes"!);
+                let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 6];
+                let len = u64_to_str(self.genesis_data.len() as u64, &mut buffer[..])
+                    .map_err(|_| ViewError::Unknown)?
+                    .len();
+                buffer[len..len + bytes.len()].copy_from_slice(bytes);
+
+                handle_ui_message(&buffer[..len + bytes.len()], message, page)
             }
+            // to verify the genesis against a known value
+            // without showing all of its bytes
             5 => {
+                let label = pic_str!(b"Genesis hash");
+                title[..label.len()].copy_from_slice(label);
+                let sha = Sha256::digest(self.genesis_data).map_err(|_| ViewError::Unknown)?;
+                handle_ui_message_hex(&[], &sha[..], message, page)
+            }
+            6 => self.base_tx.render_memo(title, message, page),
+            7 => {
                 let label = pic_str!(b"Fee(AVAX)");
                 title[..label.len()].copy_from_slice(label);
 
@@ -225,4 +247,28 @@ mod tests {
         assert_eq!(tx.chain_name, b"zondax");
         assert_eq!(tx.fx_id.len(), 1);
     }
+
+    #[test]
+    fn genesis_hash_page() {
+        let (_, tx) = CreateChainTx::from_bytes(DATA).unwrap();
+        assert_eq!(tx.genesis_data, b"genesis data");
+
+        // expert mode is always on in tests
+        let mut title = [0; 32];
+        let mut message = [0; 100];
+        tx.render_item(5, &mut title, &mut message, 0).unwrap();
+
+        let expected = b"d1f916728e110d4109ccce1d17268606f51c0acd8b67d643da8cb3b6254ed806\0";
+        assert_eq!(&title[..13], b"Genesis hash\0");
+        assert_eq!(&message[..expected.len()], &expected[..]);
+    }
+
+    #[test]
+    fn non_printable_chain_name() {
+        let name_at = DATA.windows(6).position(|w| w == b"zondax").unwrap();
+
+        let mut data = DATA.to_vec();
+        data[name_at] = b'\n';
+        assert!(CreateChainTx::from_bytes(&data).is_err());
+    }
 }
@@ -21,7 +21,7 @@ use crate::{
     handlers::handle_ui_message,
     parser::{
         nano_avax_to_fp_str, BaseTxFields, DisplayableItem, FromBytes, Header, ParserError,
-        PvmOutput, SECPOutputOwners, PVM_CREATE_SUBNET,
+        PvmOutput, SECPOutputOwners, MAX_ADDRESS_ENCODED_LEN, PVM_CREATE_SUBNET,
     },
 };
 
@@ -74,9 +74,53 @@ impl<'b> FromBytes<'b> for CreateSubnetTx<'b> {
     }
 }
 
+impl<'b> CreateSubnetTx<'b> {
+    // renders the owners threshold, locktime and
+    // addresses encoded with the network hrp
+    fn render_owners(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, zemu_sys::ViewError> {
+        use bolos::{pic_str, PIC};
+
+        if item_n == 0 {
+            let label = pic_str!(b"Threshold");
+            return self.owners.render_threshold(label, title, message, page);
+        }
+
+        let item_n = item_n - 1;
+        let addr_item_n = (self.owners.num_items() - self.owners.num_addresses()) as u8;
+
+        if item_n < addr_item_n {
+            return self.owners.render_item(item_n, title, message, page);
+        }
+
+        let address = self
+            .owners
+            .get_address_at((item_n - addr_item_n) as usize)
+            .ok_or(ViewError::NoData)?;
+
+        let label = pic_str!(b"Owner address");
+        title[..label.len()].copy_from_slice(label);
+
+        let hrp = self.tx_header.hrp().map_err(|_| ViewError::Unknown)?;
+        let mut encoded = [0; MAX_ADDRESS_ENCODED_LEN];
+
+        let addr_len = address
+            .encode_into(hrp, &mut encoded[..])
+            .map_err(|_| ViewError::Unknown)?;
+
+        handle_ui_message(&encoded[..addr_len], message, page)
+    }
+}
+
 impl<'b> DisplayableItem for CreateSubnetTx<'b> {
     fn num_items(&self) -> usize {
-        1 + self.owners.num_items() + 1 //fee
+        // description + threshold + owners + memo + fee
+        1 + 1 + self.owners.num_items() + self.base_tx.memo_num_items() + 1
     }
 
     fn render_item(
@@ -89,7 +133,8 @@ impl<'b> DisplayableItem for CreateSubnetTx<'b> {
         use bolos::{pic_str, PIC};
         use lexical_core::Number;
 
-        let owners_items = self.owners.num_items() as u8;
+        let owners_items = 1 + self.owners.num_items() as u8;
+        let memo_items = self.base_tx.memo_num_items() as u8;
 
         if item_n == 0 {
             let label = pic_str!(b"CreateSubnet");
@@ -101,8 +146,9 @@ impl<'b> DisplayableItem for CreateSubnetTx<'b> {
         let item_n = item_n - 1;
 
         match item_n {
-            x @ 0.. if x < owners_items => self.owners.render_item(item_n, title, message, page),
-            x if x == owners_items => {
+            x @ 0.. if x < owners_items => self.render_owners(x, title, message, page),
+            x if x < owners_items + memo_items => self.base_tx.render_memo(title, message, page),
+            x if x == owners_items + memo_items => {
                 let label = pic_str!(b"Fee(AVAX)");
                 title[..label.len()].copy_from_slice(label);
 
@@ -141,5 +187,6 @@ mod tests {
     fn parse_create_subnet_tx() {
         let (_, tx) = CreateSubnetTx::from_bytes(DATA).unwrap();
         assert_eq!(tx.owners.addresses.len(), 1);
+        assert_eq!(tx.owners.threshold, 1);
     }
 }
@@ -22,7 +22,7 @@ use crate::{
     handlers::handle_ui_message,
     parser::{
         nano_avax_to_fp_str, BaseExport, DisplayableItem, FromBytes, ParserError, PvmOutput,
-        PVM_EXPORT_TX,
+        TxSummary, UtxoList, PVM_EXPORT_TX,
     },
 };
 
@@ -70,7 +70,7 @@ impl<'b> FromBytes<'b> for PvmExportTx<'b> {
 
 impl<'b> DisplayableItem for PvmExportTx<'b> {
     fn num_items(&self) -> usize {
-        1 + self.0.num_outputs_items() + 1
+        1 + self.0.num_outputs_items() + self.0.base_tx.memo_num_items() + 1
     }
 
     fn render_item(
@@ -88,14 +88,16 @@ impl<'b> DisplayableItem for PvmExportTx<'b> {
             return self.0.render_export_description(title, message, page);
         }
 
-        let outputs_num_items = self.0.num_outputs_items();
+        let outputs_num_items = self.0.num_outputs_items() as u8;
+        let memo_items = self.0.base_tx.memo_num_items() as u8;
         let new_item_n = item_n - 1;
 
         match new_item_n {
-            x @ 0.. if x < outputs_num_items as u8 => {
-                self.0.render_outputs(x, title, message, page)
+            x @ 0.. if x < outputs_num_items => self.0.render_outputs(x, title, message, page),
+            x if x < outputs_num_items + memo_items => {
+                self.0.base_tx.render_memo(title, message, page)
             }
-            x if x == outputs_num_items as u8 => {
+            x if x == outputs_num_items + memo_items => {
                 let title_content = pic_str!(b"Fee");
                 title[..title_content.len()].copy_from_slice(title_content);
                 let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 2];
@@ -117,6 +119,14 @@ impl<'b> PvmExportTx<'b> {
     pub fn disable_output_if(&mut self, address: &[u8]) {
         self.0.disable_output_if(address);
     }
+
+    pub fn summary(&'b self) -> Result<TxSummary, ParserError> {
+        self.0.summary()
+    }
+
+    pub fn utxos(&self) -> UtxoList<'b> {
+        self.0.base_tx.utxos()
+    }
 }
 
 #[cfg(test)]
@@ -22,7 +22,7 @@ use crate::{
     handlers::handle_ui_message,
     parser::{
         nano_avax_to_fp_str, BaseImport, DisplayableItem, FromBytes, ParserError, PvmOutput,
-        PVM_IMPORT_TX,
+        TxSummary, UtxoList, PVM_IMPORT_TX,
     },
 };
 
@@ -61,6 +61,14 @@ impl<'b> PvmImportTx<'b> {
     pub fn disable_output_if(&mut self, address: &[u8]) {
         self.0.disable_output_if(address);
     }
+
+    pub fn summary(&'b self) -> Result<TxSummary, ParserError> {
+        self.0.summary()
+    }
+
+    pub fn utxos(&self) -> UtxoList<'b> {
+        self.0.utxos()
+    }
 }
 
 impl<'b> DisplayableItem for PvmImportTx<'b> {
@@ -69,8 +77,8 @@ impl<'b> DisplayableItem for PvmImportTx<'b> {
         // and to keep compatibility with the legacy app,
         // we show only 4 items for each output
         // tx info, amount, address and fee which is the sum of all inputs minus all outputs
-        // and the chain description
-        1 + self.0.num_input_items() + 1 + 1
+        // and the chain description, memo
+        1 + self.0.num_input_items() + 1 + self.0.base_tx.memo_num_items() + 1
     }
 
     fn render_item(
@@ -91,12 +99,16 @@ impl<'b> DisplayableItem for PvmImportTx<'b> {
         }
 
         let inputs_num_items = self.0.num_input_items() as u8;
+        let memo_items = self.0.base_tx.memo_num_items() as u8;
         let new_item_n = item_n - 1;
 
         match new_item_n {
             x @ 0.. if x < inputs_num_items as u8 => self.0.render_imports(x, title, message, page),
             x if x == inputs_num_items => self.0.render_import_description(title, message, page),
-            x if x == (inputs_num_items + 1) => {
+            x if x < inputs_num_items + 1 + memo_items => {
+                self.0.base_tx.render_memo(title, message, page)
+            }
+            x if x == (inputs_num_items + 1 + memo_items) => {
                 let title_content = pic_str!(b"Fee");
                 title[..title_content.len()].copy_from_slice(title_content);
                 let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 2];
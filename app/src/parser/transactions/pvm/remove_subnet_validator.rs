@@ -0,0 +1,260 @@
+/*******************************************************************************
+*   (c) 2021 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use crate::sys::ViewError;
+use core::{mem::MaybeUninit, ptr::addr_of_mut};
+use nom::bytes::complete::{tag, take};
+
+use crate::{
+    handlers::handle_ui_message,
+    parser::{
+        cb58_output_len, encode_node_id, nano_avax_to_fp_str, BaseTxFields, DisplayableItem,
+        FromBytes, Header, ParserError, PvmOutput, SubnetAuth, SubnetId, MAX_NODE_ID_ENCODED_LEN,
+        NODE_ID_LEN, PVM_REMOVE_SUBNET_VALIDATOR, SUBNET_ID_LEN,
+    },
+    utils::cb58_encode,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+#[cfg_attr(test, derive(Debug))]
+pub struct RemoveSubnetValidatorTx<'b> {
+    pub tx_header: Header<'b>,
+    pub base_tx: BaseTxFields<'b, PvmOutput<'b>>,
+    pub node_id: &'b [u8; NODE_ID_LEN],
+    pub subnet_id: SubnetId<'b>,
+    pub subnet_auth: SubnetAuth<'b>,
+}
+
+impl<'b> FromBytes<'b> for RemoveSubnetValidatorTx<'b> {
+    #[inline(never)]
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("RemoveSubnetValidatorTx::from_bytes_into\x00");
+
+        let (rem, _) = tag(PVM_REMOVE_SUBNET_VALIDATOR.to_be_bytes())(input)?;
+
+        let out = out.as_mut_ptr();
+
+        // tx header
+        let tx_header = unsafe { &mut *addr_of_mut!((*out).tx_header).cast() };
+        let rem = Header::from_bytes_into(rem, tx_header)?;
+
+        // base_tx
+        let base_tx = unsafe { &mut *addr_of_mut!((*out).base_tx).cast() };
+        let rem = BaseTxFields::<PvmOutput>::from_bytes_into(rem, base_tx)?;
+
+        // node_id
+        let (rem, node_id) = take(NODE_ID_LEN)(rem)?;
+        let node_id = arrayref::array_ref!(node_id, 0, NODE_ID_LEN);
+
+        // SubnetId, primary network validators are removed
+        // when their staking period ends
+        let subnet_id = unsafe { &mut *addr_of_mut!((*out).subnet_id).cast() };
+        let rem = SubnetId::from_bytes_into(rem, subnet_id)?;
+        if unsafe { subnet_id.assume_init_ref() }.is_primary_network() {
+            return Err(ParserError::UnexpectedField.into());
+        }
+
+        // subnetAuth
+        let subnet_auth = unsafe { &mut *addr_of_mut!((*out).subnet_auth).cast() };
+        let rem = SubnetAuth::from_bytes_into(rem, subnet_auth)?;
+
+        //good ptr and no uninit reads
+        unsafe {
+            addr_of_mut!((*out).node_id).write(node_id);
+        }
+
+        Ok(rem)
+    }
+}
+
+impl<'b> DisplayableItem for RemoveSubnetValidatorTx<'b> {
+    fn num_items(&self) -> usize {
+        // tx_info, validator and subnet, auth indices, memo and fee
+        1 + 1 + 1 + self.base_tx.memo_num_items() + 1
+    }
+
+    fn render_item(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, zemu_sys::ViewError> {
+        use bolos::{pic_str, PIC};
+        use lexical_core::Number;
+
+        // the fee follows the memo if there is one
+        let item_n = match item_n {
+            3.. if self.base_tx.memo_num_items() == 0 => item_n + 1,
+            n => n,
+        };
+
+        match item_n {
+            0 => {
+                let label = pic_str!(b"RemoveValidator");
+                title[..label.len()].copy_from_slice(label);
+                let content = pic_str!(b"Transaction");
+                handle_ui_message(content, message, page)
+            }
+            1 => self.render_removal(title, message, page),
+            2 => {
+                let label = pic_str!(b"Auth indices");
+                title[..label.len()].copy_from_slice(label);
+
+                self.subnet_auth.render_indices(message, page)
+            }
+            3 => self.base_tx.render_memo(title, message, page),
+            4 => {
+                let label = pic_str!(b"Fee(AVAX)");
+                title[..label.len()].copy_from_slice(label);
+
+                let fee = self.fee().map_err(|_| ViewError::Unknown)?;
+
+                let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 2];
+                let fee_buff =
+                    nano_avax_to_fp_str(fee, &mut buffer[..]).map_err(|_| ViewError::Unknown)?;
+                handle_ui_message(fee_buff, message, page)
+            }
+            _ => Err(ViewError::NoData),
+        }
+    }
+}
+
+impl<'b> RemoveSubnetValidatorTx<'b> {
+    fn fee(&'b self) -> Result<u64, ParserError> {
+        let sum_inputs = self.base_tx.sum_inputs_amount()?;
+
+        let total_outputs = self.base_tx.sum_outputs_amount()?;
+
+        let fee = sum_inputs
+            .checked_sub(total_outputs)
+            .ok_or(ParserError::OperationOverflows)?;
+        Ok(fee)
+    }
+
+    // Remove validator:
+    //      NodeID-<cb58> from subnet <cb58>
+    fn render_removal(
+        &self,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+
+        let label = pic_str!(b"Remove validator");
+        title[..label.len()].copy_from_slice(label);
+
+        // room for both ids plus the separator
+        const MAX_SIZE: usize = MAX_NODE_ID_ENCODED_LEN + 16 + cb58_output_len::<SUBNET_ID_LEN>();
+        let mut buffer = [0; MAX_SIZE];
+
+        let mut len = encode_node_id(self.node_id, &mut buffer[..])?;
+
+        let from = pic_str!(b" from subnet "!);
+        buffer[len..len + from.len()].copy_from_slice(from);
+        len += from.len();
+        len +=
+            cb58_encode(self.subnet_id.id(), &mut buffer[len..]).map_err(|_| ViewError::Unknown)?;
+
+        handle_ui_message(&buffer[..len], message, page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{prelude::v1::*, vec};
+
+    const DATA: &[u8] = &[
+        0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x30, 0x39, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xdb,
+        0xcf, 0x89, 0x0f, 0x77, 0xf4, 0x9b, 0x96, 0x85, 0x76, 0x48, 0xb7, 0x2b, 0x77, 0xf9, 0xf8,
+        0x29, 0x37, 0xf2, 0x8a, 0x68, 0x70, 0x4a, 0xf0, 0x5d, 0xa0, 0xdc, 0x12, 0xba, 0x53, 0xf2,
+        0xdb, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0xee, 0x5b, 0xe5, 0xc0, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0xda,
+        0x2b, 0xee, 0x01, 0xbe, 0x82, 0xec, 0xc0, 0x0c, 0x34, 0xf3, 0x61, 0xed, 0xa8, 0xeb, 0x30,
+        0xfb, 0x5a, 0x71, 0x5c, 0x00, 0x00, 0x00, 0x01, 0xdf, 0xaf, 0xbd, 0xf5, 0xc8, 0x1f, 0x63,
+        0x5c, 0x92, 0x57, 0x82, 0x4f, 0xf2, 0x1c, 0x8e, 0x3e, 0x6f, 0x7b, 0x63, 0x2a, 0xc3, 0x06,
+        0xe1, 0x14, 0x46, 0xee, 0x54, 0x0d, 0x34, 0x71, 0x1a, 0x15, 0x00, 0x00, 0x00, 0x01, 0xdb,
+        0xcf, 0x89, 0x0f, 0x77, 0xf4, 0x9b, 0x96, 0x85, 0x76, 0x48, 0xb7, 0x2b, 0x77, 0xf9, 0xf8,
+        0x29, 0x37, 0xf2, 0x8a, 0x68, 0x70, 0x4a, 0xf0, 0x5d, 0xa0, 0xdc, 0x12, 0xba, 0x53, 0xf2,
+        0xdb, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x01, 0xd2, 0x97, 0xb5, 0x48, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe9, 0x09, 0x4f, 0x73, 0x69,
+        0x80, 0x02, 0xfd, 0x52, 0xc9, 0x08, 0x19, 0xb4, 0x57, 0xb9, 0xfb, 0xc8, 0x66, 0xab, 0x80,
+        0x58, 0xb1, 0x09, 0x28, 0x71, 0xdb, 0x85, 0xbc, 0x75, 0x27, 0x42, 0x05, 0x4e, 0x2e, 0x8b,
+        0xe0, 0xad, 0xf8, 0x16, 0x6e, 0xc1, 0xf0, 0xf0, 0x76, 0x9f, 0x47, 0x79, 0xf1, 0x4c, 0x71,
+        0xd7, 0xeb, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x02,
+    ];
+
+    fn render_pages(tx: &RemoveSubnetValidatorTx) -> Vec<(String, String)> {
+        let str_of = |buf: &[u8]| {
+            let len = buf.iter().position(|&b| b == 0).unwrap();
+            String::from_utf8(buf[..len].to_vec()).unwrap()
+        };
+
+        (0..tx.num_items() as u8)
+            .map(|item_n| {
+                let mut title = [0; 18];
+                let mut message = [0; 200];
+                tx.render_item(item_n, &mut title, &mut message, 0).unwrap();
+                (str_of(&title), str_of(&message))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_remove_subnet_validator() {
+        let (rem, tx) = RemoveSubnetValidatorTx::from_bytes(DATA).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(tx.subnet_auth.sig_indices.len(), 2);
+
+        let pages = render_pages(&tx);
+        let expected = vec![
+            ("RemoveValidator", "Transaction"),
+            (
+                "Remove validator",
+                "NodeID-NFBbbJ4qCmNaCzeW7sxErhvWqvEQMnYcN from subnet \
+                 g4WNtLL98APX666NZGfjoDDsr6fsS27NsjhuCSTAeX5Dtx1Nb",
+            ),
+            ("Auth indices", "0,2"),
+            ("Fee(AVAX)", "2000.001"),
+        ];
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(t, m)| (t.to_string(), m.to_string()))
+            .collect();
+        assert_eq!(pages, expected);
+    }
+
+    #[test]
+    fn primary_network_is_rejected() {
+        // the subnet id goes right before the subnet auth
+        let subnet_at = DATA.len() - 16 - SUBNET_ID_LEN;
+        let mut data = DATA.to_vec();
+        data[subnet_at..][..SUBNET_ID_LEN].fill(0);
+
+        let err = RemoveSubnetValidatorTx::from_bytes(&data)
+            .map(|_| ())
+            .unwrap_err();
+        assert_eq!(err, nom::Err::Error(ParserError::UnexpectedField));
+    }
+}
@@ -0,0 +1,448 @@
+/*******************************************************************************
+*   (c) 2021 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use crate::sys::ViewError;
+use core::{mem::MaybeUninit, ptr::addr_of_mut};
+use nom::{
+    bytes::complete::tag,
+    number::complete::{be_u32, be_u64, be_u8},
+};
+
+use crate::{
+    handlers::handle_ui_message,
+    parser::{
+        nano_avax_to_fp_str, AssetId, BaseTxFields, DisplayableItem, FromBytes, Header,
+        ParserError, PvmOutput, SubnetAuth, SubnetId, DELEGATION_FEE_DIGITS, MAX_ASSET_AMOUNT_LEN,
+        MAX_ASSET_LABEL_LEN, PVM_TRANSFORM_SUBNET,
+    },
+    utils::format_amount,
+};
+
+/// Denominator of the rates, fees and uptime of the subnet,
+/// which are given in parts per million
+const PERCENT_DENOMINATOR: u64 = 1_000_000;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+#[cfg_attr(test, derive(Debug))]
+pub struct TransformSubnetTx<'b> {
+    pub tx_header: Header<'b>,
+    pub base_tx: BaseTxFields<'b, PvmOutput<'b>>,
+    pub subnet_id: SubnetId<'b>,
+    pub asset_id: AssetId<'b>,
+    pub initial_supply: u64,
+    pub max_supply: u64,
+    pub min_consumption_rate: u64,
+    pub max_consumption_rate: u64,
+    pub min_validator_stake: u64,
+    pub max_validator_stake: u64,
+    pub min_stake_duration: u32,
+    pub max_stake_duration: u32,
+    pub min_delegation_fee: u32,
+    pub min_delegator_stake: u64,
+    pub max_validator_weight_factor: u8,
+    pub uptime_requirement: u32,
+    pub subnet_auth: SubnetAuth<'b>,
+}
+
+impl<'b> FromBytes<'b> for TransformSubnetTx<'b> {
+    #[inline(never)]
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("TransformSubnetTx::from_bytes_into\x00");
+
+        let (rem, _) = tag(PVM_TRANSFORM_SUBNET.to_be_bytes())(input)?;
+
+        let out = out.as_mut_ptr();
+
+        // tx header
+        let tx_header = unsafe { &mut *addr_of_mut!((*out).tx_header).cast() };
+        let rem = Header::from_bytes_into(rem, tx_header)?;
+
+        // base_tx
+        let base_tx = unsafe { &mut *addr_of_mut!((*out).base_tx).cast() };
+        let rem = BaseTxFields::<PvmOutput>::from_bytes_into(rem, base_tx)?;
+
+        // SubnetId, the primary network can not be transformed
+        let subnet_id = unsafe { &mut *addr_of_mut!((*out).subnet_id).cast() };
+        let rem = SubnetId::from_bytes_into(rem, subnet_id)?;
+        if unsafe { subnet_id.assume_init_ref() }.is_primary_network() {
+            return Err(ParserError::UnexpectedField.into());
+        }
+
+        // the asset to stake, which is not AVAX
+        let asset_id = unsafe { &mut *addr_of_mut!((*out).asset_id).cast() };
+        let rem = AssetId::from_bytes_into(rem, asset_id)?;
+        if unsafe { asset_id.assume_init_ref() }.known_name().is_some() {
+            return Err(ParserError::UnexpectedField.into());
+        }
+
+        let (rem, initial_supply) = be_u64(rem)?;
+        let (rem, max_supply) = be_u64(rem)?;
+        let (rem, min_consumption_rate) = be_u64(rem)?;
+        let (rem, max_consumption_rate) = be_u64(rem)?;
+        let (rem, min_validator_stake) = be_u64(rem)?;
+        let (rem, max_validator_stake) = be_u64(rem)?;
+        let (rem, min_stake_duration) = be_u32(rem)?;
+        let (rem, max_stake_duration) = be_u32(rem)?;
+        let (rem, min_delegation_fee) = be_u32(rem)?;
+        let (rem, min_delegator_stake) = be_u64(rem)?;
+        let (rem, max_validator_weight_factor) = be_u8(rem)?;
+        let (rem, uptime_requirement) = be_u32(rem)?;
+
+        // same bounds the P-chain enforces
+        if initial_supply == 0 || initial_supply > max_supply {
+            return Err(ParserError::ValueOutOfRange.into());
+        }
+        if min_consumption_rate > max_consumption_rate || max_consumption_rate > PERCENT_DENOMINATOR
+        {
+            return Err(ParserError::ValueOutOfRange.into());
+        }
+        if min_validator_stake == 0
+            || min_validator_stake > initial_supply
+            || min_validator_stake > max_validator_stake
+            || max_validator_stake > max_supply
+            || min_delegator_stake == 0
+        {
+            return Err(ParserError::InvalidStakingAmount.into());
+        }
+        if min_stake_duration == 0 || min_stake_duration > max_stake_duration {
+            return Err(ParserError::InvalidStakingPeriod.into());
+        }
+        if min_delegation_fee as u64 > PERCENT_DENOMINATOR
+            || max_validator_weight_factor == 0
+            || uptime_requirement as u64 > PERCENT_DENOMINATOR
+        {
+            return Err(ParserError::ValueOutOfRange.into());
+        }
+
+        // subnetAuth
+        let subnet_auth = unsafe { &mut *addr_of_mut!((*out).subnet_auth).cast() };
+        let rem = SubnetAuth::from_bytes_into(rem, subnet_auth)?;
+
+        unsafe {
+            addr_of_mut!((*out).initial_supply).write(initial_supply);
+            addr_of_mut!((*out).max_supply).write(max_supply);
+            addr_of_mut!((*out).min_consumption_rate).write(min_consumption_rate);
+            addr_of_mut!((*out).max_consumption_rate).write(max_consumption_rate);
+            addr_of_mut!((*out).min_validator_stake).write(min_validator_stake);
+            addr_of_mut!((*out).max_validator_stake).write(max_validator_stake);
+            addr_of_mut!((*out).min_stake_duration).write(min_stake_duration);
+            addr_of_mut!((*out).max_stake_duration).write(max_stake_duration);
+            addr_of_mut!((*out).min_delegation_fee).write(min_delegation_fee);
+            addr_of_mut!((*out).min_delegator_stake).write(min_delegator_stake);
+            addr_of_mut!((*out).max_validator_weight_factor).write(max_validator_weight_factor);
+            addr_of_mut!((*out).uptime_requirement).write(uptime_requirement);
+        }
+
+        Ok(rem)
+    }
+}
+
+impl<'b> DisplayableItem for TransformSubnetTx<'b> {
+    fn num_items(&self) -> usize {
+        // tx_info, subnet_id, asset_id, supplies(2), stakes(3),
+        // durations(2), consumption rates(2), delegation fee,
+        // weight factor, uptime, memo and fee
+        1 + 1 + 1 + 2 + 3 + 2 + 2 + 1 + 1 + 1 + self.base_tx.memo_num_items() + 1
+    }
+
+    fn render_item(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, zemu_sys::ViewError> {
+        use bolos::{pic_str, PIC};
+        use lexical_core::Number;
+
+        let mut buffer = [0; MAX_ASSET_AMOUNT_LEN + u64::FORMATTED_SIZE_DECIMAL];
+
+        // an amount of the staking asset
+        let mut render_amount = |label: &[u8], amount: u64| {
+            title[..label.len()].copy_from_slice(label);
+            let amount = self
+                .asset_id
+                .format_amount(amount, &mut buffer[..])
+                .map_err(|_| ViewError::Unknown)?;
+            handle_ui_message(amount, message, page)
+        };
+
+        // the fee follows the memo if there is one
+        let item_n = match item_n {
+            15.. if self.base_tx.memo_num_items() == 0 => item_n + 1,
+            n => n,
+        };
+
+        match item_n {
+            0 => {
+                let label = pic_str!(b"TransformSubnet");
+                title[..label.len()].copy_from_slice(label);
+                let content = pic_str!(b"Transaction");
+                handle_ui_message(content, message, page)
+            }
+            1 => self.subnet_id.render_item(0, title, message, page),
+            2 => {
+                let label = pic_str!(b"Staking asset");
+                title[..label.len()].copy_from_slice(label);
+
+                let mut asset = [0; MAX_ASSET_LABEL_LEN];
+                let len = self.asset_id.write_label(&mut asset[..])?;
+                handle_ui_message(&asset[..len], message, page)
+            }
+            3 => render_amount(pic_str!(b"Initial supply"), self.initial_supply),
+            4 => render_amount(pic_str!(b"Max supply"), self.max_supply),
+            5 => render_amount(pic_str!(b"Min val. stake"), self.min_validator_stake),
+            6 => render_amount(pic_str!(b"Max val. stake"), self.max_validator_stake),
+            7 => render_amount(pic_str!(b"Min deleg. stake"), self.min_delegator_stake),
+            8 => self.render_number(
+                pic_str!(b"Min duration(s)"),
+                self.min_stake_duration as u64,
+                0,
+                title,
+                message,
+                page,
+            ),
+            9 => self.render_number(
+                pic_str!(b"Max duration(s)"),
+                self.max_stake_duration as u64,
+                0,
+                title,
+                message,
+                page,
+            ),
+            10 => self.render_number(
+                pic_str!(b"Min cons. rate(%)"),
+                self.min_consumption_rate,
+                DELEGATION_FEE_DIGITS,
+                title,
+                message,
+                page,
+            ),
+            11 => self.render_number(
+                pic_str!(b"Max cons. rate(%)"),
+                self.max_consumption_rate,
+                DELEGATION_FEE_DIGITS,
+                title,
+                message,
+                page,
+            ),
+            12 => self.render_number(
+                pic_str!(b"Min deleg. fee(%)"),
+                self.min_delegation_fee as u64,
+                DELEGATION_FEE_DIGITS,
+                title,
+                message,
+                page,
+            ),
+            13 => self.render_number(
+                pic_str!(b"Max weight factor"),
+                self.max_validator_weight_factor as u64,
+                0,
+                title,
+                message,
+                page,
+            ),
+            14 => self.render_number(
+                pic_str!(b"Uptime req.(%)"),
+                self.uptime_requirement as u64,
+                DELEGATION_FEE_DIGITS,
+                title,
+                message,
+                page,
+            ),
+            15 => self.base_tx.render_memo(title, message, page),
+            16 => {
+                let label = pic_str!(b"Fee(AVAX)");
+                title[..label.len()].copy_from_slice(label);
+
+                let fee = self.fee().map_err(|_| ViewError::Unknown)?;
+                let fee_buff =
+                    nano_avax_to_fp_str(fee, &mut buffer[..]).map_err(|_| ViewError::Unknown)?;
+                handle_ui_message(fee_buff, message, page)
+            }
+            _ => Err(ViewError::NoData),
+        }
+    }
+}
+
+impl<'b> TransformSubnetTx<'b> {
+    fn fee(&'b self) -> Result<u64, ParserError> {
+        let sum_inputs = self.base_tx.sum_inputs_amount()?;
+
+        let total_outputs = self.base_tx.sum_outputs_amount()?;
+
+        let fee = sum_inputs
+            .checked_sub(total_outputs)
+            .ok_or(ParserError::OperationOverflows)?;
+        Ok(fee)
+    }
+
+    // durations, factors and percentages, the latter with
+    // 4 decimals as they are given in parts per million
+    fn render_number(
+        &self,
+        label: &[u8],
+        value: u64,
+        decimals: usize,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use lexical_core::Number;
+
+        title[..label.len()].copy_from_slice(label);
+
+        let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL + 2];
+        let num = format_amount(value, decimals, &mut buffer).map_err(|_| ViewError::Unknown)?;
+        handle_ui_message(num.as_bytes(), message, page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{prelude::v1::*, vec};
+
+    const DATA: &[u8] = &[
+        0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x30, 0x39, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xdb,
+        0xcf, 0x89, 0x0f, 0x77, 0xf4, 0x9b, 0x96, 0x85, 0x76, 0x48, 0xb7, 0x2b, 0x77, 0xf9, 0xf8,
+        0x29, 0x37, 0xf2, 0x8a, 0x68, 0x70, 0x4a, 0xf0, 0x5d, 0xa0, 0xdc, 0x12, 0xba, 0x53, 0xf2,
+        0xdb, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0xee, 0x5b, 0xe5, 0xc0, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0xda,
+        0x2b, 0xee, 0x01, 0xbe, 0x82, 0xec, 0xc0, 0x0c, 0x34, 0xf3, 0x61, 0xed, 0xa8, 0xeb, 0x30,
+        0xfb, 0x5a, 0x71, 0x5c, 0x00, 0x00, 0x00, 0x01, 0xdf, 0xaf, 0xbd, 0xf5, 0xc8, 0x1f, 0x63,
+        0x5c, 0x92, 0x57, 0x82, 0x4f, 0xf2, 0x1c, 0x8e, 0x3e, 0x6f, 0x7b, 0x63, 0x2a, 0xc3, 0x06,
+        0xe1, 0x14, 0x46, 0xee, 0x54, 0x0d, 0x34, 0x71, 0x1a, 0x15, 0x00, 0x00, 0x00, 0x01, 0xdb,
+        0xcf, 0x89, 0x0f, 0x77, 0xf4, 0x9b, 0x96, 0x85, 0x76, 0x48, 0xb7, 0x2b, 0x77, 0xf9, 0xf8,
+        0x29, 0x37, 0xf2, 0x8a, 0x68, 0x70, 0x4a, 0xf0, 0x5d, 0xa0, 0xdc, 0x12, 0xba, 0x53, 0xf2,
+        0xdb, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x01, 0xd2, 0x97, 0xb5, 0x48, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xd4, 0x31, 0x58, 0xb1, 0x09,
+        0x28, 0x71, 0xdb, 0x85, 0xbc, 0x75, 0x27, 0x42, 0x05, 0x4e, 0x2e, 0x8b, 0xe0, 0xad, 0xf8,
+        0x16, 0x6e, 0xc1, 0xf0, 0xf0, 0x76, 0x9f, 0x47, 0x79, 0xf1, 0x4c, 0x71, 0x11, 0x11, 0x11,
+        0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11,
+        0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x00,
+        0x00, 0x00, 0x17, 0x48, 0x76, 0xe8, 0x00, 0x00, 0x00, 0x00, 0xe8, 0xd4, 0xa5, 0x10, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x86, 0xa0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xd4,
+        0xc0, 0x00, 0x00, 0x00, 0x00, 0x77, 0x35, 0x94, 0x00, 0x00, 0x00, 0x00, 0x45, 0xd9, 0x64,
+        0xb8, 0x00, 0x00, 0x01, 0x51, 0x80, 0x01, 0xe1, 0x33, 0x80, 0x00, 0x00, 0x4e, 0x20, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x7d, 0x78, 0x40, 0x05, 0x00, 0x0c, 0x35, 0x00, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    fn render_pages(tx: &TransformSubnetTx) -> Vec<(String, String)> {
+        let str_of = |buf: &[u8]| {
+            let len = buf.iter().position(|&b| b == 0).unwrap();
+            String::from_utf8(buf[..len].to_vec()).unwrap()
+        };
+
+        (0..tx.num_items() as u8)
+            .map(|item_n| {
+                let mut title = [0; 18];
+                let mut message = [0; 100];
+                tx.render_item(item_n, &mut title, &mut message, 0).unwrap();
+                (str_of(&title), str_of(&message))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_transform_subnet() {
+        let (rem, tx) = TransformSubnetTx::from_bytes(DATA).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(tx.subnet_auth.sig_indices.len(), 1);
+        assert_eq!(tx.initial_supply, 100_000_000_000);
+        assert_eq!(tx.uptime_requirement, 800_000);
+
+        let pages = render_pages(&tx);
+        let expected = vec![
+            ("TransformSubnet", "Transaction"),
+            (
+                "SubnetID",
+                "2cTBPzwBTZHfSjWqf2kSmTJvhPdPKK8F1XdeqXxEZix7WmeUi7",
+            ),
+            (
+                "Staking asset",
+                "8WwpJCixn9cKe3jAyXvxNeo5JrBFKj43ULkUeTfeLMqQJgouM",
+            ),
+            // the denomination was not provided
+            ("Initial supply", "100000000000"),
+            ("Max supply", "1000000000000"),
+            ("Min val. stake", "2000000000"),
+            ("Max val. stake", "300000000000"),
+            ("Min deleg. stake", "25000000"),
+            ("Min duration(s)", "86400"),
+            ("Max duration(s)", "31536000"),
+            ("Min cons. rate(%)", "10"),
+            ("Max cons. rate(%)", "12"),
+            ("Min deleg. fee(%)", "2"),
+            ("Max weight factor", "5"),
+            ("Uptime req.(%)", "80"),
+            ("Fee(AVAX)", "2000.001"),
+        ];
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(t, m)| (t.to_string(), m.to_string()))
+            .collect();
+        assert_eq!(pages, expected);
+    }
+
+    #[test]
+    fn out_of_range_parameters() {
+        // offset of the initial supply, after the header,
+        // the base tx and the subnet and asset ids
+        let fields = DATA.len() - 12 - (8 * 7 + 4 * 4 + 1);
+        let set = |offset: usize, value: &[u8]| {
+            let mut data = DATA.to_vec();
+            data[fields + offset..][..value.len()].copy_from_slice(value);
+            TransformSubnetTx::from_bytes(&data)
+                .map(|_| ())
+                .unwrap_err()
+        };
+
+        // initial supply above the max supply
+        assert_eq!(
+            set(0, &u64::MAX.to_be_bytes()),
+            nom::Err::Error(ParserError::ValueOutOfRange)
+        );
+        // min consumption rate above the max one
+        assert_eq!(
+            set(16, &200_000u64.to_be_bytes()),
+            nom::Err::Error(ParserError::ValueOutOfRange)
+        );
+        // min validator stake above the max one
+        assert_eq!(
+            set(32, &400_000_000_000u64.to_be_bytes()),
+            nom::Err::Error(ParserError::InvalidStakingAmount)
+        );
+        // min stake duration above the max one
+        assert_eq!(
+            set(48, &u32::MAX.to_be_bytes()),
+            nom::Err::Error(ParserError::InvalidStakingPeriod)
+        );
+        // uptime above 100%
+        assert_eq!(
+            set(69, &1_000_001u32.to_be_bytes()),
+            nom::Err::Error(ParserError::ValueOutOfRange)
+        );
+        // no weight factor
+        assert_eq!(set(68, &[0]), nom::Err::Error(ParserError::ValueOutOfRange));
+    }
+}
@@ -19,8 +19,8 @@ use zemu_sys::ViewError;
 
 use crate::handlers::handle_ui_message;
 use crate::parser::{
-    nano_avax_to_fp_str, AvmOutput, BaseTxFields, DisplayableItem, FromBytes, Header, ParserError,
-    MAX_ADDRESS_ENCODED_LEN, TRANSFER_TX,
+    nano_avax_to_fp_str, AvmOutput, BaseTxFields, ChainId, DisplayableItem, FromBytes, Header,
+    ParserError, TxSummary, UtxoList, MAX_ADDRESS_ENCODED_LEN, TRANSFER_TX,
 };
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -35,6 +35,14 @@ impl<'b> Transfer<'b> {
         self.base.disable_output_if(address);
     }
 
+    pub fn summary(&'b self) -> Result<TxSummary, ParserError> {
+        self.base.summary()
+    }
+
+    pub fn utxos(&self) -> UtxoList<'b> {
+        self.base.utxos()
+    }
+
     fn render_outputs(
         &self,
         item_n: u8,
@@ -96,12 +104,21 @@ impl<'b> Transfer<'b> {
         }
     }
 
+    // outputs are grouped by asset only if
+    // more than one asset is being moved
+    fn assets_num_items(&self) -> usize {
+        let assets = self.base.num_renderable_assets();
+        if assets > 1 {
+            assets
+        } else {
+            0
+        }
+    }
+
+    // other assets can be moved along AVAX,
+    // but the fee is only paid in the latter
     fn fee(&self) -> Result<u64, ParserError> {
-        let outputs = self.base.sum_outputs_amount()?;
-        let inputs = self.base.sum_inputs_amount()?;
-        inputs
-            .checked_sub(outputs)
-            .ok_or(ParserError::OperationOverflows)
+        self.base.avax_fee()
     }
 }
 
@@ -120,6 +137,11 @@ impl<'b> FromBytes<'b> for Transfer<'b> {
         let tx_header = unsafe { &mut *addr_of_mut!((*out).header).cast() };
         let rem = Header::from_bytes_into(rem, tx_header)?;
 
+        let header = unsafe { tx_header.assume_init_ref() };
+        if let ChainId::XChain = header.chain_id()? {
+            header.check_x_chain()?;
+        }
+
         // base_tx
         let base_tx = unsafe { &mut *addr_of_mut!((*out).base).cast() };
         let rem = BaseTxFields::<AvmOutput>::from_bytes_into(rem, base_tx)?;
@@ -140,7 +162,13 @@ impl<'b> FromBytes<'b> for Transfer<'b> {
 
 impl<'b> DisplayableItem for Transfer<'b> {
     fn num_items(&self) -> usize {
-        1usize + self.base.base_outputs_num_items() + 1 // fee
+        // description + network + assets + outputs + memo + fee
+        1usize
+            + 1
+            + self.assets_num_items()
+            + self.base.base_outputs_num_items()
+            + self.base.memo_num_items()
+            + 1
     }
 
     fn render_item(
@@ -160,15 +188,33 @@ impl<'b> DisplayableItem for Transfer<'b> {
             return handle_ui_message(content, message, page);
         }
 
-        let item_n = item_n - 1;
+        if item_n == 1 {
+            let label = pic_str!(b"Network");
+            title[..label.len()].copy_from_slice(label);
+            let network = self.header.network_id().map_err(|_| ViewError::Unknown)?;
+            return handle_ui_message(network.name(), message, page);
+        }
+
+        let item_n = item_n - 2;
+
+        let assets_items = self.assets_num_items() as u8;
+        if item_n < assets_items {
+            return self
+                .base
+                .render_asset_total(item_n as usize, title, message, page);
+        }
 
+        let item_n = item_n - assets_items;
         let outputs_items = self.base.base_outputs_num_items() as u8;
+        let memo_items = self.base.memo_num_items() as u8;
 
         match item_n {
             // render outputs
             x @ 0.. if x < outputs_items => self.render_outputs(x, title, message, page),
 
-            x if x == outputs_items => {
+            x if x < outputs_items + memo_items => self.base.render_memo(title, message, page),
+
+            x if x == outputs_items + memo_items => {
                 let t = pic_str!(b"Fee(AVAX)");
                 title[..t.len()].copy_from_slice(t);
 
@@ -226,4 +272,209 @@ mod tests {
 
         assert_eq!(tx.fee().unwrap(), 1000000);
     }
+
+    // replaces the memo at the end of DATA
+    fn with_memo(memo: &[u8]) -> std::vec::Vec<u8> {
+        let mut data = DATA[..DATA.len() - 8].to_vec();
+        data.extend_from_slice(&(memo.len() as u32).to_be_bytes());
+        data.extend_from_slice(memo);
+        data
+    }
+
+    fn render_memo(tx: &Transfer) -> std::string::String {
+        let mut title = [0; 32];
+        let mut message = [0; 1024];
+        tx.base.render_memo(&mut title, &mut message, 0).unwrap();
+
+        let len = message.iter().position(|b| *b == 0).unwrap();
+        std::string::String::from_utf8(message[..len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn transfer_empty_memo() {
+        let data = with_memo(&[]);
+        let (_, tx) = Transfer::from_bytes(&data).unwrap();
+
+        assert_eq!(tx.base.memo_num_items(), 0);
+        assert_eq!(tx.num_items(), 1 + 1 + tx.base.base_outputs_num_items() + 1);
+    }
+
+    #[test]
+    fn transfer_ascii_memo() {
+        let data = with_memo(b"payment #42");
+        let (_, tx) = Transfer::from_bytes(&data).unwrap();
+
+        assert_eq!(tx.base.memo_num_items(), 1);
+        assert_eq!(render_memo(&tx), "payment #42");
+    }
+
+    #[test]
+    fn transfer_binary_memo() {
+        let (_, tx) = Transfer::from_bytes(DATA).unwrap();
+
+        assert_eq!(tx.base.memo_num_items(), 1);
+        assert_eq!(render_memo(&tx), "0x00000000");
+    }
+
+    #[test]
+    fn transfer_hides_change_output() {
+        // owner of the second output, which goes back to the signer
+        const CHANGE: &[u8] = &[
+            0xA4, 0xAF, 0xAB, 0xFF, 0x30, 0x81, 0x95, 0x25, 0x99, 0x90, 0xA9, 0xE5, 0x31, 0xBD,
+            0x82, 0x30, 0xD1, 0x1A, 0x9A, 0x2A,
+        ];
+
+        let (_, mut tx) = Transfer::from_bytes(DATA).unwrap();
+        // amount + address for each output
+        assert_eq!(tx.base.base_outputs_num_items(), 4);
+
+        tx.disable_output_if(CHANGE);
+        assert_eq!(tx.base.base_outputs_num_items(), 2);
+
+        // the only output left is the recipient one
+        let mut title = [0; 32];
+        let mut message = [0; 1024];
+        tx.render_item(2, &mut title, &mut message, 0).unwrap();
+
+        let expected = b"0.000001 AVAX to ";
+        assert_eq!(&title[..8], b"Transfer");
+        assert_eq!(&message[..expected.len()], &expected[..]);
+    }
+
+    #[test]
+    fn transfer_groups_assets() {
+        // append a third output moving 500 units of another asset
+        let mut third = DATA[124..204].to_vec();
+        third[..32].copy_from_slice(&[0xff; 32]);
+        third[36..44].copy_from_slice(&500u64.to_be_bytes());
+
+        let mut data = DATA[..204].to_vec();
+        data[43] = 3;
+        data.extend_from_slice(&third);
+        data.extend_from_slice(&DATA[204..]);
+
+        let (_, tx) = Transfer::from_bytes(&data).unwrap();
+        assert_eq!(tx.assets_num_items(), 2);
+        assert_eq!(
+            tx.num_items(),
+            1 + 1 + 2 + tx.base.base_outputs_num_items() + tx.base.memo_num_items() + 1
+        );
+
+        let render = |item_n| {
+            let mut title = [0; 32];
+            let mut message = [0; 128];
+            tx.render_item(item_n, &mut title, &mut message, 0).unwrap();
+
+            let len = message.iter().position(|b| *b == 0).unwrap();
+            (
+                title,
+                std::string::String::from_utf8(message[..len].to_vec()).unwrap(),
+            )
+        };
+
+        // the first two outputs are AVAX
+        let (title, message) = render(2);
+        assert_eq!(&title[..6], b"Asset\0");
+        assert_eq!(message, "AVAX: 1.791745");
+
+        let (title, message) = render(3);
+        assert_eq!(&title[..6], b"Asset\0");
+        assert_eq!(
+            message,
+            "2wkBET2rRgE8pahuaczxKbmv7ciehqsne57F9gtzf1PVcUJEQG: 500"
+        );
+    }
+
+    #[test]
+    fn transfer_fee() {
+        // inputs of 8001024 and outputs of 7001024 nAVAX
+        let (_, tx) = Transfer::from_bytes(DATA).unwrap();
+        let fee_item = tx.num_items() as u8 - 1;
+
+        let mut title = [0; 32];
+        let mut message = [0; 128];
+        tx.render_item(fee_item, &mut title, &mut message, 0)
+            .unwrap();
+        assert_eq!(&title[..10], b"Fee(AVAX)\0");
+        assert_eq!(&message[..6], b"0.001\0");
+
+        // other assets moved along do not change it
+        let mut third = DATA[124..204].to_vec();
+        third[..32].copy_from_slice(&[0xff; 32]);
+        third[36..44].copy_from_slice(&500u64.to_be_bytes());
+
+        let mut data = DATA[..204].to_vec();
+        data[43] = 3;
+        data.extend_from_slice(&third);
+        data.extend_from_slice(&DATA[204..]);
+
+        let (_, tx) = Transfer::from_bytes(&data).unwrap();
+        assert_eq!(tx.fee().unwrap(), 1000000);
+    }
+
+    #[test]
+    fn transfer_single_asset_not_grouped() {
+        let (_, tx) = Transfer::from_bytes(DATA).unwrap();
+
+        assert_eq!(tx.base.num_renderable_assets(), 1);
+        assert_eq!(tx.assets_num_items(), 0);
+    }
+
+    #[test]
+    fn transfer_unsorted_outputs() {
+        // DATA outputs are sorted, swapping them is not canonical
+        let mut data = DATA[..44].to_vec();
+        data.extend_from_slice(&DATA[124..204]);
+        data.extend_from_slice(&DATA[44..124]);
+        data.extend_from_slice(&DATA[204..]);
+
+        let err = Transfer::from_bytes(&data).unwrap_err();
+        assert_eq!(err, nom::Err::Error(ParserError::InvalidOutputOrder));
+    }
+
+    #[test]
+    fn outputs_order_strict_mode() {
+        let check = BaseTxFields::<AvmOutput>::check_outputs_order_with;
+        let list = |outputs: &[&[u8]]| {
+            let mut data = (outputs.len() as u32).to_be_bytes().to_vec();
+            outputs.iter().for_each(|o| data.extend_from_slice(o));
+            data
+        };
+
+        // an AVAX output of DATA and the same output of a lower asset id
+        let avax = &DATA[44..124];
+        let mut other = [0x11; 32].to_vec();
+        other.extend_from_slice(&avax[32..]);
+
+        assert_eq!(check(&list(&[&other, avax]), true), Ok(()));
+        assert_eq!(
+            check(&list(&[avax, &other]), true),
+            Err(nom::Err::Error(ParserError::InvalidOutputOrder))
+        );
+
+        // out of strict mode the order is left to avalanchego
+        assert_eq!(check(&list(&[avax, &other]), false), Ok(()));
+    }
+
+    #[test]
+    fn transfer_network() {
+        let (_, tx) = Transfer::from_bytes(DATA).unwrap();
+
+        let mut title = [0; 32];
+        let mut message = [0; 32];
+        tx.render_item(1, &mut title, &mut message, 0).unwrap();
+
+        assert_eq!(&title[..8], b"Network\0");
+        assert_eq!(&message[..5], b"Fuji\0");
+    }
+
+    #[test]
+    fn transfer_wrong_x_chain() {
+        // DATA is a fuji transaction, put it in mainnet
+        let mut data = DATA.to_vec();
+        data[4..8].copy_from_slice(&1u32.to_be_bytes());
+
+        let err = Transfer::from_bytes(&data).unwrap_err();
+        assert_eq!(err, nom::Err::Error(ParserError::InvalidChainId));
+    }
 }
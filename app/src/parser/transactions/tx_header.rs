@@ -16,7 +16,9 @@
 use core::{convert::TryFrom, mem::MaybeUninit, ptr::addr_of_mut};
 use nom::{bytes::complete::take, number::complete::be_u32, sequence::tuple};
 
-use crate::parser::{ChainId, FromBytes, NetworkId, NetworkInfo, ParserError};
+use crate::parser::{
+    ChainId, FromBytes, NetworkId, NetworkInfo, ParserError, X_CHAIN_ID_FUJI, X_CHAIN_ID_MAINNET,
+};
 
 pub const BLOCKCHAIN_ID_LEN: usize = 32;
 
@@ -44,6 +46,24 @@ impl<'b> Header<'b> {
         let info = self.network_info()?;
         Ok(info.network_id.hrp())
     }
+
+    /// Checks the blockchain id is the X-chain of the network,
+    /// local networks are skipped as their chain ids are not fixed
+    pub fn check_x_chain(&self) -> Result<(), ParserError> {
+        use bolos::PIC;
+
+        let x_chain = match self.network_id()? {
+            NetworkId::Mainnet => PIC::new(&X_CHAIN_ID_MAINNET).into_inner(),
+            NetworkId::Fuji => PIC::new(&X_CHAIN_ID_FUJI).into_inner(),
+            NetworkId::Local => return Ok(()),
+        };
+
+        if self.blockchain_id != x_chain {
+            return Err(ParserError::InvalidChainId);
+        }
+
+        Ok(())
+    }
 }
 
 impl<'b> FromBytes<'b> for Header<'b> {
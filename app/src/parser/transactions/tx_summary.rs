@@ -0,0 +1,162 @@
+/*******************************************************************************
+*   (c) 2021 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use zemu_sys::ViewError;
+
+use core::ops::Deref;
+
+use crate::{
+    handlers::handle_ui_message,
+    parser::{
+        nano_avax_to_fp_str, u64_to_str, DisplayableItem, FromBytes, Output, ParserError,
+        TransferableOutput,
+    },
+};
+
+/// At a glance information of a transaction:
+/// how many inputs and outputs it has and the
+/// AVAX amount of the outputs that are not change.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct TxSummary {
+    pub inputs: usize,
+    pub outputs: usize,
+    pub amount: u64,
+    // fungible outputs that move no value
+    pub zero_outputs: usize,
+}
+
+impl TxSummary {
+    pub fn add_inputs(&mut self, inputs: usize) {
+        self.inputs += inputs;
+    }
+
+    /// Accounts `outputs` moving `amount`, errors if the
+    /// total would not fit instead of showing a wrapped one
+    pub fn add_outputs(&mut self, outputs: usize, amount: u64) -> Result<(), ParserError> {
+        self.amount = self
+            .amount
+            .checked_add(amount)
+            .ok_or(ParserError::ValueOutOfRange)?;
+        self.outputs += outputs;
+        Ok(())
+    }
+
+    pub fn add_zero_outputs(&mut self, outputs: usize) {
+        self.zero_outputs += outputs;
+    }
+
+    /// Sums up the AVAX moved by `outputs`, the ones of other
+    /// assets are skipped as their amounts are not in AVAX
+    pub fn avax_amount<'b, O>(
+        outputs: impl Iterator<Item = TransferableOutput<'b, O>>,
+    ) -> Result<u64, ParserError>
+    where
+        O: FromBytes<'b> + DisplayableItem + Deref<Target = Output<'b>> + 'b,
+    {
+        outputs
+            .filter(|output| output.assert_id().is_avax())
+            .filter_map(|output| output.amount())
+            .try_fold(0u64, |acc, x| {
+                acc.checked_add(x).ok_or(ParserError::ValueOutOfRange)
+            })
+    }
+}
+
+impl DisplayableItem for TxSummary {
+    fn num_items(&self) -> usize {
+        1
+    }
+
+    fn render_item(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+        use lexical_core::Number;
+
+        if item_n != 0 {
+            return Err(ViewError::NoData);
+        }
+
+        let label = pic_str!(b"Summary");
+        title[..label.len()].copy_from_slice(label);
+
+        // Inputs: N, Outputs: M, <amount> AVAX
+        let inputs_label = pic_str!(b"Inputs: "!);
+        let outputs_label = pic_str!(b", Outputs: "!);
+        let amount_label = pic_str!(b", "!);
+        let avax = pic_str!(b" AVAX"!);
+
+        let mut num = [0; u64::FORMATTED_SIZE_DECIMAL];
+        let mut amount = [0; u64::FORMATTED_SIZE_DECIMAL + 2];
+        let mut buffer = [0; u64::FORMATTED_SIZE_DECIMAL * 3 + 32];
+        let mut len = 0;
+
+        let mut append = |data: &[u8]| {
+            buffer[len..len + data.len()].copy_from_slice(data);
+            len += data.len();
+        };
+
+        append(inputs_label);
+        append(u64_to_str(self.inputs as u64, &mut num[..]).map_err(|_| ViewError::Unknown)?);
+        append(outputs_label);
+        append(u64_to_str(self.outputs as u64, &mut num[..]).map_err(|_| ViewError::Unknown)?);
+        append(amount_label);
+        append(nano_avax_to_fp_str(self.amount, &mut amount[..]).map_err(|_| ViewError::Unknown)?);
+        append(avax);
+
+        handle_ui_message(&buffer[..len], message, page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_summary() {
+        let summary = TxSummary {
+            inputs: 2,
+            outputs: 3,
+            amount: 1_500_000_000,
+            zero_outputs: 0,
+        };
+
+        let mut title = [0; 18];
+        let mut message = [0; 64];
+        summary.render_item(0, &mut title, &mut message, 0).unwrap();
+
+        let expected = b"Inputs: 2, Outputs: 3, 1.5 AVAX";
+        assert_eq!(&title[..7], b"Summary");
+        assert_eq!(&message[..expected.len()], &expected[..]);
+    }
+
+    #[test]
+    fn total_overflow() {
+        let mut summary = TxSummary::default();
+        summary.add_outputs(1, u64::MAX - 1).unwrap();
+        summary.add_outputs(1, 1).unwrap();
+        assert_eq!(summary.amount, u64::MAX);
+
+        // past u64::MAX, the summary is left as it was
+        assert_eq!(summary.add_outputs(1, 1), Err(ParserError::ValueOutOfRange));
+        assert_eq!(summary.outputs, 2);
+        assert_eq!(summary.amount, u64::MAX);
+    }
+}
@@ -21,7 +21,9 @@ mod time;
 pub use self::time::{timestamp_to_str_date, TimeError};
 pub use path_wrapper::PathWrapper;
 
-use crate::parser::{ParserError, CB58_CHECKSUM_LEN, NANO_AVAX_DECIMAL_DIGITS};
+use crate::parser::{
+    ParserError, CB58_CHECKSUM_LEN, DELEGATION_SHARES_DENOMINATOR, NANO_AVAX_DECIMAL_DIGITS,
+};
 use crate::sys::PIC;
 use lexical_core::Number;
 
@@ -47,15 +49,46 @@ pub const fn cb58_output_len<const I: usize>() -> usize {
 }
 
 pub fn nano_avax_to_fp_str(value: u64, out_str: &mut [u8]) -> Result<&mut [u8], ParserError> {
-    // the number plus '0.'
-    if out_str.len() < u64::FORMATTED_SIZE_DECIMAL + 2 {
-        return Err(ParserError::UnexpectedBufferEnd);
+    u64_to_fp_str(value, NANO_AVAX_DECIMAL_DIGITS, out_str)
+}
+
+/// Formats `value` as a fixed point number with `decimals` places
+pub fn u64_to_fp_str(
+    value: u64,
+    decimals: usize,
+    out_str: &mut [u8],
+) -> Result<&mut [u8], ParserError> {
+    let len = crate::utils::format_amount(value, decimals, out_str)
+        .map_err(|_| ParserError::UnexpectedBufferEnd)?
+        .len();
+
+    Ok(&mut out_str[..len])
+}
+
+/// Formats the delegation `shares` as a percentage with
+/// two decimals, like "2.00%", truncating any further digit
+pub fn shares_to_percent_str(shares: u32, out_str: &mut [u8]) -> Result<&mut [u8], ParserError> {
+    if shares > DELEGATION_SHARES_DENOMINATOR {
+        return Err(ParserError::ValueOutOfRange);
     }
 
-    u64_to_str(value, &mut out_str[..])?;
+    // percent points and hundredths of them
+    let units = shares / (DELEGATION_SHARES_DENOMINATOR / 100);
+    let hundredths = (shares / (DELEGATION_SHARES_DENOMINATOR / 10_000)) % 100;
 
-    intstr_to_fpstr_inplace(out_str, NANO_AVAX_DECIMAL_DIGITS)
-        .map_err(|_| ParserError::UnexpectedError)
+    let len = u32_to_str(units, out_str)?.len();
+    let tail = [
+        b'.',
+        b'0' + (hundredths / 10) as u8,
+        b'0' + (hundredths % 10) as u8,
+        b'%',
+    ];
+    out_str
+        .get_mut(len..len + tail.len())
+        .ok_or(ParserError::UnexpectedBufferEnd)?
+        .copy_from_slice(&tail);
+
+    Ok(&mut out_str[..len + tail.len()])
 }
 
 macro_rules! num_to_str {
@@ -228,7 +261,7 @@ pub fn intstr_to_fpstr_inplace(
 
 #[cfg(test)]
 mod tests {
-    use super::{intstr_to_fpstr_inplace, u64_to_str};
+    use super::{intstr_to_fpstr_inplace, shares_to_percent_str, u64_to_str, ParserError};
     use lexical_core::Number;
     use rand::Rng;
     use std::{format, string::String, vec::Vec};
@@ -312,4 +345,25 @@ mod tests {
             assert_eq!(out, expected_output)
         }
     }
+
+    #[test]
+    fn shares_to_percent() {
+        let mut buffer = [0; u32::FORMATTED_SIZE_DECIMAL + 4];
+
+        let table: &[(u32, &[u8])] = &[
+            (20_000, b"2.00%"),
+            (0, b"0.00%"),
+            (123_456, b"12.34%"),
+            (1_000_000, b"100.00%"),
+        ];
+        for (shares, expected) in table {
+            let out = shares_to_percent_str(*shares, &mut buffer).unwrap();
+            assert_eq!(out, *expected);
+        }
+
+        assert_eq!(
+            shares_to_percent_str(1_000_001, &mut buffer).unwrap_err(),
+            ParserError::ValueOutOfRange
+        );
+    }
 }
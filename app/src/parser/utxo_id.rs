@@ -29,6 +29,16 @@ pub struct UtxoId<'b> {
     out_idx: u32,
 }
 
+impl<'b> UtxoId<'b> {
+    pub fn tx_id(&self) -> &'b [u8; TX_ID_LEN] {
+        self.tx_id
+    }
+
+    pub fn out_idx(&self) -> u32 {
+        self.out_idx
+    }
+}
+
 impl<'b> FromBytes<'b> for UtxoId<'b> {
     fn from_bytes_into(
         input: &'b [u8],
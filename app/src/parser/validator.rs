@@ -15,14 +15,12 @@
 ********************************************************************************/
 use crate::handlers::handle_ui_message;
 use crate::parser::{
-    cb58_output_len, nano_avax_to_fp_str, DisplayableItem, FromBytes, ParserError,
-    CB58_CHECKSUM_LEN,
+    cb58_output_len, nano_avax_to_fp_str, AssetId, DisplayableItem, FromBytes, Header, ObjectList,
+    ParserError, PvmOutput, SubnetId, TransferableOutput, MAX_ASSET_AMOUNT_LEN,
+    MAX_ASSET_LABEL_LEN, MAX_STAKE_DURATION,
 };
-use crate::sys::{
-    hash::{Hasher, Sha256},
-    ViewError, PIC,
-};
-use crate::utils::bs58_encode;
+use crate::sys::{ViewError, PIC};
+use crate::utils::cb58_encode;
 use core::{mem::MaybeUninit, ptr::addr_of_mut};
 use nom::{
     bytes::complete::take,
@@ -33,6 +31,25 @@ use nom::{
 pub const NODE_ID_LEN: usize = 20;
 const NODE_ID_PREFIX_LEN: usize = 7;
 
+/// Max len of a node id rendered as NodeID-<cb58>
+pub const MAX_NODE_ID_ENCODED_LEN: usize = cb58_output_len::<NODE_ID_LEN>() + NODE_ID_PREFIX_LEN;
+
+/// Writes the node id in its canonical NodeID-<cb58> form,
+/// returning the number of bytes written
+pub fn encode_node_id(node_id: &[u8; NODE_ID_LEN], out: &mut [u8]) -> Result<usize, ViewError> {
+    use bolos::pic_str;
+
+    let prefix = pic_str!(b"NodeID-"!);
+    out.get_mut(..NODE_ID_PREFIX_LEN)
+        .ok_or(ViewError::Unknown)?
+        .copy_from_slice(prefix);
+
+    let len =
+        cb58_encode(node_id, &mut out[NODE_ID_PREFIX_LEN..]).map_err(|_| ViewError::Unknown)?;
+
+    Ok(NODE_ID_PREFIX_LEN + len)
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(C)]
 #[cfg_attr(test, derive(Debug))]
@@ -73,6 +90,82 @@ impl<'b> FromBytes<'b> for Validator<'b> {
     }
 }
 
+/// Returns the asset locked by `stake`, every output locks the same one,
+/// which for the primary network is AVAX
+pub fn stake_asset<'b>(
+    stake: &ObjectList<'b, TransferableOutput<'b, PvmOutput<'b>>>,
+    subnet_id: &SubnetId,
+) -> Result<AssetId<'b>, ParserError> {
+    let asset = stake
+        .iter()
+        .next()
+        .map(|o| *o.assert_id())
+        .ok_or(ParserError::InvalidStakingAmount)?;
+
+    if stake.iter().any(|o| o.assert_id() != &asset)
+        || (subnet_id.is_primary_network() && !asset.is_avax())
+    {
+        return Err(ParserError::InvalidStakingAmount);
+    }
+
+    Ok(asset)
+}
+
+impl<'b> Validator<'b> {
+    /// Errors if the staking period does not fit the primary network bounds,
+    /// subnets define their own minimum, which can not be checked here
+    pub fn check_staking_period(
+        &self,
+        header: &Header,
+        subnet_id: &SubnetId,
+    ) -> Result<(), ParserError> {
+        let duration = self
+            .endtime
+            .checked_sub(self.start_time)
+            .ok_or(ParserError::InvalidStakingPeriod)?;
+
+        if duration > MAX_STAKE_DURATION {
+            return Err(ParserError::InvalidStakingPeriod);
+        }
+
+        if subnet_id.is_primary_network() && duration < header.network_id()?.min_stake_duration() {
+            return Err(ParserError::InvalidStakingPeriod);
+        }
+
+        Ok(())
+    }
+
+    /// Renders the weight as the total stake in `asset`, subnet tokens
+    /// are shown in their base unit unless their denomination was provided,
+    /// along with their asset id
+    pub fn render_total_stake(
+        &self,
+        asset: &AssetId,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        use bolos::pic_str;
+
+        let label = pic_str!(b"Total stake");
+        title[..label.len()].copy_from_slice(label);
+
+        let mut amount_buf = [0; MAX_ASSET_AMOUNT_LEN];
+        let amount = asset
+            .format_amount(self.weight, &mut amount_buf[..])
+            .map_err(|_| ViewError::Unknown)?;
+
+        let mut buffer = [0; MAX_ASSET_AMOUNT_LEN + 1 + MAX_ASSET_LABEL_LEN];
+        let mut len = amount.len();
+        buffer[..len].copy_from_slice(amount);
+        buffer[len] = b' ';
+        len += 1;
+        len += asset.write_label(&mut buffer[len..])?;
+
+        handle_ui_message(&buffer[..len], message, page)
+    }
+}
+
 impl<'b> DisplayableItem for Validator<'b> {
     fn num_items(&self) -> usize {
         // node_id, start_time, endtime and total_stake
@@ -95,26 +188,8 @@ impl<'b> DisplayableItem for Validator<'b> {
                 let label = pic_str!(b"Validator");
                 title[..label.len()].copy_from_slice(label);
 
-                let mut data = [0; NODE_ID_LEN + CB58_CHECKSUM_LEN];
-                data[..NODE_ID_LEN].copy_from_slice(&self.node_id[..]);
-
-                let checksum = Sha256::digest(&self.node_id[..]).map_err(|_| ViewError::Unknown)?;
-                // format the node_id
-                let prefix = pic_str!(b"NodeID-"!);
-
-                // prepare the data to be encoded by appending last 4-byte
-                data[NODE_ID_LEN..]
-                    .copy_from_slice(&checksum[(Sha256::DIGEST_LEN - CB58_CHECKSUM_LEN)..]);
-
-                const MAX_SIZE: usize = cb58_output_len::<NODE_ID_LEN>() + NODE_ID_PREFIX_LEN;
-
-                let mut node_id = [0; MAX_SIZE];
-
-                node_id[..prefix.len()].copy_from_slice(prefix);
-
-                let len = bs58_encode(data, &mut node_id[NODE_ID_PREFIX_LEN..])
-                    .map_err(|_| ViewError::Unknown)?
-                    + NODE_ID_PREFIX_LEN;
+                let mut node_id = [0; MAX_NODE_ID_ENCODED_LEN];
+                let len = encode_node_id(self.node_id, &mut node_id[..])?;
 
                 handle_ui_message(&node_id[..len], message, page)
             }
@@ -146,3 +221,26 @@ impl<'b> DisplayableItem for Validator<'b> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn node_id_canonical_form() {
+        let node_id = [
+            0xde, 0x31, 0xb4, 0xd8, 0xb2, 0x29, 0x91, 0xd5, 0x1a, 0xa6, 0xaa, 0x1f, 0xc7, 0x33,
+            0xf2, 0x3a, 0x85, 0x1a, 0x8c, 0x94,
+        ];
+
+        let mut out = [0; MAX_NODE_ID_ENCODED_LEN];
+        let len = encode_node_id(&node_id, &mut out[..]).unwrap();
+        assert_eq!(&out[..len], b"NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ");
+    }
+
+    #[test]
+    fn node_id_small_buffer() {
+        let mut out = [0; NODE_ID_PREFIX_LEN + 4];
+        assert!(encode_node_id(&[0; NODE_ID_LEN], &mut out[..]).is_err());
+    }
+}
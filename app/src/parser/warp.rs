@@ -0,0 +1,365 @@
+/*******************************************************************************
+*   (c) 2021 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use core::{convert::TryFrom, mem::MaybeUninit, ptr::addr_of_mut};
+use nom::{
+    bytes::complete::{tag, take},
+    number::complete::be_u32,
+};
+use zemu_sys::ViewError;
+
+use crate::{
+    handlers::{handle_ui_message, handle_ui_message_hex},
+    parser::{
+        blockchain_id_name, cb58_output_len, DisplayableItem, FromBytes, NetworkId, ParserError,
+        BLOCKCHAIN_ID_LEN, WARP_ADDRESSED_CALL, WARP_HASH_PAYLOAD, WARP_MAX_ADDRESS_LEN,
+    },
+    utils::cb58_encode,
+};
+use bolos::{
+    hash::{Hasher, Sha256},
+    pic_str, PIC,
+};
+
+const WARP_CODEC_VERSION: [u8; 2] = [0, 0];
+
+/// The payload of a warp message
+///
+/// Hash and AddressedCall payloads are decoded, the contents
+/// of an AddressedCall and any other payload type are opaque
+/// to the app and only shown as their sha256 hash
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub enum WarpPayload<'b> {
+    Hash(&'b [u8; Sha256::DIGEST_LEN]),
+    AddressedCall {
+        source_address: &'b [u8],
+        payload: &'b [u8],
+    },
+    Unknown(&'b [u8]),
+}
+
+impl<'b> WarpPayload<'b> {
+    fn parse(input: &'b [u8]) -> Result<Self, nom::Err<ParserError>> {
+        let unknown = Ok(Self::Unknown(input));
+
+        let (rem, type_id) = match tag(WARP_CODEC_VERSION)(input).and_then(|(rem, _)| be_u32(rem)) {
+            Ok(parsed) => parsed,
+            Err(_) => return unknown,
+        };
+
+        let (rem, payload) = match type_id {
+            WARP_HASH_PAYLOAD => {
+                let (rem, hash) = take(Sha256::DIGEST_LEN)(rem)?;
+                let hash = arrayref::array_ref!(hash, 0, Sha256::DIGEST_LEN);
+
+                (rem, Self::Hash(hash))
+            }
+            WARP_ADDRESSED_CALL => {
+                let (rem, len) = be_u32(rem)?;
+                if len as usize > WARP_MAX_ADDRESS_LEN {
+                    return Err(ParserError::ValueOutOfRange.into());
+                }
+                let (rem, source_address) = take(len as usize)(rem)?;
+
+                let (rem, len) = be_u32(rem)?;
+                let (rem, payload) = take(len as usize)(rem)?;
+
+                (
+                    rem,
+                    Self::AddressedCall {
+                        source_address,
+                        payload,
+                    },
+                )
+            }
+            _ => return unknown,
+        };
+
+        if !rem.is_empty() {
+            return Err(ParserError::UnexpectedData.into());
+        }
+
+        Ok(payload)
+    }
+
+    fn num_items(&self) -> usize {
+        match self {
+            // type and hash
+            Self::Hash(_) | Self::Unknown(_) => 1 + 1,
+            // type, source address and payload hash
+            Self::AddressedCall { .. } => 1 + 1 + 1,
+        }
+    }
+
+    fn render_sha256(data: &[u8], message: &mut [u8], page: u8) -> Result<u8, ViewError> {
+        let sha = Sha256::digest(data).map_err(|_| ViewError::Unknown)?;
+        Self::render_hex(&sha[..], message, page)
+    }
+
+    fn render_hex(data: &[u8], message: &mut [u8], page: u8) -> Result<u8, ViewError> {
+        handle_ui_message_hex(&[], data, message, page)
+    }
+
+    fn render_item(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        match (item_n, self) {
+            (0, _) => {
+                let label = pic_str!(b"Payload");
+                title[..label.len()].copy_from_slice(label);
+
+                let content: &[u8] = match self {
+                    Self::Hash(_) => pic_str!(b"Hash"!),
+                    Self::AddressedCall { .. } => pic_str!(b"AddressedCall"!),
+                    Self::Unknown(_) => pic_str!(b"Unknown"!),
+                };
+                handle_ui_message(content, message, page)
+            }
+            (1, Self::Hash(hash)) => {
+                let label = pic_str!(b"Hash");
+                title[..label.len()].copy_from_slice(label);
+
+                Self::render_hex(&hash[..], message, page)
+            }
+            (1, Self::Unknown(payload)) => {
+                let label = pic_str!(b"Payload hash");
+                title[..label.len()].copy_from_slice(label);
+
+                Self::render_sha256(payload, message, page)
+            }
+            (1, Self::AddressedCall { source_address, .. }) => {
+                let label = pic_str!(b"Source address");
+                title[..label.len()].copy_from_slice(label);
+
+                Self::render_hex(source_address, message, page)
+            }
+            (2, Self::AddressedCall { payload, .. }) => {
+                let label = pic_str!(b"Payload hash");
+                title[..label.len()].copy_from_slice(label);
+
+                Self::render_sha256(payload, message, page)
+            }
+            _ => Err(ViewError::NoData),
+        }
+    }
+}
+
+/// An unsigned warp message, used for cross-chain messaging
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct WarpMessage<'b> {
+    pub network_id: NetworkId,
+    pub source_chain: &'b [u8; BLOCKCHAIN_ID_LEN],
+    pub payload: WarpPayload<'b>,
+}
+
+impl<'b> WarpMessage<'b> {
+    pub fn new(data: &'b [u8]) -> Result<Self, ParserError> {
+        let mut this = MaybeUninit::uninit();
+        let rem = Self::from_bytes_into(data, &mut this)?;
+
+        // the whole message is hashed, so it has to be shown entirely
+        if !rem.is_empty() {
+            return Err(ParserError::UnexpectedData);
+        }
+
+        Ok(unsafe { this.assume_init() })
+    }
+
+    fn render_source_chain(&self, message: &mut [u8], page: u8) -> Result<u8, ViewError> {
+        if let Some(name) = blockchain_id_name(self.network_id, self.source_chain) {
+            return handle_ui_message(name.as_bytes(), message, page);
+        }
+
+        // chains of other subnets
+        const MAX_SIZE: usize = cb58_output_len::<BLOCKCHAIN_ID_LEN>();
+        let mut encoded = [0; MAX_SIZE];
+        let len =
+            cb58_encode(self.source_chain, &mut encoded[..]).map_err(|_| ViewError::Unknown)?;
+
+        handle_ui_message(&encoded[..len], message, page)
+    }
+}
+
+impl<'b> FromBytes<'b> for WarpMessage<'b> {
+    #[inline(never)]
+    fn from_bytes_into(
+        input: &'b [u8],
+        out: &mut MaybeUninit<Self>,
+    ) -> Result<&'b [u8], nom::Err<ParserError>> {
+        crate::sys::zemu_log_stack("WarpMessage::from_bytes_into\x00");
+
+        // codec version, network id, source chain id and
+        // the length prefixed payload
+        let (rem, _) = tag(WARP_CODEC_VERSION)(input)?;
+        let (rem, network_id) = be_u32(rem)?;
+        let network_id = NetworkId::try_from(network_id)?;
+
+        let (rem, source_chain) = take(BLOCKCHAIN_ID_LEN)(rem)?;
+        let source_chain = arrayref::array_ref!(source_chain, 0, BLOCKCHAIN_ID_LEN);
+
+        let (rem, payload_len) = be_u32(rem)?;
+        let (rem, payload) = take(payload_len as usize)(rem)?;
+        let payload = WarpPayload::parse(payload)?;
+
+        let out = out.as_mut_ptr();
+
+        //good ptr and no uninit reads
+        unsafe {
+            addr_of_mut!((*out).network_id).write(network_id);
+            addr_of_mut!((*out).source_chain).write(source_chain);
+            addr_of_mut!((*out).payload).write(payload);
+        }
+
+        Ok(rem)
+    }
+}
+
+impl<'b> DisplayableItem for WarpMessage<'b> {
+    fn num_items(&self) -> usize {
+        // description, network, source chain and the payload
+        1 + 1 + 1 + self.payload.num_items()
+    }
+
+    fn render_item(
+        &self,
+        item_n: u8,
+        title: &mut [u8],
+        message: &mut [u8],
+        page: u8,
+    ) -> Result<u8, ViewError> {
+        match item_n {
+            0 => {
+                let label = pic_str!(b"Sign");
+                title[..label.len()].copy_from_slice(label);
+                let content = pic_str!(b"Warp message");
+                handle_ui_message(content, message, page)
+            }
+            1 => {
+                let label = pic_str!(b"Network");
+                title[..label.len()].copy_from_slice(label);
+                handle_ui_message(self.network_id.name(), message, page)
+            }
+            2 => {
+                let label = pic_str!(b"Source chain");
+                title[..label.len()].copy_from_slice(label);
+                self.render_source_chain(message, page)
+            }
+            x => self.payload.render_item(x - 3, title, message, page),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::prelude::v1::*;
+
+    // an AddressedCall from the fuji C-chain, carrying "hello warp"
+    const DATA: &str = "000000000005\
+                        7fc93d85c6d62c5b2ac0b519c87010ea5294012d1e407030d6acd0021cac10d5\
+                        0000002c000000000001\
+                        000000140102030405060708090a0b0c0d0e0f1011121314\
+                        0000000a68656c6c6f2077617270";
+
+    fn items(msg: &WarpMessage) -> Vec<(String, String)> {
+        (0..msg.num_items() as u8)
+            .map(|i| {
+                let mut title = [0; 32];
+                let mut message = [0; 100];
+                msg.render_item(i, &mut title, &mut message, 0).unwrap();
+
+                let s = |b: &[u8]| {
+                    let len = b.iter().position(|c| *c == 0).unwrap();
+                    String::from_utf8(b[..len].to_vec()).unwrap()
+                };
+                (s(&title), s(&message))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_addressed_call() {
+        let data = hex::decode(DATA).unwrap();
+        let msg = WarpMessage::new(&data).unwrap();
+
+        assert_eq!(msg.network_id, NetworkId::Fuji);
+        assert!(matches!(
+            msg.payload,
+            WarpPayload::AddressedCall {
+                payload: b"hello warp",
+                ..
+            }
+        ));
+
+        let expected = [
+            ("Sign", "Warp message"),
+            ("Network", "Fuji"),
+            ("Source chain", "C-Chain"),
+            ("Payload", "AddressedCall"),
+            ("Source address", "0102030405060708090a0b0c0d0e0f1011121314"),
+            (
+                "Payload hash",
+                "74c60c1c2bed97af49a49de7674292d3833eea0a825e33952dcce2067c8a015b",
+            ),
+        ];
+        let items = items(&msg);
+        assert_eq!(items.len(), expected.len());
+        for ((title, message), (t, m)) in items.iter().zip(expected.iter()) {
+            assert_eq!(title, t);
+            assert_eq!(message, m);
+        }
+    }
+
+    #[test]
+    fn opaque_payload() {
+        let mut data = hex::decode(DATA).unwrap();
+        // unknown payload type
+        data[47] = 0x07;
+
+        let msg = WarpMessage::new(&data).unwrap();
+        assert!(matches!(msg.payload, WarpPayload::Unknown(p) if p.len() == 0x2c));
+        assert_eq!(msg.num_items(), 5);
+    }
+
+    #[test]
+    fn malformed_message() {
+        let data = hex::decode(DATA).unwrap();
+
+        // truncated payload
+        assert!(WarpMessage::new(&data[..data.len() - 1]).is_err());
+
+        // trailing data
+        let mut trailing = data.clone();
+        trailing.push(0);
+        assert_eq!(
+            WarpMessage::new(&trailing),
+            Err(ParserError::UnexpectedData)
+        );
+
+        // unknown network
+        let mut network = data.clone();
+        network[5] = 0x02;
+        assert_eq!(
+            WarpMessage::new(&network),
+            Err(ParserError::InvalidNetworkId)
+        );
+    }
+}
@@ -17,6 +17,8 @@
 
 use bolos::PIC;
 
+use crate::parser::CB58_CHECKSUM_LEN;
+
 mod apdu_unwrap;
 pub use apdu_unwrap::*;
 
@@ -24,7 +26,7 @@ mod apdu_wrapper;
 pub use apdu_wrapper::*;
 
 pub mod convert_to_rs;
-pub use convert_to_rs::{convert_der_to_rs, ConvertError};
+pub use convert_to_rs::{convert_der_to_rs, normalize_low_s, ConvertError};
 
 mod buffer_upload;
 pub use buffer_upload::*;
@@ -32,7 +34,32 @@ pub use buffer_upload::*;
 mod app_mode;
 pub use app_mode::*;
 
+mod ecdsa;
+pub use ecdsa::*;
+
+mod rng;
+pub use rng::*;
+
+mod derivation_scheme;
+pub use derivation_scheme::*;
+
+mod digest_scheme;
+pub use digest_scheme::*;
+
+pub mod amount;
+pub use amount::{format_amount, max_amount_len, AmountError};
+
+#[macro_use]
+mod toggle;
+
+pub mod address_denylist;
 pub mod blind_sign_toggle;
+pub mod chain_id_allowlist;
+pub mod gas_price_threshold;
+pub mod output_order_toggle;
+pub mod pre_eip155_toggle;
+pub mod strict_outputs_toggle;
+pub mod trailing_data_toggle;
 
 #[cfg(test)]
 #[macro_export]
@@ -105,15 +132,21 @@ pub fn hex_encode(
 pub fn bs58_encode(
     input: impl AsRef<[u8]>,
     output: &mut [u8],
+) -> Result<usize, OutputBufferTooSmall> {
+    bs58_encode_bytes(input.as_ref().iter().copied(), output)
+}
+
+fn bs58_encode_bytes(
+    input: impl Iterator<Item = u8> + Clone,
+    output: &mut [u8],
 ) -> Result<usize, OutputBufferTooSmall> {
     const ALPHABET_ENCODE: &[u8; 58] =
         b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
     let table = PIC::new(ALPHABET_ENCODE).into_inner();
 
-    let input = input.as_ref();
     let mut index = 0;
 
-    for &val in input.iter() {
+    for val in input.clone() {
         let mut carry = val as usize;
         for byte in &mut output[..index] {
             carry += (*byte as usize) << 8;
@@ -130,7 +163,7 @@ pub fn bs58_encode(
         }
     }
 
-    for _ in input.iter().take_while(|v| **v == 0) {
+    for _ in input.take_while(|v| *v == 0) {
         if index == output.len() {
             return Err(OutputBufferTooSmall);
         }
@@ -146,6 +179,34 @@ pub fn bs58_encode(
     Ok(index)
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CB58Error {
+    Hash,
+    OutputBufferTooSmall,
+}
+
+impl From<OutputBufferTooSmall> for CB58Error {
+    fn from(_: OutputBufferTooSmall) -> Self {
+        Self::OutputBufferTooSmall
+    }
+}
+
+/// Encodes the input (ids, tx ids, etc) as CB58,
+/// that is, the base58 of the input followed by the
+/// last 4 bytes of its sha256 as checksum
+///
+/// Use `cb58_output_len` to size the output
+pub fn cb58_encode(input: impl AsRef<[u8]>, output: &mut [u8]) -> Result<usize, CB58Error> {
+    use bolos::hash::{Hasher, Sha256};
+
+    let input = input.as_ref();
+    let checksum = Sha256::digest(input).map_err(|_| CB58Error::Hash)?;
+    let checksum = &checksum[Sha256::DIGEST_LEN - CB58_CHECKSUM_LEN..];
+
+    let data = input.iter().chain(checksum.iter()).copied();
+    bs58_encode_bytes(data, output).map_err(Into::into)
+}
+
 /// Reads a byte slice preprended with the slice len
 pub fn read_slice(input: &[u8]) -> Option<(usize, &[u8])> {
     let len = input.first()?;
@@ -209,6 +270,51 @@ mod maybe_null_terminated_to_string {
 #[cfg(test)]
 pub use maybe_null_terminated_to_string::MaybeNullTerminatedToString;
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::cb58_output_len;
+
+    fn cb58(id: &[u8]) -> std::string::String {
+        let mut out = [0; cb58_output_len::<32>()];
+        let len = cb58_encode(id, &mut out).unwrap();
+        std::string::String::from_utf8(out[..len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn cb58_avax_asset_id() {
+        let mainnet =
+            hex::decode("21e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff")
+                .unwrap();
+        assert_eq!(
+            cb58(&mainnet),
+            "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z"
+        );
+
+        let fuji = hex::decode("3d9bdac0ed1d761330cf680efdeb1a42159eb387d6d2950c96f7d28f61bbe2aa")
+            .unwrap();
+        assert_eq!(
+            cb58(&fuji),
+            "U8iRqJoiJm8xZHAacmvYyZVwqQx6uDNtQeP3CQ6fcgQk3JqnK"
+        );
+    }
+
+    #[test]
+    fn cb58_leading_zeros() {
+        // P-chain id
+        assert_eq!(cb58(&[0; 32]), "11111111111111111111111111111111LpoYY");
+    }
+
+    #[test]
+    fn cb58_output_too_small() {
+        let mut out = [0; 10];
+        assert_eq!(
+            cb58_encode([0xff; 32], &mut out),
+            Err(CB58Error::OutputBufferTooSmall)
+        );
+    }
+}
+
 #[macro_export]
 /// Convert the return of Show::show into something more usable for apdu handlers
 ///
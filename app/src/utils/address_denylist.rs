@@ -0,0 +1,89 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use cfg_if::cfg_if;
+
+use crate::parser::ADDRESS_LEN;
+
+/// Maximum number of contract addresses that can be denied
+pub const MAX_DENIED_ADDRESSES: usize = 8;
+
+#[cfg(feature = "address-denylist")]
+mod impls {
+    use super::{ADDRESS_LEN, MAX_DENIED_ADDRESSES};
+
+    #[repr(C)]
+    pub struct AddressDenylist {
+        pub addresses: [[u8; ADDRESS_LEN]; MAX_DENIED_ADDRESSES],
+        pub len: u8,
+    }
+
+    cfg_if::cfg_if! {
+        if #[cfg(any(unix, windows))] {
+            /// Provide a mock for tests
+            #[allow(non_upper_case_globals)]
+            pub static mut address_denylist: AddressDenylist = AddressDenylist {
+                addresses: [[0; ADDRESS_LEN]; MAX_DENIED_ADDRESSES],
+                len: 0,
+            };
+
+            pub unsafe fn address_denylist_store(list: *const AddressDenylist) {
+                let list = &*list;
+                address_denylist.addresses = list.addresses;
+                address_denylist.len = list.len;
+            }
+        } else {
+            extern "C" {
+                ///Link to the C code, the list is kept in NVM
+                pub static mut address_denylist: AddressDenylist;
+
+                ///Link to the C code, writes `list` to NVM
+                pub fn address_denylist_store(list: *const AddressDenylist);
+            }
+        }
+    }
+}
+
+/// Returns the contract addresses eth transactions
+/// are never signed for
+pub fn denied_addresses() -> &'static [[u8; ADDRESS_LEN]] {
+    cfg_if! {
+        if #[cfg(feature = "address-denylist")] {
+            //safe: guaranteed no data races
+            let list = unsafe { bolos::PIC::new(&impls::address_denylist).into_inner() };
+            let len = core::cmp::min(list.len as usize, MAX_DENIED_ADDRESSES);
+            &list.addresses[..len]
+        } else {
+            &[]
+        }
+    }
+}
+
+/// Replaces the denied addresses with `addresses`,
+/// at most [`MAX_DENIED_ADDRESSES`] of them
+#[cfg(feature = "address-denylist")]
+pub fn store_denied_addresses(addresses: &[[u8; ADDRESS_LEN]]) {
+    let mut list = impls::AddressDenylist {
+        addresses: [[0; ADDRESS_LEN]; MAX_DENIED_ADDRESSES],
+        len: 0,
+    };
+
+    let len = core::cmp::min(addresses.len(), MAX_DENIED_ADDRESSES);
+    list.addresses[..len].copy_from_slice(&addresses[..len]);
+    list.len = len as u8;
+
+    //safe: guaranteed no data races
+    unsafe { impls::address_denylist_store(&list) }
+}
@@ -0,0 +1,246 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use crate::{handlers::eth::u256, parser::u64_to_str};
+
+/// Errors formatting an amount
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub enum AmountError {
+    /// The output can not hold the formatted amount
+    BufferTooShort,
+}
+
+/// Integers that can be formatted as an amount
+pub trait Amount: Copy {
+    /// Number of decimal digits of the largest value
+    const MAX_DIGITS: usize;
+
+    /// Writes the decimal digits of the value to the start of `out`,
+    /// which is at least `MAX_DIGITS` long, and returns how many were written
+    fn write_digits(self, out: &mut [u8]) -> usize;
+}
+
+impl Amount for u64 {
+    const MAX_DIGITS: usize = 20;
+
+    fn write_digits(self, out: &mut [u8]) -> usize {
+        // out fits the largest u64
+        u64_to_str(self, out)
+            .map(|digits| digits.len())
+            .unwrap_or(0)
+    }
+}
+
+impl Amount for u256 {
+    const MAX_DIGITS: usize = u256::FORMATTED_SIZE_DECIMAL;
+
+    fn write_digits(self, out: &mut [u8]) -> usize {
+        self.to_lexical(out).len()
+    }
+}
+
+/// Length of the longest amount of type `A` with `decimals` places
+pub const fn max_amount_len<A: Amount>(decimals: usize) -> usize {
+    // the digits, or a zero and the fraction if that is longer, and the point
+    let digits = if A::MAX_DIGITS > decimals {
+        A::MAX_DIGITS
+    } else {
+        decimals + 1
+    };
+    digits + 1
+}
+
+/// Formats `value`, an integer in units with `decimals` places, as a
+/// decimal number into `out`
+///
+/// The fraction has no trailing zeros and the point is only written
+/// when there is a fraction, so `1500` with 3 places is `1.5` and
+/// `2000` is `2`
+pub fn format_amount<A: Amount>(
+    value: A,
+    decimals: usize,
+    out: &mut [u8],
+) -> Result<&str, AmountError> {
+    let mut digits = [0; u256::FORMATTED_SIZE_DECIMAL];
+    let num_digits = value.write_digits(&mut digits);
+    if num_digits == 0 {
+        return Err(AmountError::BufferTooShort);
+    }
+    let digits = &digits[..num_digits];
+
+    // digits of the integer part, which is zero if there are none
+    let int_len = num_digits.saturating_sub(decimals);
+    let fraction = &digits[int_len..];
+    let trailing_zeros = fraction.iter().rev().take_while(|&&d| d == b'0').count();
+
+    // fraction is right aligned, after the zeros to the point
+    let significant = fraction.len() - trailing_zeros;
+    let padding = if significant == 0 {
+        0
+    } else {
+        decimals - fraction.len()
+    };
+
+    let len = core::cmp::max(int_len, 1)
+        + if significant == 0 {
+            0
+        } else {
+            1 + padding + significant
+        };
+    if out.len() < len {
+        return Err(AmountError::BufferTooShort);
+    }
+
+    let mut pos = if int_len == 0 {
+        out[0] = b'0';
+        1
+    } else {
+        out[..int_len].copy_from_slice(&digits[..int_len]);
+        int_len
+    };
+
+    if significant != 0 {
+        out[pos] = b'.';
+        pos += 1;
+
+        out[pos..pos + padding].fill(b'0');
+        pos += padding;
+
+        out[pos..pos + significant].copy_from_slice(&fraction[..significant]);
+        pos += significant;
+    }
+
+    // only ascii digits and the point were written
+    Ok(unsafe { core::str::from_utf8_unchecked(&out[..pos]) })
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use std::string::{String, ToString};
+
+    use super::*;
+
+    fn format<A: Amount>(value: A, decimals: usize) -> String {
+        let mut out = [0; 200];
+        format_amount(value, decimals, &mut out)
+            .unwrap()
+            .to_string()
+    }
+
+    // the integer the formatted amount stands for, as a decimal string
+    fn unformat(amount: &str, decimals: usize) -> String {
+        let (int, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+        assert!(fraction.len() <= decimals);
+
+        let digits = std::format!(
+            "{}{}{}",
+            int,
+            fraction,
+            "0".repeat(decimals - fraction.len())
+        );
+        match digits.trim_start_matches('0') {
+            "" => "0".to_string(),
+            digits => digits.to_string(),
+        }
+    }
+
+    fn check<A: Amount + ToString>(value: A, decimals: usize) {
+        let expected_len = max_amount_len::<A>(decimals);
+        let mut out = std::vec![0; expected_len];
+        let amount = format_amount(value, decimals, &mut out).unwrap();
+
+        // trimmed
+        assert!(!amount.ends_with('.'));
+        if amount.contains('.') {
+            assert!(!amount.ends_with('0'));
+        }
+        assert!(amount == "0" || amount.starts_with("0.") || !amount.starts_with('0'));
+
+        // no precision loss
+        assert_eq!(unformat(amount, decimals), value.to_string());
+
+        // anything shorter is rejected, not overrun
+        let len = amount.len();
+        let mut short = std::vec![0; len - 1];
+        assert_eq!(
+            format_amount(value, decimals, &mut short),
+            Err(AmountError::BufferTooShort)
+        );
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert_eq!(format(0u64, 0), "0");
+        assert_eq!(format(0u64, 9), "0");
+        assert_eq!(format(1_000_000_000u64, 9), "1");
+        assert_eq!(format(2_000_000_000_000u64, 9), "2000");
+        assert_eq!(format(1_500_000_000u64, 9), "1.5");
+        assert_eq!(format(1u64, 9), "0.000000001");
+        assert_eq!(format(u64::MAX, 0), "18446744073709551615");
+        assert_eq!(format(u64::MAX, 9), "18446744073.709551615");
+        assert_eq!(format(u64::MAX, 25), "0.0000018446744073709551615");
+
+        let max = *u256::max();
+        assert_eq!(
+            format(max, 18),
+            "115792089237316195423570985008687907853269984665640564039457.584007913129639935"
+        );
+        assert_eq!(
+            format(max, 78),
+            "0.115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(format(u256::from(0u64), 18), "0");
+        assert_eq!(format(u256::exp10(18), 18), "1");
+
+        for decimals in [0, 1, 9, 18, 77, 78, 79, 120] {
+            check(0u64, decimals);
+            check(u64::MAX, decimals);
+            check(max, decimals);
+        }
+    }
+
+    #[test]
+    fn buffer_too_short() {
+        let mut out = [0; 3];
+        assert_eq!(
+            format_amount(1234u64, 0, &mut out),
+            Err(AmountError::BufferTooShort)
+        );
+        assert_eq!(
+            format_amount(1234u64, 3, &mut out),
+            Err(AmountError::BufferTooShort)
+        );
+        assert_eq!(format_amount(1200u64, 3, &mut out), Ok("1.2"));
+        assert_eq!(
+            format_amount(0u64, 3, &mut []),
+            Err(AmountError::BufferTooShort)
+        );
+    }
+
+    #[cfg(not(miri))]
+    proptest! {
+        #[test]
+        fn u64_round_trip(value: u64, decimals in 0usize..40) {
+            check(value, decimals)
+        }
+
+        #[test]
+        fn u256_round_trip(a: u64, b: u64, c: u64, d: u64, decimals in 0usize..100) {
+            check(u256([a, b, c, d]), decimals)
+        }
+    }
+}
@@ -13,41 +13,7 @@
 *  See the License for the specific language governing permissions and
 *  limitations under the License.
 ********************************************************************************/
-use cfg_if::cfg_if;
-
-#[cfg(feature = "blind-sign-toggle")]
-mod impls {
-    #[repr(C)]
-    pub struct BlindSignToggle {
-        pub toggle: bool,
-        pub message: [i8; 9],
-    }
-
-    cfg_if::cfg_if! {
-        if #[cfg(any(unix, windows))] {
-            /// Provide a mock for tests
-            #[allow(non_upper_case_globals)]
-            pub static mut blind_sign: BlindSignToggle = BlindSignToggle {
-                toggle: true,
-                message: [0; 9],
-            };
-        } else {
-            extern "C" {
-                ///Link to the C code
-                pub static mut blind_sign: BlindSignToggle;
-            }
-        }
-    }
-}
-
-/// Returns if blind signing is enabled in this execution
-pub fn blind_sign_enabled() -> bool {
-    cfg_if! {
-        if #[cfg(feature = "blind-sign-toggle")] {
-            //safe: guaranteed no data races
-            unsafe { bolos::PIC::new(&impls::blind_sign).into_inner().toggle }
-        } else {
-            false
-        }
-    }
+c_toggle! {
+    /// Returns if blind signing is enabled in this execution
+    pub fn blind_sign_enabled() = blind_sign if "blind-sign-toggle" else false, mock true
 }
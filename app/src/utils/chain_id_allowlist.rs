@@ -0,0 +1,81 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use cfg_if::cfg_if;
+
+/// Chain ids of the C-chain in mainnet and fuji
+pub const DEFAULT_ALLOWED_CHAIN_IDS: &[u64] = &[43114, 43113];
+
+/// Maximum number of chain ids that can be allowed
+pub const MAX_ALLOWED_CHAIN_IDS: usize = 8;
+
+#[cfg(feature = "chain-id-allowlist")]
+mod impls {
+    use super::MAX_ALLOWED_CHAIN_IDS;
+
+    #[repr(C)]
+    pub struct ChainIdAllowlist {
+        pub ids: [u64; MAX_ALLOWED_CHAIN_IDS],
+        pub len: u8,
+        pub strict: bool,
+        pub message: [i8; 9],
+    }
+
+    cfg_if::cfg_if! {
+        if #[cfg(any(unix, windows))] {
+            /// Provide a mock for tests
+            #[allow(non_upper_case_globals)]
+            pub static mut chain_id_allowlist: ChainIdAllowlist = ChainIdAllowlist {
+                ids: [43114, 43113, 0, 0, 0, 0, 0, 0],
+                len: 2,
+                strict: false,
+                message: [0; 9],
+            };
+        } else {
+            extern "C" {
+                ///Link to the C code, the list is kept in NVM
+                pub static mut chain_id_allowlist: ChainIdAllowlist;
+            }
+        }
+    }
+}
+
+/// Returns the chain ids that eth transactions can be
+/// signed for without a warning
+pub fn allowed_chain_ids() -> &'static [u64] {
+    cfg_if! {
+        if #[cfg(feature = "chain-id-allowlist")] {
+            //safe: guaranteed no data races
+            let list = unsafe { bolos::PIC::new(&impls::chain_id_allowlist).into_inner() };
+            let len = core::cmp::min(list.len as usize, MAX_ALLOWED_CHAIN_IDS);
+            &list.ids[..len]
+        } else {
+            bolos::PIC::new(DEFAULT_ALLOWED_CHAIN_IDS).into_inner()
+        }
+    }
+}
+
+/// Returns if eth transactions for chain ids not in the
+/// allowlist are rejected instead of warned about
+pub fn strict_chain_id() -> bool {
+    cfg_if! {
+        if #[cfg(feature = "chain-id-allowlist")] {
+            //safe: guaranteed no data races
+            unsafe { bolos::PIC::new(&impls::chain_id_allowlist).into_inner().strict }
+        } else {
+            false
+        }
+    }
+}
@@ -14,6 +14,18 @@
 *  limitations under the License.
 ********************************************************************************/
 
+/// Order of the secp256k1 curve
+const SECP256K1_N: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// `SECP256K1_N / 2`, the largest S considered canonical
+const SECP256K1_HALF_N: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
 #[cfg_attr(any(test, feature = "derive-debug"), derive(Debug))]
 pub enum ConvertError<const R: usize, const S: usize> {
     /// The DER prefix (at index 0) found was different than the expected 0x30
@@ -128,3 +140,80 @@ pub fn convert_der_to_rs<const R: usize, const S: usize>(
 
     Ok((r_len, s_len))
 }
+
+/// Replaces a high S with `n - S`, as required by BIP-0062 / EIP-2
+///
+/// Returns `true` if S was normalized, in which case
+/// the parity of the recovery id (V) has to be flipped too
+pub fn normalize_low_s(s: &mut [u8; 32]) -> bool {
+    //both are big endian and of the same length,
+    // so the lexicographic order is the numeric one
+    if s[..] <= SECP256K1_HALF_N[..] {
+        return false;
+    }
+
+    let mut borrow = false;
+    for (s, n) in s.iter_mut().zip(SECP256K1_N.iter()).rev() {
+        let (diff, b1) = n.overflowing_sub(*s);
+        let (diff, b2) = diff.overflowing_sub(borrow as u8);
+
+        *s = diff;
+        borrow = b1 || b2;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn der(r: &[u8], s: &[u8]) -> std::vec::Vec<u8> {
+        let mut sig = std::vec![0x30, (4 + r.len() + s.len()) as u8, 0x02, r.len() as u8];
+        sig.extend_from_slice(r);
+        sig.extend_from_slice(&[0x02, s.len() as u8]);
+        sig.extend_from_slice(s);
+        sig
+    }
+
+    #[test]
+    fn high_s_is_normalized() {
+        // S = n - 5, DER encoded with the leading 0 since the high bit is set
+        let mut high_s = [0; 33];
+        high_s[1..].copy_from_slice(&SECP256K1_N);
+        high_s[32] -= 5;
+        let sig = der(&[0x11; 32], &high_s);
+
+        let mut r = [0; 33];
+        let mut s = [0; 33];
+        convert_der_to_rs(&sig, &mut r, &mut s).unwrap();
+
+        let s = arrayref::array_mut_ref![s, 1, 32];
+        let odd = false;
+        let flipped = normalize_low_s(s);
+        assert!(flipped);
+
+        let mut expected = [0; 32];
+        expected[31] = 5;
+        assert_eq!(s, &expected);
+        assert!(odd ^ flipped);
+    }
+
+    #[test]
+    fn low_s_is_kept() {
+        let mut s = SECP256K1_HALF_N;
+        assert!(!normalize_low_s(&mut s));
+        assert_eq!(s, SECP256K1_HALF_N);
+
+        let mut s = [0; 32];
+        s[31] = 1;
+        assert!(!normalize_low_s(&mut s));
+        assert_eq!(s[31], 1);
+
+        // just above half wraps to just below it
+        let mut s = SECP256K1_HALF_N;
+        s[31] += 1;
+        assert!(normalize_low_s(&mut s));
+        assert_eq!(s, SECP256K1_HALF_N);
+    }
+}
@@ -0,0 +1,82 @@
+/*******************************************************************************
+*   (c) 2018 - 2022 Zondax AG
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use zemu_sys::ViewError;
+
+use crate::{
+    constants::{BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_1, BIP32_PATH_ROOT_ETH},
+    handlers::handle_ui_message,
+};
+
+/// Derivation scheme of a signing path,
+/// as given by the coin type of the path
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub enum DerivationScheme {
+    /// m/44'/9000'
+    Avalanche,
+    /// m/44'/60'
+    Ethereum,
+}
+
+impl DerivationScheme {
+    pub fn from_path(components: &[u32]) -> Option<Self> {
+        match components {
+            [BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_1, ..] => Some(Self::Avalanche),
+            [BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_ETH, ..] => Some(Self::Ethereum),
+            _ => None,
+        }
+    }
+
+    pub fn render(&self, title: &mut [u8], message: &mut [u8], page: u8) -> Result<u8, ViewError> {
+        use bolos::{pic_str, PIC};
+
+        let label = pic_str!(b"Derivation");
+        title[..label.len()].copy_from_slice(label);
+
+        let scheme: &[u8] = match self {
+            Self::Avalanche => pic_str!(b"Avalanche (9000')"!),
+            Self::Ethereum => pic_str!(b"Ethereum (60')"!),
+        };
+
+        handle_ui_message(scheme, message, page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scheme_from_path() {
+        let avax = [BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_1, 0x8000_0000];
+        assert_eq!(
+            DerivationScheme::from_path(&avax),
+            Some(DerivationScheme::Avalanche)
+        );
+
+        let eth = [BIP32_PATH_ROOT_0, BIP32_PATH_ROOT_ETH, 0x8000_0000, 0, 0];
+        assert_eq!(
+            DerivationScheme::from_path(&eth),
+            Some(DerivationScheme::Ethereum)
+        );
+
+        assert_eq!(DerivationScheme::from_path(&[BIP32_PATH_ROOT_0]), None);
+        assert_eq!(
+            DerivationScheme::from_path(&[BIP32_PATH_ROOT_1, BIP32_PATH_ROOT_0]),
+            None
+        );
+    }
+}
@@ -0,0 +1,41 @@
+/*******************************************************************************
+*   (c) 2018 - 2022 Zondax AG
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use bolos::hash::{Hasher, Keccak, Sha256};
+
+use crate::constants::ApduError as Error;
+
+/// Hash function whose digest of a transaction is signed,
+/// as given by the chain the transaction belongs to
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub enum DigestScheme {
+    /// X, P-chain and atomic C-chain transactions
+    Sha256,
+    /// EVM transactions
+    Keccak256,
+}
+
+impl DigestScheme {
+    pub const DIGEST_LEN: usize = 32;
+
+    #[inline(never)]
+    pub fn digest(&self, data: &[u8]) -> Result<[u8; Self::DIGEST_LEN], Error> {
+        match self {
+            Self::Sha256 => Sha256::digest(data).map_err(|_| Error::ExecutionError),
+            Self::Keccak256 => Keccak::<32>::digest(data).map_err(|_| Error::ExecutionError),
+        }
+    }
+}
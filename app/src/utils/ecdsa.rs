@@ -0,0 +1,85 @@
+/*******************************************************************************
+*   (c) 2021 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use cfg_if::cfg_if;
+
+/// Length of an uncompressed SEC1 secp256k1 public key
+pub const SECP256K1_PUBKEY_LEN: usize = 65;
+
+cfg_if! {
+    if #[cfg(not(any(unix,windows)))] {
+        extern "C" {
+            fn crypto_verify_secp256k1(
+                pubkey: *const u8,
+                pubkey_len: u16,
+                hash: *const u8,
+                hash_len: u16,
+                signature: *const u8,
+                signature_len: u16,
+            ) -> bool;
+        }
+    }
+}
+
+/// Verifies `signature`, DER encoded, is a valid ECDSA signature
+/// over the SHA-256 digest of `message` by the owner of `pubkey`
+pub fn verify_secp256k1(
+    pubkey: &[u8; SECP256K1_PUBKEY_LEN],
+    message: &[u8],
+    signature: &[u8],
+) -> bool {
+    cfg_if! {
+        if #[cfg(not(any(unix,windows)))] {
+            use bolos::hash::{Hasher, Sha256};
+
+            let hash = match Sha256::digest(message) {
+                Ok(hash) => hash,
+                Err(_) => return false,
+            };
+
+            if signature.len() > u16::MAX as usize {
+                return false;
+            }
+
+            unsafe {
+                crypto_verify_secp256k1(
+                    pubkey.as_ptr(),
+                    pubkey.len() as u16,
+                    hash.as_ptr(),
+                    hash.len() as u16,
+                    signature.as_ptr(),
+                    signature.len() as u16,
+                )
+            }
+        } else if #[cfg(test)] {
+            use k256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+
+            let mut r = [0; 32];
+            let mut s = [0; 32];
+            if super::convert_der_to_rs(signature, &mut r, &mut s).is_err() {
+                return false;
+            }
+
+            match (VerifyingKey::from_sec1_bytes(pubkey), Signature::from_scalars(r, s)) {
+                (Ok(key), Ok(sig)) => key.verify(message, &sig).is_ok(),
+                _ => false,
+            }
+        } else {
+            // no verification backend available off-device
+            let _ = (pubkey, message, signature);
+            false
+        }
+    }
+}
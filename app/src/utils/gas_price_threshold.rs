@@ -0,0 +1,58 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use cfg_if::cfg_if;
+
+/// Gas price, in gwei, above which transactions are warned about
+/// unless the user configured a different one
+pub const DEFAULT_GAS_PRICE_THRESHOLD: u64 = 1_000;
+
+#[cfg(feature = "gas-price-threshold")]
+mod impls {
+    #[repr(C)]
+    pub struct GasPriceThreshold {
+        pub gwei: u64,
+        pub message: [i8; 21],
+    }
+
+    cfg_if::cfg_if! {
+        if #[cfg(any(unix, windows))] {
+            /// Provide a mock for tests
+            #[allow(non_upper_case_globals)]
+            pub static mut gas_price_threshold: GasPriceThreshold = GasPriceThreshold {
+                gwei: super::DEFAULT_GAS_PRICE_THRESHOLD,
+                message: [0; 21],
+            };
+        } else {
+            extern "C" {
+                ///Link to the C code, the threshold is kept in NVM
+                pub static mut gas_price_threshold: GasPriceThreshold;
+            }
+        }
+    }
+}
+
+/// Returns the gas price, in gwei, above which a warning
+/// is shown for eth transactions, with 0 meaning disabled
+pub fn gas_price_threshold() -> u64 {
+    cfg_if! {
+        if #[cfg(feature = "gas-price-threshold")] {
+            //safe: guaranteed no data races
+            unsafe { bolos::PIC::new(&impls::gas_price_threshold).into_inner().gwei }
+        } else {
+            DEFAULT_GAS_PRICE_THRESHOLD
+        }
+    }
+}
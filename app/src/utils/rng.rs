@@ -0,0 +1,40 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(not(any(unix,windows)))] {
+        extern "C" {
+            fn crypto_random_u32() -> u32;
+        }
+
+        /// Returns a random number from the device's generator
+        pub fn random_u32() -> u32 {
+            unsafe { crypto_random_u32() }
+        }
+    } else if #[cfg(test)] {
+        /// Returns a random number from the generator of the host
+        pub fn random_u32() -> u32 {
+            rand::random()
+        }
+    } else {
+        /// No random generator available off-device, nothing relies on
+        /// the number there since nothing is signed
+        pub fn random_u32() -> u32 {
+            0
+        }
+    }
+}
@@ -0,0 +1,68 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+/// Layout of the toggles kept by the C side of the app,
+/// `setting_toggle_t` in `view_internal.h`
+#[repr(C)]
+pub struct Toggle {
+    pub toggle: bool,
+    pub message: [i8; 9],
+}
+
+/// Declares `$getter` returning the state of the C toggle `$c_name`
+/// when `$feature` is enabled, otherwise the fixed `$default`
+///
+/// Tests see a mock of the toggle set to `$mock`
+macro_rules! c_toggle {
+    (
+        $(#[$doc:meta])*
+        $vis:vis fn $getter:ident() = $c_name:ident if $feature:literal else $default:literal, mock $mock:literal
+    ) => {
+        #[cfg(feature = $feature)]
+        mod impls {
+            use $crate::utils::toggle::Toggle;
+
+            cfg_if::cfg_if! {
+                if #[cfg(any(unix, windows))] {
+                    /// Provide a mock for tests
+                    #[allow(non_upper_case_globals)]
+                    pub static mut $c_name: Toggle = Toggle {
+                        toggle: $mock,
+                        message: [0; 9],
+                    };
+                } else {
+                    extern "C" {
+                        ///Link to the C code
+                        pub static mut $c_name: Toggle;
+                    }
+                }
+            }
+        }
+
+        $(#[$doc])*
+        #[cfg(feature = $feature)]
+        $vis fn $getter() -> bool {
+            //safe: guaranteed no data races
+            unsafe { bolos::PIC::new(&impls::$c_name).into_inner().toggle }
+        }
+
+        $(#[$doc])*
+        #[cfg(not(feature = $feature))]
+        $vis fn $getter() -> bool {
+            $default
+        }
+    };
+}
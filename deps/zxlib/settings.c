@@ -0,0 +1,119 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+#include "settings.h"
+
+#include <stdio.h>
+
+#if defined(GAS_PRICE_THRESHOLD)
+// Thresholds the setting goes through, the first one is the default
+static const uint64_t gas_price_thresholds[] = {1000, 10000, 100, 0};
+
+typedef struct {
+    uint8_t initialized;
+    uint64_t gwei;
+} gas_price_threshold_persistent_t;
+
+gas_price_threshold_persistent_t NV_CONST N_gas_price_threshold_impl __attribute__ ((aligned(64)));
+#define N_gas_price_threshold (*(NV_VOLATILE gas_price_threshold_persistent_t *)PIC(&N_gas_price_threshold_impl))
+
+gas_price_threshold_t gas_price_threshold;
+
+static void gas_price_threshold_store(uint64_t gwei) {
+    gas_price_threshold_persistent_t persistent;
+    persistent.initialized = 1;
+    persistent.gwei = gwei;
+    MEMCPY_NV( (void*) PIC(&N_gas_price_threshold_impl), (void*) &persistent, sizeof(gas_price_threshold_persistent_t));
+
+    gas_price_threshold.gwei = N_gas_price_threshold.gwei;
+}
+
+void gas_price_threshold_next() {
+    const uint64_t *thresholds = (const uint64_t *) PIC(gas_price_thresholds);
+    const uint8_t count = sizeof(gas_price_thresholds) / sizeof(gas_price_thresholds[0]);
+
+    uint8_t next = 0;
+    for (uint8_t i = 0; i < count; i++) {
+        if (thresholds[i] == gas_price_threshold.gwei) {
+            next = (i + 1) % count;
+        }
+    }
+
+    gas_price_threshold_store(thresholds[next]);
+}
+
+void h_gas_price_threshold_update() {
+    if (gas_price_threshold.gwei == 0) {
+        snprintf(gas_price_threshold.message, sizeof(gas_price_threshold.message), "disabled");
+    } else {
+        snprintf(gas_price_threshold.message, sizeof(gas_price_threshold.message), "%u gwei",
+                 (unsigned int) gas_price_threshold.gwei);
+    }
+}
+#endif
+
+#if defined(CHAIN_ID_ALLOWLIST)
+// C-chain of mainnet and fuji
+static const uint64_t default_chain_ids[] = {43114, 43113};
+
+typedef struct {
+    uint8_t strict;
+} chain_id_allowlist_persistent_t;
+
+chain_id_allowlist_persistent_t NV_CONST N_chain_id_allowlist_impl __attribute__ ((aligned(64)));
+#define N_chain_id_allowlist (*(NV_VOLATILE chain_id_allowlist_persistent_t *)PIC(&N_chain_id_allowlist_impl))
+
+chain_id_allowlist_t chain_id_allowlist;
+
+void chain_id_allowlist_next() {
+    chain_id_allowlist_persistent_t persistent;
+    persistent.strict = !chain_id_allowlist.strict;
+    MEMCPY_NV( (void*) PIC(&N_chain_id_allowlist_impl), (void*) &persistent, sizeof(chain_id_allowlist_persistent_t));
+
+    chain_id_allowlist.strict = N_chain_id_allowlist.strict;
+}
+
+void h_chain_id_allowlist_update() {
+    snprintf(chain_id_allowlist.message, sizeof(chain_id_allowlist.message), "%s",
+             chain_id_allowlist.strict ? "rejected" : "warned");
+}
+#endif
+
+#if defined(ADDRESS_DENYLIST)
+// Empty until a signed list is provided
+NV_CONST address_denylist_t address_denylist __attribute__ ((aligned(64)));
+
+void address_denylist_store(const address_denylist_t *list) {
+    MEMCPY_NV( (void*) PIC(&address_denylist), (void*) list, sizeof(address_denylist_t));
+}
+#endif
+
+void settings_init() {
+#if defined(GAS_PRICE_THRESHOLD)
+    if (!N_gas_price_threshold.initialized) {
+        gas_price_threshold_store(((const uint64_t *) PIC(gas_price_thresholds))[0]);
+    }
+    gas_price_threshold.gwei = N_gas_price_threshold.gwei;
+    h_gas_price_threshold_update();
+#endif
+#if defined(CHAIN_ID_ALLOWLIST)
+    const uint8_t len = sizeof(default_chain_ids) / sizeof(default_chain_ids[0]);
+    memcpy(chain_id_allowlist.ids, PIC(default_chain_ids), sizeof(default_chain_ids));
+    chain_id_allowlist.len = len;
+    chain_id_allowlist.strict = N_chain_id_allowlist.strict;
+    h_chain_id_allowlist_update();
+#endif
+}
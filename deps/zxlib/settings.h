@@ -0,0 +1,82 @@
+/*******************************************************************************
+*   (c) 2022 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+#pragma once
+#include "zxmacros.h"
+#include "stdbool.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Loads the settings kept in NVM, storing their defaults on first use
+void settings_init();
+
+#if defined(GAS_PRICE_THRESHOLD)
+// Gas price, in gwei, above which eth transactions are warned about,
+// read by the rust side, 0 disables the warning
+typedef struct gas_price_threshold_t {
+    uint64_t gwei;
+    char message[20 + 1];
+} gas_price_threshold_t;
+
+extern gas_price_threshold_t gas_price_threshold;
+
+// Moves the threshold to the next of the available ones
+void gas_price_threshold_next();
+void h_gas_price_threshold_toggle();
+void h_gas_price_threshold_update();
+#endif
+
+#if defined(CHAIN_ID_ALLOWLIST)
+#define MAX_ALLOWED_CHAIN_IDS 8
+
+// Chain ids eth transactions are signed for without a warning,
+// read by the rust side, the others are rejected when strict
+typedef struct chain_id_allowlist_t {
+    uint64_t ids[MAX_ALLOWED_CHAIN_IDS];
+    uint8_t len;
+    bool strict;
+    char message[8 + 1];
+} chain_id_allowlist_t;
+
+extern chain_id_allowlist_t chain_id_allowlist;
+
+// Switches between warning about and rejecting other chain ids
+void chain_id_allowlist_next();
+void h_chain_id_allowlist_toggle();
+void h_chain_id_allowlist_update();
+#endif
+
+#if defined(ADDRESS_DENYLIST)
+#define MAX_DENIED_ADDRESSES 8
+#define DENIED_ADDRESS_LEN 20
+
+// Contract addresses eth transactions are never signed for,
+// kept in NVM and read by the rust side
+typedef struct address_denylist_t {
+    uint8_t addresses[MAX_DENIED_ADDRESSES][DENIED_ADDRESS_LEN];
+    uint8_t len;
+} address_denylist_t;
+
+extern NV_CONST address_denylist_t address_denylist;
+
+// Writes `list` to NVM, replacing the denied addresses
+void address_denylist_store(const address_denylist_t *list);
+#endif
+
+#ifdef __cplusplus
+}
+#endif
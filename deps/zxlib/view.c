@@ -22,6 +22,7 @@
 #include "actions.h"
 #include "app_mode.h"
 #include "bagl.h"
+#include "settings.h"
 #include "ux.h"
 #include "view_templates.h"
 #include "zxerror.h"
@@ -37,11 +38,49 @@
 void io_seproxyhal_display(const bagl_element_t *element) {
   io_seproxyhal_display_default((bagl_element_t *)element);
 }
+
+#if defined(BLIND_SIGN_TOGGLE)
+DEFINE_SETTING_TOGGLE(blind_sign, "insecure", "secure")
+#endif
+
+#if defined(PRE_EIP155_TOGGLE)
+DEFINE_SETTING_TOGGLE(pre_eip155, "allowed", "refused")
+#endif
+
+#if defined(STRICT_OUTPUTS_TOGGLE)
+DEFINE_SETTING_TOGGLE(strict_outputs, "shown", "hidden")
+#endif
+
+#if defined(OUTPUT_ORDER_TOGGLE)
+DEFINE_SETTING_TOGGLE(output_order, "strict", "lenient")
+#endif
+
+#if defined(TRAILING_DATA_TOGGLE)
+DEFINE_SETTING_TOGGLE(trailing_data, "rejected", "skipped")
+#endif
+
 void view_init(void) {
 #if defined(BLIND_SIGN_TOGGLE)
   blind_sign.toggle = false;
   h_blind_sign_update();
 #endif
+#if defined(PRE_EIP155_TOGGLE)
+  pre_eip155.toggle = false;
+  h_pre_eip155_update();
+#endif
+#if defined(STRICT_OUTPUTS_TOGGLE)
+  strict_outputs.toggle = false;
+  h_strict_outputs_update();
+#endif
+#if defined(OUTPUT_ORDER_TOGGLE)
+  output_order.toggle = true;
+  h_output_order_update();
+#endif
+#if defined(TRAILING_DATA_TOGGLE)
+  trailing_data.toggle = false;
+  h_trailing_data_update();
+#endif
+  settings_init();
   view_init_impl(MENU_MAIN_APP_LINE2);
   UX_INIT();
 }
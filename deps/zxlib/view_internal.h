@@ -74,16 +74,45 @@ extern struct NanoSPBackend BACKEND_LAZY;
 
 #endif
 
-#if defined(BLIND_SIGN_TOGGLE)
-typedef struct blind_sign_toggle_t {
+// Setting of the idle menu, read by the rust side
+typedef struct setting_toggle_t {
   bool toggle;
   char message[8 + 1];
-} blind_sign_toggle_t;
+} setting_toggle_t;
+
+// Declares the setting `name` with its handlers, `h_<name>_update`
+// refreshes the message and `h_<name>_toggle` flips the setting
+#define DECLARE_SETTING_TOGGLE(name)                                           \
+  extern setting_toggle_t name;                                                \
+  void h_##name##_toggle();                                                    \
+  void h_##name##_update();
+
+// Defines the setting `name`, showing `enabled` or `disabled`
+#define DEFINE_SETTING_TOGGLE(name, enabled, disabled)                         \
+  setting_toggle_t name;                                                       \
+  void h_##name##_update() {                                                   \
+    snprintf(name.message, sizeof(name.message), "%s",                         \
+             name.toggle ? enabled : disabled);                                \
+  }
 
-extern blind_sign_toggle_t blind_sign;
+#if defined(BLIND_SIGN_TOGGLE)
+DECLARE_SETTING_TOGGLE(blind_sign)
+#endif
+
+#if defined(PRE_EIP155_TOGGLE)
+DECLARE_SETTING_TOGGLE(pre_eip155)
+#endif
+
+#if defined(STRICT_OUTPUTS_TOGGLE)
+DECLARE_SETTING_TOGGLE(strict_outputs)
+#endif
+
+#if defined(OUTPUT_ORDER_TOGGLE)
+DECLARE_SETTING_TOGGLE(output_order)
+#endif
 
-void h_blind_sign_toggle();
-void h_blind_sign_update();
+#if defined(TRAILING_DATA_TOGGLE)
+DECLARE_SETTING_TOGGLE(trailing_data)
 #endif
 ///////////////////////////////////////////////
 ///////////////////////////////////////////////
@@ -17,6 +17,7 @@
 
 #include "app_mode.h"
 #include "bagl.h"
+#include "settings.h"
 #include "ux.h"
 #include "view.h"
 #include "view_internal.h"
@@ -52,6 +53,30 @@ const ux_menu_entry_t menu_main[] = {
 #if defined(BLIND_SIGN_TOGGLE)
     {NULL, h_blind_sign_toggle, 0, &C_icon_app,
      "Signing mode:", blind_sign.message, 33, 12},
+#endif
+#if defined(PRE_EIP155_TOGGLE)
+    {NULL, h_pre_eip155_toggle, 0, &C_icon_app,
+     "Pre-EIP155 txs:", pre_eip155.message, 33, 12},
+#endif
+#if defined(STRICT_OUTPUTS_TOGGLE)
+    {NULL, h_strict_outputs_toggle, 0, &C_icon_app,
+     "Empty outputs:", strict_outputs.message, 33, 12},
+#endif
+#if defined(OUTPUT_ORDER_TOGGLE)
+    {NULL, h_output_order_toggle, 0, &C_icon_app,
+     "Output order:", output_order.message, 33, 12},
+#endif
+#if defined(TRAILING_DATA_TOGGLE)
+    {NULL, h_trailing_data_toggle, 0, &C_icon_app,
+     "Trailing data:", trailing_data.message, 33, 12},
+#endif
+#if defined(GAS_PRICE_THRESHOLD)
+    {NULL, h_gas_price_threshold_toggle, 0, &C_icon_app,
+     "High gas price:", gas_price_threshold.message, 33, 12},
+#endif
+#if defined(CHAIN_ID_ALLOWLIST)
+    {NULL, h_chain_id_allowlist_toggle, 0, &C_icon_app,
+     "Other chains:", chain_id_allowlist.message, 33, 12},
 #endif
     {NULL, NULL, 0, &C_icon_app, APPVERSION_LINE1, APPVERSION_LINE2, 33, 12},
 
@@ -180,21 +205,57 @@ static unsigned int view_message_button(unsigned int button_mask,
   return 0;
 }
 
+// Index of the entry of menu_main handled by `callback`
+static uint8_t menu_main_index(ux_menu_callback_t callback) {
+  uint8_t idx = 0;
+  while (menu_main[idx].line1 != NULL &&
+         menu_main[idx].callback != callback) {
+    idx++;
+  }
+  return idx;
+}
+
+// Flips the setting `name` and shows its entry again
+#define DEFINE_SETTING_TOGGLE_HANDLER(name)                                    \
+  void h_##name##_toggle() {                                                   \
+    name.toggle = !name.toggle;                                                \
+    h_##name##_update();                                                       \
+    view_idle_show(menu_main_index(h_##name##_toggle), NULL);                  \
+  }
+
 #if defined(BLIND_SIGN_TOGGLE)
-blind_sign_toggle_t blind_sign;
+DEFINE_SETTING_TOGGLE_HANDLER(blind_sign)
+#endif
+
+#if defined(PRE_EIP155_TOGGLE)
+DEFINE_SETTING_TOGGLE_HANDLER(pre_eip155)
+#endif
 
-void h_blind_sign_toggle() {
-  blind_sign.toggle = !blind_sign.toggle;
-  h_blind_sign_update();
-  view_idle_show(2, NULL);
+#if defined(STRICT_OUTPUTS_TOGGLE)
+DEFINE_SETTING_TOGGLE_HANDLER(strict_outputs)
+#endif
+
+#if defined(OUTPUT_ORDER_TOGGLE)
+DEFINE_SETTING_TOGGLE_HANDLER(output_order)
+#endif
+
+#if defined(TRAILING_DATA_TOGGLE)
+DEFINE_SETTING_TOGGLE_HANDLER(trailing_data)
+#endif
+
+#if defined(GAS_PRICE_THRESHOLD)
+void h_gas_price_threshold_toggle() {
+  gas_price_threshold_next();
+  h_gas_price_threshold_update();
+  view_idle_show(menu_main_index(h_gas_price_threshold_toggle), NULL);
 }
+#endif
 
-void h_blind_sign_update() {
-  if (blind_sign.toggle) {
-    snprintf(blind_sign.message, 9, "insecure");
-  } else {
-    snprintf(blind_sign.message, 9, "secure");
-  }
+#if defined(CHAIN_ID_ALLOWLIST)
+void h_chain_id_allowlist_toggle() {
+  chain_id_allowlist_next();
+  h_chain_id_allowlist_update();
+  view_idle_show(menu_main_index(h_chain_id_allowlist_toggle), NULL);
 }
 #endif
 
@@ -19,6 +19,7 @@
 #include "app_mode.h"
 #include "bagl.h"
 #include "glyphs.h"
+#include "settings.h"
 #include "view.h"
 #include "view_internal.h"
 #include "view_templates.h"
@@ -56,13 +57,35 @@ UX_STEP_CB_INIT(ux_idle_flow_2_step, bn, rs_h_expert_update(),
                     "Expert mode:",
                     BACKEND_LAZY.message,
                 });
+// Step of the idle flow showing the setting `name`
+#define UX_SETTING_TOGGLE_STEP(name, title)                                    \
+  UX_STEP_CB_INIT(ux_idle_flow_##name##_step, bn, h_##name##_update(),         \
+                  h_##name##_toggle(),                                         \
+                  {                                                            \
+                      title,                                                   \
+                      name.message,                                            \
+                  });
+
 #if defined(BLIND_SIGN_TOGGLE)
-UX_STEP_CB_INIT(ux_idle_flow_blind_toggle_step, bn, h_blind_sign_update(),
-                h_blind_sign_toggle(),
-                {
-                    "Signing mode:",
-                    blind_sign.message,
-                });
+UX_SETTING_TOGGLE_STEP(blind_sign, "Signing mode:")
+#endif
+#if defined(PRE_EIP155_TOGGLE)
+UX_SETTING_TOGGLE_STEP(pre_eip155, "Pre-EIP155 txs:")
+#endif
+#if defined(STRICT_OUTPUTS_TOGGLE)
+UX_SETTING_TOGGLE_STEP(strict_outputs, "Empty outputs:")
+#endif
+#if defined(OUTPUT_ORDER_TOGGLE)
+UX_SETTING_TOGGLE_STEP(output_order, "Output order:")
+#endif
+#if defined(TRAILING_DATA_TOGGLE)
+UX_SETTING_TOGGLE_STEP(trailing_data, "Trailing data:")
+#endif
+#if defined(GAS_PRICE_THRESHOLD)
+UX_SETTING_TOGGLE_STEP(gas_price_threshold, "High gas price:")
+#endif
+#if defined(CHAIN_ID_ALLOWLIST)
+UX_SETTING_TOGGLE_STEP(chain_id_allowlist, "Other chains:")
 #endif
 UX_STEP_NOCB(ux_idle_flow_3_step, bn,
              {
@@ -89,7 +112,25 @@ const ux_flow_step_t *const ux_idle_flow[] = {
     &ux_idle_flow_1_step,
     &ux_idle_flow_2_step,
 #if defined(BLIND_SIGN_TOGGLE)
-    &ux_idle_flow_blind_toggle_step,
+    &ux_idle_flow_blind_sign_step,
+#endif
+#if defined(PRE_EIP155_TOGGLE)
+    &ux_idle_flow_pre_eip155_step,
+#endif
+#if defined(STRICT_OUTPUTS_TOGGLE)
+    &ux_idle_flow_strict_outputs_step,
+#endif
+#if defined(OUTPUT_ORDER_TOGGLE)
+    &ux_idle_flow_output_order_step,
+#endif
+#if defined(TRAILING_DATA_TOGGLE)
+    &ux_idle_flow_trailing_data_step,
+#endif
+#if defined(GAS_PRICE_THRESHOLD)
+    &ux_idle_flow_gas_price_threshold_step,
+#endif
+#if defined(CHAIN_ID_ALLOWLIST)
+    &ux_idle_flow_chain_id_allowlist_step,
 #endif
     &ux_idle_flow_3_step,
     &ux_idle_flow_4_step,
@@ -98,19 +139,44 @@ const ux_flow_step_t *const ux_idle_flow[] = {
     FLOW_END_STEP,
 };
 
+// Flips the setting `name` and shows its step again
+#define DEFINE_SETTING_TOGGLE_HANDLER(name)                                    \
+  void h_##name##_toggle() {                                                   \
+    name.toggle = !name.toggle;                                                \
+    ux_flow_init(0, ux_idle_flow, &ux_idle_flow_##name##_step);                \
+  }
+
 #if defined(BLIND_SIGN_TOGGLE)
-blind_sign_toggle_t blind_sign;
+DEFINE_SETTING_TOGGLE_HANDLER(blind_sign)
+#endif
+
+#if defined(PRE_EIP155_TOGGLE)
+DEFINE_SETTING_TOGGLE_HANDLER(pre_eip155)
+#endif
+
+#if defined(STRICT_OUTPUTS_TOGGLE)
+DEFINE_SETTING_TOGGLE_HANDLER(strict_outputs)
+#endif
+
+#if defined(OUTPUT_ORDER_TOGGLE)
+DEFINE_SETTING_TOGGLE_HANDLER(output_order)
+#endif
 
-void h_blind_sign_toggle() {
-  blind_sign.toggle = !blind_sign.toggle;
-  ux_flow_init(0, ux_idle_flow, &ux_idle_flow_blind_toggle_step);
+#if defined(TRAILING_DATA_TOGGLE)
+DEFINE_SETTING_TOGGLE_HANDLER(trailing_data)
+#endif
+
+#if defined(GAS_PRICE_THRESHOLD)
+void h_gas_price_threshold_toggle() {
+  gas_price_threshold_next();
+  ux_flow_init(0, ux_idle_flow, &ux_idle_flow_gas_price_threshold_step);
 }
+#endif
 
-void h_blind_sign_update() {
-  snprintf(blind_sign.message, 9, "secure");
-  if (blind_sign.toggle) {
-    snprintf(blind_sign.message, 9, "insecure");
-  }
+#if defined(CHAIN_ID_ALLOWLIST)
+void h_chain_id_allowlist_toggle() {
+  chain_id_allowlist_next();
+  ux_flow_init(0, ux_idle_flow, &ux_idle_flow_chain_id_allowlist_step);
 }
 #endif
 
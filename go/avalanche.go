@@ -156,6 +156,13 @@ func (ledger *LedgerAvalanche) Sign(pathPrefix string, signingPaths []string, me
 		return nil, err
 	}
 
+	// the signers follow the root path, their addresses are reviewed
+	signers := ConcatMessageAndChangePath([]byte{}, signingPaths)
+	if signers == nil {
+		return nil, errors.New("invalid signing path")
+	}
+	serializedPath = append(serializedPath, signers...)
+
 	payloadType := PAYLOAD_INIT
 	p2 := FIRST_MESSAGE
 	header := []byte{CLA, INS_SIGN, byte(payloadType), byte(p2), byte(len(serializedPath))}
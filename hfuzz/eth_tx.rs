@@ -0,0 +1,28 @@
+use ledger_app::{get_tx_rlp_len, parse_eth_transaction, DisplayableItem};
+
+fn main() {
+    loop {
+        honggfuzz::fuzz!(|data: &[u8]| {
+            if let Ok((read, _)) = get_tx_rlp_len(data) {
+                // the header can not be longer than the data itself
+                assert!(read <= data.len());
+            }
+
+            if let Ok(tx) = parse_eth_transaction(data) {
+                let mut title = [0; 18];
+                let mut message = [0; 1024];
+
+                // render every page of every item
+                for item_n in 0..tx.num_items() as u8 {
+                    let mut page = 0;
+                    while let Ok(pages) = tx.render_item(item_n, &mut title, &mut message, page) {
+                        page += 1;
+                        if page >= pages {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
@@ -16,6 +16,8 @@ export const CHAIN_ID_SIZE = 8
 export const CONTRACT_ADDRESS_LEN = 20
 export const TYPE_1 = 1
 export const VERSION_1 = 1
+export const NFT_INFO_KEY_ID = 1
+export const NFT_INFO_ALGORITHM_ID = 1
 
 export const INS = {
   GET_VERSION: 0x00,
@@ -30,6 +30,8 @@ import {
   LAST_MESSAGE,
   LedgerError,
   NEXT_MESSAGE,
+  NFT_INFO_ALGORITHM_ID,
+  NFT_INFO_KEY_ID,
   P1_VALUES,
   PAYLOAD_TYPE,
   processErrorResponse,
@@ -45,7 +47,7 @@ import { LedgerEthTransactionResolution, LoadConfig } from '@ledgerhq/hw-app-eth
 export * from './types'
 export { LedgerError }
 
-function processGetAddrResponse(response: Buffer) {
+function processGetAddrResponse(response: Buffer, withChainCode = false) {
   let partialResponse = response
 
   const errorCodeData = partialResponse.slice(-2)
@@ -63,12 +65,20 @@ function processGetAddrResponse(response: Buffer) {
   //"advance" buffer
   partialResponse = partialResponse.slice(20)
 
+  //the chain code is only present if requested
+  let chain_code
+  if (withChainCode) {
+    chain_code = Buffer.from(partialResponse.slice(0, 32))
+    partialResponse = partialResponse.slice(32)
+  }
+
   const address = Buffer.from(partialResponse.subarray(0, -2)).toString()
 
   return {
     publicKey,
     hash,
     address,
+    chain_code,
     returnCode,
     errorMessage: errorCodeToString(returnCode),
   }
@@ -133,11 +143,15 @@ export default class AvalancheApp {
     return chunks
   }
 
-  private async signGetChunks(message: Buffer, path?: string) {
+  private async signGetChunks(message: Buffer, path?: string, signing_paths?: Array<string>) {
     if (path === undefined) {
       return AvalancheApp.prepareChunks(message, Buffer.alloc(0))
-    } else {
+    } else if (signing_paths === undefined) {
       return AvalancheApp.prepareChunks(message, serializePath(path))
+    } else {
+      // the signers follow the root path, their addresses are reviewed
+      const signers = this.concatMessageAndChangePath(Buffer.alloc(0), signing_paths)
+      return AvalancheApp.prepareChunks(message, Buffer.concat([serializePath(path), signers]))
     }
   }
 
@@ -314,7 +328,7 @@ export default class AvalancheApp {
     const msg = this.concatMessageAndChangePath(message, paths)
 
     // Send transaction for review
-    const response = await this.signGetChunks(msg, path_prefix).then(chunks => {
+    const response = await this.signGetChunks(msg, path_prefix, signing_paths).then(chunks => {
       return this.signSendChunk(1, chunks.length, chunks[0], FIRST_MESSAGE, INS.SIGN).then(async response => {
         // initialize response
         let result = {
@@ -448,19 +462,20 @@ export default class AvalancheApp {
     }, processErrorResponse)
   }
 
-  private async _pubkey(path: string, show: boolean, hrp?: string, chainid?: string): Promise<ResponseAddress> {
+  private async _pubkey(path: string, show: boolean, hrp?: string, chainid?: string, chaincode = false): Promise<ResponseAddress> {
     const p1 = show ? P1_VALUES.SHOW_ADDRESS_IN_DEVICE : P1_VALUES.ONLY_RETRIEVE
+    const p2 = chaincode ? 0x01 : 0x00
     const serializedPath = serializePath(path)
     const serializedHrp = serializeHrp(hrp)
     const serializedChainID = serializeChainID(chainid)
 
     return this.transport
-      .send(CLA, INS.GET_ADDR, p1, 0, Buffer.concat([serializedHrp, serializedChainID, serializedPath]), [LedgerError.NoErrors])
-      .then(processGetAddrResponse, processErrorResponse)
+      .send(CLA, INS.GET_ADDR, p1, p2, Buffer.concat([serializedHrp, serializedChainID, serializedPath]), [LedgerError.NoErrors])
+      .then((response: Buffer) => processGetAddrResponse(response, chaincode), processErrorResponse)
   }
 
-  async getAddressAndPubKey(path: string, show: boolean, hrp?: string, chainid?: string) {
-    return this._pubkey(path, show, hrp, chainid)
+  async getAddressAndPubKey(path: string, show: boolean, hrp?: string, chainid?: string, chaincode?: boolean) {
+    return this._pubkey(path, show, hrp, chainid, chaincode)
   }
 
   private async _xpub(path: string, show: boolean, hrp?: string, chainid?: string): Promise<ResponseXPub> {
@@ -541,13 +556,15 @@ export default class AvalancheApp {
   // The implementation aligns with the reference app-ethereum does, but it is provided as
   // an alternative to avoid writing a full NFT service provider to be use in pair with the
   // hw-app-eth package.
-  async provideNftInfo(contract_address: string, token_name: string, chainId: number): Promise<ResponseBase> {
+  // The signature is the DER signature of the NFT information and the key and algorithm ids
+  // by the NFT metadata provider.
+  async provideNftInfo(contract_address: string, token_name: string, chainId: number, signature: Buffer): Promise<ResponseBase> {
     const p2 = 0
     const p1 = 0
 
     let offset = 0
-    // allocate version, type, name_len, name, contract_address and chain_id
-    const buffer = Buffer.alloc(1 + 1 + 1 + CHAIN_ID_SIZE + COLLECTION_NAME_MAX_LEN + CONTRACT_ADDRESS_LEN + CHAIN_ID_SIZE)
+    // allocate version, type, name_len, name, contract_address, chain_id, key_id, algorithm_id, sig_len and signature
+    const buffer = Buffer.alloc(1 + 1 + 1 + COLLECTION_NAME_MAX_LEN + CONTRACT_ADDRESS_LEN + CHAIN_ID_SIZE + 1 + 1 + 1 + signature.length)
 
     // write type and version
     buffer.writeInt8(TYPE_1, offset) // type_1
@@ -576,9 +593,17 @@ export default class AvalancheApp {
 
     // copy chainID
     const id = BigInt(chainId)
-    buffer.writeBigUInt64BE(id, offset)
+    offset = buffer.writeBigUInt64BE(id, offset)
+
+    // append key and algorithm ids, which are signed as well
+    offset = buffer.writeUInt8(NFT_INFO_KEY_ID, offset)
+    offset = buffer.writeUInt8(NFT_INFO_ALGORITHM_ID, offset)
+
+    // followed by the length prefixed signature
+    offset = buffer.writeUInt8(signature.length, offset)
+    offset += signature.copy(buffer, offset)
 
-    return this.transport.send(CLA_ETH, INS.ETH_PROVIDE_NFT_INFO, p1, p2, buffer).then((response: Buffer) => {
+    return this.transport.send(CLA_ETH, INS.ETH_PROVIDE_NFT_INFO, p1, p2, buffer.slice(0, offset)).then((response: Buffer) => {
       const errorCodeData = response.slice(-2)
       const returnCode = errorCodeData[0] * 256 + errorCodeData[1]
       let errorMessage = errorCodeToString(returnCode)
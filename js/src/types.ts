@@ -7,6 +7,7 @@ export interface ResponseAddress extends ResponseBase {
   publicKey: Buffer
   hash: Buffer
   address: string
+  chain_code?: Buffer
 }
 
 export interface ResponseXPub extends ResponseBase {
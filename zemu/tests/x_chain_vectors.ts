@@ -119,8 +119,8 @@ export const X_OPERATION = Buffer.from([
         0x00, 0x00, 0x00, 0x05, // op:
         0x00, 0x00, 0x00, 0x0d, // number of address indices:
         0x00, 0x00, 0x00, 0x02, // address index 0:
-        0x00, 0x00, 0x00, 0x07, // address index 1:
-        0x00, 0x00, 0x00, 0x03, // groupID:
+        0x00, 0x00, 0x00, 0x03, // address index 1:
+        0x00, 0x00, 0x00, 0x07, // groupID:
         0x00, 0x00, 0x30, 0x39, // length of payload:
         0x00, 0x00, 0x00, 48, // payload:
         0xe8, 0xbf, 0x99, 0xe6, 0x98, 0xaf, 0xe4, 0xbb, 0x80, 0xe4, 0xb9, 0x88, 0xe4, 0xb8, 0x8d,